@@ -0,0 +1,42 @@
+//! Measures the lexer on a large generated programme.
+//!
+//! To compare two revisions of the lexer, save a criterion baseline on the
+//! first and measure the second against it on the same machine:
+//!
+//! ```sh
+//! cargo bench --bench lexer -- --save-baseline before
+//! cargo bench --bench lexer -- --baseline before
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use quark::compiler::lexer::Lex;
+use quark::language::utils::SourceMap;
+
+/// The number of gate lines in the generated programme.
+const LINES: usize = 10_000;
+
+/// Generates a large programme resembling machine-emitted gate sequences.
+fn programme() -> String
+{
+	(0..LINES)
+		.map(|line| {
+			format!(
+				"let gate_{line} = rotate(q_{line}, 0.{line} * angle) + [1, 2 | 3, \
+				 4]; // step {line}\n"
+			)
+		})
+		.collect()
+}
+
+fn lex(criterion: &mut Criterion)
+{
+	let mut source = SourceMap::new();
+	let file = source.add("bench.q", programme());
+
+	criterion.bench_function("lex 10k lines", |bencher| {
+		bencher.iter(|| black_box(file).lex(&source).unwrap())
+	});
+}
+
+criterion_group!(benches, lex);
+criterion_main!(benches);
@@ -1,13 +1,21 @@
 use std::ffi::OsStr;
 use std::io::{self, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::{fs, process};
 
 use anyhow::{ensure, Context, Result};
 use clap::Parser;
 
 use super::*;
-use crate::compiler::Compile;
+use crate::compiler::emitter::{emit_file, Stage};
+use crate::compiler::formatter::try_format_file;
+use crate::compiler::repl::{Python, Session};
+#[cfg(feature = "serde")]
+use crate::compiler::serve;
+use crate::compiler::{
+	Document, Format, Level, Levels, Lint, Project, Version,
+};
+use crate::language::utils::SourceMap;
 
 /// The default name of the Quark source file.
 const SOURCE: &str = "source.q";
@@ -27,6 +35,24 @@ const README_CONTENTS: &str = r##"# Quark Project"##;
 /// The default Python interpreter used to run the compiled output.
 const PYTHON: &str = "python3";
 
+/// The levels of the lints, given on the command line.  A lint given several
+/// levels takes the strictest.
+#[derive(clap::Args)]
+pub struct Lints
+{
+	/// Does not report what a lint finds.
+	#[clap(short = 'A', long = "allow", value_name = "LINT")]
+	allow: Vec<Lint>,
+
+	/// Reports what a lint finds as a warning, as every lint does by default.
+	#[clap(short = 'W', long = "warn", value_name = "LINT")]
+	warn: Vec<Lint>,
+
+	/// Reports what a lint finds as an error.
+	#[clap(short = 'D', long = "deny", value_name = "LINT")]
+	deny: Vec<Lint>,
+}
+
 /// The command line arguments for the Quark CLI.
 ///
 /// This enum represents the different commands that the user can run with the
@@ -53,6 +79,34 @@ pub enum Command
 		/// The (relative) path to the output Python file.
 		#[clap(short, long, default_value = TARGET)]
 		output: String,
+
+		/// Prints the output of a compiler stage instead of writing files.
+		#[clap(long, value_enum)]
+		emit: Option<Stage>,
+
+		/// Prints the tokens or syntax tree as versioned JSON, or the Python
+		/// as a JSON string.
+		#[clap(long, requires = "emit")]
+		json: bool,
+
+		/// The version of Python the compiled code must run on.
+		#[clap(long, default_value_t = Version::default())]
+		python: Version,
+
+		#[clap(flatten)]
+		lints: Lints,
+	},
+
+	/// Prints the syntax tree of your Quark file.
+	Parse
+	{
+		/// The (relative) path to the Quark file.
+		#[clap(default_value = SOURCE)]
+		input: String,
+
+		/// Prints the tokens and syntax tree as versioned JSON.
+		#[clap(long)]
+		json: bool,
 	},
 
 	/// Compiles and executes your Quark code.
@@ -61,15 +115,60 @@ pub enum Command
 		/// The (relative) path to the Quark file.
 		#[clap(default_value = SOURCE)]
 		input: String,
+
+		/// The version of Python the compiled code must run on.
+		#[clap(long, default_value_t = Version::default())]
+		python: Version,
+
+		#[clap(flatten)]
+		lints: Lints,
 	},
 
-	/// Checks your current project for errors.
+	/// Checks your current project for errors and warnings.
 	Check
 	{
 		/// The (relative) path to the Quark file.
 		#[clap(default_value = SOURCE)]
 		input: String,
+
+		#[clap(flatten)]
+		lints: Lints,
+	},
+
+	/// Generates documentation from the doc comments of your project.
+	Doc
+	{
+		/// The (relative) path to the Quark file.
+		#[clap(default_value = SOURCE)]
+		input: String,
+
+		/// The format of the documentation.
+		#[clap(short, long, value_enum, default_value_t = Format::Markdown)]
+		format: Format,
+
+		/// The (relative) path to the output file; standard output if omitted.
+		#[clap(short, long)]
+		output: Option<String>,
+	},
+
+	/// Formats your Quark files in the canonical style.
+	Fmt
+	{
+		/// The (relative) paths to the Quark files.
+		#[clap(default_value = SOURCE)]
+		inputs: Vec<String>,
+
+		/// Checks that the files are formatted without changing them.
+		#[clap(long)]
+		check: bool,
 	},
+
+	/// Starts a language server speaking the Language Server Protocol over
+	/// standard input and output.  Needs the `serde` feature.
+	Lsp,
+
+	/// Starts an interactive session that runs Quark code as you type it.
+	Repl,
 }
 
 impl Command
@@ -93,36 +192,81 @@ impl Command
 				fs::write(readme, README_CONTENTS).context(error::CREATE_README)
 			}
 
-			Self::Build { input, output } =>
+			Self::Build {
+				input,
+				output,
+				emit,
+				json,
+				python: version,
+				lints,
+			} =>
 			{
 				let input = Path::new(input);
 				let extension = input.extension().and_then(OsStr::to_str);
 				ensure!(extension == Some("q"), error::SOURCE_EXTENSION);
 
+				if let Some(stage) = emit
+				{
+					let emitted = match stage
+					{
+						// Imports are only resolved when the whole project is loaded.
+						Stage::Py =>
+						{
+							let project = lints.check(Project::load(input)?)?;
+							python(project.synthesise_for(*version), *json)?
+						}
+
+						_ =>
+						{
+							let text =
+								fs::read_to_string(input).context(error::READ_SOURCE)?;
+
+							let mut source = SourceMap::new();
+							let file = source.add(input, text);
+							emit_file(file, &source, *stage, *json)?
+						}
+					};
+
+					return writeln!(io::stdout(), "{emitted}").context(error::OUTPUT);
+				}
+
 				let output = Path::new(output);
 				let extension = output.extension().and_then(OsStr::to_str);
 				ensure!(extension == Some("py"), error::TARGET_EXTENSION);
 
-				let target = fs::read_to_string(input)
-					.context(error::READ_SOURCE)?
-					.compile()?;
+				let project = lints.check(Project::load(input)?)?;
+				write(project.synthesise_for(*version), output)
+			}
+
+			Self::Parse { input, json } =>
+			{
+				let input = Path::new(input);
+				let extension = input.extension().and_then(OsStr::to_str);
+				ensure!(extension == Some("q"), error::SOURCE_EXTENSION);
+
+				let text = fs::read_to_string(input).context(error::READ_SOURCE)?;
 
-				fs::write(output, target).context(error::CREATE_TARGET)
+				let mut source = SourceMap::new();
+				let file = source.add(input, text);
+
+				let parsed = emit_file(file, &source, Stage::Ast, *json)?;
+				writeln!(io::stdout(), "{parsed}").context(error::OUTPUT)
 			}
 
-			Self::Run { input } =>
+			Self::Run {
+				input,
+				python: version,
+				lints,
+			} =>
 			{
 				let input = Path::new(input);
 				let extension = input.extension().map(OsStr::to_str);
 				ensure!(extension == Some(Some("q")), error::SOURCE_EXTENSION);
 
-				let target = fs::read_to_string(input)
-					.context(error::READ_SOURCE)?
-					.compile()?;
-
 				let file = Path::new(TARGET);
 
-				fs::write(file, target).context(error::CREATE_TARGET)?;
+				let project = lints.check(Project::load(input)?)?;
+				write(project.synthesise_for(*version), file)?;
 
 				let output = process::Command::new(PYTHON)
 					.arg(file)
@@ -133,18 +277,221 @@ impl Command
 				io::stdout().write_all(&output).context(error::OUTPUT)
 			}
 
-			Self::Check { input } =>
+			Self::Check { input, lints } =>
 			{
 				let input = Path::new(input);
 				let extension = input.extension().map(OsStr::to_str);
 				ensure!(extension == Some(Some("q")), error::SOURCE_EXTENSION);
 
-				fs::read_to_string(input)
-					.context(error::READ_SOURCE)?
-					.compile()?;
+				lints.check(Project::load(input)?)?;
+
+				eprintln!("No errors found.");
+				Ok(())
+			}
+
+			Self::Doc {
+				input,
+				format,
+				output,
+			} =>
+			{
+				let input = Path::new(input);
+				let extension = input.extension().map(OsStr::to_str);
+				ensure!(extension == Some(Some("q")), error::SOURCE_EXTENSION);
+
+				let documentation = Project::load(input)?.document(*format);
+
+				match output
+				{
+					Some(output) =>
+					{
+						fs::write(output, documentation).context(error::CREATE_DOCS)
+					}
+					None => io::stdout()
+						.write_all(documentation.as_bytes())
+						.context(error::OUTPUT),
+				}
+			}
+
+			Self::Fmt { inputs, check } =>
+			{
+				let mut unformatted = 0;
+
+				for input in inputs
+				{
+					let input = Path::new(input);
+					let extension = input.extension().map(OsStr::to_str);
+					ensure!(extension == Some(Some("q")), error::SOURCE_EXTENSION);
+
+					let text = fs::read_to_string(input).context(error::READ_SOURCE)?;
+
+					let mut source = SourceMap::new();
+					let file = source.add(input, text.clone());
+					let formatted = try_format_file(file, &source)?;
 
-				Ok(eprintln!("No errors found."))
+					if formatted == text
+					{
+						continue;
+					}
+
+					if *check
+					{
+						eprintln!("Would reformat {}", input.display());
+						unformatted += 1;
+					}
+					else
+					{
+						fs::write(input, formatted).context(error::CREATE_SOURCE)?;
+					}
+				}
+
+				ensure!(unformatted == 0, error::UNFORMATTED);
+				Ok(())
+			}
+
+			#[cfg(feature = "serde")]
+			Self::Lsp => serve(io::stdin().lock(), io::stdout().lock()),
+
+			#[cfg(not(feature = "serde"))]
+			Self::Lsp => anyhow::bail!(error::SERDE),
+
+			Self::Repl =>
+			{
+				let mut session = Session::new(Python::new(PYTHON)?)?;
+				session.run(io::stdin().lock(), io::stdout().lock())
 			}
 		}
 	}
 }
+
+impl Lints
+{
+	/// Lints a project, printing what the lints that warn find.
+	///
+	/// ### Parameters
+	/// * `project` - The project.
+	///
+	/// ### Returns
+	/// * The project.
+	///
+	/// ### Errors
+	/// * If a lint that is denied finds something.
+	fn check(&self, project: Project) -> Result<Project>
+	{
+		let mut levels = Levels::default();
+
+		for (lints, level) in [
+			(&self.allow, Level::Allow),
+			(&self.warn, Level::Warn),
+			(&self.deny, Level::Deny),
+		]
+		{
+			for lint in lints
+			{
+				levels.set(*lint, level);
+			}
+		}
+
+		for warning in project.lint(&levels)?
+		{
+			eprintln!("Warning: {warning}");
+		}
+
+		Ok(project)
+	}
+}
+
+/// Prints the Python modules of a compiled project.
+///
+/// ### Parameters
+/// * `modules` - The compiled modules, with the entry module last.
+/// * `json` - Whether to print a JSON object mapping each path to its code.
+///
+/// ### Returns
+/// * The code of the entry module alone, or of every module under a comment
+///   naming its path if the project has several.
+///
+/// ### Errors
+/// * If JSON is asked of a build without the `serde` feature.
+fn python(modules: Vec<(PathBuf, String)>, json: bool) -> Result<String>
+{
+	if json
+	{
+		return python_json(modules);
+	}
+
+	Ok(match modules.as_slice()
+	{
+		[(_, code)] => code.clone(),
+		_ => modules
+			.iter()
+			.map(|(path, code)| format!("# {}\n{code}", path.display()))
+			.collect::<Vec<_>>()
+			.join("\n\n"),
+	})
+}
+
+/// Prints the Python modules of a compiled project as a JSON object mapping
+/// each path to its code.
+///
+/// ### Parameters
+/// * `modules` - The compiled modules.
+///
+/// ### Returns
+/// * The JSON object.
+#[cfg(feature = "serde")]
+fn python_json(modules: Vec<(PathBuf, String)>) -> Result<String>
+{
+	let modules: serde_json::Map<_, _> = modules
+		.into_iter()
+		.map(|(path, code)| (path.display().to_string(), code.into()))
+		.collect();
+
+	Ok(serde_json::Value::from(modules).to_string())
+}
+
+/// Reports that JSON output needs the `serde` feature.
+///
+/// ### Errors
+/// * Always, as Quark was built without the `serde` feature.
+#[cfg(not(feature = "serde"))]
+fn python_json(_: Vec<(PathBuf, String)>) -> Result<String>
+{
+	anyhow::bail!(error::SERDE)
+}
+
+/// Writes the Python modules of a compiled project to disk.
+///
+/// The entry module is written to `output`; every imported module is written
+/// next to it, at the same path relative to `output` as its Quark source has
+/// relative to the entry file.
+///
+/// ### Parameters
+/// * `modules` - The compiled modules, with the entry module last.
+/// * `output` - The path of the entry Python file.
+///
+/// ### Errors
+/// * If any of the files cannot be written.
+fn write(mut modules: Vec<(PathBuf, String)>, output: &Path) -> Result<()>
+{
+	let directory = output.parent().unwrap_or(Path::new(""));
+
+	if let Some((_, target)) = modules.pop()
+	{
+		fs::write(output, target).context(error::CREATE_TARGET)?;
+	}
+
+	for (path, target) in modules
+	{
+		let path = directory.join(path);
+
+		if let Some(parent) = path.parent()
+		{
+			fs::create_dir_all(parent).context(error::CREATE_TARGET)?;
+		}
+
+		fs::write(path, target).context(error::CREATE_TARGET)?;
+	}
+
+	Ok(())
+}
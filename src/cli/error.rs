@@ -10,17 +10,29 @@ pub const CREATE_DIRECTORY: &str = "Failed to create project directory 📁";
 /// Error message when the Quark source file cannot be created.
 pub const CREATE_SOURCE: &str = "Failed to create Quark source file 📄";
 
+/// Error message when a Quark source file cannot be read.
+pub const READ_SOURCE: &str = "Failed to read Quark source file 📄";
+
+/// Error message when some files are not formatted.
+pub const UNFORMATTED: &str =
+	"Some files are not formatted; run `quark fmt` to format them";
+
 /// Error message when the README file cannot be created.
 pub const CREATE_README: &str = "Failed to create README file 📄";
 
 /// Error message when the output file cannot be created.
 pub const CREATE_TARGET: &str = "Failed to create target file 📄";
 
-/// Error message when the input file cannot be read.
-pub const READ_SOURCE: &str = "Failed to read the source file 📄";
+/// Error message when the documentation file cannot be created.
+pub const CREATE_DOCS: &str = "Failed to create documentation file 📄";
 
 /// Error message when the target Python file cannot be run.
 pub const RUN_TARGET: &str = "Failed to run the target Python file 🐍";
 
 /// Error message when the output cannot be written to the console.
 pub const OUTPUT: &str = "Failed to write to the output to standard output 📝";
+
+/// Error message when JSON or the language server is asked of a build without
+/// `serde`.
+#[cfg(not(feature = "serde"))]
+pub const SERDE: &str = "Quark was built without the `serde` feature 📦";
@@ -5,6 +5,11 @@ use lexer::Lex;
 use parser::Parse;
 use synthesiser::Synthesis;
 
+use crate::language::utils::SourceMap;
+
+/// The name given to source code that is not read from a file.
+pub(crate) const SOURCE: &str = "<source>";
+
 /// Types that can be compiled.
 ///
 /// This trait is used to compile Quark code into Python code.
@@ -24,12 +29,10 @@ impl Compile for String
 {
 	fn compile(self) -> Result<String>
 	{
-		let source: Vec<Vec<_>> = self
-			.lines()
-			.map(|line| format!("{line}\n").chars().collect())
-			.collect();
+		let mut source = SourceMap::new();
+		let file = source.add(SOURCE, self);
 
-		let target = self
+		let target = file
 			.lex(&source)?
 			.parse(&source)?
 			.analyse(&source)?
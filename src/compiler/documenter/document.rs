@@ -0,0 +1,198 @@
+use std::path::Path;
+
+use crate::compiler::Project;
+use crate::language::grammar::declaration::{self, Declaration};
+use crate::language::grammar::statement::Kind;
+use crate::language::grammar::Programme;
+
+/// The formats documentation can be rendered in.
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum Format
+{
+	/// A Markdown document.
+	Markdown,
+
+	/// A standalone HTML page.
+	Html,
+}
+
+/// Types that can be documented.
+///
+/// This trait is used to render the documentation comments of Quark code into
+/// a human-readable reference.
+pub trait Document
+{
+	/// Renders the documentation of this structure.
+	///
+	/// ### Parameters
+	/// * `format` - The format to render the documentation in.
+	///
+	/// ### Returns
+	/// * The rendered documentation.
+	fn document(&self, format: Format) -> String;
+}
+
+/// A documented item of a module.
+struct Item
+{
+	/// The signature of the item, as it would be written in Quark.
+	signature: String,
+
+	/// The paragraphs of the item's documentation.
+	paragraphs: Vec<String>,
+}
+
+impl Document for Project
+{
+	fn document(&self, format: Format) -> String
+	{
+		let modules: Vec<_> = self
+			.modules()
+			.map(|(path, programme)| (path, programme.items()))
+			.filter(|(_, items)| !items.is_empty())
+			.collect();
+
+		match format
+		{
+			Format::Markdown => markdown(&modules),
+			Format::Html => html(&modules),
+		}
+	}
+}
+
+impl Programme
+{
+	/// Collects the documented top-level functions, types and constants.
+	fn items(&self) -> Vec<Item>
+	{
+		self
+			.statements
+			.iter()
+			.filter_map(|statement| match &statement.kind
+			{
+				Kind::Declaration(declaration) => declaration.item(),
+				_ => None,
+			})
+			.collect()
+	}
+}
+
+impl Declaration
+{
+	/// Creates the documented item of the declaration.
+	///
+	/// ### Returns
+	/// * The item if the declaration is a documented function, struct, enum or
+	///   constant.
+	fn item(&self) -> Option<Item>
+	{
+		if self.docs.is_empty()
+		{
+			return None;
+		}
+
+		let signature = match &self.kind
+		{
+			declaration::Kind::Function(function) => function.signature(),
+			declaration::Kind::Struct(r#struct) => r#struct.signature(),
+			declaration::Kind::Enum(r#enum) => r#enum.signature(),
+
+			declaration::Kind::Variable {
+				name,
+				is_mutable: false,
+				..
+			} => format!("let {name}"),
+
+			declaration::Kind::Destructuring {
+				names,
+				is_mutable: false,
+				..
+			} => format!("let ({})", names.join(", ")),
+
+			declaration::Kind::Variable { .. }
+			| declaration::Kind::Destructuring { .. } => return None,
+		};
+
+		let paragraphs = self
+			.docs
+			.split(|line| line.is_empty())
+			.filter(|lines| !lines.is_empty())
+			.map(|lines| lines.join(" "))
+			.collect();
+
+		Some(Item {
+			signature,
+			paragraphs,
+		})
+	}
+}
+
+/// Renders documented modules as Markdown.
+fn markdown(modules: &[(&Path, Vec<Item>)]) -> String
+{
+	let mut output = String::new();
+
+	for (path, items) in modules
+	{
+		output.push_str(&format!("# `{}`\n", path.display()));
+
+		for Item {
+			signature,
+			paragraphs,
+		} in items
+		{
+			output.push_str(&format!("\n## `{signature}`\n"));
+
+			for paragraph in paragraphs
+			{
+				output.push_str(&format!("\n{paragraph}\n"));
+			}
+		}
+
+		output.push('\n');
+	}
+
+	output.trim_end().to_string() + "\n"
+}
+
+/// Renders documented modules as a standalone HTML page.
+fn html(modules: &[(&Path, Vec<Item>)]) -> String
+{
+	let mut output = String::from(
+		"<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Quark \
+		 Documentation</title>\n</head>\n<body>\n",
+	);
+
+	for (path, items) in modules
+	{
+		let path = escape(&path.display().to_string());
+		output.push_str(&format!("<h1><code>{path}</code></h1>\n"));
+
+		for Item {
+			signature,
+			paragraphs,
+		} in items
+		{
+			let signature = escape(signature);
+			output.push_str(&format!("<h2><code>{signature}</code></h2>\n"));
+
+			for paragraph in paragraphs
+			{
+				output.push_str(&format!("<p>{}</p>\n", escape(paragraph)));
+			}
+		}
+	}
+
+	output.push_str("</body>\n</html>\n");
+	output
+}
+
+/// Escapes text for use in HTML.
+fn escape(text: &str) -> String
+{
+	text
+		.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+		.replace('"', "&quot;")
+}
@@ -0,0 +1,742 @@
+use std::collections::HashMap;
+
+use crate::compiler::semanter::table::{Binder, Binding, Table};
+use crate::language::grammar::declaration::{self, Declaration};
+use crate::language::grammar::expression::{self, Expression, Items, Part};
+use crate::language::grammar::functions::{Body, Parameter, Params};
+use crate::language::grammar::statement::{self, Statement};
+use crate::language::grammar::subscript::{Index, Subscript};
+use crate::language::grammar::{Arm, Block, Programme};
+use crate::language::lexicon::token::{self, Segment};
+use crate::language::lexicon::Token;
+use crate::language::semantics::r#type::Type;
+use crate::language::utils::{SourceFile, Span};
+
+/// A node of a dumped compiler structure, ready to be printed as an indented
+/// tree.
+pub struct Dump
+{
+	/// The kind of the node, such as `Infix` or `Identifier`.
+	kind: String,
+
+	/// The details of the node, such as its operator or name.
+	detail: Option<String>,
+
+	/// The span of the node.
+	span: Span,
+
+	/// The type of the node, if it is a typed expression.
+	r#type: Option<Type>,
+
+	/// The children of the node.
+	children: Vec<Dump>,
+}
+
+impl Dump
+{
+	/// Creates a node.
+	///
+	/// ### Parameters
+	/// * `kind` - The kind of the node.
+	/// * `detail` - The details of the node.
+	/// * `span` - The span of the node.
+	/// * `children` - The children of the node.
+	///
+	/// ### Returns
+	/// * The node, without a type.
+	fn new(
+		kind: &str,
+		detail: Option<String>,
+		span: Span,
+		children: Vec<Dump>,
+	) -> Self
+	{
+		Self {
+			kind: kind.to_string(),
+			detail,
+			span,
+			r#type: None,
+			children,
+		}
+	}
+
+	/// Dumps lexical tokens.
+	///
+	/// ### Parameters
+	/// * `tokens` - The tokens.
+	///
+	/// ### Returns
+	/// * A node holding a child for each token.
+	pub fn tokens(tokens: &[Token]) -> Self
+	{
+		let span = whole_span(tokens, Span::default());
+		Self::new("Tokens", None, span, dump_tokens(tokens))
+	}
+
+	/// Dumps the abstract syntax tree of a programme.
+	///
+	/// ### Parameters
+	/// * `programme` - The programme.
+	/// * `file` - The source file of the programme.
+	/// * `is_typed` - Whether to annotate expressions with their types.
+	///
+	/// ### Returns
+	/// * The root node of the tree.
+	pub fn programme(
+		programme: &Programme,
+		file: &SourceFile,
+		is_typed: bool,
+	) -> Self
+	{
+		let mut builder = Builder {
+			file,
+			table: is_typed.then(Table::new),
+		};
+
+		let children = programme
+			.statements
+			.iter()
+			.map(|statement| builder.statement(statement))
+			.collect();
+
+		Self::new("Programme", None, programme.span, children)
+	}
+
+	/// Prints the node as an indented tree.
+	///
+	/// ### Parameters
+	/// * `file` - The source file the node's spans point into.
+	///
+	/// ### Returns
+	/// * One line per node, with children indented below their parent.
+	pub fn text(&self, file: &SourceFile) -> String
+	{
+		let mut output = String::new();
+		self.write(file, 0, &mut output);
+		output.trim_end().to_string()
+	}
+
+	/// Writes the node and its children as indented lines.
+	fn write(&self, file: &SourceFile, depth: usize, output: &mut String)
+	{
+		output.push_str(&"  ".repeat(depth));
+		output.push_str(&self.kind);
+
+		if let Some(detail) = &self.detail
+		{
+			output.push_str(&format!(" {detail}"));
+		}
+
+		if let Some(r#type) = &self.r#type
+		{
+			output.push_str(&format!(": {type}"));
+		}
+
+		let start = file.location(self.span.start.offset);
+		let end = file.location(self.span.end.offset);
+		output.push_str(&format!(" {start:?}--{end:?}\n"));
+
+		for child in &self.children
+		{
+			child.write(file, depth + 1, output);
+		}
+	}
+}
+
+/// A walker that dumps the abstract syntax tree of a programme, tracking the
+/// symbols in scope so that it can annotate expressions with their types.
+struct Builder<'a>
+{
+	/// The source file of the programme.
+	file: &'a SourceFile,
+
+	/// The symbols in scope, or `None` if types are not wanted.
+	table: Option<Table>,
+}
+
+impl Builder<'_>
+{
+	/// Dumps a statement, then declares the symbols it introduces.
+	fn statement(&mut self, statement: &Statement) -> Dump
+	{
+		use statement::Kind::*;
+
+		let span = statement.span;
+
+		let dump = match &statement.kind
+		{
+			Declaration(declaration) => self.declaration(declaration),
+
+			Expression(expression) =>
+			{
+				let children = vec![self.expression(expression)];
+				Dump::new("Expression", None, span, children)
+			}
+
+			If(if_) =>
+			{
+				let mut children = vec![
+					self.expression(&if_.condition),
+					self.block("Then", &if_.body),
+				];
+				children.extend(if_.else_ifs.iter().map(|else_if| {
+					let children = vec![
+						self.expression(&else_if.condition),
+						self.block("Then", &else_if.body),
+					];
+					Dump::new("ElseIf", None, else_if.span, children)
+				}));
+				children
+					.extend(if_.else_body.iter().map(|body| self.block("Else", body)));
+				Dump::new("If", None, span, children)
+			}
+
+			While(while_) =>
+			{
+				let children = vec![
+					self.expression(&while_.condition),
+					self.block("Body", &while_.body),
+				];
+				Dump::new("While", None, span, children)
+			}
+
+			Match(match_) =>
+			{
+				let mut children = vec![self.expression(&match_.scrutinee)];
+				children.extend(match_.arms.iter().map(|arm| self.arm(arm)));
+				Dump::new("Match", None, span, children)
+			}
+
+			Break(_) => Dump::new("Break", None, span, Vec::new()),
+			Continue(_) => Dump::new("Continue", None, span, Vec::new()),
+
+			Return(return_) =>
+			{
+				let children = vec![self.expression(&return_.expression)];
+				Dump::new("Return", None, span, children)
+			}
+
+			Assignment(assignment) =>
+			{
+				let name = Some(assignment.identifier.clone());
+				let mut children: Vec<_> = assignment
+					.subscript
+					.iter()
+					.map(|subscript| self.subscript(subscript))
+					.collect();
+				children.push(self.expression(&assignment.expression));
+				Dump::new("Assignment", name, span, children)
+			}
+
+			Echo(echo) =>
+			{
+				let children = self.expressions(&echo.arguments);
+				Dump::new("Echo", None, span, children)
+			}
+
+			Import(import) =>
+			{
+				let path = Some(format!("{:?}", import.path));
+				Dump::new("Import", path, span, Vec::new())
+			}
+
+			Use(use_) =>
+			{
+				let path = Some(format!("{}::{}", use_.module, use_.name));
+				Dump::new("Use", path, span, Vec::new())
+			}
+
+			FunctionCall(call) =>
+			{
+				let name = Some(call.name.clone());
+				let children = self.items(call.arguments.iter());
+				Dump::new("Call", name, span, children)
+			}
+		};
+
+		if let Some(table) = &mut self.table
+		{
+			let _ = statement.analyse(table);
+		}
+
+		dump
+	}
+
+	/// Dumps a declaration.
+	fn declaration(&mut self, declaration: &Declaration) -> Dump
+	{
+		let span = declaration.span;
+
+		match &declaration.kind
+		{
+			declaration::Kind::Variable {
+				name,
+				value,
+				is_mutable,
+			} =>
+			{
+				let kind = match is_mutable
+				{
+					true => "Variable",
+					false => "Constant",
+				};
+
+				let children = vec![self.expression(value)];
+				Dump::new(kind, Some(name.clone()), span, children)
+			}
+
+			declaration::Kind::Destructuring {
+				names,
+				value,
+				is_mutable,
+			} =>
+			{
+				let kind = match is_mutable
+				{
+					true => "Variable",
+					false => "Constant",
+				};
+
+				let names = format!("({})", names.join(", "));
+				let children = vec![self.expression(value)];
+				Dump::new(kind, Some(names), span, children)
+			}
+
+			declaration::Kind::Function(function) =>
+			{
+				// The types of the parameters are inferred from the body, and
+				// are in scope within it.
+				let outer = self.table.clone();
+				let mut inferred = HashMap::new();
+
+				if let Some(table) = &mut self.table
+				{
+					let mut scratch = table.clone();
+					let _ = function.infer(&mut scratch);
+					inferred = scratch.inferred;
+
+					for parameter in parameters_of(&function.parameters)
+					{
+						let r#type = inferred.get(&parameter.span).cloned();
+						let binding = Binding {
+							r#type: r#type.unwrap_or(parameter.r#type.clone()),
+							binder: Binder::Parameter,
+							span: parameter.span,
+						};
+						table.bindings.insert(parameter.name.clone(), binding);
+					}
+				}
+
+				let mut children = parameters(&function.parameters, &inferred);
+				children.push(self.block("Body", &function.body));
+				self.table = outer;
+
+				let signature = match inferred.get(&function.span)
+				{
+					Some(r#type) => function.signature_of(r#type),
+					None => function.signature(),
+				};
+
+				Dump::new("Function", Some(signature), span, children)
+			}
+
+			declaration::Kind::Struct(r#struct) =>
+			{
+				let children = r#struct
+					.fields
+					.iter()
+					.map(|field| {
+						let text = Some(format!("{}: {}", field.name, field.r#type));
+						Dump::new("Field", text, field.span, Vec::new())
+					})
+					.collect();
+
+				Dump::new("Struct", Some(r#struct.name.clone()), span, children)
+			}
+
+			declaration::Kind::Enum(r#enum) =>
+			{
+				let children = r#enum
+					.variants
+					.iter()
+					.map(|variant| {
+						let text = Some(variant.signature());
+						Dump::new("Variant", text, variant.span, Vec::new())
+					})
+					.collect();
+
+				Dump::new("Enum", Some(r#enum.name.clone()), span, children)
+			}
+		}
+	}
+
+	/// Dumps an arm of a match statement.
+	fn arm(&mut self, arm: &Arm) -> Dump
+	{
+		let mut children: Vec<_> = arm
+			.patterns
+			.iter()
+			.map(|pattern| {
+				let text = Some(pattern.to_string());
+				Dump::new("Pattern", text, pattern.span, Vec::new())
+			})
+			.collect();
+
+		if let Some(guard) = &arm.guard
+		{
+			children.push(self.expression(guard));
+		}
+
+		children.push(self.block("Then", &arm.body));
+		Dump::new("Arm", None, arm.span, children)
+	}
+
+	/// Dumps a block, whose declarations go out of scope at its end.
+	fn block(&mut self, kind: &str, block: &Block) -> Dump
+	{
+		let outer = self.table.clone();
+
+		let children = block
+			.statements
+			.iter()
+			.flatten()
+			.map(|statement| self.statement(statement))
+			.collect();
+
+		self.table = outer;
+
+		Dump::new(kind, None, block.span, children)
+	}
+
+	/// Dumps an expression.
+	fn expression(&mut self, expression: &Expression) -> Dump
+	{
+		use expression::Kind::*;
+
+		let span = expression.span;
+
+		let mut dump = match &expression.kind
+		{
+			Identifier(token) => match &token.kind
+			{
+				token::Kind::Identifier(name) =>
+				{
+					Dump::new("Identifier", Some(name.clone()), span, Vec::new())
+				}
+				_ => unreachable!(),
+			},
+
+			Literal(token) =>
+			{
+				Dump::new("Literal", Some(self.text(token.span)), span, Vec::new())
+			}
+
+			Parenthesised(inner) =>
+			{
+				let children = vec![self.expression(inner)];
+				Dump::new("Parenthesised", None, span, children)
+			}
+
+			Tuple(items) =>
+			{
+				let children = self.expressions(&items.expressions);
+				Dump::new("Tuple", None, span, children)
+			}
+
+			List(items) =>
+			{
+				let children = self.items(items.iter().flatten());
+				Dump::new("List", None, span, children)
+			}
+
+			Matrix(rows) =>
+			{
+				let children = rows
+					.iter()
+					.map(|row| {
+						let row_span = row.as_ref().map_or(span, |items| items.span);
+						let children = self.items(row.iter());
+						Dump::new("Row", None, row_span, children)
+					})
+					.collect();
+				Dump::new("Matrix", None, span, children)
+			}
+
+			Prefix { operator, operand } =>
+			{
+				let operator = Some(self.text(operator.span));
+				let children = vec![self.expression(operand)];
+				Dump::new("Prefix", operator, span, children)
+			}
+
+			Infix {
+				left,
+				operator,
+				right,
+			} =>
+			{
+				let operator = Some(self.text(operator.span));
+				let children = vec![self.expression(left), self.expression(right)];
+				Dump::new("Infix", operator, span, children)
+			}
+
+			FunctionCall(call) =>
+			{
+				let name = Some(call.name.clone());
+				let children = self.items(call.arguments.iter());
+				Dump::new("Call", name, span, children)
+			}
+
+			Index { target, subscript } =>
+			{
+				let children = vec![self.expression(target), self.subscript(subscript)];
+				Dump::new("Index", None, span, children)
+			}
+
+			Member { target, name } =>
+			{
+				let name = Some(self.text(name.span));
+				let children = vec![self.expression(target)];
+				Dump::new("Member", name, span, children)
+			}
+
+			MethodCall {
+				target,
+				name,
+				arguments,
+			} =>
+			{
+				let name = Some(self.text(name.span));
+				let mut children = vec![self.expression(target)];
+				children.extend(self.items(arguments.iter()));
+				Dump::new("MethodCall", name, span, children)
+			}
+
+			Lambda(lambda) =>
+			{
+				let mut children = parameters(&lambda.parameters, &HashMap::new());
+				children.push(match &lambda.body
+				{
+					Body::Expression(body) => self.expression(body),
+					Body::Block(body) => self.block("Body", body),
+				});
+				Dump::new("Lambda", None, span, children)
+			}
+
+			Variant {
+				r#enum,
+				name,
+				arguments,
+			} =>
+			{
+				let name = Some(format!(
+					"{}::{}",
+					self.text(r#enum.span),
+					self.text(name.span)
+				));
+				let children = self.items(arguments.iter());
+				Dump::new("Variant", name, span, children)
+			}
+
+			Interpolation(parts) =>
+			{
+				let children = parts
+					.iter()
+					.map(|part| match part
+					{
+						Part::Text(text) =>
+						{
+							Dump::new("Text", Some(format!("{text:?}")), span, Vec::new())
+						}
+						Part::Expression(expression) => self.expression(expression),
+					})
+					.collect();
+				Dump::new("Interpolation", None, span, children)
+			}
+		};
+
+		dump.r#type = self
+			.table
+			.as_ref()
+			.and_then(|table| expression.r#type(table).ok());
+
+		dump
+	}
+
+	/// Dumps the subscript of an index expression or assignment.
+	fn subscript(&mut self, subscript: &Subscript) -> Dump
+	{
+		let children = subscript
+			.indices
+			.iter()
+			.map(|index| match index
+			{
+				Index::Position(position) => self.expression(position),
+				Index::Slice {
+					span,
+					start,
+					end,
+					is_inclusive,
+				} =>
+				{
+					let operator = match (start, end, is_inclusive)
+					{
+						(None, None, false) => self.text(*span),
+						(_, _, false) => String::from(".."),
+						(_, _, true) => String::from("..="),
+					};
+
+					let children = self.expressions(start.iter().chain(end));
+					Dump::new("Slice", Some(operator), *span, children)
+				}
+			})
+			.collect();
+
+		Dump::new("Subscript", None, subscript.span, children)
+	}
+
+	/// Dumps some expressions.
+	fn expressions<'e>(
+		&mut self,
+		expressions: impl IntoIterator<Item = &'e Expression>,
+	) -> Vec<Dump>
+	{
+		expressions
+			.into_iter()
+			.map(|expression| self.expression(expression))
+			.collect()
+	}
+
+	/// Dumps the expressions of some lists of items.
+	fn items<'i>(&mut self, items: impl Iterator<Item = &'i Items>) -> Vec<Dump>
+	{
+		items
+			.flat_map(|items| self.expressions(&items.expressions))
+			.collect()
+	}
+
+	/// Gets the source text of a span.
+	fn text(&self, span: Span) -> String
+	{
+		self.file.text[span.start.offset..=span.end.offset].to_string()
+	}
+}
+
+/// Dumps the parameters of a function or lambda.
+///
+/// ### Parameters
+/// * `parameters` - The parameters.
+/// * `inferred` - The types inferred for parameters, by span.
+///
+/// ### Returns
+/// * A node for each parameter.
+fn parameters(
+	parameters: &Option<Params>,
+	inferred: &HashMap<Span, Type>,
+) -> Vec<Dump>
+{
+	parameters_of(parameters)
+		.map(|parameter| {
+			let name = Some(match inferred.get(&parameter.span)
+			{
+				Some(r#type) => parameter.signature_of(r#type),
+				None => parameter.signature(),
+			});
+			Dump::new("Parameter", name, parameter.span, Vec::new())
+		})
+		.collect()
+}
+
+/// Gets the parameters of a function or lambda.
+///
+/// ### Parameters
+/// * `parameters` - The parameters, if there are any.
+///
+/// ### Returns
+/// * Each parameter, in order.
+fn parameters_of(
+	parameters: &Option<Params>,
+) -> impl Iterator<Item = &Parameter>
+{
+	parameters
+		.iter()
+		.flat_map(|parameters| &parameters.parameters)
+}
+
+/// Dumps lexical tokens, including the tokens embedded in interpolations.
+///
+/// ### Parameters
+/// * `tokens` - The tokens.
+///
+/// ### Returns
+/// * A node for each token.
+fn dump_tokens(tokens: &[Token]) -> Vec<Dump>
+{
+	tokens
+		.iter()
+		.map(|token| {
+			// The derived debug output of a kind is its name, followed by its
+			// value in parentheses if it has one.
+			let debug = format!("{:?}", token.kind);
+			let (kind, detail) = match debug.split_once('(')
+			{
+				Some((kind, value)) =>
+				{
+					(kind, Some(value[..value.len() - 1].to_string()))
+				}
+				None => (debug.as_str(), None),
+			};
+
+			let children = match &token.kind
+			{
+				token::Kind::Interpolation(segments) => segments
+					.iter()
+					.map(|segment| match segment
+					{
+						Segment::Text(text) => Dump::new(
+							"Text",
+							Some(format!("{text:?}")),
+							token.span,
+							Vec::new(),
+						),
+						Segment::Code(tokens) => Dump::new(
+							"Code",
+							None,
+							whole_span(tokens, token.span),
+							dump_tokens(tokens),
+						),
+					})
+					.collect(),
+				_ => Vec::new(),
+			};
+
+			let detail = match children.is_empty()
+			{
+				true => detail,
+				false => None,
+			};
+
+			Dump::new(kind, detail, token.span, children)
+		})
+		.collect()
+}
+
+/// Gets the span covering some tokens.
+///
+/// ### Parameters
+/// * `tokens` - The tokens.
+/// * `default` - The span to use if there are no tokens.
+///
+/// ### Returns
+/// * The span from the start of the first token to the end of the last.
+fn whole_span(tokens: &[Token], default: Span) -> Span
+{
+	match (tokens.first(), tokens.last())
+	{
+		(Some(first), Some(last)) => Span {
+			start: first.span.start,
+			end: last.span.end,
+		},
+		_ => default,
+	}
+}
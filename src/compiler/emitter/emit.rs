@@ -0,0 +1,149 @@
+use anyhow::{bail, Result};
+
+use super::dump::Dump;
+use super::*;
+use crate::compiler::lexer::Lex;
+use crate::compiler::parser::{Parse, Tree};
+use crate::compiler::synthesiser::Synthesis;
+use crate::compiler::SOURCE;
+use crate::language::utils::{FileId, SourceMap};
+
+/// The stages of the compiler whose output can be emitted.
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum Stage
+{
+	/// The lexical tokens, with their spans.
+	Tokens,
+
+	/// The abstract syntax tree.
+	Ast,
+
+	/// The abstract syntax tree, with the type of each expression.
+	TypedAst,
+
+	/// The synthesised Python.
+	Py,
+}
+
+/// Emits the output of a stage of the compiler for Quark source code.
+///
+/// ### Parameters
+/// * `text` - The Quark source code.
+/// * `stage` - The stage to emit.
+/// * `json` - Whether to emit JSON rather than an indented tree.
+///
+/// ### Returns
+/// * The output of the stage.
+///
+/// ### Errors
+/// * If the source code cannot be compiled as far as the stage.
+/// * If JSON is asked of a stage that has no JSON form.
+pub fn emit(text: &str, stage: Stage, json: bool) -> Result<String>
+{
+	let mut source = SourceMap::new();
+	let file = source.add(SOURCE, text.to_string());
+
+	emit_file(file, &source, stage, json)
+}
+
+/// Emits the output of a stage of the compiler for a Quark source file.
+///
+/// Types are annotated on a best-effort basis: an expression whose type cannot
+/// be determined, such as one that uses a function parameter, is left bare.
+///
+/// As JSON, the tokens and syntax tree are both written as a versioned
+/// [`Syntax`](super::Syntax), and the Python as a single string.  Typed syntax
+/// trees have no JSON form.
+///
+/// ### Parameters
+/// * `file` - The source file.
+/// * `source` - The source code.
+/// * `stage` - The stage to emit.
+/// * `json` - Whether to emit JSON rather than an indented tree.
+///
+/// ### Returns
+/// * The output of the stage.
+///
+/// ### Errors
+/// * If the file cannot be compiled as far as the stage.
+/// * If JSON is asked of a stage that has no JSON form.
+pub fn emit_file(
+	file: FileId,
+	source: &SourceMap,
+	stage: Stage,
+	json: bool,
+) -> Result<String>
+{
+	if json
+	{
+		return json_file(file, source, stage);
+	}
+
+	let tokens = file.lex(source)?;
+	let text = source.file(file);
+
+	let dump = match stage
+	{
+		Stage::Tokens => Dump::tokens(&tokens),
+
+		Stage::Ast =>
+		{
+			let Tree(programme) = tokens.parse(source)?;
+			Dump::programme(&programme, text, false)
+		}
+
+		Stage::TypedAst =>
+		{
+			let Tree(programme) = tokens.parse(source)?;
+			Dump::programme(&programme, text, true)
+		}
+
+		Stage::Py =>
+		{
+			let python = tokens.parse(source)?.analyse(source)?;
+			return Ok(python.synthesise());
+		}
+	};
+
+	Ok(dump.text(text))
+}
+
+/// Emits the output of a stage of the compiler for a Quark source file as
+/// JSON.
+///
+/// ### Parameters
+/// * `file` - The source file.
+/// * `source` - The source code.
+/// * `stage` - The stage to emit.
+///
+/// ### Returns
+/// * The output of the stage as JSON.
+///
+/// ### Errors
+/// * If the file cannot be compiled as far as the stage.
+/// * If the stage has no JSON form.
+#[cfg(feature = "serde")]
+fn json_file(file: FileId, source: &SourceMap, stage: Stage) -> Result<String>
+{
+	match stage
+	{
+		Stage::Tokens | Stage::Ast => Syntax::from_file(file, source)?.to_json(),
+		Stage::TypedAst => bail!(error::TYPED_JSON),
+
+		Stage::Py =>
+		{
+			let python = file.lex(source)?.parse(source)?.analyse(source)?;
+			Ok(serde_json::Value::from(python.synthesise()).to_string())
+		}
+	}
+}
+
+/// Reports that JSON output needs the `serde` feature.
+///
+/// ### Errors
+/// * Always, as Quark was built without the `serde` feature.
+#[cfg(not(feature = "serde"))]
+fn json_file(_: FileId, _: &SourceMap, _: Stage) -> Result<String>
+{
+	bail!(error::SERDE)
+}
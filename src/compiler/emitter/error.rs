@@ -0,0 +1,12 @@
+/// An error message indicating JSON written for another version of the schema.
+#[cfg(feature = "serde")]
+pub const VERSION: &str = "This JSON was written for another schema version";
+
+/// An error message indicating JSON asked of a typed syntax tree.
+#[cfg(feature = "serde")]
+pub const TYPED_JSON: &str =
+	"Typed syntax trees have no JSON form; use `--emit ast` for versioned JSON";
+
+/// An error message indicating JSON asked of a build without `serde`.
+#[cfg(not(feature = "serde"))]
+pub const SERDE: &str = "Quark was built without the `serde` feature 📦";
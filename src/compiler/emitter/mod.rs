@@ -0,0 +1,9 @@
+mod dump;
+pub mod emit;
+mod error;
+#[cfg(feature = "serde")]
+pub mod syntax;
+
+pub use emit::*;
+#[cfg(feature = "serde")]
+pub use syntax::*;
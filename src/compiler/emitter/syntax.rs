@@ -0,0 +1,119 @@
+use anyhow::{ensure, Result};
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::compiler::lexer::Lex;
+use crate::compiler::parser::{Parse, Tree};
+use crate::compiler::SOURCE;
+use crate::language::grammar::Programme;
+use crate::language::lexicon::Token;
+use crate::language::utils::{FileId, SourceMap};
+
+/// The version of the JSON schema of [`Syntax`].
+///
+/// The version changes whenever a change to the token or syntax tree types
+/// changes the shape of their JSON, so that tools reading it can tell which
+/// shape to expect.
+pub const SCHEMA_VERSION: u32 = 9;
+
+/// The tokens and syntax tree of a Quark source file, as written by
+/// `quark parse --json`.
+///
+/// Every span holds the file and 0-based byte offsets of its first and last
+/// symbols in `text`.  Enum variants are written as `"Variant"` when they hold
+/// nothing, and as `{ "Variant": ... }` otherwise.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Syntax
+{
+	/// The version of the schema, which is always [`SCHEMA_VERSION`].
+	pub version: u32,
+
+	/// The path of the source file.
+	pub path: String,
+
+	/// The full text of the source file.
+	pub text: String,
+
+	/// The tokens of the source file.
+	pub tokens: Vec<Token>,
+
+	/// The syntax tree of the source file.
+	pub programme: Programme,
+}
+
+impl Syntax
+{
+	/// Lexes and parses Quark source code.
+	///
+	/// ### Parameters
+	/// * `text` - The Quark source code.
+	///
+	/// ### Returns
+	/// * The tokens and syntax tree of the source code.
+	///
+	/// ### Errors
+	/// * If the source code cannot be lexed or parsed.
+	pub fn new(text: &str) -> Result<Self>
+	{
+		let mut source = SourceMap::new();
+		let file = source.add(SOURCE, text.to_string());
+
+		Self::from_file(file, &source)
+	}
+
+	/// Lexes and parses a Quark source file.
+	///
+	/// ### Parameters
+	/// * `file` - The source file.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The tokens and syntax tree of the file.
+	///
+	/// ### Errors
+	/// * If the file cannot be lexed or parsed.
+	pub fn from_file(file: FileId, source: &SourceMap) -> Result<Self>
+	{
+		let tokens = file.lex(source)?;
+		let Tree(programme) = tokens.clone().parse(source)?;
+		let file = source.file(file);
+
+		Ok(Self {
+			version: SCHEMA_VERSION,
+			path: file.path.display().to_string(),
+			text: file.text.clone(),
+			tokens,
+			programme,
+		})
+	}
+
+	/// Reads the tokens and syntax tree of a source file from JSON.
+	///
+	/// ### Parameters
+	/// * `json` - The JSON, as written by [`Syntax::to_json`].
+	///
+	/// ### Returns
+	/// * The tokens and syntax tree.
+	///
+	/// ### Errors
+	/// * If the JSON does not follow the current version of the schema.
+	pub fn from_json(json: &str) -> Result<Self>
+	{
+		let syntax: Self = serde_json::from_str(json)?;
+		ensure!(syntax.version == SCHEMA_VERSION, error::VERSION);
+
+		Ok(syntax)
+	}
+
+	/// Writes the tokens and syntax tree as JSON.
+	///
+	/// ### Returns
+	/// * The indented JSON.
+	///
+	/// ### Errors
+	/// * If the tokens or syntax tree cannot be serialised.
+	pub fn to_json(&self) -> Result<String>
+	{
+		Ok(serde_json::to_string_pretty(self)?)
+	}
+}
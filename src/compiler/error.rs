@@ -1,31 +1,162 @@
+use std::fmt::{self, Display, Formatter};
+
 use crate::language::utils::*;
 
 pub trait Error
 {
-	/// Creates a pretty string that reports an error.
+	/// Creates a diagnostic that reports an error with a pretty excerpt of the
+	/// source code.
+	///
+	/// ### Parameters
+	/// * `span` - The span of the error.
+	/// * `message` - The error message.
+	///
+	/// ### Returns
+	/// * The diagnostic that reports the error.
+	fn error(&self, span: Span, message: &str) -> Diagnostic;
+}
+
+/// An error tied to a span of the source code.
+///
+/// Diagnostics display as their report, so they read the same as plain error
+/// messages, while tools such as the language server can still recover where
+/// the error happened by downcasting.
+#[derive(Debug)]
+pub struct Diagnostic
+{
+	/// The span of the error.
+	pub span: Span,
+
+	/// The error message.
+	pub message: String,
+
+	/// Other spans that explain the error, each with a note saying how.
+	pub related: Vec<(Span, String)>,
+
+	/// The text shown to the user.
+	report: String,
+}
+
+impl Diagnostic
+{
+	/// Creates a diagnostic whose report is just its message.
 	///
 	/// ### Parameters
 	/// * `span` - The span of the error.
 	/// * `message` - The error message.
 	///
 	/// ### Returns
-	/// * The pretty string that reports an error.
-	fn error(&self, span: Span, message: &str) -> String;
+	/// * The new diagnostic.
+	pub fn new(span: Span, message: String) -> Self
+	{
+		Self {
+			span,
+			report: message.clone(),
+			message,
+			related: Vec::new(),
+		}
+	}
+
+	/// Adds a span that explains the error, such as where a conflicting type
+	/// came from.
+	///
+	/// ### Parameters
+	/// * `span` - The span.
+	/// * `note` - How the span explains the error.
+	///
+	/// ### Returns
+	/// * The diagnostic with the related span.
+	pub fn relate(mut self, span: Span, note: String) -> Self
+	{
+		self.related.push((span, note));
+		self
+	}
+
+	/// Ties an error to a span, unless it is already tied to one.
+	///
+	/// ### Parameters
+	/// * `error` - The error.
+	/// * `span` - The span of the code that caused the error.
+	///
+	/// ### Returns
+	/// * The error as a diagnostic.
+	pub fn attach(error: anyhow::Error, span: Span) -> anyhow::Error
+	{
+		match error.downcast::<Self>()
+		{
+			Ok(diagnostic) => diagnostic.into(),
+			Err(error) => Self::new(span, error.to_string()).into(),
+		}
+	}
+
+	/// Renders the report of the diagnostic with excerpts of the source code:
+	/// one for its span, followed by one for each related span.
+	///
+	/// ### Parameters
+	/// * `source` - The source code the spans point into.
+	///
+	/// ### Returns
+	/// * The diagnostic with the rendered report.
+	pub fn render(self, source: &impl Error) -> Self
+	{
+		let mut report = source.error(self.span, &self.message).report;
+
+		for (span, note) in &self.related
+		{
+			report.push('\n');
+			report.push_str(&source.error(*span, note).report);
+		}
+
+		Self { report, ..self }
+	}
+}
+
+impl Display for Diagnostic
+{
+	fn fmt(&self, formatter: &mut Formatter) -> fmt::Result
+	{
+		write!(formatter, "{}", self.report)
+	}
 }
 
-impl Error for &[Vec<char>]
+impl std::error::Error for Diagnostic {}
+
+impl Error for SourceMap
 {
-	fn error(&self, Span { start, end }: Span, message: &str) -> String
+	fn error(&self, span: Span, message: &str) -> Diagnostic
 	{
-		let Position { line, column } = start;
+		let Span { start, end } = span;
+
+		let file = self.file(start.file);
+		let Location { line, column } = file.location(start.offset);
+		let last = file.location(end.offset);
 
-		let header = format!("--> {start:?}--{end:?}");
+		let header = format!(
+			"--> {}:{}:{}",
+			file.path.display(),
+			line + 1,
+			column + 1
+		);
 		let prefix = format!("{} | ", line + 1);
-		let source = self[line].iter().collect::<String>();
+		let source = file.line(line);
 		let indent = " ".repeat(prefix.len() + column);
-		let length = end.column - start.column + 1;
+		let length = if last.line == line
+		{
+			last.column.saturating_sub(column) + 1
+		}
+		else
+		{
+			source.chars().count().saturating_sub(column).max(1)
+		};
 		let arrows = "^".repeat(length);
+		let report =
+			format!("{header}\n{prefix}{source}\n{indent}{arrows}\n{message}");
 
-		format!("{header}\n{prefix}{source}{indent}{arrows}\n{message}")
+		Diagnostic {
+			span,
+			message: message.to_string(),
+			related: Vec::new(),
+			report,
+		}
 	}
 }
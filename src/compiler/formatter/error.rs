@@ -0,0 +1,3 @@
+/// An error message indicating the formatter changed the meaning of a file.
+pub const EQUIVALENCE: &str =
+	"Formatting would change the meaning of this file; please report this bug";
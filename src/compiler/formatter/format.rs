@@ -0,0 +1,136 @@
+use anyhow::{ensure, Result};
+
+use super::writer::Writer;
+use super::*;
+use crate::compiler::lexer::Lex;
+use crate::compiler::parser::{Parse, Tree};
+use crate::compiler::SOURCE;
+use crate::language::grammar::{Statement, SyntaxTree};
+use crate::language::lexicon::token::{self, Segment};
+use crate::language::lexicon::Token;
+use crate::language::utils::{FileId, SourceMap, Span};
+
+/// Formats Quark source code in the canonical style.
+///
+/// ### Parameters
+/// * `text` - The Quark source code.
+///
+/// ### Returns
+/// * The formatted source code, or the source code unchanged if it cannot be
+///   parsed.
+pub fn format(text: &str) -> String
+{
+	try_format(text).unwrap_or_else(|_| text.to_string())
+}
+
+/// Formats Quark source code in the canonical style.
+///
+/// ### Parameters
+/// * `text` - The Quark source code.
+///
+/// ### Returns
+/// * The formatted source code.
+///
+/// ### Errors
+/// * If the source code cannot be parsed.
+pub fn try_format(text: &str) -> Result<String>
+{
+	let mut source = SourceMap::new();
+	let file = source.add(SOURCE, text.to_string());
+
+	try_format_file(file, &source)
+}
+
+/// Formats a Quark source file in the canonical style.
+///
+/// ### Parameters
+/// * `file` - The source file.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The formatted text of the file.
+///
+/// ### Errors
+/// * If the file cannot be parsed.
+/// * If the formatted text does not parse to the same abstract syntax tree.
+pub fn try_format_file(file: FileId, source: &SourceMap) -> Result<String>
+{
+	// Parse the file first so that syntax errors point into the source.
+	file.lex(source)?.parse(source)?;
+
+	let tree = SyntaxTree::try_from_file(file, source)?;
+	let formatted = Writer::write(&tree);
+
+	let mut target = SourceMap::new();
+	let path = source.file(file).path.clone();
+	let formatted_file = target.add(path, formatted.clone());
+
+	let before = statements(file, source)?;
+	let after = statements(formatted_file, &target)?;
+	ensure!(before == after, error::EQUIVALENCE);
+
+	Ok(formatted)
+}
+
+/// Determines if two pieces of Quark source code have the same abstract
+/// syntax tree, regardless of their layout and comments.
+///
+/// ### Parameters
+/// * `left` - The first piece of source code.
+/// * `right` - The second piece of source code.
+///
+/// ### Returns
+/// * `true` if both parse to the same abstract syntax tree.
+/// * `false` otherwise.
+///
+/// ### Errors
+/// * If either piece of source code cannot be parsed.
+pub fn equivalent(left: &str, right: &str) -> Result<bool>
+{
+	let mut source = SourceMap::new();
+	let left = source.add(SOURCE, left.to_string());
+	let right = source.add(SOURCE, right.to_string());
+
+	Ok(statements(left, &source)? == statements(right, &source)?)
+}
+
+/// Parses the statements of a file with every span erased, so that trees can
+/// be compared by structure alone.
+///
+/// ### Parameters
+/// * `file` - The source file.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The statements of the file.
+///
+/// ### Errors
+/// * If the file cannot be parsed.
+fn statements(file: FileId, source: &SourceMap) -> Result<Vec<Statement>>
+{
+	let mut tokens = file.lex(source)?;
+	tokens.iter_mut().for_each(erase);
+
+	let Tree(programme) = tokens.parse(source)?;
+	Ok(programme.statements)
+}
+
+/// Erases the span of a token, including the tokens of its interpolations.
+///
+/// ### Parameters
+/// * `token` - The token.
+fn erase(token: &mut Token)
+{
+	token.span = Span::default();
+
+	if let token::Kind::Interpolation(segments) = &mut token.kind
+	{
+		for segment in segments
+		{
+			if let Segment::Code(tokens) = segment
+			{
+				tokens.iter_mut().for_each(erase);
+			}
+		}
+	}
+}
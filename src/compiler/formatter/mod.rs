@@ -0,0 +1,5 @@
+mod error;
+pub mod format;
+mod writer;
+
+pub use format::*;
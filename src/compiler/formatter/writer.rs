@@ -0,0 +1,645 @@
+use crate::language::grammar::concrete::{Element, Kind, Node, SyntaxTree};
+use crate::language::lexicon::token::Kind::{
+	Bar, Boolean, BraceLeft, BraceRight, BracketLeft, BracketRight,
+	BracketRightWithA, BracketRightWithM, Colon, ColonColon, Comma, Doc, Dot,
+	DotDot, DotDotEqual, Else, Enum, Function, Greater, Identifier,
+	Interpolation, Less, Minus, Number, ParenthesisLeft, ParenthesisRight, Plus,
+	Semicolon, Struct,
+};
+use crate::language::lexicon::trivia::{self, Trivia};
+use crate::language::lexicon::{token, Lexeme};
+
+/// The text of one level of indentation.
+const INDENT: &str = "    ";
+
+/// A writer that prints a concrete syntax tree in the canonical style.
+///
+/// The writer walks the tokens of the tree in order, deciding the spacing
+/// between each pair of tokens from their kinds alone, and keeps every comment
+/// next to the token it was attached to.
+pub struct Writer
+{
+	/// The formatted text so far.
+	output: String,
+
+	/// The current level of indentation.
+	depth: usize,
+
+	/// Whether the next token starts a statement.
+	statement_start: bool,
+
+	/// Whether nothing has been written on the current line yet.
+	line_start: bool,
+
+	/// Whether the next statement is the first of its file or block, so that
+	/// no blank line is kept before it.
+	first: bool,
+
+	/// The kind of the last token written, if it can affect spacing.
+	previous: Option<token::Kind>,
+
+	/// Whether the last token written is a prefix operator.
+	prefix: bool,
+
+	/// Whether the writer is between the bars around the parameters of a
+	/// lambda.
+	parameters: bool,
+
+	/// Whether the writer is in the header of a function or the declaration of
+	/// a struct or enum, where `<` and `>` enclose type arguments rather than
+	/// compare.
+	types: bool,
+}
+
+impl Writer
+{
+	/// Prints a concrete syntax tree in the canonical style.
+	///
+	/// ### Parameters
+	/// * `tree` - The concrete syntax tree.
+	///
+	/// ### Returns
+	/// * The formatted source code.
+	pub fn write(tree: &SyntaxTree) -> String
+	{
+		let mut writer = Self {
+			output: String::new(),
+			depth: 0,
+			statement_start: true,
+			line_start: true,
+			first: true,
+			previous: None,
+			prefix: false,
+			parameters: false,
+			types: false,
+		};
+
+		writer.children(&tree.root.children);
+		writer.lines(&tree.end, false);
+
+		let output = writer.output.trim_end();
+
+		match output.is_empty()
+		{
+			true => String::new(),
+			false => format!("{output}\n"),
+		}
+	}
+
+	/// Prints the children of a node.
+	///
+	/// ### Parameters
+	/// * `children` - The children.
+	fn children(&mut self, children: &[Element])
+	{
+		for child in children
+		{
+			match child
+			{
+				Element::Lexeme(lexeme) => self.lexeme(lexeme),
+				Element::Node(node) => self.node(node),
+			}
+		}
+	}
+
+	/// Prints a node.
+	///
+	/// ### Parameters
+	/// * `node` - The node.
+	fn node(&mut self, node: &Node)
+	{
+		match node.kind
+		{
+			Kind::Programme | Kind::Parenthesised => self.children(&node.children),
+
+			Kind::Statement =>
+			{
+				self.statement_start = true;
+				self.children(&node.children);
+				self.newline();
+				self.statement_start = true;
+				self.types = false;
+			}
+
+			Kind::Block => self.block(node),
+			Kind::Members => self.members(node),
+
+			Kind::Brackets => match Self::rows(node)
+			{
+				Some(rows) => self.matrix(node, &rows),
+				None => self.children(&node.children),
+			},
+		}
+	}
+
+	/// Prints a block, with each of its statements on its own line.
+	///
+	/// ### Parameters
+	/// * `node` - The block node.
+	fn block(&mut self, node: &Node)
+	{
+		let [Element::Lexeme(open), statements @ .., Element::Lexeme(close)] =
+			node.children.as_slice()
+		else
+		{
+			return self.children(&node.children);
+		};
+
+		self.types = false;
+		self.lexeme(open);
+
+		let is_empty = statements.is_empty()
+			&& !has_comments(&open.trailing)
+			&& !has_comments(&close.leading);
+
+		if !is_empty
+		{
+			self.newline();
+			self.depth += 1;
+			self.first = true;
+
+			self.children(statements);
+			self.lines(&close.leading, false);
+
+			self.depth -= 1;
+			self.statement_start = false;
+		}
+
+		self.previous = None;
+		self.token(close);
+	}
+
+	/// Prints the members of a struct or enum, with each of them on its own
+	/// line.
+	///
+	/// ### Parameters
+	/// * `node` - The members node.
+	fn members(&mut self, node: &Node)
+	{
+		let [Element::Lexeme(open), members @ .., Element::Lexeme(close)] =
+			node.children.as_slice()
+		else
+		{
+			return self.children(&node.children);
+		};
+
+		self.lexeme(open);
+
+		let is_empty = members.is_empty()
+			&& !has_comments(&open.trailing)
+			&& !has_comments(&close.leading);
+
+		if !is_empty
+		{
+			self.newline();
+			self.depth += 1;
+			self.first = true;
+			self.statement_start = true;
+
+			// The comma between the sizes of a matrix type is not between
+			// members.
+			let mut angles = 0_usize;
+
+			for member in members
+			{
+				self.children(std::slice::from_ref(member));
+
+				if is_kind(member, &Less)
+				{
+					angles += 1;
+				}
+				else if is_kind(member, &Greater)
+				{
+					angles = angles.saturating_sub(1);
+				}
+				else if is_kind(member, &Comma) && angles == 0
+				{
+					self.newline();
+					self.statement_start = true;
+				}
+			}
+
+			self.newline();
+			self.lines(&close.leading, false);
+
+			self.depth -= 1;
+			self.statement_start = false;
+		}
+
+		self.previous = None;
+		self.token(close);
+	}
+
+	/// Splits a bracketed node into the rows and cells of a matrix, if it is
+	/// laid out over several lines.
+	///
+	/// ### Parameters
+	/// * `node` - The bracketed node.
+	///
+	/// ### Returns
+	/// * The cells of each row, or `None` if the node should be printed on a
+	///   single line.
+	fn rows(node: &Node) -> Option<Vec<Vec<String>>>
+	{
+		let lexemes = node.lexemes();
+		let (_, inner) = lexemes.split_first()?;
+		let (_, inner) = inner.split_last()?;
+
+		let is_multiline = lexemes[1..].iter().any(|lexeme| {
+			lexeme
+				.leading
+				.iter()
+				.any(|trivia| trivia.kind == trivia::Kind::Newline)
+		});
+
+		let is_matrix = node.children.iter().any(|child| {
+			matches!(child, Element::Lexeme(lexeme) if lexeme.token.kind == Bar)
+		});
+
+		let has_comments = inner.iter().any(|lexeme| {
+			has_comments(&lexeme.leading) || has_comments(&lexeme.trailing)
+		}) || has_comments(&lexemes[0].trailing)
+			|| has_comments(&lexemes[lexemes.len() - 1].leading);
+
+		if !is_multiline || !is_matrix || has_comments
+		{
+			return None;
+		}
+
+		let elements = &node.children[1..node.children.len() - 1];
+
+		let rows = elements
+			.split(|child| is_kind(child, &Bar))
+			.map(|row| {
+				row
+					.split(|child| is_kind(child, &Comma))
+					.map(Self::inline)
+					.collect()
+			})
+			.collect();
+
+		Some(rows)
+	}
+
+	/// Prints a matrix with one row per line and its columns aligned.
+	///
+	/// ### Parameters
+	/// * `node` - The bracketed node.
+	/// * `rows` - The printed cells of each row.
+	fn matrix(&mut self, node: &Node, rows: &[Vec<String>])
+	{
+		let (Some(Element::Lexeme(open)), Some(Element::Lexeme(close))) =
+			(node.children.first(), node.children.last())
+		else
+		{
+			return self.children(&node.children);
+		};
+
+		self.lexeme(open);
+
+		let line = self.output.rsplit('\n').next().unwrap_or_default();
+		let column = line.chars().count();
+
+		let columns = rows.iter().map(Vec::len).max().unwrap_or_default();
+		let widths: Vec<_> = (0..columns)
+			.map(|index| {
+				rows
+					.iter()
+					.filter_map(|row| row.get(index))
+					.map(|cell| cell.chars().count())
+					.max()
+					.unwrap_or_default()
+			})
+			.collect();
+
+		for (index, row) in rows.iter().enumerate()
+		{
+			let cells: Vec<_> = row
+				.iter()
+				.zip(&widths)
+				.map(|(cell, &width)| format!("{cell:>width$}"))
+				.collect();
+			let text = cells.join(", ");
+			let text = text.trim_end();
+
+			if index > 0
+			{
+				self.output.push_str(" |");
+
+				if !text.is_empty()
+				{
+					self.output.push('\n');
+					self.output.push_str(&" ".repeat(column));
+				}
+			}
+
+			self.output.push_str(text);
+		}
+
+		self.previous = None;
+		self.token(close);
+	}
+
+	/// Prints a sequence of elements on a single line.
+	///
+	/// ### Parameters
+	/// * `elements` - The elements, which must not contain comments.
+	///
+	/// ### Returns
+	/// * The printed elements.
+	fn inline(elements: &[Element]) -> String
+	{
+		let mut writer = Self {
+			output: String::new(),
+			depth: 0,
+			statement_start: false,
+			line_start: false,
+			first: false,
+			previous: None,
+			prefix: false,
+			parameters: false,
+			types: false,
+		};
+
+		writer.children(elements);
+		writer.output
+	}
+
+	/// Prints a token along with the comments before it.
+	///
+	/// ### Parameters
+	/// * `lexeme` - The token.
+	fn lexeme(&mut self, lexeme: &Lexeme)
+	{
+		if self.statement_start
+		{
+			self.lines(&lexeme.leading, true);
+			self.first = false;
+			self.statement_start = false;
+			self.previous = None;
+		}
+		else if has_comments(&lexeme.leading)
+		{
+			// Comments on their own lines inside a statement stay on their own
+			// lines, indented as a continuation of the statement.
+			self.newline();
+			self.depth += 1;
+			self.lines(&lexeme.leading, false);
+			self.depth -= 1;
+		}
+
+		self.token(lexeme);
+
+		if let Doc(_) = lexeme.token.kind
+		{
+			self.newline();
+			self.statement_start = true;
+		}
+	}
+
+	/// Prints a token along with the comments after it on the same line.
+	///
+	/// ### Parameters
+	/// * `lexeme` - The token.
+	fn token(&mut self, lexeme: &Lexeme)
+	{
+		let kind = &lexeme.token.kind;
+
+		if self.line_start
+		{
+			// An `else` pushed onto a line of its own by a comment after the
+			// closing brace lines up with the brace rather than continuing the
+			// statement.
+			let is_continuation = self.previous.is_some() && *kind != Else;
+
+			let depth = match self.statement_start || !is_continuation
+			{
+				true => self.depth,
+				false => self.depth + 1,
+			};
+
+			self.output.push_str(&INDENT.repeat(depth));
+			self.line_start = false;
+		}
+		else if let Some(previous) = &self.previous
+		{
+			let is_closing = *kind == Bar && self.parameters;
+			let is_angle =
+				(self.types || self.parameters) && is_angled(previous, kind);
+
+			if !self.prefix && !is_closing && !is_angle && is_spaced(previous, kind)
+			{
+				self.output.push(' ');
+			}
+		}
+
+		self.output.push_str(lexeme.text.trim_end());
+
+		// A bar in place of an operand opens the parameters of a lambda, and
+		// hugs them like a prefix operator.
+		let is_operand = !self.previous.as_ref().is_some_and(ends_operand);
+		let is_opening = *kind == Bar && is_operand && !self.parameters;
+
+		if *kind == Bar
+		{
+			self.parameters = is_opening;
+		}
+
+		self.prefix = matches!(kind, Plus | Minus) && is_operand || is_opening;
+		self.types |= matches!(kind, Function | Struct | Enum);
+		self.previous = Some(kind.clone());
+
+		for trivia in &lexeme.trailing
+		{
+			match trivia.kind
+			{
+				trivia::Kind::Comment =>
+				{
+					self.output.push(' ');
+					self.output.push_str(trivia.text.trim_end());
+					self.newline();
+				}
+
+				trivia::Kind::BlockComment =>
+				{
+					self.output.push(' ');
+					self.output.push_str(&trivia.text);
+				}
+
+				_ => (),
+			}
+		}
+	}
+
+	/// Prints the comments of some trivia on their own lines, keeping at most
+	/// one blank line wherever the source had blank lines.
+	///
+	/// ### Parameters
+	/// * `trivia` - The trivia.
+	/// * `before` - Whether a statement follows the trivia.
+	fn lines(&mut self, trivia: &[Trivia], before: bool)
+	{
+		let mut newlines = 0;
+
+		for piece in trivia
+		{
+			match piece.kind
+			{
+				trivia::Kind::Newline => newlines += 1,
+				trivia::Kind::Whitespace => (),
+
+				trivia::Kind::Comment | trivia::Kind::BlockComment =>
+				{
+					self.blank(newlines);
+					self.output.push_str(&INDENT.repeat(self.depth));
+					self.output.push_str(piece.text.trim_end());
+					self.output.push('\n');
+					self.line_start = true;
+					self.first = false;
+					newlines = 0;
+				}
+			}
+		}
+
+		if before
+		{
+			self.blank(newlines);
+		}
+	}
+
+	/// Prints a blank line if the source had one, unless it would open a file
+	/// or block.
+	///
+	/// ### Parameters
+	/// * `newlines` - The number of line breaks in the source.
+	fn blank(&mut self, newlines: usize)
+	{
+		if newlines > 1 && !self.first
+		{
+			self.output.push('\n');
+		}
+	}
+
+	/// Ends the current line, if anything has been written on it.
+	fn newline(&mut self)
+	{
+		if !self.line_start
+		{
+			self.output.push('\n');
+			self.line_start = true;
+		}
+	}
+}
+
+/// Determines if some trivia contains comments.
+///
+/// ### Parameters
+/// * `trivia` - The trivia.
+///
+/// ### Returns
+/// * `true` if there is at least one comment.
+/// * `false` otherwise.
+fn has_comments(trivia: &[Trivia]) -> bool
+{
+	trivia.iter().any(|piece| {
+		matches!(piece.kind, trivia::Kind::Comment | trivia::Kind::BlockComment)
+	})
+}
+
+/// Determines if an element is a token of a given kind.
+///
+/// ### Parameters
+/// * `element` - The element.
+/// * `kind` - The kind of token.
+///
+/// ### Returns
+/// * `true` if the element is a token of the given kind.
+/// * `false` otherwise.
+fn is_kind(element: &Element, kind: &token::Kind) -> bool
+{
+	matches!(element, Element::Lexeme(lexeme) if &lexeme.token.kind == kind)
+}
+
+/// Determines if a token can end an operand, so that a following `+` or `-`
+/// is an infix operator.
+///
+/// ### Parameters
+/// * `kind` - The kind of token.
+///
+/// ### Returns
+/// * `true` if the token can end an operand.
+/// * `false` otherwise.
+fn ends_operand(kind: &token::Kind) -> bool
+{
+	matches!(
+		kind,
+		Identifier(_)
+			| token::Kind::String(_)
+			| Interpolation(_)
+			| Number(_)
+			| Boolean(_)
+			| ParenthesisRight
+			| BracketRight
+			| BracketRightWithM
+			| BracketRightWithA
+	)
+}
+
+/// Determines if two adjacent tokens are separated by a space.
+///
+/// ### Parameters
+/// * `previous` - The kind of the first token.
+/// * `next` - The kind of the second token.
+///
+/// ### Returns
+/// * `true` if the tokens are separated by a space.
+/// * `false` otherwise.
+fn is_spaced(previous: &token::Kind, next: &token::Kind) -> bool
+{
+	match (previous, next)
+	{
+		// A full slice is an index of its own.
+		(Comma, Colon) => true,
+
+		(
+			_,
+			Comma | Semicolon | Colon | ColonColon | Dot | DotDot | DotDotEqual
+			| ParenthesisRight | BracketRight | BracketRightWithM | BracketRightWithA,
+		) => false,
+
+		// Ranges hug their bounds.
+		(
+			ParenthesisLeft | BracketLeft | ColonColon | Dot | DotDot | DotDotEqual,
+			_,
+		) => false,
+
+		(BraceLeft, BraceRight) => false,
+
+		// Calls and indexing hug their callee.
+		(
+			Identifier(_) | ParenthesisRight | BracketRight | BracketRightWithM
+			| BracketRightWithA,
+			ParenthesisLeft | BracketLeft,
+		) => false,
+
+		_ => true,
+	}
+}
+
+/// Determines if two adjacent tokens are joined by the angle brackets around
+/// type arguments, as in `first<T>(xs: List<T>)`.
+///
+/// ### Parameters
+/// * `previous` - The kind of the first token.
+/// * `next` - The kind of the second token.
+///
+/// ### Returns
+/// * `true` if the tokens are not separated by a space.
+/// * `false` otherwise.
+fn is_angled(previous: &token::Kind, next: &token::Kind) -> bool
+{
+	matches!(
+		(previous, next),
+		(_, Less | Greater) | (Less, _) | (Greater, ParenthesisLeft)
+	)
+}
@@ -0,0 +1,128 @@
+use crate::language::lexicon::Symbol;
+use crate::language::utils::{FileId, Position};
+
+/// A cursor over the text of a source file.
+///
+/// The cursor walks the text by byte offset and hands out symbols on demand,
+/// so lexing never copies the source into an intermediate buffer.
+#[derive(Clone)]
+pub struct Cursor<'a>
+{
+	/// The source file being lexed.
+	file: FileId,
+
+	/// The full text of the source file.
+	text: &'a str,
+
+	/// The byte offset of the next symbol.
+	offset: usize,
+}
+
+impl<'a> Cursor<'a>
+{
+	/// Creates a cursor at the start of a source file.
+	///
+	/// ### Parameters
+	/// * `file` - The identifier of the source file.
+	/// * `text` - The text of the source file.
+	///
+	/// ### Returns
+	/// * The new cursor.
+	pub fn new(file: FileId, text: &'a str) -> Self
+	{
+		Self {
+			file,
+			text,
+			offset: 0,
+		}
+	}
+
+	/// Gets the position of the next symbol.
+	pub fn here(&self) -> Position
+	{
+		Position {
+			file: self.file,
+			offset: self.offset,
+		}
+	}
+
+	/// Looks at the next symbol without consuming it.
+	///
+	/// ### Returns
+	/// * The next symbol, or `None` at the end of the file.
+	pub fn peek(&self) -> Option<Symbol>
+	{
+		let character = self.text[self.offset..].chars().next()?;
+		Some(Symbol::new(self.here(), character))
+	}
+
+	/// Looks at the character after the next symbol without consuming either.
+	///
+	/// ### Returns
+	/// * The character after the next symbol, or `None` past the end of the
+	///   file.
+	pub fn peek_second(&self) -> Option<char>
+	{
+		self.text[self.offset..].chars().nth(1)
+	}
+
+	/// Determines if the remaining text starts with a prefix.
+	///
+	/// ### Parameters
+	/// * `prefix` - The text to look for.
+	///
+	/// ### Returns
+	/// * `true` if the next symbols spell out `prefix`.
+	/// * `false` otherwise.
+	pub fn starts_with(&self, prefix: &str) -> bool
+	{
+		self.text[self.offset..].starts_with(prefix)
+	}
+
+	/// Consumes the next symbol if it satisfies a predicate.
+	///
+	/// ### Parameters
+	/// * `predicate` - The condition the next symbol must satisfy.
+	///
+	/// ### Returns
+	/// * The consumed symbol, or `None` if nothing was consumed.
+	pub fn next_if(
+		&mut self,
+		predicate: impl FnOnce(&Symbol) -> bool,
+	) -> Option<Symbol>
+	{
+		let symbol = self.peek()?;
+
+		if predicate(&symbol)
+		{
+			self.offset += symbol.character.len_utf8();
+			Some(symbol)
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Gets the text consumed since a position.
+	///
+	/// ### Parameters
+	/// * `start` - The position to slice from.
+	///
+	/// ### Returns
+	/// * The text between `start` and the next symbol.
+	pub fn slice(&self, start: Position) -> &'a str
+	{
+		&self.text[start.offset..self.offset]
+	}
+}
+
+impl Iterator for Cursor<'_>
+{
+	type Item = Symbol;
+
+	fn next(&mut self) -> Option<Symbol>
+	{
+		self.next_if(|_| true)
+	}
+}
@@ -6,3 +6,31 @@ pub const QUOTE: &str = "This opening quote was never closed";
 
 /// An error message indicating a block comment was never closed.
 pub const COMMENT: &str = "This block comment was never closed";
+
+/// An error message indicating an unknown escape sequence.
+pub const ESCAPE: &str =
+	"Unknown escape sequence. Try `\\n`, `\\t`, `\\\\`, `\\\"` or `\\u{...}`";
+
+/// An error message indicating a malformed unicode escape sequence.
+pub const UNICODE: &str =
+	"Invalid unicode escape. Try `\\u{...}` with 1 to 6 hexadecimal digits";
+
+/// An error message indicating an interpolation was never closed.
+pub const INTERPOLATION: &str = "This interpolation was never closed";
+
+/// An error message indicating a radix prefix without digits.
+pub const DIGITS: &str = "Expected digits after this prefix";
+
+/// An error message indicating a digit outside the base of its literal.
+pub const DIGIT: &str = "This digit is not valid in a number of this base";
+
+/// An error message indicating an exponent without digits.
+pub const EXPONENT: &str = "Expected the digits of an exponent after this";
+
+/// An error message indicating a misplaced digit separator.
+pub const SEPARATOR: &str = "Digit separators `_` must sit between two digits";
+
+/// An error message indicating a decimal integer with leading zeros, which
+/// Python would not accept.
+pub const LEADING_ZERO: &str =
+	"Decimal integers cannot start with `0`; use `0o` for an octal number";
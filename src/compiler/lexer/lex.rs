@@ -1,8 +1,11 @@
-use std::iter::Peekable;
+use std::mem;
 
 use anyhow::Result;
 
-use crate::language::lexicon::{Symbol, Token};
+use super::Cursor;
+use crate::language::lexicon::trivia::Kind::Newline;
+use crate::language::lexicon::{Lexeme, Token, Trivia};
+use crate::language::utils::{FileId, SourceMap};
 
 /// Types that can be lexed.
 ///
@@ -12,22 +15,36 @@ pub trait Lex
 	/// Lexes the Quark source code into tokens.
 	///
 	/// ### Parameters
-	/// * `source` - The Quark source code.
+	/// * `source` - The database of Quark source files.
 	///
 	/// ### Returns
 	/// * The lexical tokens.
 	///
 	/// ### Errors
 	/// * If the Quark source code cannot be lexed.
-	fn lex(self, source: &[Vec<char>]) -> Result<Vec<Token>>;
+	fn lex(self, source: &SourceMap) -> Result<Vec<Token>>;
+
+	/// Lexes the Quark source code into tokens that keep their exact text and
+	/// the whitespace and comments around them.
+	///
+	/// ### Parameters
+	/// * `source` - The database of Quark source files.
+	///
+	/// ### Returns
+	/// * The lossless tokens.
+	/// * The trivia after the last token.
+	///
+	/// ### Errors
+	/// * If the Quark source code cannot be lexed.
+	fn lex_lossless(self, source: &SourceMap)
+		-> Result<(Vec<Lexeme>, Vec<Trivia>)>;
 }
 
-impl Lex for String
+impl Lex for FileId
 {
-	fn lex(self, source: &[Vec<char>]) -> Result<Vec<Token>>
+	fn lex(self, source: &SourceMap) -> Result<Vec<Token>>
 	{
-		let mut stream =
-			self.lines().enumerate().flat_map(Symbol::vector).peekable();
+		let mut stream = Cursor::new(self, &source.file(self).text);
 
 		let mut tokens = Vec::new();
 
@@ -38,6 +55,49 @@ impl Lex for String
 
 		Ok(tokens)
 	}
+
+	fn lex_lossless(self, source: &SourceMap)
+		-> Result<(Vec<Lexeme>, Vec<Trivia>)>
+	{
+		let mut stream = Cursor::new(self, &source.file(self).text);
+
+		let mut lexemes: Vec<Lexeme> = Vec::new();
+		let mut trivia = Vec::new();
+
+		loop
+		{
+			while let Some(piece) = Trivia::try_from_stream(&mut stream, source)?
+			{
+				trivia.push(piece);
+			}
+
+			// The trivia before the first line break trails the previous token.
+			if let Some(previous) = lexemes.last_mut()
+			{
+				let line = trivia
+					.iter()
+					.position(|piece| piece.kind == Newline)
+					.unwrap_or(trivia.len());
+
+				previous.trailing = trivia.drain(..line).collect();
+			}
+
+			let start = stream.here();
+
+			let Some(token) = Token::try_from_stream(&mut stream, source)?
+			else
+			{
+				return Ok((lexemes, trivia));
+			};
+
+			lexemes.push(Lexeme {
+				leading: mem::take(&mut trivia),
+				text: stream.slice(start).to_string(),
+				token,
+				trailing: Vec::new(),
+			});
+		}
+	}
 }
 
 impl Token
@@ -54,19 +114,16 @@ impl Token
 	///
 	/// ### Errors
 	/// * If the token cannot be created.
-	pub fn try_from_stream<I>(
-		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+	pub fn try_from_stream(
+		stream: &mut Cursor,
+		source: &SourceMap,
 	) -> Result<Option<Self>>
-	where
-		I: Iterator<Item = Symbol>,
 	{
 		let starting_symbol = match stream.peek()
 		{
 			Some(symbol) => symbol.character,
 			None => return Ok(None),
 		};
-
 		let token = match starting_symbol
 		{
 			// If the next symbol is whitespace.
@@ -88,9 +145,9 @@ impl Token
 			// If the next symbol potentially starts a number.
 			_ if starting_symbol.is_ascii_digit() =>
 			{
-				Self::from_number_head(stream, source)
+				Self::try_from_number_head(stream, source)?
 			}
-			'.' => Self::from_number_head(stream, source),
+			'.' => Self::try_from_number_head(stream, source)?,
 
 			// If the next symbol potentially starts a string.
 			'"' => Self::try_from_string_head(stream, source)?,
@@ -111,23 +168,3 @@ impl Token
 		Ok(token)
 	}
 }
-
-impl Symbol
-{
-	/// Creates a vector of symbols.
-	///
-	/// ### Parameters
-	/// * `line` - The line number of each symbol in the vector.
-	/// * `string` - The string of characters to be converted.
-	///
-	/// ### Returns
-	/// * A vector of symbols created from the given string.
-	pub fn vector((line, string): (usize, &str)) -> Vec<Self>
-	{
-		format!("{string}\n")
-			.chars()
-			.enumerate()
-			.map(|(column, value)| Self::new(line, column, value))
-			.collect()
-	}
-}
@@ -1,5 +1,7 @@
+pub mod cursor;
 mod error;
 pub mod lex;
 mod rules;
 
+pub use cursor::*;
 pub use lex::*;
@@ -1,12 +1,9 @@
-use std::iter::Peekable;
-
-use anyhow::{bail, Result};
+use anyhow::Result;
 
 use super::*;
-use crate::compiler::Error;
 use crate::language::lexicon::token::Kind::*;
-use crate::language::lexicon::{Symbol, Token};
-use crate::language::utils::Span;
+use crate::language::lexicon::{Token, Trivia};
+use crate::language::utils::{SourceMap, Span};
 
 impl Token
 {
@@ -23,63 +20,46 @@ impl Token
 	///
 	/// ### Errors
 	/// * If the token cannot be created.
-	pub fn try_from_comment_head<I>(
-		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+	pub fn try_from_comment_head(
+		stream: &mut Cursor,
+		source: &SourceMap,
 	) -> Result<Option<Self>>
-	where
-		I: Iterator<Item = Symbol>,
 	{
+		// Ordinary comments are trivia, so they are skipped like whitespace.
+		if Trivia::try_skip(stream, source)?.is_some()
+		{
+			return Self::try_from_whitespace(stream, source);
+		}
+
 		// Consume and get the position of the first slash symbol.
-		let position = match stream.next()
+		let start = match stream.next()
 		{
 			Some(symbol) => symbol.position,
 			None => return Ok(None),
 		};
 
-		let span = Span {
-			start: position,
-			end: position,
-		};
-
-		match stream.peek().map(|&symbol| symbol.character)
+		// The only comments that are not trivia are documentation comments.
+		if !stream.starts_with("//")
 		{
-			// If the next symbol is a slash, then this is an end-of-line comment.
-			Some('/') =>
-			{
-				while stream.next_if(|symbol| symbol.character != '\n').is_some()
-				{}
-				Self::try_from_stream(stream, source)
-			}
+			let span = Span { start, end: start };
+			return Ok(Some(Self { span, kind: Slash }));
+		}
 
-			// If the next symbol is an asterisk, then this is a block comment.
-			Some('*') =>
-			{
-				stream.next();
+		stream.next();
+		let mut end = stream.next().expect("Third slash").position;
+		let text = stream.here();
 
-				while let Some(Symbol {
-					character: value, ..
-				}) = stream.next()
-				{
-					if value == '*'
-					{
-						match stream.next().map(|symbol| symbol.character)
-						{
-							Some('/') =>
-							{
-								return Self::try_from_stream(stream, source);
-							}
-							Some(_) => continue,
-							None => break,
-						}
-					}
-				}
+		while let Some(symbol) = stream.next_if(|symbol| symbol.character != '\n')
+		{
+			end = symbol.position;
+		}
 
-				bail!(source.error(span, error::COMMENT));
-			}
+		let text = stream.slice(text);
+		let text = text.strip_prefix(' ').unwrap_or(text).trim_end();
 
-			// Otherwise, this was a single slash token all along.
-			_ => Ok(Some(Self { span, kind: Slash })),
-		}
+		Ok(Some(Self {
+			span: Span { start, end },
+			kind: Doc(text.to_string()),
+		}))
 	}
 }
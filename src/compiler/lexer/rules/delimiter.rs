@@ -1,5 +1,4 @@
-use std::iter::Peekable;
-
+use super::Cursor;
 use crate::language::lexicon::token::Kind::*;
 use crate::language::lexicon::{Symbol, Token};
 use crate::language::utils::Span;
@@ -15,8 +14,7 @@ impl Token
 	/// ### Returns
 	/// * The next token if it can be constructed from the stream.
 	/// * `None` if the stream is empty.
-	pub fn from_delimiter<I>(stream: &mut Peekable<I>) -> Option<Self>
-	where I: Iterator<Item = Symbol>
+	pub fn from_delimiter(stream: &mut Cursor) -> Option<Self>
 	{
 		let Symbol {
 			position: start,
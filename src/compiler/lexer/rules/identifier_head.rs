@@ -1,5 +1,4 @@
-use std::iter::Peekable;
-
+use super::Cursor;
 use crate::language::lexicon::token;
 use crate::language::lexicon::{Symbol, Token};
 use crate::language::utils::Span;
@@ -15,27 +14,22 @@ impl Token
 	/// ### Returns
 	/// * The next token if it can be constructed from the stream.
 	/// * `None` if the stream is empty.
-	pub fn from_identifier_head<I>(stream: &mut Peekable<I>) -> Option<Self>
-	where I: Iterator<Item = Symbol>
+	pub fn from_identifier_head(stream: &mut Cursor) -> Option<Self>
 	{
 		let Symbol {
-			position: start,
-			character,
+			position: start, ..
 		} = stream.next()?;
 
 		let mut end = start;
 
-		let mut lexeme = String::from(character);
-
 		while let Some(symbol) = stream.next_if(Symbol::continues_identifier)
 		{
-			lexeme.push(symbol.character);
 			end = symbol.position;
 		}
 
 		Self {
 			span: Span { start, end },
-			kind: token::Kind::from_identifier(lexeme),
+			kind: token::Kind::from_identifier(stream.slice(start)),
 		}
 		.into()
 	}
@@ -58,39 +52,55 @@ impl Symbol
 
 impl token::Kind
 {
+	/// The words that `from_identifier` reserves as keywords or literals.
+	pub const KEYWORDS: [&'static str; 23] = [
+		"true", "false", "let", "var", "func", "struct", "enum", "if", "else",
+		"while", "match", "echo", "for", "in", "return", "break", "continue",
+		"import", "use", "and", "or", "not", "xor",
+	];
+
 	/// Creates a kind of token from an identifier lexeme.
 	///
+	/// The lexeme is borrowed from the source text, but tokens own their text,
+	/// so an identifier is copied into a new `String`.  Only keywords and
+	/// literals are created without allocating.
+	///
 	/// ### Parameters
 	/// * `lexeme` - The lexeme of the identifier.
 	///
 	/// ### Returns
 	/// * The kind of token.
-	pub fn from_identifier(lexeme: String) -> Self
+	pub fn from_identifier(lexeme: &str) -> Self
 	{
 		use token::Kind::*;
 
-		match lexeme.as_str()
+		match lexeme
 		{
 			"true" => Boolean(true),
 			"false" => Boolean(false),
 			"let" => Constant,
 			"var" => Variable,
 			"func" => Function,
+			"struct" => Struct,
+			"enum" => Enum,
 			// "proc" => Procedure,
 			"if" => If,
 			"else" => Else,
 			"while" => While,
+			"match" => Match,
 			"echo" => Echo,
 			"for" => For,
 			"in" => In,
 			"return" => Return,
 			"break" => Break,
 			"continue" => Continue,
+			"import" => Import,
+			"use" => Use,
 			"and" => And,
 			"or" => Or,
 			"not" => Not,
 			"xor" => Xor,
-			_ => Identifier(lexeme),
+			_ => Identifier(lexeme.to_string()),
 		}
 	}
 }
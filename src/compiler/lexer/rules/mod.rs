@@ -5,6 +5,7 @@ mod number_head;
 mod operator_head;
 mod string_head;
 mod symbol;
+mod trivia;
 mod whitespace;
 
 pub use super::*;
@@ -1,13 +1,15 @@
-use std::iter::Peekable;
+use anyhow::{bail, Result};
 
-use crate::language::lexicon::token::Kind::{Dot, Number};
+use super::*;
+use crate::compiler::Error;
+use crate::language::lexicon::token::Kind::{Dot, DotDot, DotDotEqual, Number};
 use crate::language::lexicon::{Symbol, Token};
-use crate::language::utils::Span;
+use crate::language::utils::{Position, SourceMap, Span};
 
 impl Token
 {
 	/// Creates a token from a stream that starts with a symbol that potentially
-	/// starts a number.
+	/// starts a number, or with a `.`, `..` or `..=` symbol.
 	///
 	/// ### Parameters
 	/// * `stream` - The stream of symbols.
@@ -16,51 +18,109 @@ impl Token
 	/// ### Returns
 	/// * The next token if it can be constructed from the stream.
 	/// * `None` if the stream is empty.
-	pub fn from_number_head<I>(
-		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
-	) -> Option<Self>
-	where
-		I: Iterator<Item = Symbol>,
+	///
+	/// ### Errors
+	/// * If the number literal is malformed.
+	pub fn try_from_number_head(
+		stream: &mut Cursor,
+		source: &SourceMap,
+	) -> Result<Option<Self>>
 	{
-		let Symbol {
+		let Some(Symbol {
 			position: start,
 			character: value,
-		} = stream.next()?;
-
-		let mut end = start;
-		let mut seen_dot = (value == '.');
+		}) = stream.next()
+		else
+		{
+			return Ok(None);
+		};
 
-		if seen_dot && !stream.peek().is_some_and(Symbol::is_digit)
+		if value == '.'
+			&& stream.peek().is_some_and(|symbol| symbol.character == '.')
 		{
-			return Self {
-				span: Span { start, end },
-				kind: Dot,
+			let mut end = stream.next().expect("Dot").position;
+			let mut kind = DotDot;
+
+			if let Some(symbol) = stream.next_if(|symbol| symbol.character == '=')
+			{
+				end = symbol.position;
+				kind = DotDotEqual;
 			}
-			.into();
+
+			let span = Span { start, end };
+			return Ok(Some(Self { span, kind }));
+		}
+
+		if value == '.' && !stream.peek().is_some_and(|symbol| symbol.is_digit())
+		{
+			let span = Span { start, end: start };
+			return Ok(Some(Self { span, kind: Dot }));
 		}
 
-		let mut lexeme = String::from(value);
+		let radix = match (value, stream.peek().map(|symbol| symbol.character))
+		{
+			('0', Some('x' | 'X')) => 16,
+			('0', Some('o' | 'O')) => 8,
+			('0', Some('b' | 'B')) => 2,
+			_ => 10,
+		};
+
+		let token = match radix
+		{
+			10 => Self::try_from_decimal(stream, source, start, value)?,
+			_ => Self::try_from_radix(stream, source, start, radix)?,
+		};
+
+		Ok(Some(token))
+	}
+
+	/// Creates a decimal number token whose first symbol has been consumed.
+	///
+	/// ### Rule
+	/// * _decimal_ -> _digits_? { `.` _digits_ }? _exponent_? `i`?
+	/// * _exponent_ -> { `e` | `E` } { `+` | `-` }? _digits_
+	/// * _digits_ -> _digit_ { `_`? _digit_ }*
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of symbols.
+	/// * `source` - The source code.
+	/// * `start` - The position of the first symbol.
+	/// * `value` - The first symbol.
+	///
+	/// ### Returns
+	/// * The number token.
+	///
+	/// ### Errors
+	/// * If the number literal is malformed, or is an integer other than zero
+	///   with leading zeros.
+	fn try_from_decimal(
+		stream: &mut Cursor,
+		source: &SourceMap,
+		start: Position,
+		value: char,
+	) -> Result<Self>
+	{
+		let mut end = start;
+		let mut seen_dot = (value == '.');
 
-		while let Some(&Symbol {
+		while let Some(Symbol {
 			position,
 			character,
 		}) = stream.peek()
 		{
-			let next = source[position.line].get(position.column + 1);
+			let next = stream.peek_second();
+
 			match character
 			{
-				_ if character.is_ascii_digit() =>
+				'0'..='9' | '_' =>
 				{
-					lexeme.push(character);
 					end = position;
 					stream.next();
 				}
 
-				'.' if !seen_dot && next.is_some_and(char::is_ascii_digit) =>
+				'.' if !seen_dot && next.is_some_and(|next| next.is_ascii_digit()) =>
 				{
 					seen_dot = true;
-					lexeme.push(character);
 					stream.next();
 				}
 
@@ -68,17 +128,158 @@ impl Token
 			}
 		}
 
+		if let Some(symbol) = stream.next_if(Symbol::starts_exponent)
+		{
+			end = symbol.position;
+
+			if let Some(sign) = stream.next_if(Symbol::is_sign)
+			{
+				end = sign.position;
+			}
+
+			if !stream.peek().is_some_and(|symbol| symbol.is_digit())
+			{
+				bail!(source.error(Span { start, end }, error::EXPONENT))
+			}
+
+			while let Some(symbol) = stream.next_if(Symbol::continues_digits)
+			{
+				end = symbol.position;
+			}
+		}
+
+		let lexeme = stream.slice(start);
+		let mut lexeme = Self::try_digits(lexeme, source, start, end, 10)?;
+
 		if let Some(symbol) = stream.next_if(|&symbol| symbol.character == 'i')
 		{
 			lexeme.push('j');
 			end = symbol.position;
 		}
 
-		Self {
+		// Only a zero may be written with leading zeros, as in Python.
+		let is_integer = !lexeme.contains(['.', 'e', 'E', 'j']);
+
+		let digits = lexeme.trim_start_matches('0');
+
+		if is_integer && digits.len() < lexeme.len() && !digits.is_empty()
+		{
+			bail!(source.error(Span { start, end }, error::LEADING_ZERO))
+		}
+
+		Ok(Self {
 			span: Span { start, end },
 			kind: Number(lexeme),
+		})
+	}
+
+	/// Creates a hexadecimal, octal or binary number token whose leading `0` has
+	/// been consumed.
+	///
+	/// ### Rule
+	/// * _radix_ -> `0` { `x` | `o` | `b` } _digits_
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of symbols.
+	/// * `source` - The source code.
+	/// * `start` - The position of the leading `0`.
+	/// * `radix` - The base of the number.
+	///
+	/// ### Returns
+	/// * The number token.
+	///
+	/// ### Errors
+	/// * If the number literal is malformed.
+	fn try_from_radix(
+		stream: &mut Cursor,
+		source: &SourceMap,
+		start: Position,
+		radix: u32,
+	) -> Result<Self>
+	{
+		let mut end = stream.next().expect("Radix prefix").position;
+		let digits = stream.here();
+
+		while let Some(symbol) = stream.next_if(|symbol| {
+			symbol.character.is_ascii_alphanumeric() || symbol.character == '_'
+		})
+		{
+			if symbol.character != '_' && !symbol.character.is_digit(radix)
+			{
+				let span = Span {
+					start: symbol.position,
+					end: symbol.position,
+				};
+				bail!(source.error(span, error::DIGIT))
+			}
+
+			end = symbol.position;
+		}
+
+		let digits = stream.slice(digits);
+
+		if digits.is_empty()
+		{
+			bail!(source.error(Span { start, end }, error::DIGITS))
+		}
+
+		let digits = Self::try_digits(digits, source, start, end, radix)?;
+
+		let prefix = match radix
+		{
+			16 => "0x",
+			8 => "0o",
+			_ => "0b",
+		};
+
+		Ok(Self {
+			span: Span { start, end },
+			kind: Number(format!("{prefix}{digits}")),
+		})
+	}
+
+	/// Checks the digit separators of a number literal and removes them.
+	///
+	/// ### Parameters
+	/// * `digits` - The text of the literal.
+	/// * `source` - The source code.
+	/// * `start` - The start of the literal.
+	/// * `end` - The end of the literal.
+	/// * `radix` - The base of the literal.
+	///
+	/// ### Returns
+	/// * The literal without digit separators.
+	///
+	/// ### Errors
+	/// * If a separator does not sit between two digits.
+	fn try_digits(
+		digits: &str,
+		source: &SourceMap,
+		start: Position,
+		end: Position,
+		radix: u32,
+	) -> Result<String>
+	{
+		let characters: Vec<_> = digits.chars().collect();
+
+		for (index, &character) in characters.iter().enumerate()
+		{
+			let is_digit = |offset: Option<usize>| {
+				offset
+					.and_then(|offset| characters.get(offset))
+					.is_some_and(|character| character.is_digit(radix))
+			};
+
+			let is_between =
+				is_digit(index.checked_sub(1)) && is_digit(Some(index + 1));
+
+			if character == '_' && !is_between
+			{
+				bail!(source.error(Span { start, end }, error::SEPARATOR))
+			}
 		}
-		.into()
+
+		Ok(digits.replace('_', ""))
 	}
 }
 
@@ -93,4 +294,34 @@ impl Symbol
 	{
 		self.character.is_ascii_digit()
 	}
+
+	/// Determines if the symbol can continue a run of digits.
+	///
+	/// ### Returns
+	/// * `true` if the symbol is a digit or a digit separator.
+	/// * `false` otherwise.
+	pub fn continues_digits(&self) -> bool
+	{
+		self.is_digit() || self.character == '_'
+	}
+
+	/// Determines if the symbol starts the exponent of a number.
+	///
+	/// ### Returns
+	/// * `true` if the symbol is `e` or `E`.
+	/// * `false` otherwise.
+	pub fn starts_exponent(&self) -> bool
+	{
+		matches!(self.character, 'e' | 'E')
+	}
+
+	/// Determines if the symbol is the sign of an exponent.
+	///
+	/// ### Returns
+	/// * `true` if the symbol is `+` or `-`.
+	/// * `false` otherwise.
+	pub fn is_sign(&self) -> bool
+	{
+		matches!(self.character, '+' | '-')
+	}
 }
@@ -1,5 +1,4 @@
-use std::iter::Peekable;
-
+use super::Cursor;
 use crate::language::lexicon::token;
 use crate::language::lexicon::{Symbol, Token};
 use crate::language::utils::Span;
@@ -15,8 +14,7 @@ impl Token
 	/// ### Returns
 	/// * The next token if it can be constructed from the stream.
 	/// * `None` if the stream is empty.
-	pub fn from_operator_head<I>(stream: &mut Peekable<I>) -> Option<Self>
-	where I: Iterator<Item = Symbol>
+	pub fn from_operator_head(stream: &mut Cursor) -> Option<Self>
 	{
 		let Symbol {
 			position: start,
@@ -25,28 +23,25 @@ impl Token
 
 		let mut end = start;
 
-		let mut lexeme = String::from(character);
-
 		if let Some(symbol) = stream.next_if(|&symbol| {
-			let lexeme = format!("{}{}", character, symbol.character);
 			matches!(
-				lexeme.as_str(),
-				"->"
-					| "-=" | "*="
-					| "/=" | "%="
-					| "^=" | "=="
-					| "!=" | ">="
-					| "<=" | "+="
+				(character, symbol.character),
+				('-', '>')
+					| ('=', '>') | ('-', '=')
+					| ('*', '=') | ('/', '=')
+					| ('%', '=') | ('^', '=')
+					| ('=', '=') | ('!', '=')
+					| ('>', '=') | ('<', '=')
+					| ('+', '=')
 			)
 		})
 		{
-			lexeme.push(symbol.character);
 			end = symbol.position;
 		}
 
 		Self {
 			span: Span { start, end },
-			kind: token::Kind::from_operator(lexeme),
+			kind: token::Kind::from_operator(stream.slice(start)),
 		}
 		.into()
 	}
@@ -62,11 +57,11 @@ impl token::Kind
 	/// ### Returns
 	/// * The operator token if the lexeme is a valid operator.
 	/// * `None` otherwise.
-	pub fn from_operator(lexeme: String) -> Self
+	pub fn from_operator(lexeme: &str) -> Self
 	{
 		use token::Kind::*;
 
-		match lexeme.as_str()
+		match lexeme
 		{
 			"+" => Plus,
 			"-" => Minus,
@@ -76,6 +71,7 @@ impl token::Kind
 			"^" => Caret,
 			"=" => Equal,
 			"->" => ArrowRight,
+			"=>" => FatArrow,
 			"+=" => PlusEqual,
 			"-=" => MinusEqual,
 			"*=" => AsteriskEqual,
@@ -1,12 +1,12 @@
-use std::iter::Peekable;
+use std::mem;
 
 use anyhow::{bail, Result};
 
 use super::*;
 use crate::compiler::Error;
-use crate::language::lexicon::token;
+use crate::language::lexicon::token::{self, Segment};
 use crate::language::lexicon::{Symbol, Token};
-use crate::language::utils::Span;
+use crate::language::utils::{Position, SourceMap, Span};
 
 impl Token
 {
@@ -23,12 +23,10 @@ impl Token
 	///
 	/// ### Errors
 	/// * If the token cannot be created.
-	pub fn try_from_string_head<I>(
-		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+	pub fn try_from_string_head(
+		stream: &mut Cursor,
+		source: &SourceMap,
 	) -> Result<Option<Self>>
-	where
-		I: Iterator<Item = Symbol>,
 	{
 		// Consume and get the position of the opening quote.
 		let start = match stream.next()
@@ -37,39 +35,195 @@ impl Token
 			None => return Ok(None),
 		};
 
-		let mut end = start;
+		let mut segments = Vec::new();
+		let mut text = String::new();
 
-		let mut lexeme = String::new();
-
-		loop
+		let end = loop
 		{
 			match stream.next()
 			{
-				Some(symbol) if symbol.character == '"' =>
-				{
-					end = symbol.position;
-					break;
-				}
+				Some(Symbol {
+					position,
+					character: '"',
+				}) => break position,
+
+				Some(Symbol {
+					position,
+					character: '\\',
+				}) => text.push(Self::try_escape(stream, source, position)?),
 
-				Some(symbol) if symbol.character == '\n' =>
+				Some(Symbol {
+					position,
+					character: '{',
+				}) =>
 				{
-					lexeme.push_str("\\n");
+					segments.push(Segment::Text(mem::take(&mut text)));
+					let code = Self::try_interpolation(stream, source, position)?;
+					segments.push(Segment::Code(code));
 				}
 
-				Some(symbol) =>
+				Some(Symbol {
+					character: '\r', ..
+				}) if stream.peek().is_some_and(|next| next.character == '\n') =>
+				{}
+
+				Some(symbol) => text.push(symbol.character),
+
+				None =>
 				{
-					lexeme.push(symbol.character);
+					let span = Span { start, end: start };
+					bail!(source.error(span, error::QUOTE))
 				}
-
-				None => bail!(source.error(Span { start, end }, error::QUOTE)),
 			}
+		};
+
+		let kind = if segments.is_empty()
+		{
+			token::Kind::String(text)
 		}
+		else
+		{
+			segments.push(Segment::Text(text));
+			segments.retain(|segment| segment != &Segment::Text(String::new()));
+			token::Kind::Interpolation(segments)
+		};
 
 		let token = Self {
 			span: Span { start, end },
-			kind: token::Kind::String(lexeme),
+			kind,
 		};
 
 		Ok(Some(token))
 	}
+
+	/// Resolves an escape sequence whose backslash has just been consumed.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of symbols.
+	/// * `source` - The source code.
+	/// * `start` - The position of the backslash.
+	///
+	/// ### Returns
+	/// * The character the escape sequence stands for.
+	///
+	/// ### Errors
+	/// * If the escape sequence is unknown or malformed.
+	fn try_escape(
+		stream: &mut Cursor,
+		source: &SourceMap,
+		start: Position,
+	) -> Result<char>
+	{
+		let Some(Symbol {
+			position: mut end,
+			character,
+		}) = stream.next()
+		else
+		{
+			bail!(source.error(Span { start, end: start }, error::ESCAPE))
+		};
+
+		let character = match character
+		{
+			'n' => '\n',
+			't' => '\t',
+			'r' => '\r',
+			'0' => '\0',
+			'\\' | '"' | '\'' | '{' | '}' => character,
+
+			'u' =>
+			{
+				let mut digits = String::new();
+
+				if stream.next_if(|symbol| symbol.character == '{').is_some()
+				{
+					while let Some(symbol) = stream.next_if(Symbol::is_hex_digit)
+					{
+						digits.push(symbol.character);
+					}
+				}
+
+				match stream.next_if(|symbol| symbol.character == '}')
+				{
+					Some(symbol) => end = symbol.position,
+					None => bail!(source.error(Span { start, end }, error::UNICODE)),
+				}
+
+				let scalar = u32::from_str_radix(&digits, 16)
+					.ok()
+					.filter(|_| digits.len() <= 6)
+					.and_then(char::from_u32);
+
+				match scalar
+				{
+					Some(character) => character,
+					None => bail!(source.error(Span { start, end }, error::UNICODE)),
+				}
+			}
+
+			_ => bail!(source.error(Span { start, end }, error::ESCAPE)),
+		};
+
+		Ok(character)
+	}
+
+	/// Lexes the tokens of an interpolation whose opening brace has just been
+	/// consumed, up to and including its closing brace.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of symbols.
+	/// * `source` - The source code.
+	/// * `start` - The position of the opening brace.
+	///
+	/// ### Returns
+	/// * The tokens between the braces.
+	///
+	/// ### Errors
+	/// * If the interpolation is never closed.
+	/// * If any of its tokens cannot be created.
+	fn try_interpolation(
+		stream: &mut Cursor,
+		source: &SourceMap,
+		start: Position,
+	) -> Result<Vec<Self>>
+	{
+		let mut tokens = Vec::new();
+		let mut depth = 0_usize;
+
+		loop
+		{
+			let token = match Self::try_from_stream(stream, source)?
+			{
+				Some(token) => token,
+				None =>
+				{
+					let span = Span { start, end: start };
+					bail!(source.error(span, error::INTERPOLATION))
+				}
+			};
+
+			match token.kind
+			{
+				token::Kind::BraceRight if depth == 0 => return Ok(tokens),
+				token::Kind::BraceRight => depth -= 1,
+				token::Kind::BraceLeft => depth += 1,
+				_ => (),
+			}
+
+			tokens.push(token);
+		}
+	}
+}
+
+impl Symbol
+{
+	/// Determines if the symbol is a hexadecimal digit.
+	///
+	/// ### Returns
+	/// * `true` if the symbol is a hexadecimal digit.
+	/// * `false` otherwise.
+	pub fn is_hex_digit(&self) -> bool
+	{
+		self.character.is_ascii_hexdigit()
+	}
 }
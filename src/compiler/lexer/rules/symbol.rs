@@ -1,12 +1,10 @@
-use std::iter::Peekable;
-
 use anyhow::{bail, Result};
 
 use super::*;
 use crate::compiler::Error;
 use crate::language::lexicon::token::Kind::*;
 use crate::language::lexicon::{Symbol, Token};
-use crate::language::utils::Span;
+use crate::language::utils::{SourceMap, Span};
 
 impl Token
 {
@@ -23,12 +21,10 @@ impl Token
 	///
 	/// ### Errors
 	/// * If the token cannot be created.
-	pub fn try_from_symbol<I>(
-		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+	pub fn try_from_symbol(
+		stream: &mut Cursor,
+		source: &SourceMap,
 	) -> Result<Option<Self>>
-	where
-		I: Iterator<Item = Symbol>,
 	{
 		let Symbol {
 			position,
@@ -39,7 +35,7 @@ impl Token
 			None => return Ok(None),
 		};
 
-		let span = Span {
+		let mut span = Span {
 			start: position,
 			end: position,
 		};
@@ -47,7 +43,15 @@ impl Token
 		let kind = match character
 		{
 			',' => Comma,
-			':' => Colon,
+			':' => match stream.next_if(|symbol| symbol.character == ':')
+			{
+				Some(symbol) =>
+				{
+					span.end = symbol.position;
+					ColonColon
+				}
+				None => Colon,
+			},
 			';' => Semicolon,
 			'|' => Bar,
 			_ => bail!(source.error(span, error::SYMBOL)),
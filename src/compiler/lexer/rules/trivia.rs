@@ -0,0 +1,171 @@
+use anyhow::{bail, Result};
+
+use super::*;
+use crate::compiler::Error;
+use crate::language::lexicon::trivia::Kind::*;
+use crate::language::lexicon::trivia::{self, Trivia};
+use crate::language::lexicon::Symbol;
+use crate::language::utils::{Position, SourceMap, Span};
+
+impl Trivia
+{
+	/// Creates trivia from a stream that potentially starts with whitespace or
+	/// a comment.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of symbols.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The next piece of trivia.
+	/// * `None` if the stream does not start with trivia.
+	///
+	/// ### Errors
+	/// * If a block comment is never closed.
+	pub fn try_from_stream(
+		stream: &mut Cursor,
+		source: &SourceMap,
+	) -> Result<Option<Self>>
+	{
+		let start = stream.here();
+
+		let Some(kind) = Self::try_skip(stream, source)?
+		else
+		{
+			return Ok(None);
+		};
+
+		let text = stream.slice(start);
+		let last = text.chars().next_back().map_or(0, char::len_utf8);
+
+		let end = Position {
+			offset: stream.here().offset - last,
+			..start
+		};
+
+		Ok(Some(Self {
+			span: Span { start, end },
+			kind,
+			text: text.to_string(),
+		}))
+	}
+
+	/// Consumes the next piece of trivia without copying its text.
+	///
+	/// ### Rule
+	/// * _trivia_ -> _whitespace_ | _newline_ | _comment_ | _block_comment_
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of symbols.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The kind of the consumed trivia.
+	/// * `None` if the stream does not start with trivia.
+	///
+	/// ### Errors
+	/// * If a block comment is never closed.
+	pub fn try_skip(
+		stream: &mut Cursor,
+		source: &SourceMap,
+	) -> Result<Option<trivia::Kind>>
+	{
+		let Some(symbol) = stream.peek()
+		else
+		{
+			return Ok(None);
+		};
+
+		let kind = match symbol.character
+		{
+			'\n' =>
+			{
+				stream.next();
+				Newline
+			}
+
+			'\r' if stream.peek_second() == Some('\n') =>
+			{
+				stream.next();
+				stream.next();
+				Newline
+			}
+
+			_ if symbol.is_whitespace() =>
+			{
+				stream.next();
+				while stream.next_if(Symbol::is_blank).is_some()
+				{}
+				Whitespace
+			}
+
+			// Exactly three slashes start a documentation comment, which is a
+			// token rather than trivia.
+			'/' if stream.starts_with("///") && !stream.starts_with("////") =>
+			{
+				return Ok(None)
+			}
+
+			'/' if stream.starts_with("//") =>
+			{
+				while stream.next_if(|symbol| symbol.character != '\n').is_some()
+				{}
+				Comment
+			}
+
+			'/' if stream.starts_with("/*") =>
+			{
+				stream.next();
+				stream.next();
+
+				let mut depth = 1;
+
+				while depth > 0
+				{
+					if stream.starts_with("*/")
+					{
+						depth -= 1;
+					}
+					else if stream.starts_with("/*")
+					{
+						depth += 1;
+					}
+					else if stream.next().is_some()
+					{
+						continue;
+					}
+					else
+					{
+						let span = Span {
+							start: symbol.position,
+							end: symbol.position,
+						};
+						bail!(source.error(span, error::COMMENT))
+					}
+
+					stream.next();
+					stream.next();
+				}
+
+				BlockComment
+			}
+
+			_ => return Ok(None),
+		};
+
+		Ok(Some(kind))
+	}
+}
+
+impl Symbol
+{
+	/// Determines if the symbol is whitespace that does not end a line.
+	///
+	/// ### Returns
+	/// * `true` if the symbol is whitespace other than `\n` or `\r`.
+	/// * `false` otherwise.
+	pub fn is_blank(&self) -> bool
+	{
+		self.is_whitespace() && !matches!(self.character, '\n' | '\r')
+	}
+}
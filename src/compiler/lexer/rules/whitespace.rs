@@ -1,8 +1,8 @@
-use std::iter::Peekable;
-
 use anyhow::Result;
 
-use crate::language::lexicon::{Symbol, Token};
+use super::Cursor;
+use crate::language::lexicon::{Symbol, Token, Trivia};
+use crate::language::utils::SourceMap;
 
 impl Token
 {
@@ -18,14 +18,12 @@ impl Token
 	///
 	/// ### Errors
 	/// * If the token cannot be created.
-	pub fn try_from_whitespace<I>(
-		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+	pub fn try_from_whitespace(
+		stream: &mut Cursor,
+		source: &SourceMap,
 	) -> Result<Option<Self>>
-	where
-		I: Iterator<Item = Symbol>,
 	{
-		while stream.next_if(Symbol::is_whitespace).is_some()
+		while Trivia::try_skip(stream, source)?.is_some()
 		{}
 
 		Self::try_from_stream(stream, source)
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+use anyhow::{bail, Result};
+
+use crate::compiler::{Error, Project};
+use crate::language::utils::Span;
+
+/// The lints a programme can be checked for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, clap::ValueEnum)]
+pub enum Lint
+{
+	/// A constant, variable, parameter or pattern binding that is never used.
+	UnusedVariable,
+
+	/// A function that is never called nor used as a value.
+	UnusedFunction,
+
+	/// A variable declared with `var` that is never assigned a new value.
+	NeverReassigned,
+
+	/// A statement after a `return`, `break` or `continue`, which never runs.
+	UnreachableCode,
+
+	/// A `while true` loop that never breaks nor returns.
+	InfiniteLoop,
+
+	/// An `if` or `while` condition whose value is known before the programme
+	/// runs.
+	ConstantCondition,
+
+	/// A declaration that hides an earlier declaration of the same name.
+	Shadowing,
+}
+
+impl Lint
+{
+	/// Every lint.
+	pub const ALL: [Self; 7] = [
+		Self::UnusedVariable,
+		Self::UnusedFunction,
+		Self::NeverReassigned,
+		Self::UnreachableCode,
+		Self::InfiniteLoop,
+		Self::ConstantCondition,
+		Self::Shadowing,
+	];
+
+	/// Gets the code that names the lint on the command line and in warnings.
+	///
+	/// ### Returns
+	/// * The code, such as `unused-variable`.
+	pub fn code(self) -> &'static str
+	{
+		match self
+		{
+			Self::UnusedVariable => "unused-variable",
+			Self::UnusedFunction => "unused-function",
+			Self::NeverReassigned => "never-reassigned",
+			Self::UnreachableCode => "unreachable-code",
+			Self::InfiniteLoop => "infinite-loop",
+			Self::ConstantCondition => "constant-condition",
+			Self::Shadowing => "shadowing",
+		}
+	}
+}
+
+/// How a lint is reported when it finds something.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Level
+{
+	/// The lint is not reported.
+	Allow,
+
+	/// The lint is reported as a warning, which does not stop compilation.
+	#[default]
+	Warn,
+
+	/// The lint is reported as an error.
+	Deny,
+}
+
+/// The level of each lint.  A lint that is not configured warns.
+#[derive(Clone, Debug, Default)]
+pub struct Levels(HashMap<Lint, Level>);
+
+impl Levels
+{
+	/// Sets the level of a lint.
+	///
+	/// ### Parameters
+	/// * `lint` - The lint.
+	/// * `level` - Its level.
+	pub fn set(&mut self, lint: Lint, level: Level)
+	{
+		self.0.insert(lint, level);
+	}
+
+	/// Gets the level of a lint.
+	///
+	/// ### Parameters
+	/// * `lint` - The lint.
+	///
+	/// ### Returns
+	/// * Its level.
+	pub fn get(&self, lint: Lint) -> Level
+	{
+		self.0.get(&lint).copied().unwrap_or_default()
+	}
+}
+
+/// Something a lint found, tied to a span of the source code.
+///
+/// Warnings display as their report, which names the lint that found them and,
+/// once the project renders it, shows an excerpt of the source code.
+#[derive(Clone, Debug)]
+pub struct Warning
+{
+	/// The lint that found the problem.
+	pub lint: Lint,
+
+	/// The span of the problem.
+	pub span: Span,
+
+	/// The warning message.
+	pub message: String,
+
+	/// The text shown to the user.
+	report: String,
+}
+
+impl Warning
+{
+	/// Creates a warning whose report is its message and the code of its
+	/// lint.
+	///
+	/// ### Parameters
+	/// * `lint` - The lint that found the problem.
+	/// * `span` - The span of the problem.
+	/// * `message` - The warning message.
+	///
+	/// ### Returns
+	/// * The new warning.
+	pub fn new(lint: Lint, span: Span, message: String) -> Self
+	{
+		Self {
+			lint,
+			span,
+			report: format!("{message} [{}]", lint.code()),
+			message,
+		}
+	}
+}
+
+impl Display for Warning
+{
+	fn fmt(&self, formatter: &mut Formatter) -> fmt::Result
+	{
+		write!(formatter, "{}", self.report)
+	}
+}
+
+impl Project
+{
+	/// Lints every module of the project.
+	///
+	/// The functions and constants declared at the top level of an imported
+	/// module are exported, so they are never reported as unused.
+	///
+	/// ### Parameters
+	/// * `levels` - The level of each lint.
+	///
+	/// ### Returns
+	/// * What the lints that warn found, each reported with an excerpt of the
+	///   source code.  Imported modules come first and the entry file last.
+	///
+	/// ### Errors
+	/// * If a lint that is denied finds something.
+	pub fn lint(&self, levels: &Levels) -> Result<Vec<Warning>>
+	{
+		let count = self.modules().count();
+		let mut warnings = Vec::new();
+
+		for (index, (_, programme)) in self.modules().enumerate()
+		{
+			for warning in programme.lint(index + 1 == count)
+			{
+				let report = self.source().error(warning.span, &warning.report);
+
+				match levels.get(warning.lint)
+				{
+					Level::Allow => (),
+					Level::Warn => warnings.push(Warning {
+						report: report.to_string(),
+						..warning
+					}),
+					Level::Deny => bail!(report),
+				}
+			}
+		}
+
+		Ok(warnings)
+	}
+}
@@ -0,0 +1,4 @@
+pub mod lint;
+mod pass;
+
+pub use lint::*;
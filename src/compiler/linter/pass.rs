@@ -0,0 +1,494 @@
+use std::collections::HashSet;
+
+use super::lint::{Lint, Warning};
+use crate::compiler::semanter::flow::Graph;
+use crate::compiler::semanter::table::Binder;
+use crate::language::grammar::declaration::{self, Declaration};
+use crate::language::grammar::expression::{self, Expression};
+use crate::language::grammar::functions::Params;
+use crate::language::grammar::pattern::{self, Pattern};
+use crate::language::grammar::statement::{self, Statement};
+use crate::language::grammar::visit::{self, Visit};
+use crate::language::grammar::{
+	Arm, AssignmentStmt, Block, BreakStmt, FunctionCall, FunctionDclr, IfStmt,
+	Lambda, Programme, ReturnStmt, WhileStmt,
+};
+use crate::language::lexicon::token;
+use crate::language::utils::Span;
+
+impl Programme
+{
+	/// Lints the programme.
+	///
+	/// ### Parameters
+	/// * `is_entry` - Whether the programme is the entry file of its project,
+	///   whose top-level functions and constants no other module can use.
+	///
+	/// ### Returns
+	/// * What the lints found, in the order of the source code.
+	pub(crate) fn lint(&self, is_entry: bool) -> Vec<Warning>
+	{
+		let mut linter = Linter {
+			is_entry,
+			scopes: Vec::new(),
+			unreachable: HashSet::new(),
+			warnings: Vec::new(),
+		};
+
+		linter.flow(self.graph());
+		linter.scope(&self.statements);
+		linter
+			.warnings
+			.sort_by_key(|warning| warning.span.start.offset);
+		linter.warnings
+	}
+}
+
+/// What declares a name the linter follows.
+#[derive(Clone, Copy, PartialEq)]
+enum Declared
+{
+	/// A binding to a value.
+	Binding(Binder),
+
+	/// A function declaration.
+	Function,
+}
+
+/// A name declared in a scope.
+struct Local<'ast>
+{
+	/// The name.
+	name: &'ast str,
+
+	/// What declares the name.
+	declared: Declared,
+
+	/// The span of the declaration.
+	span: Span,
+
+	/// Whether the name is used.
+	is_used: bool,
+
+	/// Whether the name is assigned a new value.
+	is_reassigned: bool,
+}
+
+/// A visitor that follows the names declared in a programme, from their
+/// declaration to the end of their scope, and what happens to them.
+struct Linter<'ast>
+{
+	/// Whether the programme is the entry file of its project.
+	is_entry: bool,
+
+	/// The names declared in each scope, innermost scope last.
+	scopes: Vec<Vec<Local<'ast>>>,
+
+	/// The spans of the statements that can never run, in the bodies visited
+	/// so far.
+	unreachable: HashSet<Span>,
+
+	/// What the lints found.
+	warnings: Vec<Warning>,
+}
+
+impl<'ast> Linter<'ast>
+{
+	/// Reports something a lint found.
+	fn warn(&mut self, lint: Lint, span: Span, message: String)
+	{
+		self.warnings.push(Warning::new(lint, span, message));
+	}
+
+	/// Lints the statements of a scope, then reports the names declared in it
+	/// that are never used or never reassigned.
+	///
+	/// The functions of a scope are declared before its statements, so that
+	/// they may be called before their declaration.
+	fn scope(&mut self, statements: impl IntoIterator<Item = &'ast Statement>)
+	{
+		let statements: Vec<_> = statements.into_iter().collect();
+		self.scopes.push(Vec::new());
+
+		for statement in &statements
+		{
+			if let statement::Kind::Declaration(Declaration {
+				kind: declaration::Kind::Function(function),
+				span,
+				..
+			}) = &statement.kind
+			{
+				self.declare(&function.name, Declared::Function, *span);
+			}
+		}
+
+		// Only the first statement that can never run after one that can is
+		// reported.
+		let mut previous = None;
+
+		for statement in statements
+		{
+			if let Some(previous) = previous.filter(|previous: &&Statement| {
+				self.unreachable.contains(&statement.span)
+					&& !self.unreachable.contains(&previous.span)
+			})
+			{
+				let reason = match previous.kind
+				{
+					statement::Kind::Return(_) => " after `return`",
+					statement::Kind::Break(_) => " after `break`",
+					statement::Kind::Continue(_) => " after `continue`",
+					statement::Kind::Match(_) =>
+					{
+						", as every arm of the `match` before it leaves"
+					}
+					_ => ", as every branch of the `if` before it leaves",
+				};
+
+				let message = format!("Unreachable statement{reason}");
+				self.warn(Lint::UnreachableCode, statement.span, message);
+			}
+
+			self.visit_statement(statement);
+			previous = Some(statement);
+		}
+
+		self.end();
+	}
+
+	/// Notes the statements of a body that can never run.
+	fn flow(&mut self, graph: Graph)
+	{
+		let unreachable = graph.unreachable().map(|statement| statement.span);
+		self.unreachable.extend(unreachable);
+	}
+
+	/// Ends the innermost scope, reporting the names declared in it that are
+	/// never used or never reassigned.
+	fn end(&mut self)
+	{
+		let is_exported = self.scopes.len() == 1 && !self.is_entry;
+		let locals = self.scopes.pop().unwrap_or_default();
+
+		for local in locals
+		{
+			let name = local.name;
+
+			if name.starts_with('_')
+			{
+				continue;
+			}
+
+			match local.declared
+			{
+				Declared::Function if !local.is_used && !is_exported =>
+				{
+					let message = format!("Function `{name}` is never used");
+					self.warn(Lint::UnusedFunction, local.span, message);
+				}
+				Declared::Binding(binder) =>
+				{
+					let is_exported = is_exported && binder == Binder::Let;
+
+					if !local.is_used && !is_exported
+					{
+						let message = format!("`{name}` is never used");
+						self.warn(Lint::UnusedVariable, local.span, message);
+					}
+
+					if binder == Binder::Var && !local.is_reassigned
+					{
+						let message = format!(
+							"`{name}` is never reassigned; declare it with `let` instead"
+						);
+						self.warn(Lint::NeverReassigned, local.span, message);
+					}
+				}
+				Declared::Function => (),
+			}
+		}
+	}
+
+	/// Declares a name in the innermost scope, reporting whether it shadows an
+	/// earlier declaration.
+	fn declare(&mut self, name: &'ast str, declared: Declared, span: Span)
+	{
+		let scope = self.scopes.last().expect("scope");
+		let is_hoisted = scope
+			.iter()
+			.any(|local| local.name == name && local.span == span);
+
+		if name == "_" || is_hoisted
+		{
+			return;
+		}
+
+		let is_declared =
+			self.scopes.iter().flatten().any(|local| local.name == name);
+
+		if is_declared && !name.starts_with('_')
+		{
+			let message = format!("`{name}` shadows an earlier declaration");
+			self.warn(Lint::Shadowing, span, message);
+		}
+
+		let scope = self.scopes.last_mut().expect("scope");
+		scope.push(Local {
+			name,
+			declared,
+			span,
+			is_used: false,
+			is_reassigned: false,
+		});
+	}
+
+	/// Declares the parameters of a function or lambda in a new scope.
+	fn parameters(&mut self, parameters: &'ast Option<Params>)
+	{
+		self.scopes.push(Vec::new());
+
+		for parameter in parameters.iter().flat_map(|p| &p.parameters)
+		{
+			let declared = Declared::Binding(Binder::Parameter);
+			self.declare(&parameter.name, declared, parameter.span);
+		}
+	}
+
+	/// Finds the declaration a name refers to.
+	fn lookup(&mut self, name: &str) -> Option<&mut Local<'ast>>
+	{
+		self
+			.scopes
+			.iter_mut()
+			.rev()
+			.find_map(|scope| scope.iter_mut().rev().find(|local| local.name == name))
+	}
+}
+
+impl<'ast> Visit<'ast> for Linter<'ast>
+{
+	fn visit_block(&mut self, node: &'ast Block)
+	{
+		self.scope(node.statements.iter().flatten());
+	}
+
+	fn visit_declaration(&mut self, node: &'ast Declaration)
+	{
+		let binder = |is_mutable| match is_mutable
+		{
+			true => Declared::Binding(Binder::Var),
+			false => Declared::Binding(Binder::Let),
+		};
+
+		match &node.kind
+		{
+			declaration::Kind::Variable {
+				name,
+				value,
+				is_mutable,
+			} =>
+			{
+				self.visit_expression(value);
+				self.declare(name, binder(*is_mutable), node.span);
+			}
+			declaration::Kind::Destructuring {
+				names,
+				value,
+				is_mutable,
+			} =>
+			{
+				self.visit_expression(value);
+
+				for name in names
+				{
+					self.declare(name, binder(*is_mutable), node.span);
+				}
+			}
+			declaration::Kind::Function(function) =>
+			{
+				self.declare(&function.name, Declared::Function, node.span);
+				self.visit_function(function);
+			}
+			declaration::Kind::Struct(_) | declaration::Kind::Enum(_) => (),
+		}
+	}
+
+	fn visit_function(&mut self, node: &'ast FunctionDclr)
+	{
+		self.flow(node.graph());
+		self.parameters(&node.parameters);
+		self.visit_block(&node.body);
+		self.end();
+	}
+
+	fn visit_lambda(&mut self, node: &'ast Lambda)
+	{
+		self.flow(node.graph());
+		self.parameters(&node.parameters);
+		visit::walk_lambda(self, node);
+		self.end();
+	}
+
+	fn visit_arm(&mut self, node: &'ast Arm)
+	{
+		self.scopes.push(Vec::new());
+		visit::walk_arm(self, node);
+		self.end();
+	}
+
+	fn visit_pattern(&mut self, node: &'ast Pattern)
+	{
+		if let pattern::Kind::Variant { bindings, .. } = &node.kind
+		{
+			for binding in bindings
+			{
+				if let Some(name) = binding.identifier()
+				{
+					let declared = Declared::Binding(Binder::Pattern);
+					self.declare(name, declared, binding.span);
+				}
+			}
+		}
+	}
+
+	fn visit_if(&mut self, node: &'ast IfStmt)
+	{
+		let conditions = node.else_ifs.iter().map(|else_if| &else_if.condition);
+
+		for condition in std::iter::once(&node.condition).chain(conditions)
+		{
+			if is_constant(condition)
+			{
+				let message = String::from("This condition is constant");
+				self.warn(Lint::ConstantCondition, condition.span, message);
+			}
+		}
+
+		visit::walk_if(self, node);
+	}
+
+	fn visit_while(&mut self, node: &'ast WhileStmt)
+	{
+		if is_true(&node.condition)
+		{
+			let mut exits = Exits::default();
+			exits.visit_block(&node.body);
+
+			if !exits.found
+			{
+				let message = String::from("This `while true` loop never breaks");
+				self.warn(Lint::InfiniteLoop, node.condition.span, message);
+			}
+		}
+		else if is_constant(&node.condition)
+		{
+			let message = String::from("This condition is constant");
+			self.warn(Lint::ConstantCondition, node.condition.span, message);
+		}
+
+		visit::walk_while(self, node);
+	}
+
+	fn visit_assignment(&mut self, node: &'ast AssignmentStmt)
+	{
+		if let Some(local) = self.lookup(&node.identifier)
+		{
+			local.is_reassigned = true;
+		}
+
+		visit::walk_assignment(self, node);
+	}
+
+	fn visit_function_call(&mut self, node: &'ast FunctionCall)
+	{
+		if let Some(local) = self.lookup(&node.name)
+		{
+			local.is_used = true;
+		}
+
+		visit::walk_function_call(self, node);
+	}
+
+	fn visit_expression(&mut self, node: &'ast Expression)
+	{
+		if let expression::Kind::Identifier(token) = &node.kind
+		{
+			let name = token.identifier().unwrap_or_default();
+
+			if let Some(local) = self.lookup(name)
+			{
+				local.is_used = true;
+			}
+		}
+
+		visit::walk_expression(self, node);
+	}
+}
+
+/// A visitor that finds whether a loop body can leave the loop, by a `break`
+/// of the loop itself or by a `return`.
+#[derive(Default)]
+struct Exits
+{
+	/// The number of loops within the body around the node being visited.
+	depth: usize,
+
+	/// Whether the body can leave the loop.
+	found: bool,
+}
+
+impl<'ast> Visit<'ast> for Exits
+{
+	fn visit_while(&mut self, node: &'ast WhileStmt)
+	{
+		self.depth += 1;
+		visit::walk_while(self, node);
+		self.depth -= 1;
+	}
+
+	fn visit_break(&mut self, _node: &'ast BreakStmt)
+	{
+		self.found |= self.depth == 0;
+	}
+
+	fn visit_return(&mut self, _node: &'ast ReturnStmt)
+	{
+		self.found = true;
+	}
+
+	// A `return` in a nested function or lambda only leaves that function.
+	fn visit_function(&mut self, _node: &'ast FunctionDclr) {}
+
+	fn visit_lambda(&mut self, _node: &'ast Lambda) {}
+}
+
+/// Determines if an expression is the literal `true`, perhaps parenthesised.
+fn is_true(expression: &Expression) -> bool
+{
+	match &expression.kind
+	{
+		expression::Kind::Literal(token) =>
+		{
+			token.kind == token::Kind::Boolean(true)
+		}
+		expression::Kind::Parenthesised(expression) => is_true(expression),
+		_ => false,
+	}
+}
+
+/// Determines if the value of an expression is known before the programme
+/// runs, since it is made of literals alone.
+fn is_constant(expression: &Expression) -> bool
+{
+	match &expression.kind
+	{
+		expression::Kind::Literal(_) => true,
+		expression::Kind::Parenthesised(expression) => is_constant(expression),
+		expression::Kind::Prefix { operand, .. } => is_constant(operand),
+		expression::Kind::Infix { left, right, .. } =>
+		{
+			is_constant(left) && is_constant(right)
+		}
+		_ => false,
+	}
+}
@@ -0,0 +1,22 @@
+/// An error message indicating an imported file could not be read.
+pub const MISSING: &str = "This module could not be found";
+
+/// An error message indicating an imported file lies outside the project.
+pub const OUTSIDE: &str = "Modules must live inside the project directory";
+
+/// An error message indicating a module name is not a valid identifier.
+pub const NAME: &str = "Module file names must be valid identifiers";
+
+/// An error message indicating a module name that the generated Python cannot
+/// bind.
+pub const RESERVED: &str =
+	"Module file names must not be Python keywords or names the generated Python uses";
+
+/// An error message indicating two imports bind the same name.
+pub const DUPLICATE: &str = "A module with this name has already been imported";
+
+/// An error message indicating a cycle of imports.
+pub const CYCLE: &str = "This import creates a cycle";
+
+/// Error message when the entry file cannot be read.
+pub const READ_SOURCE: &str = "Failed to read the source file 📄";
@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use super::*;
+use crate::compiler::lexer::Lex;
+use crate::compiler::parser::{Parse, Tree};
+use crate::compiler::semanter::table::Table;
+use crate::compiler::synthesiser::Version;
+use crate::compiler::Error;
+use crate::language::grammar::statement::Kind;
+use crate::language::grammar::Programme;
+use crate::language::utils::{SourceMap, Span};
+
+/// The names every synthesised Python module imports.
+const IMPORTED: [&str; 4] = ["np", "numpy", "dataclass", "dataclasses"];
+
+/// The keywords of Python, which cannot name a Python module.
+const KEYWORDS: [&str; 35] = [
+	"False", "None", "True", "and", "as", "assert", "async", "await", "break",
+	"class", "continue", "def", "del", "elif", "else", "except", "finally",
+	"for", "from", "global", "if", "import", "in", "is", "lambda", "nonlocal",
+	"not", "or", "pass", "raise", "return", "try", "while", "with", "yield",
+];
+
+/// A Quark project made of an entry file and every module it imports.
+///
+/// Each file is lexed, parsed and analysed exactly once, no matter how many
+/// other modules import it.
+pub struct Project
+{
+	/// The directory containing the entry file.
+	root: PathBuf,
+
+	/// The source files of every loaded module.
+	source: SourceMap,
+
+	/// The analysed modules, with every module placed after its imports.
+	modules: Vec<Module>,
+
+	/// The exports of each analysed module, keyed by its canonical path.
+	exports: HashMap<PathBuf, Table>,
+
+	/// The canonical paths of the modules currently being loaded.
+	stack: Vec<PathBuf>,
+}
+
+/// A single analysed Quark file.
+struct Module
+{
+	/// The path of the file relative to the project root.
+	path: PathBuf,
+
+	/// The abstract syntax tree of the file.
+	tree: Tree,
+}
+
+impl Project
+{
+	/// Loads a project starting from its entry file.
+	///
+	/// ### Parameters
+	/// * `entry` - The path to the entry Quark file.
+	///
+	/// ### Returns
+	/// * The loaded and analysed project.
+	///
+	/// ### Errors
+	/// * If any module cannot be read, parsed or analysed.
+	/// * If the imports of the project form a cycle.
+	pub fn load(entry: &Path) -> Result<Self>
+	{
+		let code = fs::read_to_string(entry).context(error::READ_SOURCE)?;
+		Self::load_source(entry, code)
+	}
+
+	/// Loads a project from the contents of its entry file, which need not
+	/// match the file on disk.  The entry file is always the first file of the
+	/// project's source map.
+	///
+	/// ### Parameters
+	/// * `entry` - The path to the entry Quark file.
+	/// * `code` - The contents of the entry file.
+	///
+	/// ### Returns
+	/// * The loaded and analysed project.
+	///
+	/// ### Errors
+	/// * If any module cannot be read, parsed or analysed.
+	/// * If the imports of the project form a cycle.
+	pub fn load_source(entry: &Path, code: String) -> Result<Self>
+	{
+		let entry = entry.canonicalize().unwrap_or_else(|_| entry.to_path_buf());
+		let root = entry.parent().map(Path::to_path_buf).unwrap_or_default();
+
+		let mut project = Self {
+			root,
+			source: SourceMap::new(),
+			modules: Vec::new(),
+			exports: HashMap::new(),
+			stack: Vec::new(),
+		};
+
+		project.module(entry, code)?;
+
+		Ok(project)
+	}
+
+	/// Gets the modules of the project.
+	///
+	/// ### Returns
+	/// * The path of each module relative to the project root, paired with its
+	///   programme.  Imported modules come first and the entry file last.
+	pub fn modules(&self) -> impl Iterator<Item = (&Path, &Programme)>
+	{
+		self
+			.modules
+			.iter()
+			.map(|Module { path, tree: Tree(programme) }| {
+				(path.as_path(), programme)
+			})
+	}
+
+	/// Gets the source files of every module of the project.
+	///
+	/// ### Returns
+	/// * The source map of the project.
+	pub fn source(&self) -> &SourceMap
+	{
+		&self.source
+	}
+
+	/// Synthesises every module of the project into Python.
+	///
+	/// ### Returns
+	/// * The path of each Python module relative to the project root, paired
+	///   with its code.  Imported modules come first and the entry file last.
+	pub fn synthesise(self) -> Vec<(PathBuf, String)>
+	{
+		self.synthesise_for(Version::default())
+	}
+
+	/// Synthesises every module of the project into Python code for a version
+	/// of Python.
+	///
+	/// ### Parameters
+	/// * `version` - The version of Python the code must run on.
+	///
+	/// ### Returns
+	/// * The path of each Python module relative to the project root, paired
+	///   with its code.  Imported modules come first and the entry file last.
+	pub fn synthesise_for(self, version: Version) -> Vec<(PathBuf, String)>
+	{
+		self
+			.modules
+			.into_iter()
+			.map(|Module { path, tree }| {
+				(path.with_extension("py"), tree.synthesise_for(version))
+			})
+			.collect()
+	}
+
+	/// Lexes, parses and analyses a module, loading its imports first.
+	///
+	/// ### Parameters
+	/// * `path` - The canonical path of the module.
+	/// * `code` - The contents of the module.
+	///
+	/// ### Returns
+	/// * The symbols exported by the module.
+	///
+	/// ### Errors
+	/// * If the module or any of its imports cannot be compiled.
+	fn module(&mut self, path: PathBuf, code: String) -> Result<Table>
+	{
+		let relative = path.strip_prefix(&self.root).unwrap_or(&path);
+		let file = self.source.add(relative, code);
+
+		self.stack.push(path.clone());
+
+		let Tree(mut programme) = file.lex(&self.source)?.parse(&self.source)?;
+		let mut modules = HashMap::new();
+
+		for statement in &mut programme.statements
+		{
+			let Kind::Import(import) = &mut statement.kind
+			else
+			{
+				continue;
+			};
+
+			let directory = path.parent().unwrap_or(&self.root);
+
+			let Ok(dependency) = directory.join(&import.path).canonicalize()
+			else
+			{
+				bail!(self.source.error(import.span, error::MISSING))
+			};
+
+			let Ok(relative) = dependency.strip_prefix(&self.root)
+			else
+			{
+				bail!(self.source.error(import.span, error::OUTSIDE))
+			};
+
+			let components: Vec<_> = relative
+				.with_extension("")
+				.components()
+				.map(|component| match component
+				{
+					Component::Normal(name) => name.to_string_lossy().into_owned(),
+					_ => unreachable!(),
+				})
+				.collect();
+
+			if !components.iter().all(|name| is_identifier(name))
+			{
+				bail!(self.source.error(import.span, error::NAME))
+			}
+
+			if let Some(name) = components.iter().find(|name| is_reserved(name))
+			{
+				let message = format!("{}: `{name}`", error::RESERVED);
+				bail!(self.source.error(import.span, &message))
+			}
+
+			import.module = components.join(".");
+
+			let exports = self.dependency(&dependency, import.span)?;
+
+			if modules.insert(import.name.clone(), exports).is_some()
+			{
+				bail!(self.source.error(import.span, error::DUPLICATE))
+			}
+		}
+
+		let (tree, exports) =
+			Tree(programme).analyse_module(&self.source, modules)?;
+
+		self.stack.pop();
+
+		let relative = path.strip_prefix(&self.root).unwrap_or(&path);
+		self.modules.push(Module {
+			path: relative.to_path_buf(),
+			tree,
+		});
+		self.exports.insert(path, exports.clone());
+
+		Ok(exports)
+	}
+
+	/// Loads an imported module unless it has already been loaded.
+	///
+	/// ### Parameters
+	/// * `path` - The canonical path of the imported module.
+	/// * `span` - The span of the import statement.
+	///
+	/// ### Returns
+	/// * The symbols exported by the imported module.
+	///
+	/// ### Errors
+	/// * If the import creates a cycle.
+	/// * If the imported module cannot be compiled.
+	fn dependency(&mut self, path: &Path, span: Span) -> Result<Table>
+	{
+		if let Some(exports) = self.exports.get(path)
+		{
+			return Ok(exports.clone());
+		}
+
+		if let Some(index) = self.stack.iter().position(|module| module == path)
+		{
+			let cycle = self.stack[index..]
+				.iter()
+				.chain([&path.to_path_buf()])
+				.map(|module| {
+					module
+						.strip_prefix(&self.root)
+						.unwrap_or(module)
+						.display()
+						.to_string()
+				})
+				.collect::<Vec<_>>()
+				.join(" -> ");
+
+			let message = format!("{}: {cycle}", error::CYCLE);
+			bail!(self.source.error(span, &message))
+		}
+
+		let Ok(code) = fs::read_to_string(path)
+		else
+		{
+			bail!(self.source.error(span, error::MISSING))
+		};
+
+		self.module(path.to_path_buf(), code)
+	}
+}
+
+/// Determines if a module name can be used as a Python identifier.
+///
+/// ### Parameters
+/// * `name` - The name of the module.
+///
+/// ### Returns
+/// * `true` if the name is a valid identifier.
+/// * `false` otherwise.
+fn is_identifier(name: &str) -> bool
+{
+	let mut characters = name.chars();
+
+	characters
+		.next()
+		.is_some_and(|first| first.is_alphabetic() || first == '_')
+		&& characters.all(|next| next.is_alphanumeric() || next == '_')
+}
+
+/// Determines if a module name would clash with the synthesised Python, as a
+/// Python keyword or as a name every module imports.
+///
+/// ### Parameters
+/// * `name` - The name of the module.
+///
+/// ### Returns
+/// * `true` if the name is reserved.
+/// * `false` otherwise.
+fn is_reserved(name: &str) -> bool
+{
+	IMPORTED.contains(&name) || KEYWORDS.contains(&name)
+}
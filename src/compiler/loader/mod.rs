@@ -0,0 +1,4 @@
+mod error;
+pub mod load;
+
+pub use load::*;
@@ -1,9 +1,29 @@
 pub mod compiler;
+pub mod documenter;
+pub mod emitter;
 mod error;
-mod lexer;
+pub mod formatter;
+pub mod lexer;
+pub mod linter;
+pub mod loader;
 mod parser;
+pub mod repl;
 mod semanter;
+#[cfg(feature = "serde")]
+pub mod server;
 mod synthesiser;
 
 pub use compiler::*;
+pub use documenter::{Document, Format};
+pub use emitter::{emit, Stage};
+#[cfg(feature = "serde")]
+pub use emitter::{Syntax, SCHEMA_VERSION};
+pub use formatter::{format, try_format};
+pub use linter::{Level, Levels, Lint, Warning};
+pub use loader::Project;
+pub use semanter::flow::{BasicBlock, BlockId, Graph};
+#[cfg(feature = "serde")]
+pub use server::serve;
+pub use synthesiser::Version;
 use error::*;
+pub use error::Diagnostic;
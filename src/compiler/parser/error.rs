@@ -41,3 +41,86 @@ pub const MATRIX_BRACKET: &str =
 pub const PARAMS_AFTER: &str = "Expected function params `(` after this";
 
 pub const COMMA: &str = "Expected `,` here";
+
+/// An error message indicating a missing module path.
+pub const PATH_AFTER: &str = "Expected a module path such as `\"lib/gates.q\"` after this";
+
+/// An error message indicating a missing `::`.
+pub const PATH_SEPARATOR: &str = "Expected `::` after this";
+
+/// An error message indicating an interpolation holds more than an expression.
+pub const INTERPOLATION_END: &str = "Expected the interpolation to end with `}` here";
+
+/// An error message indicating an unmatched delimiter.
+pub const DELIMITER: &str = "This delimiter is unmatched";
+
+/// An error message indicating a missing pattern.
+pub const PATTERN: &str =
+	"Expected a pattern such as `0`, `0b01`, `1..4`, `true` or `_` here";
+
+/// An error message indicating a range bound that is not a number.
+pub const BOUND_AFTER: &str = "Expected a number to end the range after this";
+
+/// An error message indicating a match statement without arms.
+pub const ARM: &str = "Expected at least one match arm here";
+
+/// An error message indicating a missing `=>`.
+pub const ARROW_AFTER: &str = "Expected `=>` after this";
+
+/// An error message indicating a missing body of match arms.
+pub const ARMS_AFTER: &str = "Expected match arms `{` `}` after this";
+
+/// An error message indicating an unmatched subscript bracket.
+pub const SUBSCRIPT_BRACKET: &str = "This bracket is unmatched. Try `]`";
+
+/// An error message indicating a missing index.
+pub const INDEX: &str = "Expected an index such as `0`, `1..3` or `:` here";
+
+/// An error message indicating an inclusive range without an end.
+pub const END_AFTER: &str =
+	"Expected an expression to end the range after this";
+
+/// An error message indicating a missing member name.
+pub const MEMBER_AFTER: &str =
+	"Expected a member name such as `len` or `shape` after this";
+
+/// An error message indicating unclosed lambda parameters.
+pub const LAMBDA_BAR: &str = "This bar is unmatched. Try `|`";
+
+/// An error message indicating a lambda without a body.
+pub const LAMBDA_BODY: &str =
+	"Expected an expression or a block `{` `}` after the lambda parameters";
+
+/// An error message indicating a missing type.
+pub const TYPE_AFTER: &str =
+	"Expected a type such as `Number` or the name of a struct after this";
+
+/// An error message indicating a function type without a return type.
+pub const TYPE_ARROW_AFTER: &str = "Expected `->` and a return type after this";
+
+/// An error message indicating a missing `:`.
+pub const COLON_AFTER: &str = "Expected `:` after this";
+
+/// An error message indicating a struct without a body of fields.
+pub const FIELDS_AFTER: &str = "Expected struct fields `{` `}` after this";
+
+/// An error message indicating an enum without a body of variants.
+pub const VARIANTS_AFTER: &str = "Expected enum variants `{` `}` after this";
+
+/// An error message indicating a missing variant name.
+pub const VARIANT_AFTER: &str = "Expected a variant name after this";
+
+/// An error message indicating a payload value bound to something other than a
+/// name.
+pub const BINDING: &str = "Expected a name or `_` to bind here";
+
+/// An error message indicating unmatched angle brackets.
+pub const ANGLE: &str = "This angle bracket is unmatched. Try `>`";
+
+/// An error message indicating a missing type parameter.
+pub const TYPE_PARAMETER: &str =
+	"Expected a type parameter such as `T` or a size parameter such as `n` here";
+
+/// An error message indicating a missing matrix size.
+pub const SIZE: &str =
+	"Expected a size such as `2` or a size parameter such as `n` here";
@@ -3,6 +3,7 @@ use anyhow::Result;
 use crate::compiler::parser::parse;
 use crate::language::grammar::*;
 use crate::language::lexicon::Token;
+use crate::language::utils::SourceMap;
 
 /// Types that can be parsed.
 ///
@@ -20,7 +21,7 @@ pub trait Parse
 	///
 	/// ### Errors
 	/// * If the Quark code cannot be parsed.
-	fn parse(self, source: &[Vec<char>]) -> Result<parse::Tree>;
+	fn parse(self, source: &SourceMap) -> Result<parse::Tree>;
 }
 
 /// The abstract syntax tree.
@@ -30,7 +31,7 @@ pub struct Tree(pub Programme);
 
 impl Parse for Vec<Token>
 {
-	fn parse(self, source: &[Vec<char>]) -> Result<parse::Tree>
+	fn parse(self, source: &SourceMap) -> Result<parse::Tree>
 	{
 		let mut stream = self.into_iter().peekable();
 
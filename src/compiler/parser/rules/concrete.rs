@@ -0,0 +1,311 @@
+use std::iter::Peekable;
+
+use anyhow::{bail, Result};
+
+use super::*;
+use crate::compiler::lexer::Lex;
+use crate::compiler::Error;
+use crate::language::grammar::concrete::{Element, Kind, Node, SyntaxTree};
+use crate::language::lexicon::token::Kind::*;
+use crate::language::lexicon::{Lexeme, Trivia};
+use crate::language::utils::{FileId, SourceMap};
+
+impl SyntaxTree
+{
+	/// Creates the concrete syntax tree of a source file.
+	///
+	/// ### Parameters
+	/// * `file` - The source file.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The concrete syntax tree.
+	///
+	/// ### Errors
+	/// * If the source file cannot be lexed.
+	/// * If its delimiters are unbalanced.
+	pub fn try_from_file(file: FileId, source: &SourceMap) -> Result<Self>
+	{
+		let (lexemes, end) = file.lex_lossless(source)?;
+		Self::try_from_lexemes(lexemes, end, source)
+	}
+
+	/// Creates a concrete syntax tree from lossless tokens.
+	///
+	/// ### Parameters
+	/// * `lexemes` - The lossless tokens.
+	/// * `end` - The trivia after the last token.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The concrete syntax tree.
+	///
+	/// ### Errors
+	/// * If the delimiters of the tokens are unbalanced.
+	pub fn try_from_lexemes(
+		lexemes: Vec<Lexeme>,
+		end: Vec<Trivia>,
+		source: &SourceMap,
+	) -> Result<Self>
+	{
+		let mut stream = lexemes.into_iter().peekable();
+		let root = Node::try_from_statements(&mut stream, source, None)?;
+
+		Ok(Self { root, end })
+	}
+}
+
+impl Node
+{
+	/// Creates a programme or block node from a stream of tokens.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of tokens.
+	/// * `source` - The source code.
+	/// * `open` - The opening brace of a block, or `None` for a programme.
+	///
+	/// ### Returns
+	/// * The node, up to and including the closing brace of a block.
+	///
+	/// ### Errors
+	/// * If a delimiter is unmatched.
+	fn try_from_statements<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+		open: Option<Lexeme>,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Lexeme>,
+	{
+		let kind = match open
+		{
+			Some(_) => Kind::Block,
+			None => Kind::Programme,
+		};
+
+		let mut children = Vec::new();
+
+		loop
+		{
+			match stream.peek().map(|lexeme| &lexeme.token.kind)
+			{
+				Some(BraceRight) | None =>
+				{
+					match (&open, stream.next())
+					{
+						(Some(_), Some(close)) => children.push(Element::Lexeme(close)),
+						(None, None) => (),
+						(Some(open), None) =>
+						{
+							bail!(source.error(open.token.span, error::DELIMITER))
+						}
+						(None, Some(close)) =>
+						{
+							bail!(source.error(close.token.span, error::DELIMITER))
+						}
+					}
+
+					break;
+				}
+
+				Some(_) =>
+				{
+					let statement = Self::try_from_statement(stream, source)?;
+					children.push(Element::Node(statement));
+				}
+			}
+		}
+
+		if let Some(open) = open
+		{
+			children.insert(0, Element::Lexeme(open));
+		}
+
+		Ok(Self { kind, children })
+	}
+
+	/// Creates a statement node from a stream of tokens.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of tokens.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The statement node.
+	///
+	/// ### Errors
+	/// * If a delimiter is unmatched.
+	fn try_from_statement<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Lexeme>,
+	{
+		let mut children = Vec::new();
+		let mut is_record = false;
+
+		// A closing brace ends the enclosing block rather than the statement.
+		while let Some(lexeme) =
+			stream.next_if(|lexeme| lexeme.token.kind != BraceRight)
+		{
+			match lexeme.token.kind
+			{
+				Semicolon =>
+				{
+					children.push(Element::Lexeme(lexeme));
+					break;
+				}
+
+				BraceLeft if is_record =>
+				{
+					let members = Self::try_from_members(stream, source, lexeme)?;
+					children.push(Element::Node(members));
+					break;
+				}
+
+				Struct | Enum =>
+				{
+					is_record = true;
+					children.push(Element::Lexeme(lexeme));
+				}
+
+				BraceLeft =>
+				{
+					let block = Self::try_from_statements(stream, source, Some(lexeme))?;
+					children.push(Element::Node(block));
+
+					if !stream.peek().is_some_and(|next| next.token.kind == Else)
+					{
+						break;
+					}
+				}
+
+				_ => children.push(Self::try_from_token(stream, source, lexeme)?),
+			}
+		}
+
+		Ok(Self {
+			kind: Kind::Statement,
+			children,
+		})
+	}
+
+	/// Creates the members node of a struct or enum from a stream of tokens.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of tokens.
+	/// * `source` - The source code.
+	/// * `open` - The opening brace of the members.
+	///
+	/// ### Returns
+	/// * The node, up to and including the closing brace.
+	///
+	/// ### Errors
+	/// * If a delimiter is unmatched.
+	fn try_from_members<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+		open: Lexeme,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Lexeme>,
+	{
+		let span = open.token.span;
+		let mut children = vec![Element::Lexeme(open)];
+
+		loop
+		{
+			match stream.next()
+			{
+				Some(lexeme) if lexeme.token.kind == BraceRight =>
+				{
+					children.push(Element::Lexeme(lexeme));
+					break;
+				}
+				Some(lexeme) =>
+				{
+					children.push(Self::try_from_token(stream, source, lexeme)?)
+				}
+				None => bail!(source.error(span, error::DELIMITER)),
+			}
+		}
+
+		Ok(Self {
+			kind: Kind::Members,
+			children,
+		})
+	}
+
+	/// Creates an element from a token that is not part of a block.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of tokens.
+	/// * `source` - The source code.
+	/// * `lexeme` - The token.
+	///
+	/// ### Returns
+	/// * The token itself, or the group it opens.
+	///
+	/// ### Errors
+	/// * If a delimiter is unmatched.
+	fn try_from_token<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+		lexeme: Lexeme,
+	) -> Result<Element>
+	where
+		I: Iterator<Item = Lexeme>,
+	{
+		let kind = match lexeme.token.kind
+		{
+			ParenthesisLeft => Kind::Parenthesised,
+			BracketLeft => Kind::Brackets,
+
+			ParenthesisRight | BracketRight | BracketRightWithM
+			| BracketRightWithA =>
+			{
+				bail!(source.error(lexeme.token.span, error::DELIMITER))
+			}
+
+			_ => return Ok(Element::Lexeme(lexeme)),
+		};
+
+		let span = lexeme.token.span;
+		let mut children = vec![Element::Lexeme(lexeme)];
+
+		loop
+		{
+			let Some(lexeme) = stream.next()
+			else
+			{
+				bail!(source.error(span, error::DELIMITER))
+			};
+
+			match (kind, &lexeme.token.kind)
+			{
+				(Kind::Parenthesised, ParenthesisRight)
+				| (
+					Kind::Brackets,
+					BracketRight | BracketRightWithM | BracketRightWithA,
+				) =>
+				{
+					children.push(Element::Lexeme(lexeme));
+					break;
+				}
+
+				(_, BraceLeft) =>
+				{
+					let block = Self::try_from_statements(stream, source, Some(lexeme))?;
+					children.push(Element::Node(block));
+				}
+
+				(_, BraceRight) => bail!(source.error(span, error::DELIMITER)),
+
+				_ => children.push(Self::try_from_token(stream, source, lexeme)?),
+			}
+		}
+
+		Ok(Element::Node(Self { kind, children }))
+	}
+}
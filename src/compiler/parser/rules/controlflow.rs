@@ -1,16 +1,19 @@
-use anyhow::{bail, Result};
+use anyhow::{bail, ensure, Result};
 use std::iter::Peekable;
 
 use crate::{
 	compiler::{
 		error::Error,
-		parser::{error, rules::utils::block},
+		parser::{
+			error,
+			rules::utils::{block, missing},
+		},
 	},
 	language::{
-		grammar::{controlflow::*, Expression},
+		grammar::{controlflow::*, Expression, Pattern},
 		lexicon::token::Kind::*,
 		lexicon::Token,
-		utils::Span,
+		utils::{SourceMap, Span},
 	},
 };
 
@@ -18,21 +21,24 @@ impl IfStmt
 {
 	pub fn try_from_stream<I>(
 		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+		source: &SourceMap,
 	) -> Result<Self>
 	where
 		I: Iterator<Item = Token>,
 	{
 		let Span { start, mut end } = stream.next().expect("while").span;
 
+		let first = stream.peek().map(|token| token.span);
 		let result = Expression::try_from_stream(stream, source);
 
 		let condition = match result
 		{
 			Ok(condition) => condition,
-			Err(_) =>
+			Err(cause) =>
 			{
-				bail!(source.error(Span { start, end }, error::CONDITION_AFTER))
+				let after = Span { start, end };
+				let message = error::CONDITION_AFTER;
+				return Err(missing(cause, first, source, after, message));
 			}
 		};
 
@@ -52,54 +58,281 @@ impl IfStmt
 			_ => bail!(source.error(Span { start, end }, error::BLOCK_AFTER)),
 		};
 
-		let else_body = match stream.peek()
+		let mut else_ifs = Vec::new();
+		let mut else_body = None;
+
+		while let Some(Token { span, .. }) =
+			stream.next_if(|token| token.kind == Else)
 		{
-			Some(Token { kind: Else, .. }) =>
+			end = span.end;
+
+			match stream.peek()
 			{
-				end = stream.next().expect("else Token").span.end;
-				match stream.peek()
+				Some(Token { kind: If, .. }) =>
+				{
+					let else_if = ElseIf::try_from_stream(stream, source, span)?;
+					end = else_if.span.end;
+					else_ifs.push(else_if);
+				}
+
+				Some(Token {
+					kind: BraceLeft, ..
+				}) =>
 				{
-					Some(Token {
-						kind: BraceLeft, ..
-					}) =>
-					{
-						let body = block(stream, source)?;
-						end = body.span.end;
-						Some(body)
-					}
-					_ => bail!(source.error(Span { start, end }, error::BLOCK_AFTER)),
+					let body = block(stream, source)?;
+					end = body.span.end;
+					else_body = Some(body);
+					break;
 				}
+
+				_ => bail!(source.error(Span { start, end }, error::BLOCK_AFTER)),
 			}
-			_ => None,
-		};
+		}
 
 		Ok(Self {
 			span: Span { start, end },
 			condition,
+			else_ifs,
 			else_body,
 			body,
 		})
 	}
 }
+
+impl ElseIf
+{
+	/// Parses an `else if` branch whose `else` keyword has been consumed.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of tokens, starting at the `if` keyword.
+	/// * `source` - The source code.
+	/// * `span` - The span of the `else` keyword.
+	///
+	/// ### Returns
+	/// * The branch.
+	///
+	/// ### Errors
+	/// * If the condition or block of the branch is missing.
+	fn try_from_stream<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+		span: Span,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Token>,
+	{
+		let start = span.start;
+		let mut end = stream.next().expect("if").span.end;
+
+		let first = stream.peek().map(|token| token.span);
+		let condition = match Expression::try_from_stream(stream, source)
+		{
+			Ok(condition) => condition,
+			Err(cause) =>
+			{
+				let after = Span { start, end };
+				let message = error::CONDITION_AFTER;
+				return Err(missing(cause, first, source, after, message));
+			}
+		};
+
+		end = condition.span.end;
+
+		match stream.peek()
+		{
+			Some(Token {
+				kind: BraceLeft, ..
+			}) =>
+			{
+				let body = block(stream, source)?;
+				let span = Span {
+					start,
+					end: body.span.end,
+				};
+				Ok(Self {
+					span,
+					condition,
+					body,
+				})
+			}
+
+			_ => bail!(source.error(Span { start, end }, error::BLOCK_AFTER)),
+		}
+	}
+}
+impl MatchStmt
+{
+	/// Creates a match statement from a stream of tokens.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of tokens, starting at the `match` keyword.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The match statement.
+	///
+	/// ### Errors
+	/// * If the matched expression or an arm is malformed.
+	/// * If the braces around the arms are unmatched.
+	pub fn try_from_stream<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Token>,
+	{
+		let span = stream.next().expect("match").span;
+		let start = span.start;
+
+		let first = stream.peek().map(|token| token.span);
+		let scrutinee = match Expression::try_from_stream(stream, source)
+		{
+			Ok(scrutinee) => scrutinee,
+			Err(cause) =>
+			{
+				let message = error::EXPRESSION_AFTER;
+				return Err(missing(cause, first, source, span, message));
+			}
+		};
+
+		let open = match stream.next_if(|token| token.kind == BraceLeft)
+		{
+			Some(open) => open,
+			None =>
+			{
+				let span = Span {
+					start,
+					end: scrutinee.span.end,
+				};
+				bail!(source.error(span, error::ARMS_AFTER))
+			}
+		};
+
+		let mut arms = Vec::new();
+
+		loop
+		{
+			match stream.peek()
+			{
+				Some(Token {
+					kind: BraceRight,
+					span,
+				}) =>
+				{
+					ensure!(!arms.is_empty(), source.error(*span, error::ARM));
+					break;
+				}
+				Some(_) => arms.push(Arm::try_from_stream(stream, source)?),
+				None => bail!(source.error(open.span, error::BRACE)),
+			}
+		}
+
+		let end = stream.next().expect("Right brace").span.end;
+
+		Ok(Self {
+			span: Span { start, end },
+			scrutinee,
+			arms,
+		})
+	}
+}
+
+impl Arm
+{
+	/// Creates an arm of a match statement from a stream of tokens.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of tokens.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The arm.
+	///
+	/// ### Errors
+	/// * If a pattern, the guard, the `=>` or the body is malformed.
+	fn try_from_stream<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Token>,
+	{
+		let pattern = Pattern::try_from_stream(stream, source)?;
+		let start = pattern.span.start;
+		let mut end = pattern.span.end;
+		let mut patterns = vec![pattern];
+
+		while stream.next_if(|token| token.kind == Bar).is_some()
+		{
+			let pattern = Pattern::try_from_stream(stream, source)?;
+			end = pattern.span.end;
+			patterns.push(pattern);
+		}
+
+		let guard = match stream.next_if(|token| token.kind == If)
+		{
+			Some(token) =>
+			{
+				let Ok(guard) = Expression::try_from_stream(stream, source)
+				else
+				{
+					bail!(source.error(token.span, error::CONDITION_AFTER))
+				};
+
+				end = guard.span.end;
+				Some(guard)
+			}
+			None => None,
+		};
+
+		match stream.next_if(|token| token.kind == FatArrow)
+		{
+			Some(arrow) => end = arrow.span.end,
+			None => bail!(source.error(Span { start, end }, error::ARROW_AFTER)),
+		}
+
+		let body = match stream.peek()
+		{
+			Some(Token {
+				kind: BraceLeft, ..
+			}) => block(stream, source)?,
+			_ => bail!(source.error(Span { start, end }, error::BLOCK_AFTER)),
+		};
+
+		Ok(Self {
+			span: Span {
+				start,
+				end: body.span.end,
+			},
+			patterns,
+			guard,
+			body,
+		})
+	}
+}
+
 impl WhileStmt
 {
 	pub fn try_from_stream<I>(
 		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+		source: &SourceMap,
 	) -> Result<Self>
 	where
 		I: Iterator<Item = Token>,
 	{
 		let Span { start, mut end } = stream.next().expect("while").span;
 
+		let first = stream.peek().map(|token| token.span);
 		let result = Expression::try_from_stream(stream, source);
 
 		let condition = match result
 		{
 			Ok(condition) => condition,
-			Err(_) =>
+			Err(cause) =>
 			{
-				bail!(source.error(Span { start, end }, error::CONDITION_AFTER))
+				let after = Span { start, end };
+				let message = error::CONDITION_AFTER;
+				return Err(missing(cause, first, source, after, message));
 			}
 		};
 
@@ -5,9 +5,11 @@ use anyhow::{bail, Result};
 use super::*;
 use crate::compiler::Error;
 use crate::language::grammar::declaration::{Declaration, Kind};
-use crate::language::grammar::{Expression, FunctionDclr};
+use crate::language::grammar::{
+	EnumDclr, Expression, FunctionDclr, StructDclr,
+};
 use crate::language::lexicon::token::{Kind::*, Token};
-use crate::language::utils::Span;
+use crate::language::utils::{SourceMap, Span};
 
 impl Declaration
 {
@@ -28,7 +30,7 @@ impl Declaration
 	/// * If the stream does not start with a declarator.
 	pub fn try_from_stream<I>(
 		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+		source: &SourceMap,
 	) -> Result<Self>
 	where
 		I: Iterator<Item = Token>,
@@ -44,6 +46,27 @@ impl Declaration
 				Ok(Self {
 					span,
 					kind: Kind::Function(declaration),
+					docs: Vec::new(),
+				})
+			}
+			Struct =>
+			{
+				let declaration = StructDclr::try_from_stream(stream, source)?;
+				let span = declaration.span;
+				Ok(Self {
+					span,
+					kind: Kind::Struct(declaration),
+					docs: Vec::new(),
+				})
+			}
+			Enum =>
+			{
+				let declaration = EnumDclr::try_from_stream(stream, source)?;
+				let span = declaration.span;
+				Ok(Self {
+					span,
+					kind: Kind::Enum(declaration),
+					docs: Vec::new(),
 				})
 			}
 			Constant | Variable =>
@@ -54,6 +77,12 @@ impl Declaration
 
 				let is_mutable = (declarator.kind == Variable);
 
+				if let Some(open) =
+					stream.next_if(|token| token.kind == ParenthesisLeft)
+				{
+					return destructuring(declarator, open, is_mutable, stream, source);
+				}
+
 				let identifier = match stream.next()
 				{
 					Some(token) if matches!(token.kind, Identifier(_)) => token,
@@ -92,9 +121,94 @@ impl Declaration
 					value,
 					is_mutable,
 				};
-				Ok(Self { span, kind })
+				Ok(Self {
+					span,
+					kind,
+					docs: Vec::new(),
+				})
 			}
 			_ => unreachable!(),
 		}
 	}
 }
+
+/// Creates a destructuring declaration from a stream of tokens.
+///
+/// ### Parameters
+/// * `declarator` - The `let` or `var` that starts the declaration.
+/// * `open` - The `(` before the names.
+/// * `is_mutable` - Whether the variables are mutable.
+/// * `stream` - The stream of tokens, after the `(`.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The declaration.
+///
+/// ### Errors
+/// * If the names are malformed or not followed by `=` and an expression.
+fn destructuring<I>(
+	declarator: Token,
+	open: Token,
+	is_mutable: bool,
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+) -> Result<Declaration>
+where
+	I: Iterator<Item = Token>,
+{
+	let mut names = Vec::new();
+
+	let close = loop
+	{
+		match stream.next()
+		{
+			Some(Token {
+				kind: Identifier(name),
+				..
+			}) => names.push(name),
+			Some(token) if token.kind == ParenthesisRight && !names.is_empty() =>
+			{
+				break token;
+			}
+			Some(token) => bail!(source.error(token.span, error::IDENTIFIER)),
+			None => bail!(source.error(open.span, error::PARENTHESIS)),
+		}
+
+		match stream.next()
+		{
+			Some(token) if token.kind == Comma => (),
+			Some(token) if token.kind == ParenthesisRight => break token,
+			_ => bail!(source.error(open.span, error::PARENTHESIS)),
+		}
+	};
+
+	let after = Span {
+		start: declarator.span.start,
+		end: close.span.end,
+	};
+
+	match stream.next()
+	{
+		Some(token) if token.kind == Equal => token,
+		_ => bail!(source.error(after, error::EQUALS_AFTER)),
+	};
+
+	let value = Expression::try_from_stream(stream, source)?;
+
+	let span = Span {
+		start: declarator.span.start,
+		end: value.span.end,
+	};
+
+	let kind = Kind::Destructuring {
+		names,
+		value,
+		is_mutable,
+	};
+
+	Ok(Declaration {
+		span,
+		kind,
+		docs: Vec::new(),
+	})
+}
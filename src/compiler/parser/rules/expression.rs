@@ -3,11 +3,12 @@ use std::iter::Peekable;
 use anyhow::{bail, Result};
 
 use super::*;
-use crate::compiler::Error;
-use crate::language::grammar::expression::{Expression, Items, Kind};
-use crate::language::grammar::FunctionCall;
-use crate::language::lexicon::token::{Kind::*, Token};
-use crate::language::utils::Span;
+use crate::compiler::{Diagnostic, Error};
+use crate::language::grammar::expression::{Expression, Items, Kind, Part};
+use crate::language::grammar::functions::Body;
+use crate::language::grammar::{FunctionCall, Lambda, Subscript};
+use crate::language::lexicon::token::{Kind::*, Segment, Token};
+use crate::language::utils::{Position, SourceMap, Span};
 
 impl Expression
 {
@@ -27,7 +28,7 @@ impl Expression
 	/// * If the stream is empty.
 	pub fn try_from_stream<I>(
 		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+		source: &SourceMap,
 	) -> Result<Self>
 	where
 		I: Iterator<Item = Token>,
@@ -35,7 +36,7 @@ impl Expression
 		Self::or(stream, source)
 	}
 
-	fn or<I>(stream: &mut Peekable<I>, source: &[Vec<char>]) -> Result<Self>
+	fn or<I>(stream: &mut Peekable<I>, source: &SourceMap) -> Result<Self>
 	where I: Iterator<Item = Token>
 	{
 		let mut expression = Self::and(stream, source)?;
@@ -63,7 +64,7 @@ impl Expression
 		Ok(expression)
 	}
 
-	fn and<I>(stream: &mut Peekable<I>, source: &[Vec<char>]) -> Result<Self>
+	fn and<I>(stream: &mut Peekable<I>, source: &SourceMap) -> Result<Self>
 	where I: Iterator<Item = Token>
 	{
 		let mut expression = Self::equality(stream, source)?;
@@ -93,7 +94,7 @@ impl Expression
 
 	fn equality<I>(
 		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+		source: &SourceMap,
 	) -> Result<Self>
 	where
 		I: Iterator<Item = Token>,
@@ -124,7 +125,7 @@ impl Expression
 
 	fn comparison<I>(
 		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+		source: &SourceMap,
 	) -> Result<Self>
 	where
 		I: Iterator<Item = Token>,
@@ -153,7 +154,7 @@ impl Expression
 		Ok(expression)
 	}
 
-	fn term<I>(stream: &mut Peekable<I>, source: &[Vec<char>]) -> Result<Self>
+	fn term<I>(stream: &mut Peekable<I>, source: &SourceMap) -> Result<Self>
 	where I: Iterator<Item = Token>
 	{
 		let mut expression = Self::factor(stream, source)?;
@@ -180,7 +181,7 @@ impl Expression
 		Ok(expression)
 	}
 
-	fn factor<I>(stream: &mut Peekable<I>, source: &[Vec<char>]) -> Result<Self>
+	fn factor<I>(stream: &mut Peekable<I>, source: &SourceMap) -> Result<Self>
 	where I: Iterator<Item = Token>
 	{
 		let mut expression = Self::prefix(stream, source)?;
@@ -207,7 +208,7 @@ impl Expression
 		Ok(expression)
 	}
 
-	fn prefix<I>(stream: &mut Peekable<I>, source: &[Vec<char>]) -> Result<Self>
+	fn prefix<I>(stream: &mut Peekable<I>, source: &SourceMap) -> Result<Self>
 	where I: Iterator<Item = Token>
 	{
 		if let Some(operator) = stream.next_if(Token::is_prefix_operator)
@@ -225,13 +226,107 @@ impl Expression
 		}
 		else
 		{
-			Self::primary(stream, source)
+			Self::postfix(stream, source)
 		}
 	}
 
+	fn postfix<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Token>,
+	{
+		let mut expression = Self::primary(stream, source)?;
+
+		loop
+		{
+			expression = match stream.peek().map(|token| &token.kind)
+			{
+				Some(BracketLeft) =>
+				{
+					let subscript = Subscript::try_from_stream(stream, source)?;
+
+					let span = Span {
+						start: expression.span.start,
+						end: subscript.span.end,
+					};
+
+					let kind = Kind::Index {
+						target: Box::new(expression),
+						subscript,
+					};
+
+					Self { span, kind }
+				}
+
+				Some(Dot) => Self::access(expression, stream, source)?,
+
+				_ => return Ok(expression),
+			};
+		}
+	}
+
+	/// Creates a member access or method call of an expression from a stream
+	/// of tokens starting at the `.`.
+	fn access<I>(
+		target: Self,
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Token>,
+	{
+		let dot = stream.next().expect("Dot").span;
+
+		let name = match stream.next()
+		{
+			Some(token) if matches!(token.kind, Identifier(_)) => token,
+			_ => bail!(source.error(dot, error::MEMBER_AFTER)),
+		};
+
+		if !stream
+			.peek()
+			.is_some_and(|token| token.kind == ParenthesisLeft)
+		{
+			let span = Span {
+				start: target.span.start,
+				end: name.span.end,
+			};
+
+			let kind = Kind::Member {
+				target: Box::new(target),
+				name,
+			};
+
+			return Ok(Self { span, kind });
+		}
+
+		let span_left = stream.next().expect("Parenthesis").span;
+		let arguments = utils::items(stream, source)?;
+		let span_right = match stream.next()
+		{
+			Some(token) if matches!(token.kind, ParenthesisRight) => token.span,
+			_ => bail!(source.error(span_left, error::PARENTHESIS)),
+		};
+
+		let span = Span {
+			start: target.span.start,
+			end: span_right.end,
+		};
+
+		let kind = Kind::MethodCall {
+			target: Box::new(target),
+			name,
+			arguments,
+		};
+
+		Ok(Self { span, kind })
+	}
+
 	fn primary<I>(
 		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+		source: &SourceMap,
 	) -> Result<Self>
 	where
 		I: Iterator<Item = Token>,
@@ -281,6 +376,49 @@ impl Expression
 						})
 					}
 
+					Some(Token {
+						kind: ColonColon, ..
+					}) =>
+					{
+						let separator = stream.next().expect("ColonColon").span;
+
+						let variant = match stream.next()
+						{
+							Some(token) if matches!(token.kind, Identifier(_)) => token,
+							_ => bail!(source.error(separator, error::VARIANT_AFTER)),
+						};
+
+						end = variant.span.end;
+
+						let arguments = match stream
+							.next_if(|token| token.kind == ParenthesisLeft)
+						{
+							Some(open) =>
+							{
+								let arguments = utils::items(stream, source)?;
+								end = match stream.next()
+								{
+									Some(token) if token.kind == ParenthesisRight =>
+									{
+										token.span.end
+									}
+									_ => bail!(source.error(open.span, error::PARENTHESIS)),
+								};
+								arguments
+							}
+							None => None,
+						};
+
+						Kind::Variant {
+							r#enum: Box::new(Token {
+								span: token.span,
+								kind: Identifier(name),
+							}),
+							name: Box::new(variant),
+							arguments,
+						}
+					}
+
 					_ =>
 					{
 						Kind::Identifier(Token {
@@ -305,20 +443,50 @@ impl Expression
 				Self { span, kind }
 			}
 
+			Interpolation(segments) =>
+			{
+				let parts = segments
+					.into_iter()
+					.map(|segment| Part::try_from_segment(segment, token.span, source))
+					.collect::<Result<_>>()?;
+
+				let span = token.span;
+				let kind = Kind::Interpolation(parts);
+
+				Self { span, kind }
+			}
+
+			Bar =>
+			{
+				let lambda = Lambda::try_from_stream(token, stream, source)?;
+
+				let span = lambda.span;
+				let kind = Kind::Lambda(Box::new(lambda));
+
+				Self { span, kind }
+			}
+
 			ParenthesisLeft =>
 			{
-				let expression = Box::new(Self::try_from_stream(stream, source)?);
+				let expression = Self::try_from_stream(stream, source)?;
 
 				let start = token.span.start;
 
-				let end = match stream.next()
+				let (end, kind) = match stream.next()
 				{
-					Some(token) if (token.kind == ParenthesisRight) => token.span.end,
+					Some(close) if (close.kind == ParenthesisRight) =>
+					{
+						(close.span.end, Kind::Parenthesised(Box::new(expression)))
+					}
+					Some(comma) if comma.kind == Comma =>
+					{
+						let (end, items) = Self::tuple(expression, &token, stream, source)?;
+						(end, Kind::Tuple(items))
+					}
 					_ => bail!(source.error(token.span, error::PARENTHESIS)),
 				};
 
 				let span = Span { start, end };
-				let kind = Kind::Parenthesised(expression);
 
 				Self { span, kind }
 			}
@@ -401,6 +569,177 @@ impl Expression
 
 		Ok(expression)
 	}
+
+	/// Creates the values of a tuple from a stream of tokens.
+	///
+	/// ### Parameters
+	/// * `first` - The first value of the tuple.
+	/// * `open` - The parenthesis that opens the tuple.
+	/// * `stream` - The stream of tokens, after the comma that follows the first
+	///   value.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The end of the closing parenthesis, and the values.
+	///
+	/// ### Errors
+	/// * If a value cannot be created.
+	/// * If the parenthesis is unmatched.
+	fn tuple<I>(
+		first: Self,
+		open: &Token,
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+	) -> Result<(Position, Items)>
+	where
+		I: Iterator<Item = Token>,
+	{
+		let mut items = Items {
+			span: first.span,
+			expressions: vec![first],
+		};
+
+		// After each comma comes another value or the closing parenthesis.
+		loop
+		{
+			if let Some(close) =
+				stream.next_if(|token| token.kind == ParenthesisRight)
+			{
+				return Ok((close.span.end, items));
+			}
+
+			let expression = Self::try_from_stream(stream, source)?;
+			items.span.end = expression.span.end;
+			items.expressions.push(expression);
+
+			match stream.next()
+			{
+				Some(token) if token.kind == Comma => (),
+				Some(token) if token.kind == ParenthesisRight =>
+				{
+					return Ok((token.span.end, items));
+				}
+				_ => bail!(source.error(open.span, error::PARENTHESIS)),
+			}
+		}
+	}
+}
+
+impl Lambda
+{
+	/// Creates a lambda from a stream of tokens following its opening `|`.
+	///
+	/// ### Parameters
+	/// * `bar` - The opening `|`.
+	/// * `stream` - The stream of tokens.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The lambda if it can be constructed from the stream.
+	///
+	/// ### Errors
+	/// * If the parameters are not closed with `|`.
+	/// * If the body cannot be created.
+	pub fn try_from_stream<I>(
+		bar: Token,
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Token>,
+	{
+		let parameters = match stream.peek()
+		{
+			Some(Token { kind: Bar, .. }) => None,
+			_ => utils::params(stream, source)?,
+		};
+
+		if stream.next_if(|token| token.kind == Bar).is_none()
+		{
+			bail!(source.error(bar.span, error::LAMBDA_BAR))
+		}
+
+		let body = match stream.peek()
+		{
+			Some(Token {
+				kind: BraceLeft, ..
+			}) => Body::Block(utils::block(stream, source)?),
+			Some(_) =>
+			{
+				let body = Expression::try_from_stream(stream, source)?;
+				Body::Expression(Box::new(body))
+			}
+			None => bail!(source.error(bar.span, error::LAMBDA_BODY)),
+		};
+
+		let end = match &body
+		{
+			Body::Expression(expression) => expression.span.end,
+			Body::Block(block) => block.span.end,
+		};
+
+		let span = Span {
+			start: bar.span.start,
+			end,
+		};
+
+		Ok(Self {
+			span,
+			parameters,
+			body,
+		})
+	}
+}
+
+impl Part
+{
+	/// Creates a part of an interpolated string from a segment of its token.
+	///
+	/// ### Parameters
+	/// * `segment` - The segment of the interpolated string token.
+	/// * `span` - The span of the whole interpolated string.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The part of the interpolated string.
+	///
+	/// ### Errors
+	/// * If an embedded expression cannot be created.
+	pub fn try_from_segment(
+		segment: Segment,
+		span: Span,
+		source: &SourceMap,
+	) -> Result<Self>
+	{
+		let tokens = match segment
+		{
+			Segment::Text(text) => return Ok(Self::Text(text)),
+			Segment::Code(tokens) if tokens.is_empty() =>
+			{
+				bail!(source.error(span, error::EXPRESSION))
+			}
+			Segment::Code(tokens) => tokens,
+		};
+
+		// The tokens end at the closing brace, so an expression cut short is
+		// reported after its last token rather than as an empty stream.
+		let last = tokens.last().expect("Tokens").span;
+		let mut stream = tokens.into_iter().peekable();
+		let expression =
+			Expression::try_from_stream(&mut stream, source).map_err(|error| {
+				match error.downcast::<Diagnostic>()
+				{
+					Ok(diagnostic) => diagnostic,
+					Err(_) => source.error(last, error::EXPRESSION_AFTER),
+				}
+			})?;
+
+		match stream.next()
+		{
+			Some(token) => bail!(source.error(token.span, error::INTERPOLATION_END)),
+			None => Ok(Self::Expression(expression)),
+		}
+	}
 }
 
 impl Token
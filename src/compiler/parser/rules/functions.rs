@@ -6,7 +6,9 @@ use crate::compiler::Error;
 use crate::language::lexicon::token::Kind::*;
 
 use crate::language::semantics::r#type::Type;
-use crate::language::{grammar::FunctionDclr, lexicon::Token, utils::Span};
+use crate::language::grammar::FunctionDclr;
+use crate::language::lexicon::Token;
+use crate::language::utils::{SourceMap, Span};
 
 impl FunctionDclr
 {
@@ -14,7 +16,7 @@ impl FunctionDclr
 	/// has seen a Declaration token
 	pub fn try_from_stream<I>(
 		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+		source: &SourceMap,
 	) -> Result<Self>
 	where
 		I: Iterator<Item = Token>,
@@ -37,6 +39,12 @@ impl FunctionDclr
 			_ => bail!(source.error(Span { start, end }, error::FUNCTION_NAME)),
 		};
 
+		let generics = match stream.next_if(|token| token.kind == Less)
+		{
+			Some(open) => generics(open.span, stream, source)?,
+			None => Vec::new(),
+		};
+
 		let span_left = match stream.next()
 		{
 			Some(token) if matches!(token.kind, ParenthesisLeft) => token.span,
@@ -46,7 +54,12 @@ impl FunctionDclr
 			}
 		};
 
-		let parameters = utils::params(stream, source)?;
+		let mut parameters = utils::params(stream, source)?;
+
+		for parameter in parameters.iter_mut().flat_map(|p| &mut p.parameters)
+		{
+			parameter.r#type = parameter.r#type.clone().generalise(&generics);
+		}
 
 		match stream.next()
 		{
@@ -54,37 +67,24 @@ impl FunctionDclr
 			_ => bail!(source.error(span_left, error::PARENTHESIS)),
 		};
 
-		let type_string = match stream.peek()
+		let return_type = match stream.next_if(|token| token.kind == ArrowRight)
 		{
-			Some(Token {
-				kind: ArrowRight, ..
-			}) =>
+			Some(arrow) =>
 			{
-				let span = stream.next().expect("ArrowRight Token").span;
-				match stream.next()
+				let is_type = stream.peek().is_some_and(|token| {
+					matches!(token.kind, Identifier(_) | ParenthesisLeft | Bar)
+				});
+
+				if !is_type
 				{
-					Some(Token {
-						kind: Identifier(name),
-						span,
-					}) =>
-					{
-						end = span.end;
-						Some(name)
-					}
-					_ => bail!(source.error(span, error::EXPECTED_RETURN_TYPE)),
+					bail!(source.error(arrow.span, error::EXPECTED_RETURN_TYPE))
 				}
-			}
-			_ => None,
-		};
 
-		let return_type = match type_string.as_deref()
-		{
-			Some("Number") => Type::Number,
-			Some("String") => Type::String,
-			Some("Bool") => Type::Boolean,
-			Some("Unit") => Type::Unit,
+				let (r#type, span) = utils::r#type(stream, source, arrow.span)?;
+				end = span.end;
+				r#type.generalise(&generics)
+			}
 			None => Type::Unit,
-			_ => unreachable!(),
 		};
 
 		match stream.peek()
@@ -101,6 +101,7 @@ impl FunctionDclr
 				Ok(Self {
 					span,
 					name,
+					generics,
 					return_type,
 					parameters,
 					body,
@@ -111,3 +112,47 @@ impl FunctionDclr
 		}
 	}
 }
+
+/// Creates the names of the type and size parameters of a generic function,
+/// such as `T` and `n` in `func f<T, n>`.
+///
+/// ### Parameters
+/// * `open` - The span of the `<` opening the names.
+/// * `stream` - The stream of tokens, after the `<`.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The names, in order.
+///
+/// ### Errors
+/// * If a name is missing or the `<` is unmatched.
+fn generics<I>(
+	open: Span,
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+) -> Result<Vec<std::string::String>>
+where
+	I: Iterator<Item = Token>,
+{
+	let mut generics = Vec::new();
+
+	loop
+	{
+		match stream.next()
+		{
+			Some(Token {
+				kind: Identifier(name),
+				..
+			}) => generics.push(name),
+			Some(token) => bail!(source.error(token.span, error::TYPE_PARAMETER)),
+			None => bail!(source.error(open, error::ANGLE)),
+		}
+
+		match stream.next()
+		{
+			Some(token) if token.kind == Comma => (),
+			Some(token) if token.kind == Greater => return Ok(generics),
+			_ => bail!(source.error(open, error::ANGLE)),
+		}
+	}
+}
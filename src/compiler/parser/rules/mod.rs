@@ -1,9 +1,14 @@
+mod concrete;
 mod controlflow;
 mod declaration;
 mod expression;
 mod functions;
+mod module;
+mod pattern;
 mod programme;
 mod statement;
+mod subscript;
+mod types;
 mod utils;
 
 pub use super::*;
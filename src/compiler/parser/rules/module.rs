@@ -0,0 +1,121 @@
+use std::iter::Peekable;
+
+use anyhow::{bail, Result};
+
+use super::*;
+use crate::compiler::Error;
+use crate::language::grammar::{ImportStmt, UseStmt};
+use crate::language::lexicon::token::{Kind::*, Token};
+use crate::language::utils::{SourceMap, Span};
+
+impl ImportStmt
+{
+	/// Creates an import statement from a stream of tokens.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of tokens.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The import statement if it can be constructed from the stream.
+	///
+	/// ### Errors
+	/// * If the import statement cannot be created.
+	///
+	/// ### Panics
+	/// * If the stream does not start with an `import` token.
+	pub fn try_from_stream<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Token>,
+	{
+		let span = stream.next().expect("Import Token").span;
+
+		match stream.next()
+		{
+			Some(Token {
+				kind: String(path),
+				span: Span { end, .. },
+			}) if path.ends_with(".q") =>
+			{
+				let span = Span {
+					start: span.start,
+					end,
+				};
+
+				Ok(Self::new(span, path))
+			}
+			_ => bail!(source.error(span, error::PATH_AFTER)),
+		}
+	}
+}
+
+impl UseStmt
+{
+	/// Creates a use statement from a stream of tokens.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of tokens.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The use statement if it can be constructed from the stream.
+	///
+	/// ### Errors
+	/// * If the use statement cannot be created.
+	///
+	/// ### Panics
+	/// * If the stream does not start with a `use` token.
+	pub fn try_from_stream<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Token>,
+	{
+		let Span { start, mut end } = stream.next().expect("Use Token").span;
+
+		let module = match stream.next()
+		{
+			Some(Token {
+				kind: Identifier(module),
+				span,
+			}) =>
+			{
+				end = span.end;
+				module
+			}
+			_ => bail!(source.error(Span { start, end }, error::IDENTIFIER_AFTER)),
+		};
+
+		match stream.next()
+		{
+			Some(Token {
+				kind: ColonColon,
+				span,
+			}) => end = span.end,
+			_ => bail!(source.error(Span { start, end }, error::PATH_SEPARATOR)),
+		}
+
+		let name = match stream.next()
+		{
+			Some(Token {
+				kind: Identifier(name),
+				span,
+			}) =>
+			{
+				end = span.end;
+				name
+			}
+			_ => bail!(source.error(Span { start, end }, error::IDENTIFIER_AFTER)),
+		};
+
+		Ok(Self {
+			span: Span { start, end },
+			module,
+			name,
+		})
+	}
+}
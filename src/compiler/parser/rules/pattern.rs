@@ -0,0 +1,220 @@
+use std::iter::Peekable;
+
+use anyhow::{bail, Result};
+
+use super::*;
+use crate::compiler::Error;
+use crate::language::grammar::pattern::{Kind, Pattern};
+use crate::language::lexicon::token::{Kind::*, Token};
+use crate::language::utils::{SourceMap, Span};
+
+impl Pattern
+{
+	/// Creates a pattern from a stream of tokens.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of tokens, which must not be empty.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The pattern.
+	///
+	/// ### Errors
+	/// * If the stream does not start with a pattern.
+	pub fn try_from_stream<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Token>,
+	{
+		let token = stream.next().expect("Pattern");
+		let span = token.span;
+
+		let start = match token.kind
+		{
+			Identifier(ref name) if name == "_" =>
+			{
+				let kind = Kind::Wildcard;
+				return Ok(Self { span, kind });
+			}
+
+			Boolean(_) | String(_) =>
+			{
+				let kind = Kind::Literal(token);
+				return Ok(Self { span, kind });
+			}
+
+			Identifier(_) => return variant(token, stream, source),
+
+			Number(_) | Minus => number(token, stream, source)?,
+
+			_ => bail!(source.error(span, error::PATTERN)),
+		};
+
+		let is_inclusive = match stream.peek().map(|token| &token.kind)
+		{
+			Some(DotDot) => false,
+			Some(DotDotEqual) => true,
+			_ =>
+			{
+				let span = start.span;
+				let kind = Kind::Literal(start);
+				return Ok(Self { span, kind });
+			}
+		};
+
+		let operator = stream.next().expect("Range operator");
+
+		let end = match stream.next()
+		{
+			Some(token) if matches!(token.kind, Number(_) | Minus) =>
+			{
+				number(token, stream, source)?
+			}
+			_ =>
+			{
+				let span = Span {
+					start: start.span.start,
+					end: operator.span.end,
+				};
+				bail!(source.error(span, error::BOUND_AFTER))
+			}
+		};
+
+		let span = Span {
+			start: start.span.start,
+			end: end.span.end,
+		};
+
+		let kind = Kind::Range {
+			start,
+			end,
+			is_inclusive,
+		};
+
+		Ok(Self { span, kind })
+	}
+}
+
+/// Creates a number token from a number, or from a `-` and the number that
+/// follows it.
+///
+/// ### Parameters
+/// * `token` - The number or `-` token.
+/// * `stream` - The stream of tokens after the token.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The number token, holding the number with its sign.
+///
+/// ### Errors
+/// * If a `-` is not followed by a number.
+fn number<I>(
+	token: Token,
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+) -> Result<Token>
+where
+	I: Iterator<Item = Token>,
+{
+	if token.kind != Minus
+	{
+		return Ok(token);
+	}
+
+	match stream.next()
+	{
+		Some(Token {
+			span,
+			kind: Number(number),
+		}) => Ok(Token {
+			span: Span {
+				start: token.span.start,
+				end: span.end,
+			},
+			kind: Number(format!("-{number}")),
+		}),
+
+		_ => bail!(source.error(token.span, error::PATTERN)),
+	}
+}
+
+/// Creates a variant pattern from a stream of tokens following the name of its
+/// enum.
+///
+/// ### Parameters
+/// * `r#enum` - The name of the enum.
+/// * `stream` - The stream of tokens after the name.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The variant pattern.
+///
+/// ### Errors
+/// * If the name of the enum is not followed by `::` and a variant name.
+/// * If a value of the payload is bound to something other than a name.
+fn variant<I>(
+	r#enum: Token,
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+) -> Result<Pattern>
+where
+	I: Iterator<Item = Token>,
+{
+	let separator = match stream.next()
+	{
+		Some(token) if token.kind == ColonColon => token.span,
+		_ => bail!(source.error(r#enum.span, error::PATH_SEPARATOR)),
+	};
+
+	let name = match stream.next()
+	{
+		Some(token) if matches!(token.kind, Identifier(_)) => token,
+		_ => bail!(source.error(separator, error::VARIANT_AFTER)),
+	};
+
+	let mut end = name.span.end;
+	let mut bindings = Vec::new();
+
+	if let Some(open) = stream.next_if(|token| token.kind == ParenthesisLeft)
+	{
+		loop
+		{
+			match stream.next()
+			{
+				Some(token) if matches!(token.kind, Identifier(_)) =>
+				{
+					bindings.push(token)
+				}
+				Some(token) => bail!(source.error(token.span, error::BINDING)),
+				None => bail!(source.error(open.span, error::PARENTHESIS)),
+			}
+
+			match stream.next()
+			{
+				Some(token) if token.kind == Comma => (),
+				Some(token) if token.kind == ParenthesisRight =>
+				{
+					end = token.span.end;
+					break;
+				}
+				Some(token) => bail!(source.error(token.span, error::COMMA)),
+				None => bail!(source.error(open.span, error::PARENTHESIS)),
+			}
+		}
+	}
+
+	let span = Span {
+		start: r#enum.span.start,
+		end,
+	};
+
+	let kind = Kind::Variant {
+		r#enum,
+		name,
+		bindings,
+	};
+
+	Ok(Pattern { span, kind })
+}
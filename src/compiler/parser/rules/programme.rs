@@ -1,10 +1,12 @@
 use std::iter::Peekable;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 
+use super::*;
+use crate::compiler::Error;
 use crate::language::grammar::{Programme, Statement};
 use crate::language::lexicon::Token;
-use crate::language::utils::{Position, Span};
+use crate::language::utils::{SourceMap, Span};
 
 impl Programme
 {
@@ -22,7 +24,7 @@ impl Programme
 	/// * If the programme cannot be created.
 	pub fn try_from_stream<I>(
 		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+		source: &SourceMap,
 	) -> Result<Programme>
 	where
 		I: Iterator<Item = Token>,
@@ -34,16 +36,18 @@ impl Programme
 			statements.push(statement);
 		}
 
+		if let Some(token) = stream.next()
+		{
+			bail!(source.error(token.span, error::EXPRESSION))
+		}
+
 		let span = match (statements.first(), statements.last())
 		{
 			(Some(first), Some(last)) => Span {
 				start: first.span.start,
 				end: last.span.end,
 			},
-			_ => Span {
-				start: Position { line: 1, column: 1 },
-				end: Position { line: 1, column: 1 },
-			},
+			_ => Span::default(),
 		};
 
 		Ok(Programme { span, statements })
@@ -7,10 +7,10 @@ use crate::compiler::Error;
 use crate::language::grammar::statement::{Kind, Statement};
 use crate::language::grammar::{
 	controlflow::*, AssignmentStmt, Declaration, EchoStmt, Expression,
-	FunctionCall, ReturnStmt,
+	FunctionCall, ImportStmt, ReturnStmt, Subscript, UseStmt,
 };
 use crate::language::lexicon::token::{Kind::*, Token};
-use crate::language::utils::Span;
+use crate::language::utils::{SourceMap, Span};
 
 impl Statement
 {
@@ -28,7 +28,7 @@ impl Statement
 	/// * If the statement cannot be created.
 	pub fn try_from_stream<I>(
 		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+		source: &SourceMap,
 	) -> Result<Option<Statement>>
 	where
 		I: Iterator<Item = Token>,
@@ -36,8 +36,22 @@ impl Statement
 		let start;
 		let mut end;
 
+		let mut docs = Vec::new();
+
+		while let Some(Token {
+			kind: Doc(text), ..
+		}) = stream.next_if(|token| matches!(token.kind, Doc(_)))
+		{
+			docs.push(text);
+		}
+
 		let kind = match stream.peek()
 		{
+			// Documentation at the end of a block documents nothing.
+			Some(Token {
+				kind: BraceRight, ..
+			}) if !docs.is_empty() => return Ok(None),
+
 			Some(token) =>
 			{
 				start = token.span.start;
@@ -62,6 +76,13 @@ impl Statement
 				Kind::If(if_)
 			}
 
+			Match =>
+			{
+				let match_ = MatchStmt::try_from_stream(stream, source)?;
+				end = match_.span.end;
+				Kind::Match(match_)
+			}
+
 			Continue =>
 			{
 				let span = stream.next().expect("Continue Token").span;
@@ -128,16 +149,52 @@ impl Statement
 				Kind::Echo(echo)
 			}
 
-			Function =>
+			Import =>
 			{
-				let declaration = Declaration::try_from_stream(stream, source)?;
+				let import = ImportStmt::try_from_stream(stream, source)?;
+				let span = import.span;
+
+				end = match stream.next()
+				{
+					Some(Token {
+						span,
+						kind: Semicolon,
+					}) => span.end,
+					_ => bail!(source.error(span, error::SEMICOLON_AFTER)),
+				};
+
+				Kind::Import(import)
+			}
+
+			Use =>
+			{
+				let use_ = UseStmt::try_from_stream(stream, source)?;
+				let span = use_.span;
+
+				end = match stream.next()
+				{
+					Some(Token {
+						span,
+						kind: Semicolon,
+					}) => span.end,
+					_ => bail!(source.error(span, error::SEMICOLON_AFTER)),
+				};
+
+				Kind::Use(use_)
+			}
+
+			Function | Struct | Enum =>
+			{
+				let mut declaration = Declaration::try_from_stream(stream, source)?;
 				end = declaration.span.end;
+				declaration.docs = docs;
 				Kind::Declaration(declaration)
 			}
 
 			Constant | Variable =>
 			{
-				let declaration = Declaration::try_from_stream(stream, source)?;
+				let mut declaration = Declaration::try_from_stream(stream, source)?;
+				declaration.docs = docs;
 				end = match stream.next()
 				{
 					Some(Token {
@@ -161,19 +218,34 @@ impl Statement
 					_ => unreachable!(),
 				};
 
+				let subscript = match peek
+				{
+					Some(Token {
+						kind: BracketLeft, ..
+					}) => Some(Subscript::try_from_stream(stream, source)?),
+					_ => None,
+				};
+
+				let peek = stream.peek();
+
 				let kind = match peek
 				{
 					Some(Token { kind: Equal, .. }) =>
 					{
 						end = stream.next().expect("Equal Token").span.end;
+						let first = stream.peek().map(|token| token.span);
 						let result = Expression::try_from_stream(stream, source);
 
 						let value = match result
 						{
 							Ok(value) => value,
-							Err(_) =>
+							Err(cause) =>
 							{
-								bail!(source.error(Span { start, end }, error::EXPRESSION_AFTER))
+								let after = Span { start, end };
+								let message = error::EXPRESSION_AFTER;
+								return Err(utils::missing(
+									cause, first, source, after, message,
+								));
 							}
 						};
 
@@ -185,6 +257,7 @@ impl Statement
 						Kind::Assignment(AssignmentStmt {
 							span,
 							identifier: name,
+							subscript,
 							expression: value,
 						})
 					}
@@ -214,6 +287,16 @@ impl Statement
 							arguments,
 						})
 					}
+					_ if subscript.is_some() =>
+					{
+						let span = Span {
+							start,
+							end: subscript
+								.map_or(identifier.span, |subscript| subscript.span)
+								.end,
+						};
+						bail!(source.error(span, error::EQUALS_AFTER))
+					}
 					_ =>
 					{
 						bail!(source.error(identifier.span, error::PARAMS_AFTER))
@@ -255,7 +338,7 @@ impl EchoStmt
 {
 	pub fn try_from_stream<I>(
 		stream: &mut Peekable<I>,
-		source: &[Vec<char>],
+		source: &SourceMap,
 	) -> Result<Self>
 	where
 		I: Iterator<Item = Token>,
@@ -265,6 +348,7 @@ impl EchoStmt
 		let mut end = span.end;
 
 		let mut arguments = Vec::new();
+		let first = stream.peek().map(|token| token.span);
 		let expression = Expression::try_from_stream(stream, source);
 
 		match expression
@@ -274,9 +358,11 @@ impl EchoStmt
 				end = expression.span.end;
 				arguments.push(expression);
 			}
-			Err(_) =>
+			Err(cause) =>
 			{
-				bail!(source.error(Span { start, end }, error::EXPRESSION_AFTER))
+				let after = Span { start, end };
+				let message = error::EXPRESSION_AFTER;
+				return Err(utils::missing(cause, first, source, after, message));
 			}
 		}
 
@@ -0,0 +1,153 @@
+use std::iter::Peekable;
+
+use anyhow::{bail, ensure, Result};
+
+use super::*;
+use crate::compiler::Error;
+use crate::language::grammar::subscript::{Index, Subscript};
+use crate::language::grammar::Expression;
+use crate::language::lexicon::token::{Kind::*, Token};
+use crate::language::utils::{SourceMap, Span};
+
+impl Subscript
+{
+	/// Creates a subscript from a stream of tokens.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of tokens, starting at the `[`.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The subscript.
+	///
+	/// ### Errors
+	/// * If an index is malformed.
+	/// * If the `[` is unmatched.
+	pub fn try_from_stream<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Token>,
+	{
+		let open = stream.next().expect("Left bracket").span;
+		let mut indices = vec![index(stream, source, open)?];
+
+		loop
+		{
+			match stream.next()
+			{
+				Some(Token { kind: Comma, .. }) =>
+				{
+					indices.push(index(stream, source, open)?)
+				}
+
+				Some(Token {
+					kind: BracketRight,
+					span,
+				}) =>
+				{
+					let span = Span {
+						start: open.start,
+						end: span.end,
+					};
+
+					return Ok(Self { span, indices });
+				}
+
+				Some(token) => bail!(source.error(token.span, error::COMMA)),
+				None => bail!(source.error(open, error::SUBSCRIPT_BRACKET)),
+			}
+		}
+	}
+}
+
+/// Creates an index of a subscript from a stream of tokens.
+///
+/// ### Parameters
+/// * `stream` - The stream of tokens.
+/// * `source` - The source code.
+/// * `open` - The span of the `[` of the subscript.
+///
+/// ### Returns
+/// * The index.
+///
+/// ### Errors
+/// * If the stream does not start with an index.
+/// * If an inclusive range has no end.
+fn index<I>(
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+	open: Span,
+) -> Result<Index>
+where
+	I: Iterator<Item = Token>,
+{
+	let start = match stream.peek()
+	{
+		Some(Token { kind: Colon, span }) =>
+		{
+			let span = *span;
+			stream.next();
+
+			return Ok(Index::Slice {
+				span,
+				start: None,
+				end: None,
+				is_inclusive: false,
+			});
+		}
+
+		Some(Token {
+			kind: Comma | BracketRight,
+			span,
+		}) => bail!(source.error(*span, error::INDEX)),
+
+		Some(Token {
+			kind: DotDot | DotDotEqual,
+			..
+		}) => None,
+
+		Some(_) => Some(Expression::try_from_stream(stream, source)?),
+		None => bail!(source.error(open, error::SUBSCRIPT_BRACKET)),
+	};
+
+	let Some(operator) =
+		stream.next_if(|token| matches!(token.kind, DotDot | DotDotEqual))
+	else
+	{
+		return Ok(Index::Position(start.expect("Index")));
+	};
+
+	let is_inclusive = operator.kind == DotDotEqual;
+
+	let end = match stream.peek()
+	{
+		Some(Token {
+			kind: Comma | BracketRight,
+			..
+		})
+		| None => None,
+		Some(_) => Some(Expression::try_from_stream(stream, source)?),
+	};
+
+	ensure!(
+		end.is_some() || !is_inclusive,
+		source.error(operator.span, error::END_AFTER)
+	);
+
+	let span = Span {
+		start: start
+			.as_ref()
+			.map_or(operator.span, |start| start.span)
+			.start,
+		end: end.as_ref().map_or(operator.span, |end| end.span).end,
+	};
+
+	Ok(Index::Slice {
+		span,
+		start,
+		end,
+		is_inclusive,
+	})
+}
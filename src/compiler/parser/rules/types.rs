@@ -0,0 +1,259 @@
+use std::iter::Peekable;
+
+use anyhow::{bail, Result};
+
+use super::*;
+use crate::compiler::Error;
+use crate::language::grammar::types::{EnumDclr, Field, StructDclr, Variant};
+use crate::language::lexicon::token::{Kind::*, Token};
+use crate::language::utils::{SourceMap, Span};
+
+impl StructDclr
+{
+	/// Creates a struct declaration from a stream of tokens.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of tokens, starting with `struct`.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The struct declaration.
+	///
+	/// ### Errors
+	/// * If the name or the fields of the struct are missing or malformed.
+	pub fn try_from_stream<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Token>,
+	{
+		let (start, name) = header(stream, source)?;
+
+		let (fields, end) =
+			members(stream, source, start, error::FIELDS_AFTER, |stream| {
+				let (name, span) = identifier(stream, source)?;
+
+				let colon = match stream.next()
+				{
+					Some(token) if token.kind == Colon => token.span,
+					_ => bail!(source.error(span, error::COLON_AFTER)),
+				};
+
+				let (r#type, end) = utils::r#type(stream, source, colon)?;
+
+				let span = Span {
+					start: span.start,
+					end: end.end,
+				};
+
+				Ok(Field { span, name, r#type })
+			})?;
+
+		let span = Span {
+			start: start.start,
+			end: end.end,
+		};
+
+		Ok(Self { span, name, fields })
+	}
+}
+
+impl EnumDclr
+{
+	/// Creates an enum declaration from a stream of tokens.
+	///
+	/// ### Parameters
+	/// * `stream` - The stream of tokens, starting with `enum`.
+	/// * `source` - The source code.
+	///
+	/// ### Returns
+	/// * The enum declaration.
+	///
+	/// ### Errors
+	/// * If the name or the variants of the enum are missing or malformed.
+	pub fn try_from_stream<I>(
+		stream: &mut Peekable<I>,
+		source: &SourceMap,
+	) -> Result<Self>
+	where
+		I: Iterator<Item = Token>,
+	{
+		let (start, name) = header(stream, source)?;
+
+		let (variants, end) =
+			members(stream, source, start, error::VARIANTS_AFTER, |stream| {
+				let (name, mut span) = identifier(stream, source)?;
+				let mut payload = Vec::new();
+
+				if let Some(open) =
+					stream.next_if(|token| token.kind == ParenthesisLeft)
+				{
+					let mut after = open.span;
+
+					loop
+					{
+						let (r#type, _) = utils::r#type(stream, source, after)?;
+						payload.push(r#type);
+
+						match stream.next()
+						{
+							Some(token) if token.kind == Comma => after = token.span,
+							Some(token) if token.kind == ParenthesisRight =>
+							{
+								span.end = token.span.end;
+								break;
+							}
+							_ => bail!(source.error(open.span, error::PARENTHESIS)),
+						}
+					}
+				}
+
+				Ok(Variant {
+					span,
+					name,
+					payload,
+				})
+			})?;
+
+		let span = Span {
+			start: start.start,
+			end: end.end,
+		};
+
+		Ok(Self {
+			span,
+			name,
+			variants,
+		})
+	}
+}
+
+/// Creates the keyword and name that start a struct or enum declaration from a
+/// stream of tokens.
+///
+/// ### Parameters
+/// * `stream` - The stream of tokens, starting with the keyword.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The span of the keyword and the name.
+///
+/// ### Errors
+/// * If the keyword is not followed by a name.
+fn header<I>(
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+) -> Result<(Span, std::string::String)>
+where
+	I: Iterator<Item = Token>,
+{
+	let keyword = stream.next().expect("Struct or Enum").span;
+
+	match stream.next()
+	{
+		Some(Token {
+			kind: Identifier(name),
+			span,
+		}) => Ok((
+			Span {
+				start: keyword.start,
+				end: span.end,
+			},
+			name,
+		)),
+		_ => bail!(source.error(keyword, error::IDENTIFIER_AFTER)),
+	}
+}
+
+/// Creates the comma-separated members of a struct or enum between braces from
+/// a stream of tokens.
+///
+/// ### Parameters
+/// * `stream` - The stream of tokens, starting at the `{`.
+/// * `source` - The source code.
+/// * `after` - The span of the code before the `{`.
+/// * `message` - The error message if there is no `{`.
+/// * `member` - Creates a member from the stream.
+///
+/// ### Returns
+/// * The members, and the span of the `}`.
+///
+/// ### Errors
+/// * If the braces are missing or unmatched.
+/// * If a member cannot be created.
+fn members<I, T>(
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+	after: Span,
+	message: &str,
+	mut member: impl FnMut(&mut Peekable<I>) -> Result<T>,
+) -> Result<(Vec<T>, Span)>
+where
+	I: Iterator<Item = Token>,
+{
+	let open = match stream.next()
+	{
+		Some(token) if token.kind == BraceLeft => token.span,
+		_ => bail!(source.error(after, message)),
+	};
+
+	let mut members = Vec::new();
+
+	loop
+	{
+		if let Some(close) = stream.next_if(|token| token.kind == BraceRight)
+		{
+			return Ok((members, close.span));
+		}
+
+		if stream.peek().is_none()
+		{
+			bail!(source.error(open, error::BRACE))
+		}
+
+		members.push(member(stream)?);
+
+		match stream.peek()
+		{
+			Some(Token { kind: Comma, .. }) =>
+			{
+				stream.next().expect("Comma");
+			}
+			Some(Token {
+				kind: BraceRight, ..
+			}) => (),
+			Some(token) => bail!(source.error(token.span, error::COMMA)),
+			None => bail!(source.error(open, error::BRACE)),
+		}
+	}
+}
+
+/// Creates a name from a stream of tokens.
+///
+/// ### Parameters
+/// * `stream` - The stream of tokens, which must not be empty.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The name and its span.
+///
+/// ### Errors
+/// * If the next token is not an identifier.
+fn identifier<I>(
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+) -> Result<(std::string::String, Span)>
+where
+	I: Iterator<Item = Token>,
+{
+	match stream.next()
+	{
+		Some(Token {
+			kind: Identifier(name),
+			span,
+		}) => Ok((name, span)),
+		Some(token) => bail!(source.error(token.span, error::IDENTIFIER)),
+		None => unreachable!(),
+	}
+}
@@ -3,24 +3,26 @@ use std::iter::Peekable;
 use anyhow::{bail, Result};
 
 use super::*;
-use crate::compiler::Error;
+use crate::compiler::{Diagnostic, Error};
 use crate::language::grammar::expression::{Expression, Items};
 use crate::language::grammar::functions::{Parameter, Params};
 use crate::language::grammar::{Block, Statement};
 use crate::language::lexicon::token::{Kind::*, Token};
-use crate::language::utils::Span;
+use crate::language::semantics::r#type::{Size, Type};
+use crate::language::utils::{SourceMap, Span};
 
 // PRIOR: checks if the next token is a expression until closing token
 pub fn items<I>(
 	stream: &mut std::iter::Peekable<I>,
-	source: &[Vec<char>],
+	source: &SourceMap,
 ) -> Result<Option<Items>>
 where
 	I: Iterator<Item = Token>,
 {
 	match stream.peek()
 	{
-		Some(token) if token.is_item_closing() => Ok(None),
+		// A leading bar opens a lambda rather than ending the items.
+		Some(token) if token.is_item_closing() && token.kind != Bar => Ok(None),
 		None => Ok(None),
 		Some(_) =>
 		{
@@ -68,7 +70,7 @@ where
 
 pub fn block<I>(
 	stream: &mut Peekable<I>,
-	source: &[Vec<char>],
+	source: &SourceMap,
 ) -> Result<Block>
 where
 	I: Iterator<Item = Token>,
@@ -107,7 +109,7 @@ where
 // PRIOR: checks if the next token is a param until closing token
 pub fn params<I>(
 	stream: &mut Peekable<I>,
-	source: &[Vec<char>],
+	source: &SourceMap,
 ) -> Result<Option<Params>>
 where
 	I: Iterator<Item = Token>,
@@ -141,22 +143,347 @@ where
 	}
 }
 
+/// Creates a type annotation from a stream of tokens.
+///
+/// ### Parameters
+/// * `stream` - The stream of tokens.
+/// * `source` - The source code.
+/// * `after` - The span of the code before the annotation.
+///
+/// ### Returns
+/// * The type and the span of its annotation.
+///
+/// ### Errors
+/// * If the next token is neither the name of a type, a `(` opening the types
+///   of a tuple nor a `|` opening the parameters of a function type.
+/// * If the types of a tuple or a function, or the arguments of `List` or
+///   `Matrix`, are malformed.
+pub fn r#type<I>(
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+	after: Span,
+) -> Result<(Type, Span)>
+where
+	I: Iterator<Item = Token>,
+{
+	match stream.next()
+	{
+		Some(Token {
+			kind: Identifier(name),
+			span,
+		}) => match stream.next_if(|token| token.kind == Less)
+		{
+			Some(open) if name == "List" => list(span, open.span, stream, source),
+			Some(open) if name == "Matrix" => matrix(span, open.span, stream, source),
+			Some(open) => bail!(source.error(open.span, error::TYPE_AFTER)),
+			None => Ok((Type::from_name(&name), span)),
+		},
+		Some(open) if open.kind == ParenthesisLeft =>
+		{
+			tuple(open.span, stream, source)
+		}
+		Some(open) if open.kind == Bar => function(open.span, stream, source),
+		_ => bail!(source.error(after, error::TYPE_AFTER)),
+	}
+}
+
+/// Creates the type of a function, such as `|Number, Bool| -> String`, from a
+/// stream of tokens.  The type is written as it is displayed.
+///
+/// ### Parameters
+/// * `open` - The span of the `|` opening the types of the parameters.
+/// * `stream` - The stream of tokens, after the `|`.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The type and the span of its annotation.
+///
+/// ### Errors
+/// * If a type is missing, the bar is unmatched or the return type is missing.
+fn function<I>(
+	open: Span,
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+) -> Result<(Type, Span)>
+where
+	I: Iterator<Item = Token>,
+{
+	let mut parameters = Vec::new();
+	let mut after = open;
+
+	match stream.next_if(|token| token.kind == Bar)
+	{
+		Some(close) => after = close.span,
+		None => loop
+		{
+			let (r#type, _) = r#type(stream, source, after)?;
+			parameters.push(r#type);
+
+			match stream.next()
+			{
+				Some(token) if token.kind == Comma => after = token.span,
+				Some(token) if token.kind == Bar =>
+				{
+					after = token.span;
+					break;
+				}
+				_ => bail!(source.error(open, error::LAMBDA_BAR)),
+			}
+		},
+	}
+
+	let Some(arrow) = stream.next_if(|token| token.kind == ArrowRight)
+	else
+	{
+		bail!(source.error(after, error::TYPE_ARROW_AFTER))
+	};
+
+	let (returns, span) = r#type(stream, source, arrow.span)?;
+
+	let r#type = Type::Function {
+		parameters,
+		returns: Box::new(returns),
+	};
+
+	let span = Span {
+		start: open.start,
+		end: span.end,
+	};
+
+	Ok((r#type, span))
+}
+
+/// Creates the type of a tuple from a stream of tokens.  As with tuple
+/// expressions, a comma tells a tuple from a type in parentheses.
+///
+/// ### Parameters
+/// * `open` - The span of the `(` opening the types.
+/// * `stream` - The stream of tokens, after the `(`.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The type and the span of its annotation.
+///
+/// ### Errors
+/// * If a type is missing or the parenthesis is unmatched.
+fn tuple<I>(
+	open: Span,
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+) -> Result<(Type, Span)>
+where
+	I: Iterator<Item = Token>,
+{
+	let mut types = Vec::new();
+	let mut after = open;
+	let mut is_tuple = false;
+
+	loop
+	{
+		let (r#type, _) = r#type(stream, source, after)?;
+		types.push(r#type);
+
+		match stream.next()
+		{
+			Some(token) if token.kind == Comma =>
+			{
+				after = token.span;
+				is_tuple = true;
+			}
+			Some(token) if token.kind == ParenthesisRight =>
+			{
+				after = token.span;
+				break;
+			}
+			_ => bail!(source.error(open, error::PARENTHESIS)),
+		}
+
+		if let Some(close) = stream.next_if(|token| token.kind == ParenthesisRight)
+		{
+			after = close.span;
+			break;
+		}
+	}
+
+	let span = Span {
+		start: open.start,
+		end: after.end,
+	};
+
+	match is_tuple
+	{
+		true => Ok((Type::Tuple(types), span)),
+		false => Ok((types.remove(0), span)),
+	}
+}
+
+/// Creates a list type, such as `List<T>`, from a stream of tokens.
+///
+/// ### Parameters
+/// * `name` - The span of `List`.
+/// * `open` - The span of the `<` opening the type of the items.
+/// * `stream` - The stream of tokens, after the `<`.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The type and the span of its annotation.
+///
+/// ### Errors
+/// * If the type of the items is missing or the `<` is unmatched.
+fn list<I>(
+	name: Span,
+	open: Span,
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+) -> Result<(Type, Span)>
+where
+	I: Iterator<Item = Token>,
+{
+	let (r#type, _) = r#type(stream, source, open)?;
+	let close = angle(open, stream, source)?;
+
+	let span = Span {
+		start: name.start,
+		end: close.end,
+	};
+
+	Ok((Type::List(Box::new(r#type)), span))
+}
+
+/// Creates a matrix type, such as `Matrix<2, n>`, from a stream of tokens.
+///
+/// ### Parameters
+/// * `name` - The span of `Matrix`.
+/// * `open` - The span of the `<` opening the sizes.
+/// * `stream` - The stream of tokens, after the `<`.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The type and the span of its annotation.
+///
+/// ### Errors
+/// * If there are not two sizes or the `<` is unmatched.
+fn matrix<I>(
+	name: Span,
+	open: Span,
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+) -> Result<(Type, Span)>
+where
+	I: Iterator<Item = Token>,
+{
+	let rows = size(open, stream, source)?;
+
+	let comma = match stream.next()
+	{
+		Some(token) if token.kind == Comma => token.span,
+		Some(token) => bail!(source.error(token.span, error::COMMA)),
+		None => bail!(source.error(open, error::ANGLE)),
+	};
+
+	let columns = size(comma, stream, source)?;
+	let close = angle(open, stream, source)?;
+
+	let span = Span {
+		start: name.start,
+		end: close.end,
+	};
+
+	Ok((Type::Matrix(Box::new([rows, columns])), span))
+}
+
+/// Creates the length of a dimension of a matrix type from the next token.
+///
+/// ### Parameters
+/// * `after` - The span of the token before the size.
+/// * `stream` - The stream of tokens.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The size.
+///
+/// ### Errors
+/// * If the token is neither a whole number nor a name.
+fn size<I>(
+	after: Span,
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+) -> Result<Size>
+where
+	I: Iterator<Item = Token>,
+{
+	match stream.next()
+	{
+		Some(Token {
+			kind: Identifier(name),
+			..
+		}) => Ok(Size::Parameter(name)),
+		Some(Token {
+			kind: Number(number),
+			span,
+		}) => match number.parse()
+		{
+			Ok(length) => Ok(Size::Fixed(length)),
+			Err(_) => bail!(source.error(span, error::SIZE)),
+		},
+		Some(token) => bail!(source.error(token.span, error::SIZE)),
+		None => bail!(source.error(after, error::SIZE)),
+	}
+}
+
+/// Consumes the `>` closing the arguments of a type.
+///
+/// ### Parameters
+/// * `open` - The span of the `<` opening the arguments.
+/// * `stream` - The stream of tokens.
+/// * `source` - The source code.
+///
+/// ### Returns
+/// * The span of the `>`.
+///
+/// ### Errors
+/// * If the next token is not a `>`.
+fn angle<I>(
+	open: Span,
+	stream: &mut Peekable<I>,
+	source: &SourceMap,
+) -> Result<Span>
+where
+	I: Iterator<Item = Token>,
+{
+	match stream.next_if(|token| token.kind == Greater)
+	{
+		Some(close) => Ok(close.span),
+		None => bail!(source.error(open, error::ANGLE)),
+	}
+}
+
 fn try_parameter_from_stream<I>(
 	stream: &mut Peekable<I>,
-	source: &[Vec<char>],
+	source: &SourceMap,
 ) -> Result<Parameter>
 where
 	I: Iterator<Item = Token>,
 {
 	let token = stream.next().expect("Identifier");
-	match token.kind
+	let name = match token.kind
 	{
-		Identifier(name) => Ok(Parameter {
-			span: token.span,
-			name,
-		}),
+		Identifier(name) => name,
 		_ => bail!(source.error(token.span, error::IDENTIFIER)),
-	}
+	};
+
+	// A parameter may be annotated with its type, as in `xs: List<T>`.
+	let r#type = match stream.next_if(|token| token.kind == Colon)
+	{
+		Some(colon) => r#type(stream, source, colon.span)?.0,
+		None => Type::Unit,
+	};
+
+	Ok(Parameter {
+		span: token.span,
+		name,
+		r#type,
+	})
 }
 
 impl Token
@@ -170,3 +497,48 @@ impl Token
 			|| self.kind == Bar
 	}
 }
+
+/// Reports that an expression is missing after some code, when parsing the
+/// expression failed.
+///
+/// An error found inside the first token of the expression is kept instead,
+/// as it can only come from the code embedded in an interpolated string and
+/// already points at where that code went wrong.
+///
+/// ### Parameters
+/// * `error` - The error parsing the expression failed with.
+/// * `first` - The span of the first token of the expression, if any.
+/// * `source` - The source code.
+/// * `after` - The span of the code before the expression.
+/// * `message` - The message saying what was expected.
+///
+/// ### Returns
+/// * The error to report.
+pub fn missing(
+	error: anyhow::Error,
+	first: Option<Span>,
+	source: &SourceMap,
+	after: Span,
+	message: &str,
+) -> anyhow::Error
+{
+	let is_inside = match (error.downcast_ref::<Diagnostic>(), first)
+	{
+		(Some(Diagnostic { span, .. }), Some(first)) =>
+		{
+			span.start.file == first.start.file
+				&& span.start.offset > first.start.offset
+				&& span.end.offset <= first.end.offset
+		}
+		_ => false,
+	};
+
+	if is_inside
+	{
+		error
+	}
+	else
+	{
+		source.error(after, message).into()
+	}
+}
@@ -0,0 +1,12 @@
+/// An error message indicating an unknown meta-command.
+pub const COMMAND: &str =
+	"Unknown command; type `:help` for a list of commands";
+
+/// An error message indicating input that is not a single expression.
+pub const EXPRESSION: &str = "Expected a single expression";
+
+/// An error message indicating the Python interpreter could not be started.
+pub const START: &str = "Failed to start the Python interpreter 🐍";
+
+/// An error message indicating the Python interpreter stopped responding.
+pub const CONNECTION: &str = "Lost the connection to the Python interpreter 🐍";
@@ -0,0 +1,6 @@
+mod error;
+pub mod runtime;
+pub mod session;
+
+pub use runtime::*;
+pub use session::*;
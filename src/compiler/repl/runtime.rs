@@ -0,0 +1,214 @@
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use super::*;
+
+/// The Python programme that runs the code the REPL sends it.
+///
+/// Each request is a line holding a command and the length of its code in
+/// bytes, followed by the code.  The reply is whatever the code prints, then
+/// an optional `value` or `error` section, then an `end` marker.  An
+/// expression evaluating to `None` has no `value` section.  Every marker
+/// starts with a NUL so that it cannot be confused with printed output.
+const DRIVER: &str = r#"
+import sys, traceback
+
+scope = {"__name__": "__main__"}
+
+while True:
+    header = sys.stdin.buffer.readline().split()
+    if not header:
+        break
+    command, length = header[0].decode(), int(header[1])
+    code = sys.stdin.buffer.read(length).decode()
+    try:
+        if command == "reset":
+            scope.clear()
+            scope["__name__"] = "__main__"
+        elif command == "exec":
+            exec(code, scope)
+        else:
+            value = eval(code, scope)
+            if value is not None:
+                print("\0value")
+                print(value)
+    except BaseException as error:
+        print("\0error")
+        message = traceback.format_exception_only(type(error), error)
+        print("".join(message).strip())
+    print("\0end", flush=True)
+"#;
+
+/// A place where synthesised Python code runs.
+///
+/// State persists between calls, so a variable defined by one call can be used
+/// by the next.
+pub trait Runtime
+{
+	/// Executes Python statements.
+	///
+	/// ### Parameters
+	/// * `code` - The Python statements.
+	///
+	/// ### Returns
+	/// * What the statements print.
+	///
+	/// ### Errors
+	/// * If the statements raise an exception.
+	fn execute(&mut self, code: &str) -> Result<String>;
+
+	/// Evaluates a Python expression.
+	///
+	/// ### Parameters
+	/// * `code` - The Python expression.
+	///
+	/// ### Returns
+	/// * What the expression prints, and its value unless it is `None`.
+	///
+	/// ### Errors
+	/// * If the expression raises an exception.
+	fn evaluate(&mut self, code: &str) -> Result<(String, Option<String>)>;
+
+	/// Forgets everything defined so far.
+	///
+	/// ### Errors
+	/// * If the runtime cannot be reset.
+	fn reset(&mut self) -> Result<()>;
+}
+
+/// A Python interpreter running alongside the REPL for its whole session.
+pub struct Python
+{
+	/// The interpreter process.
+	child: Child,
+
+	/// The stream the interpreter reads requests from.
+	input: ChildStdin,
+
+	/// The stream the interpreter writes replies to.
+	output: BufReader<ChildStdout>,
+}
+
+impl Python
+{
+	/// Starts a Python interpreter.
+	///
+	/// ### Parameters
+	/// * `interpreter` - The command that runs Python.
+	///
+	/// ### Returns
+	/// * The running interpreter.
+	///
+	/// ### Errors
+	/// * If the interpreter cannot be started.
+	pub fn new(interpreter: &str) -> Result<Self>
+	{
+		let mut child = Command::new(interpreter)
+			.args(["-u", "-c", DRIVER])
+			.stdin(Stdio::piped())
+			.stdout(Stdio::piped())
+			.spawn()
+			.context(error::START)?;
+
+		let input = child.stdin.take().context(error::START)?;
+		let output = child.stdout.take().context(error::START)?;
+
+		Ok(Self {
+			child,
+			input,
+			output: BufReader::new(output),
+		})
+	}
+
+	/// Sends a request to the interpreter and waits for its reply.
+	///
+	/// ### Parameters
+	/// * `command` - The command of the request.
+	/// * `code` - The code of the request.
+	///
+	/// ### Returns
+	/// * What the code printed, and its value if it has one.
+	///
+	/// ### Errors
+	/// * If the code raises an exception.
+	/// * If the interpreter stops responding.
+	fn request(
+		&mut self,
+		command: &str,
+		code: &str,
+	) -> Result<(String, Option<String>)>
+	{
+		write!(self.input, "{command} {}\n{code}", code.len())
+			.and_then(|_| self.input.flush())
+			.context(error::CONNECTION)?;
+
+		let mut printed = String::new();
+		let mut value = None;
+		let mut exception = None;
+
+		loop
+		{
+			let mut line = String::new();
+
+			let length = self.output.read_line(&mut line);
+
+			if length.context(error::CONNECTION)? == 0
+			{
+				bail!(error::CONNECTION)
+			}
+
+			let section = match line.trim_end()
+			{
+				"\0end" => break,
+				"\0value" => value.insert(String::new()),
+				"\0error" => exception.insert(String::new()),
+				_ => value
+					.as_mut()
+					.or(exception.as_mut())
+					.unwrap_or(&mut printed),
+			};
+
+			if !line.starts_with('\0')
+			{
+				section.push_str(&line);
+			}
+		}
+
+		match exception
+		{
+			Some(exception) => Err(anyhow!("{printed}{}", exception.trim_end())),
+			None => Ok((printed, value.map(|value| value.trim_end().to_string()))),
+		}
+	}
+}
+
+impl Runtime for Python
+{
+	fn execute(&mut self, code: &str) -> Result<String>
+	{
+		let (printed, _) = self.request("exec", code)?;
+		Ok(printed)
+	}
+
+	fn evaluate(&mut self, code: &str) -> Result<(String, Option<String>)>
+	{
+		self.request("eval", code)
+	}
+
+	fn reset(&mut self) -> Result<()>
+	{
+		self.request("reset", "")?;
+		Ok(())
+	}
+}
+
+impl Drop for Python
+{
+	fn drop(&mut self)
+	{
+		let _ = self.child.kill();
+		let _ = self.child.wait();
+	}
+}
@@ -0,0 +1,442 @@
+use std::io::{BufRead, Write};
+
+use anyhow::{bail, Result};
+
+use super::*;
+use crate::compiler::lexer::Lex;
+use crate::compiler::parser::{Parse, Tree};
+use crate::compiler::semanter::table::Table;
+use crate::compiler::synthesiser::hoist::Hoist;
+use crate::compiler::synthesiser::{layout, Synthesis, DATACLASS, HEADER};
+use crate::language::grammar::{Expression, Statement};
+use crate::language::lexicon::token::Kind::{
+	BraceLeft, BraceRight, BracketLeft, BracketRight, BracketRightWithA,
+	BracketRightWithM, ParenthesisLeft, ParenthesisRight, Semicolon,
+};
+use crate::language::lexicon::Token;
+use crate::language::semantics::prelude::PRELUDE;
+use crate::language::semantics::r#type::Type;
+use crate::language::utils::SourceMap;
+
+/// The name given to the code typed into the REPL.
+const SOURCE: &str = "<repl>";
+
+/// The prompt shown before a new input.
+const PROMPT: &str = "quark> ";
+
+/// The prompt shown while an input continues onto another line.
+const CONTINUATION: &str = "...... ";
+
+/// The text shown by the `:help` command.
+const HELP: &str = "\
+Type Quark statements or expressions to run them.  An input continues onto
+the next line until its braces, brackets and parentheses are balanced, or
+until an empty line is entered.
+
+:type <expr>  Shows the type of an expression
+:ast <code>   Shows the abstract syntax tree of some code
+:py <code>    Shows the Python some code compiles to
+:reset        Forgets every declaration
+:help         Shows this message
+:quit         Leaves the REPL";
+
+/// An interactive session that compiles each input against everything
+/// declared before it and runs it in a persistent runtime.
+pub struct Session<R: Runtime>
+{
+	/// The runtime the compiled code runs in.
+	runtime: R,
+
+	/// The symbols declared so far.
+	table: Table,
+
+	/// The lines of an input that is not yet complete.
+	buffer: String,
+}
+
+/// The reply of a session to a line of input.
+#[derive(Debug, PartialEq)]
+pub enum Reply
+{
+	/// The input continues onto the next line.
+	Incomplete,
+
+	/// The input is complete, and this is its result.
+	Output(String),
+
+	/// The user asked to leave the session.
+	Quit,
+}
+
+/// A complete input to the REPL.
+enum Input
+{
+	/// A lone expression, whose value is shown.
+	Expression(Expression),
+
+	/// One or more statements.
+	Statements(Vec<Statement>),
+}
+
+impl<R: Runtime> Session<R>
+{
+	/// Starts a session.
+	///
+	/// ### Parameters
+	/// * `runtime` - The runtime the compiled code runs in.
+	///
+	/// ### Returns
+	/// * The new session.
+	///
+	/// ### Errors
+	/// * If the prelude cannot be defined in the runtime.
+	pub fn new(runtime: R) -> Result<Self>
+	{
+		let mut session = Self {
+			runtime,
+			table: Table::new(),
+			buffer: String::new(),
+		};
+
+		session.prelude()?;
+
+		Ok(session)
+	}
+
+	/// Reads lines from the user until they leave, writing the result of each
+	/// input.
+	///
+	/// ### Parameters
+	/// * `input` - The stream the user types into.
+	/// * `output` - The stream shown to the user.
+	///
+	/// ### Errors
+	/// * If the streams cannot be read or written.
+	pub fn run(
+		&mut self,
+		input: impl BufRead,
+		mut output: impl Write,
+	) -> Result<()>
+	{
+		write!(output, "{}", self.prompt())?;
+		output.flush()?;
+
+		for line in input.lines()
+		{
+			match self.line(&line?)
+			{
+				Ok(Reply::Quit) => return Ok(()),
+				Ok(Reply::Incomplete) => (),
+				Ok(Reply::Output(text)) if text.is_empty() => (),
+				Ok(Reply::Output(text)) => writeln!(output, "{text}")?,
+				Err(error) => writeln!(output, "{error}")?,
+			}
+
+			write!(output, "{}", self.prompt())?;
+			output.flush()?;
+		}
+
+		writeln!(output)?;
+		Ok(())
+	}
+
+	/// Gets the prompt to show before the next line.
+	///
+	/// ### Returns
+	/// * The prompt.
+	pub fn prompt(&self) -> &'static str
+	{
+		match self.buffer.is_empty()
+		{
+			true => PROMPT,
+			false => CONTINUATION,
+		}
+	}
+
+	/// Handles a line of input.
+	///
+	/// ### Parameters
+	/// * `line` - The line.
+	///
+	/// ### Returns
+	/// * The reply to the line.
+	///
+	/// ### Errors
+	/// * If the input cannot be compiled or run.
+	pub fn line(&mut self, line: &str) -> Result<Reply>
+	{
+		let is_continued = !self.buffer.is_empty();
+
+		self.buffer.push_str(line);
+		self.buffer.push('\n');
+
+		// An empty line ends an input whose delimiters will never balance.
+		let is_abandoned = is_continued && line.trim().is_empty();
+
+		if !is_balanced(&self.buffer) && !is_abandoned
+		{
+			return Ok(Reply::Incomplete);
+		}
+
+		let input = std::mem::take(&mut self.buffer);
+		let input = input.trim();
+
+		let Some(command) = input.strip_prefix(':')
+		else
+		{
+			return self.evaluate(input).map(Reply::Output);
+		};
+
+		let (command, code) = command
+			.split_once(char::is_whitespace)
+			.unwrap_or((command, ""));
+
+		let output = match command
+		{
+			"type" => match parse(code)?
+			{
+				Input::Expression(expression) =>
+				{
+					describe(&expression.r#type(&self.table)?)
+				}
+				Input::Statements(_) => bail!(error::EXPRESSION),
+			},
+
+			"ast" => match parse(code)?
+			{
+				Input::Expression(expression) => format!("{expression:#?}"),
+				Input::Statements(statements) => format!("{statements:#?}"),
+			},
+
+			"py" => match parse(code)?
+			{
+				Input::Expression(expression) =>
+				{
+					let (statements, expression) =
+						Hoist::default().expression(expression);
+					let definitions = python(statements);
+					let expression = expression.synthesise();
+
+					match definitions.is_empty()
+					{
+						true => expression,
+						false => format!("{definitions}\n{expression}"),
+					}
+				}
+				Input::Statements(statements) => python(statements),
+			},
+
+			"reset" =>
+			{
+				self.runtime.reset()?;
+				self.table = Table::new();
+				self.prelude()?;
+				String::new()
+			}
+
+			"help" => HELP.to_string(),
+			"quit" | "q" => return Ok(Reply::Quit),
+			_ => bail!(error::COMMAND),
+		};
+
+		Ok(Reply::Output(output))
+	}
+
+	/// Compiles and runs an input.
+	///
+	/// ### Parameters
+	/// * `code` - The input.
+	///
+	/// ### Returns
+	/// * What the input printed, followed by its value and type if it is an
+	///   expression.
+	///
+	/// ### Errors
+	/// * If the input cannot be compiled or run.
+	fn evaluate(&mut self, code: &str) -> Result<String>
+	{
+		if code.is_empty()
+		{
+			return Ok(String::new());
+		}
+
+		match parse(code)?
+		{
+			Input::Expression(expression) =>
+			{
+				let r#type = expression.r#type(&self.table)?;
+
+				// Lambdas with block bodies must be defined before the expression
+				// can refer to them.
+				let (statements, expression) = Hoist::default().expression(expression);
+				if !statements.is_empty()
+				{
+					self.runtime.execute(&python(statements))?;
+				}
+
+				let (printed, value) =
+					self.runtime.evaluate(&expression.synthesise())?;
+
+				let Some(value) = value
+				else
+				{
+					return Ok(printed.trim_end().to_string());
+				};
+
+				let value = match r#type
+				{
+					Type::Boolean => value.to_lowercase(),
+					Type::String => format!("{value:?}"),
+					_ => value,
+				};
+
+				Ok(format!("{printed}{value}: {}", describe(&r#type)))
+			}
+
+			Input::Statements(statements) =>
+			{
+				// Declarations only take effect once their code has run.
+				let mut table = self.table.clone();
+
+				for statement in &statements
+				{
+					statement.analyse(&mut table)?;
+				}
+
+				let printed = self.runtime.execute(&python(statements))?;
+				self.table = table;
+
+				Ok(printed.trim_end().to_string())
+			}
+		}
+	}
+
+	/// Defines the prelude in the runtime.
+	///
+	/// ### Errors
+	/// * If the prelude cannot be defined.
+	fn prelude(&mut self) -> Result<()>
+	{
+		let constants: String = PRELUDE
+			.iter()
+			.map(|constant| format!("{} = {}\n", constant.name, constant.python))
+			.collect();
+
+		let code = format!("{HEADER}{constants}{DATACLASS}\n");
+		self.runtime.execute(&code)?;
+		Ok(())
+	}
+}
+
+/// Describes the type of an expression to the user.
+///
+/// ### Parameters
+/// * `type` - The type of the expression.
+///
+/// ### Returns
+/// * The name of the type, or `unknown` if the type is not known.
+fn describe(r#type: &Type) -> String
+{
+	match r#type
+	{
+		Type::Unit => String::from("unknown"),
+		_ => r#type.to_string(),
+	}
+}
+
+/// Parses an input, which is either a lone expression or some statements.
+/// The semicolon after the last statement may be left out.
+///
+/// ### Parameters
+/// * `code` - The input.
+///
+/// ### Returns
+/// * The parsed input.
+///
+/// ### Errors
+/// * If the input cannot be parsed.
+fn parse(code: &str) -> Result<Input>
+{
+	let mut source = SourceMap::new();
+	let file = source.add(SOURCE, code.to_string());
+	let mut tokens = file.lex(&source)?;
+
+	let mut stream = tokens.clone().into_iter().peekable();
+
+	if let Ok(expression) = Expression::try_from_stream(&mut stream, &source)
+	{
+		stream.next_if(|token| token.kind == Semicolon);
+
+		if stream.peek().is_none()
+		{
+			return Ok(Input::Expression(expression));
+		}
+	}
+
+	match tokens.last()
+	{
+		Some(Token {
+			kind: Semicolon | BraceRight,
+			..
+		})
+		| None => (),
+
+		Some(&Token { span, .. }) => tokens.push(Token {
+			span,
+			kind: Semicolon,
+		}),
+	}
+
+	let Tree(programme) = tokens.parse(&source)?;
+	Ok(Input::Statements(programme.statements))
+}
+
+/// Synthesises statements into Python.
+///
+/// ### Parameters
+/// * `statements` - The statements.
+///
+/// ### Returns
+/// * The Python code.
+fn python(statements: Vec<Statement>) -> String
+{
+	let syntax = Hoist::default()
+		.statements(statements)
+		.into_iter()
+		.map(|statement| statement.synthesise())
+		.collect();
+
+	layout(syntax).trim_end().to_string()
+}
+
+/// Determines if the delimiters of some code are balanced, so that it can be
+/// compiled.  Code that cannot be lexed counts as balanced so that its error
+/// is reported straight away.
+///
+/// ### Parameters
+/// * `code` - The code.
+///
+/// ### Returns
+/// * `true` if no delimiter is left open.
+/// * `false` otherwise.
+fn is_balanced(code: &str) -> bool
+{
+	let mut source = SourceMap::new();
+	let file = source.add(SOURCE, code.to_string());
+
+	let Ok(tokens) = file.lex(&source)
+	else
+	{
+		return true;
+	};
+
+	let depth = tokens.iter().fold(0, |depth, token| match token.kind
+	{
+		BraceLeft | ParenthesisLeft | BracketLeft => depth + 1,
+		BraceRight | ParenthesisRight | BracketRight | BracketRightWithM
+		| BracketRightWithA => depth - 1,
+		_ => depth,
+	});
+
+	depth <= 0
+}
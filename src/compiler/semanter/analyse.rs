@@ -1,6 +1,11 @@
+use std::collections::HashMap;
+
 use anyhow::Result;
 
+use super::table::Table;
 use crate::compiler::parser::parse::Tree;
+use crate::compiler::Diagnostic;
+use crate::language::utils::SourceMap;
 
 impl Tree
 {
@@ -11,10 +16,39 @@ impl Tree
 	///
 	/// ### Errors
 	/// * If there are semantic errors in the abstract syntax tree.
-	pub fn analyse(self, _source: &[Vec<char>]) -> Result<Self>
+	pub fn analyse(self, source: &SourceMap) -> Result<Self>
+	{
+		let (tree, _) = self.analyse_module(source, HashMap::new())?;
+		Ok(tree)
+	}
+
+	/// Semantically analyses the abstract syntax tree of a module.
+	///
+	/// ### Parameters
+	/// * `source` - The source code, to render errors with.
+	/// * `modules` - The exported symbols of the modules this one may import.
+	///
+	/// ### Returns
+	/// * The abstract syntax tree if it is semantically accurate.
+	/// * The symbols exported by the module.
+	///
+	/// ### Errors
+	/// * If there are semantic errors in the abstract syntax tree.
+	pub fn analyse_module(
+		self,
+		source: &SourceMap,
+		modules: HashMap<String, Table>,
+	) -> Result<(Self, Table)>
 	{
 		let Self(programme) = &self;
-		programme.analyse()?;
-		Ok(self)
+		let exports = programme
+			.analyse(modules)
+			.map_err(|error| match error.downcast::<Diagnostic>()
+			{
+				Ok(diagnostic) => diagnostic.render(source).into(),
+				Err(error) => error,
+			})?
+			.exports();
+		Ok((self, exports))
 	}
 }
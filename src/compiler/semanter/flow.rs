@@ -0,0 +1,336 @@
+use std::collections::HashSet;
+
+use crate::language::grammar::functions::Body;
+use crate::language::grammar::pattern;
+use crate::language::grammar::statement::{Kind::*, Statement};
+use crate::language::grammar::{
+	Block, FunctionDclr, IfStmt, Lambda, MatchStmt, Programme, WhileStmt,
+};
+
+/// The index of a basic block in a control-flow graph.
+pub type BlockId = usize;
+
+/// A run of statements that always run one after the other.
+#[derive(Clone, Debug, Default)]
+pub struct BasicBlock<'ast>
+{
+	/// The statements of the block, in order.  A statement that transfers
+	/// control, such as an `if` or a `return`, is the last of its block: the
+	/// statements of its bodies are in the blocks that follow it.
+	pub statements: Vec<&'ast Statement>,
+
+	/// The blocks control may go to after the block.
+	pub successors: Vec<BlockId>,
+}
+
+/// The control-flow graph of the body of a function, a lambda or the top
+/// level of a programme.
+///
+/// The bodies of the functions and lambdas declared within the body are not
+/// part of the graph, as they only run when called: each has a graph of its
+/// own.
+#[derive(Clone, Debug)]
+pub struct Graph<'ast>
+{
+	/// The basic blocks of the graph.  The entry block comes first and the
+	/// exit block, which has no statements, second.
+	pub blocks: Vec<BasicBlock<'ast>>,
+}
+
+impl<'ast> Graph<'ast>
+{
+	/// The block control enters the body through.
+	pub const ENTRY: BlockId = 0;
+	/// The block control leaves the body through, by `return` or by running
+	/// the last statement of the body.
+	pub const EXIT: BlockId = 1;
+
+	/// Builds the control-flow graph of a body.
+	///
+	/// ### Parameters
+	/// * `statements` - The statements of the body.
+	///
+	/// ### Returns
+	/// * The control-flow graph of the body.
+	pub fn new(statements: impl IntoIterator<Item = &'ast Statement>) -> Self
+	{
+		let mut builder = Builder {
+			blocks: vec![BasicBlock::default(), BasicBlock::default()],
+			loops: Vec::new(),
+		};
+
+		let end = builder.statements(statements, Self::ENTRY);
+		builder.edge(end, Self::EXIT);
+
+		Self {
+			blocks: builder.blocks,
+		}
+	}
+
+	/// Gets the blocks control may come from before a block.
+	///
+	/// ### Parameters
+	/// * `block` - The block.
+	///
+	/// ### Returns
+	/// * The blocks that have the block as a successor.
+	pub fn predecessors(&self, block: BlockId) -> Vec<BlockId>
+	{
+		(0..self.blocks.len())
+			.filter(|&id| self.blocks[id].successors.contains(&block))
+			.collect()
+	}
+
+	/// Finds the blocks control can reach from the entry block.
+	///
+	/// ### Returns
+	/// * The reachable blocks.
+	pub fn reachable(&self) -> HashSet<BlockId>
+	{
+		let mut reachable = HashSet::from([Self::ENTRY]);
+		let mut pending = vec![Self::ENTRY];
+
+		while let Some(block) = pending.pop()
+		{
+			for &successor in &self.blocks[block].successors
+			{
+				if reachable.insert(successor)
+				{
+					pending.push(successor);
+				}
+			}
+		}
+
+		reachable
+	}
+
+	/// Finds the statements of the body that can never run.
+	///
+	/// ### Returns
+	/// * The statements in the blocks control cannot reach, in the order of their
+	///   blocks.
+	pub fn unreachable(&self) -> impl Iterator<Item = &'ast Statement> + '_
+	{
+		let reachable = self.reachable();
+
+		self
+			.blocks
+			.iter()
+			.enumerate()
+			.filter(move |(id, _)| !reachable.contains(id))
+			.flat_map(|(_, block)| block.statements.iter().copied())
+	}
+}
+
+impl Programme
+{
+	/// Builds the control-flow graph of the top level of the programme.
+	///
+	/// ### Returns
+	/// * The control-flow graph.
+	pub fn graph(&self) -> Graph<'_>
+	{
+		Graph::new(&self.statements)
+	}
+}
+
+impl FunctionDclr
+{
+	/// Builds the control-flow graph of the body of the function.
+	///
+	/// ### Returns
+	/// * The control-flow graph.
+	pub fn graph(&self) -> Graph<'_>
+	{
+		Graph::new(self.body.statements.iter().flatten())
+	}
+}
+
+impl Lambda
+{
+	/// Builds the control-flow graph of the body of the lambda.
+	///
+	/// ### Returns
+	/// * The control-flow graph, which has only an entry and an exit block if the
+	///   body is an expression.
+	pub fn graph(&self) -> Graph<'_>
+	{
+		match &self.body
+		{
+			Body::Block(block) => Graph::new(block.statements.iter().flatten()),
+			Body::Expression(_) => Graph::new([]),
+		}
+	}
+}
+
+/// Builds a control-flow graph one statement at a time.
+struct Builder<'ast>
+{
+	/// The basic blocks built so far.
+	blocks: Vec<BasicBlock<'ast>>,
+
+	/// The header and the following block of each loop around the statement
+	/// being added, innermost loop last.
+	loops: Vec<(BlockId, BlockId)>,
+}
+
+impl<'ast> Builder<'ast>
+{
+	/// Adds an empty block.
+	fn block(&mut self) -> BlockId
+	{
+		self.blocks.push(BasicBlock::default());
+		self.blocks.len() - 1
+	}
+
+	/// Adds an edge from one block to another.
+	fn edge(&mut self, from: BlockId, to: BlockId)
+	{
+		self.blocks[from].successors.push(to);
+	}
+
+	/// Adds statements that run one after the other.
+	///
+	/// ### Parameters
+	/// * `statements` - The statements.
+	/// * `block` - The block control is in before the statements.
+	///
+	/// ### Returns
+	/// * The block control is in after the statements.
+	fn statements(
+		&mut self,
+		statements: impl IntoIterator<Item = &'ast Statement>,
+		block: BlockId,
+	) -> BlockId
+	{
+		statements
+			.into_iter()
+			.fold(block, |block, statement| self.statement(statement, block))
+	}
+
+	/// Adds the statements of a body, which control enters through a new
+	/// block.
+	///
+	/// ### Parameters
+	/// * `body` - The body.
+	/// * `from` - The block control enters the body from.
+	///
+	/// ### Returns
+	/// * The block control is in after the body.
+	fn body(&mut self, body: &'ast Block, from: BlockId) -> BlockId
+	{
+		let entry = self.block();
+		self.edge(from, entry);
+		self.statements(body.statements.iter().flatten(), entry)
+	}
+
+	/// Adds a statement.
+	///
+	/// ### Parameters
+	/// * `statement` - The statement.
+	/// * `block` - The block control is in before the statement.
+	///
+	/// ### Returns
+	/// * The block control is in after the statement, which has no predecessors
+	///   if the statement never finishes.
+	fn statement(&mut self, statement: &'ast Statement, block: BlockId)
+		-> BlockId
+	{
+		match &statement.kind
+		{
+			If(IfStmt {
+				body,
+				else_ifs,
+				else_body,
+				..
+			}) =>
+			{
+				self.blocks[block].statements.push(statement);
+				let join = self.block();
+
+				let bodies = std::iter::once(body)
+					.chain(else_ifs.iter().map(|else_if| &else_if.body))
+					.chain(else_body);
+
+				for body in bodies
+				{
+					let end = self.body(body, block);
+					self.edge(end, join);
+				}
+
+				if else_body.is_none()
+				{
+					self.edge(block, join);
+				}
+
+				join
+			}
+			Match(MatchStmt { arms, .. }) =>
+			{
+				self.blocks[block].statements.push(statement);
+				let join = self.block();
+
+				for arm in arms
+				{
+					let end = self.body(&arm.body, block);
+					self.edge(end, join);
+				}
+
+				// Whether the arms cover every value is only known from types,
+				// so a match without a catch-all arm may match nothing.
+				let is_total = arms.iter().any(|arm| {
+					arm.guard.is_none()
+						&& arm
+							.patterns
+							.iter()
+							.any(|pattern| pattern.kind == pattern::Kind::Wildcard)
+				});
+
+				if !is_total
+				{
+					self.edge(block, join);
+				}
+
+				join
+			}
+			While(WhileStmt { body, .. }) =>
+			{
+				let header = self.block();
+				self.edge(block, header);
+				self.blocks[header].statements.push(statement);
+
+				let after = self.block();
+				self.edge(header, after);
+
+				self.loops.push((header, after));
+				let end = self.body(body, header);
+				self.loops.pop();
+
+				self.edge(end, header);
+				after
+			}
+			Break(_) | Continue(_) | Return(_) =>
+			{
+				self.blocks[block].statements.push(statement);
+
+				// Semantic analysis rejects `break` and `continue` outside of
+				// a loop, so they can only be found here in a loop.
+				let target = match (&statement.kind, self.loops.last())
+				{
+					(Break(_), Some(&(_, after))) => after,
+					(Continue(_), Some(&(header, _))) => header,
+					_ => Graph::EXIT,
+				};
+
+				self.edge(block, target);
+				self.block()
+			}
+			Declaration(_) | Expression(_) | Assignment(_) | Echo(_) | Import(_)
+			| Use(_) | FunctionCall(_) =>
+			{
+				self.blocks[block].statements.push(statement);
+				block
+			}
+		}
+	}
+}
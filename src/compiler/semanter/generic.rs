@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, ensure, Result};
+
+use super::table::Table;
+use crate::language::grammar::expression::{Expression, Items};
+use crate::language::semantics::r#type::{Size, Type};
+
+/// The types and sizes that the type and size parameters of a generic function
+/// stand for at a call, inferred from the arguments.
+#[derive(Debug, Default)]
+pub struct Bindings
+{
+	types: HashMap<String, Type>,
+	sizes: HashMap<String, usize>,
+}
+
+impl Bindings
+{
+	/// Infers the type and size parameters of a call from its arguments,
+	/// checking each argument against its parameter.
+	///
+	/// ### Parameters
+	/// * `name` - The name of what is called.
+	/// * `parameters` - The types of the parameters.
+	/// * `arguments` - The arguments, as many as there are parameters.
+	/// * `symbol` - The symbol table.
+	///
+	/// ### Returns
+	/// * What each type and size parameter stands for.
+	///
+	/// ### Errors
+	/// * If an argument is not of the type of its parameter.
+	/// * If a parameter would stand for two different types or sizes.
+	pub fn new(
+		name: &str,
+		parameters: &[Type],
+		arguments: &Option<Items>,
+		symbol: &Table,
+	) -> Result<Self>
+	{
+		let arguments = arguments
+			.iter()
+			.flat_map(|arguments| &arguments.expressions);
+
+		let mut bindings = Self::default();
+
+		for (argument, parameter) in arguments.zip(parameters)
+		{
+			bindings.bind(name, parameter, argument, symbol)?;
+		}
+
+		Ok(bindings)
+	}
+
+	/// Gets a type with each type and size parameter replaced by what it stands
+	/// for.  A type parameter that nothing was inferred for stands for `Unit`.
+	///
+	/// ### Parameters
+	/// * `type` - The type, such as the return type of a generic function.
+	///
+	/// ### Returns
+	/// * The type at the call.
+	pub fn substitute(&self, r#type: &Type) -> Type
+	{
+		match r#type
+		{
+			Type::Variable(name) =>
+			{
+				self.types.get(name).cloned().unwrap_or(Type::Unit)
+			}
+			Type::Tuple(types) => Type::Tuple(
+				types.iter().map(|r#type| self.substitute(r#type)).collect(),
+			),
+			Type::List(r#type) => Type::List(Box::new(self.substitute(r#type))),
+			Type::Matrix(sizes) =>
+			{
+				Type::Matrix(Box::new(sizes.clone().map(|size| match size
+				{
+					Size::Parameter(name) if self.sizes.contains_key(&name) =>
+					{
+						Size::Fixed(self.sizes[&name])
+					}
+					size => size,
+				})))
+			}
+			Type::Function {
+				parameters,
+				returns,
+			} => Type::Function {
+				parameters: parameters
+					.iter()
+					.map(|r#type| self.substitute(r#type))
+					.collect(),
+				returns: Box::new(self.substitute(returns)),
+			},
+			r#type => r#type.clone(),
+		}
+	}
+
+	/// Binds the parameters in the type of a parameter from its argument.
+	/// Matrices are typed `Unit`, so the sizes of a matrix are inferred from
+	/// the shape of the argument.
+	fn bind(
+		&mut self,
+		name: &str,
+		parameter: &Type,
+		argument: &Expression,
+		symbol: &Table,
+	) -> Result<()>
+	{
+		let r#type = argument.r#type(symbol)?;
+
+		match parameter
+		{
+			Type::Matrix(sizes) if matches!(r#type, Type::Unit | Type::List(_)) =>
+			{
+				match argument.shape(symbol).as_deref()
+				{
+					Some(&[rows, columns]) =>
+					{
+						let [expected_rows, expected_columns] = sizes.as_ref();
+						ensure!(
+							self.size(name, expected_rows, rows)?
+								&& self.size(name, expected_columns, columns)?,
+							"`{name}` expects a `{parameter}` argument, not a {rows} by \
+							 {columns} matrix"
+						);
+						Ok(())
+					}
+					Some(shape) => bail!(
+						"`{name}` expects a `{parameter}` argument, not a value with {} \
+						 dimension{}",
+						shape.len(),
+						if shape.len() == 1 { "" } else { "s" }
+					),
+					None => Ok(()),
+				}
+			}
+			_ => self.unify(name, parameter, &r#type),
+		}
+	}
+
+	/// Binds the type and size parameters in a type from the type of a value
+	/// given for it, checking that the value is accepted.
+	fn unify(&mut self, name: &str, parameter: &Type, r#type: &Type)
+		-> Result<()>
+	{
+		ensure!(
+			parameter.accepts(r#type),
+			"`{name}` expects a `{parameter}` argument, not `{type}`"
+		);
+
+		match (parameter, r#type)
+		{
+			(_, Type::Unit) => Ok(()),
+			(Type::Variable(variable), _) => match self.types.get(variable)
+			{
+				Some(bound) =>
+				{
+					ensure!(
+						bound.accepts(r#type) && r#type.accepts(bound),
+						"`{name}` needs `{variable}` to be both `{bound}` and `{type}`"
+					);
+					Ok(())
+				}
+				None =>
+				{
+					self.types.insert(variable.clone(), r#type.clone());
+					Ok(())
+				}
+			},
+			(Type::Tuple(parameters), Type::Tuple(types))
+				if parameters.len() == types.len() =>
+			{
+				parameters
+					.iter()
+					.zip(types)
+					.try_for_each(|(parameter, r#type)| {
+						self.unify(name, parameter, r#type)
+					})
+			}
+			(Type::List(parameter), Type::List(r#type)) =>
+			{
+				self.unify(name, parameter, r#type)
+			}
+			(Type::Matrix(sizes), Type::Matrix(lengths)) =>
+			{
+				for (size, length) in sizes.iter().zip(lengths.iter())
+				{
+					if let Size::Fixed(length) = length
+					{
+						ensure!(
+							self.size(name, size, *length)?,
+							"`{name}` expects a `{parameter}` argument, not `{type}`"
+						);
+					}
+				}
+				Ok(())
+			}
+			_ => Ok(()),
+		}
+	}
+
+	/// Binds a size parameter from the length of a dimension of a matrix.
+	///
+	/// ### Returns
+	/// * `true` if the length is that of the size.
+	/// * `false` if the size is fixed at another length.
+	///
+	/// ### Errors
+	/// * If the size parameter already stands for another length.
+	fn size(&mut self, name: &str, size: &Size, length: usize) -> Result<bool>
+	{
+		match size
+		{
+			Size::Fixed(fixed) => Ok(*fixed == length),
+			Size::Parameter(parameter) => match self.sizes.get(parameter)
+			{
+				Some(&bound) =>
+				{
+					ensure!(
+						bound == length,
+						"`{name}` needs `{parameter}` to be both {bound} and {length}"
+					);
+					Ok(true)
+				}
+				None =>
+				{
+					self.sizes.insert(parameter.clone(), length);
+					Ok(true)
+				}
+			},
+		}
+	}
+}
@@ -0,0 +1,1306 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use super::table::Table;
+use crate::compiler::Diagnostic;
+use crate::language::grammar::declaration::{self, Declaration};
+use crate::language::grammar::expression::{Expression, Items, Kind, Part};
+use crate::language::grammar::functions::Body;
+use crate::language::grammar::pattern::{self, Pattern};
+use crate::language::grammar::statement::{self, Statement};
+use crate::language::grammar::subscript::{Index, Subscript};
+use crate::language::grammar::{Block, FunctionDclr, Lambda, MatchStmt};
+use crate::language::lexicon::token;
+use crate::language::semantics::{member, prelude, r#type::Type};
+use crate::language::utils::Span;
+
+impl FunctionDclr
+{
+	/// Infers the types of the parameters and the result of the function from
+	/// how its body uses them.
+	///
+	/// Each parameter that is not annotated, and the result if it is not
+	/// annotated, starts as a type variable.  Every use in the body constrains
+	/// the variables, and the constraints are solved by unification as they are
+	/// found.  A variable of the type of the function that nothing constrains
+	/// becomes a type parameter, such as `T`, so that each call may give it a
+	/// type of its own.  Any other variable that nothing constrains stays
+	/// `Unit`.
+	///
+	/// ### Parameters
+	/// * `symbol` - The symbol table of the enclosing scope, in which the types
+	///   inferred for the function, its parameters and the declarations in its
+	///   body are recorded.
+	///
+	/// ### Returns
+	/// * The type of the function.
+	///
+	/// ### Errors
+	/// * If two constraints need a value to be of different types.  The error
+	///   points at the second, and relates the span the first came from.
+	/// * If the body uses a member that the type of a value does not have.
+	pub fn infer(&self, symbol: &mut Table) -> Result<Type>
+	{
+		let mut inference = Inference::new(symbol, &self.generics);
+
+		let parameters: Vec<_> = self
+			.parameters
+			.iter()
+			.flat_map(|parameters| &parameters.parameters)
+			.map(|parameter| {
+				let r#type = match &parameter.r#type
+				{
+					Type::Unit => inference.fresh(),
+					r#type => r#type.clone(),
+				};
+
+				inference.declare(&parameter.name, r#type.clone());
+				(parameter.span, r#type)
+			})
+			.collect();
+
+		inference.returns = match &self.return_type
+		{
+			Type::Unit => inference.fresh(),
+			r#type => r#type.clone(),
+		};
+
+		inference.block(&self.body)?;
+		inference.check()?;
+
+		let signature = Type::Function {
+			parameters: parameters
+				.iter()
+				.map(|(_, r#type)| r#type.clone())
+				.collect(),
+			returns: Box::new(inference.returns.clone()),
+		};
+
+		inference.generalise(&signature);
+		let r#type = inference.settle(&signature);
+
+		// The members the body uses on values of a type parameter are required
+		// of the arguments at each call.
+		let requirements: Vec<_> = inference
+			.requirements
+			.iter()
+			.filter_map(|requirement| {
+				let index = variable(&inference.resolve(&requirement.r#type).0)?;
+				let parameter = inference.names.get(&index)?;
+				Some((
+					parameter.clone(),
+					requirement.member.clone(),
+					requirement.origin,
+				))
+			})
+			.collect();
+
+		let inferred: Vec<_> = parameters
+			.iter()
+			.chain(&inference.declarations)
+			.map(|(span, r#type)| (*span, inference.settle(r#type)))
+			.collect();
+
+		symbol.inferred.extend(inferred);
+		symbol.inferred.insert(self.span, r#type.clone());
+		symbol.requirements.insert(self.name.clone(), requirements);
+
+		Ok(r#type)
+	}
+}
+
+impl Statement
+{
+	/// Infers the types within a statement outside of any function, with the
+	/// same constraints as the statements of a function body.
+	///
+	/// ### Parameters
+	/// * `symbol` - The symbol table of the scope of the statement.
+	///
+	/// ### Errors
+	/// * If two constraints need a value to be of different types.
+	/// * If the statement uses a member that the type of a value does not have.
+	pub fn infer(&self, symbol: &Table) -> Result<()>
+	{
+		let mut inference = Inference::new(symbol, &[]);
+		inference.statement(self)?;
+		inference.check()
+	}
+}
+
+impl Expression
+{
+	/// Infers the type of an expression outside of any function.
+	///
+	/// ### Parameters
+	/// * `symbol` - The symbol table of the scope of the expression.
+	///
+	/// ### Returns
+	/// * The type of the expression, which is `Unit` where it is not known.
+	///
+	/// ### Errors
+	/// * If two constraints need a value to be of different types.
+	/// * If the expression uses a member that the type of a value does not have.
+	pub fn infer(&self, symbol: &Table) -> Result<Type>
+	{
+		let mut inference = Inference::new(symbol, &[]);
+		let r#type = inference.expression(self)?;
+		inference.check()?;
+		Ok(inference.settle(&r#type))
+	}
+}
+
+/// A member used on a value whose type was not known where it was used.
+struct Requirement
+{
+	/// The type of the value.
+	r#type: Type,
+
+	/// The name of the member.
+	member: String,
+
+	/// The span of the code to report if the type has no such member.
+	span: Span,
+
+	/// The span of the code that uses the member.
+	origin: Span,
+}
+
+/// The state of inferring the types within a function body.
+///
+/// A type variable is a `Variable` whose name is its index behind a `'`, which
+/// no type parameter can be named, so that the type parameters of a generic
+/// function stay rigid.
+struct Inference<'a>
+{
+	/// The symbol table of the enclosing scope.
+	symbol: &'a Table,
+
+	/// The type and size parameters of the function, which are not values.
+	generics: &'a [String],
+
+	/// The type each type variable has been found to stand for, with the span
+	/// of the code it was found from, indexed by variable.
+	bindings: Vec<Option<(Type, Span)>>,
+
+	/// The types of the names declared in the function, innermost scope last.
+	scopes: Vec<HashMap<String, Type>>,
+
+	/// The type of the values the function returns.
+	returns: Type,
+
+	/// The types of the declarations in the body, with their spans.
+	declarations: Vec<(Span, Type)>,
+
+	/// The members used on values whose type was not known where they were
+	/// used, which are checked once it is.
+	requirements: Vec<Requirement>,
+
+	/// The type parameter each type variable of the type of the function
+	/// becomes, once the body has been inferred.
+	names: HashMap<usize, String>,
+}
+
+impl<'a> Inference<'a>
+{
+	/// Creates the state of inferring a function declared in a scope.
+	fn new(symbol: &'a Table, generics: &'a [String]) -> Self
+	{
+		Self {
+			symbol,
+			generics,
+			bindings: Vec::new(),
+			scopes: vec![HashMap::new()],
+			returns: Type::Unit,
+			declarations: Vec::new(),
+			requirements: Vec::new(),
+			names: HashMap::new(),
+		}
+	}
+
+	/// Creates a type variable that stands for nothing yet.
+	fn fresh(&mut self) -> Type
+	{
+		self.bindings.push(None);
+		Type::Variable(format!("'{}", self.bindings.len() - 1))
+	}
+
+	/// Declares a name in the innermost scope.
+	fn declare(&mut self, name: &str, r#type: Type)
+	{
+		if name != "_"
+		{
+			let scope = self.scopes.last_mut().expect("scope");
+			scope.insert(name.to_string(), r#type);
+		}
+	}
+
+	/// Looks up the type of a name, in the function first and then in the
+	/// enclosing scope.  The type parameters of a generic function from the
+	/// enclosing scope are replaced with fresh type variables, since each use
+	/// may bind them differently.
+	///
+	/// ### Parameters
+	/// * `name` - The name.
+	/// * `span` - The span of the code that uses the name.
+	/// * `fresh` - The type variable each type parameter is replaced with.
+	///
+	/// ### Returns
+	/// * The type of the name.
+	///
+	/// ### Errors
+	/// * If the name is not declared, or names a type or size parameter of the
+	///   function.
+	fn lookup(
+		&mut self,
+		name: &str,
+		span: Span,
+		fresh: &mut HashMap<String, Type>,
+	) -> Result<Type>
+	{
+		if let Some(r#type) =
+			self.scopes.iter().rev().find_map(|scope| scope.get(name))
+		{
+			return Ok(r#type.clone());
+		}
+
+		if self.generics.iter().any(|generic| generic == name)
+		{
+			let message =
+				format!("`{name}` is a type or size parameter, not a value");
+			return Err(Diagnostic::new(span, message).into());
+		}
+
+		let Some(r#type) = self
+			.symbol
+			.bindings
+			.get(name)
+			.map(|binding| binding.r#type.clone())
+			.or_else(|| self.symbol.functions.get(name).cloned())
+			.or_else(|| {
+				prelude::lookup(name).map(|constant| constant.r#type.clone())
+			})
+		else
+		{
+			let message = format!("Unknown identifier `{name}`");
+			return Err(Diagnostic::new(span, message).into());
+		};
+
+		Ok(self.instantiate(&r#type, fresh))
+	}
+
+	/// Replaces the type parameters in a type with fresh type variables.
+	///
+	/// ### Parameters
+	/// * `type` - The type.
+	/// * `fresh` - The type variable each type parameter is replaced with.
+	fn instantiate(
+		&mut self,
+		r#type: &Type,
+		fresh: &mut HashMap<String, Type>,
+	) -> Type
+	{
+		match r#type
+		{
+			Type::Variable(name) => match fresh.get(name)
+			{
+				Some(variable) => variable.clone(),
+				None =>
+				{
+					let variable = self.fresh();
+					fresh.insert(name.clone(), variable.clone());
+					variable
+				}
+			},
+			Type::Tuple(types) => Type::Tuple(
+				types
+					.iter()
+					.map(|r#type| self.instantiate(r#type, fresh))
+					.collect(),
+			),
+			Type::List(r#type) =>
+			{
+				Type::List(Box::new(self.instantiate(r#type, fresh)))
+			}
+			Type::Function {
+				parameters,
+				returns,
+			} => Type::Function {
+				parameters: parameters
+					.iter()
+					.map(|r#type| self.instantiate(r#type, fresh))
+					.collect(),
+				returns: Box::new(self.instantiate(returns, fresh)),
+			},
+			r#type => r#type.clone(),
+		}
+	}
+
+	/// Follows the type variables a type stands for.
+	///
+	/// ### Returns
+	/// * The type, which is only a type variable if that stands for nothing yet.
+	/// * The span of the code the type was last found from, if it was found
+	///   through a type variable.
+	fn resolve(&self, r#type: &Type) -> (Type, Option<Span>)
+	{
+		let mut r#type = r#type.clone();
+		let mut origin = None;
+
+		while let Some((bound, span)) =
+			variable(&r#type).and_then(|index| self.bindings[index].as_ref())
+		{
+			r#type = bound.clone();
+			origin = Some(*span);
+		}
+
+		(r#type, origin)
+	}
+
+	/// Replaces every type variable in a type with what it stands for, or with
+	/// the type parameter it has become, or with `Unit` if it stands for
+	/// nothing.
+	fn settle(&self, r#type: &Type) -> Type
+	{
+		match self.resolve(r#type).0
+		{
+			r#type if variable(&r#type).is_some() =>
+			{
+				let index = variable(&r#type).unwrap_or_default();
+				self
+					.names
+					.get(&index)
+					.map_or(Type::Unit, |name| Type::Variable(name.clone()))
+			}
+			Type::Tuple(types) =>
+			{
+				Type::Tuple(types.iter().map(|r#type| self.settle(r#type)).collect())
+			}
+			Type::List(r#type) => Type::List(Box::new(self.settle(&r#type))),
+			Type::Function {
+				parameters,
+				returns,
+			} => Type::Function {
+				parameters: parameters
+					.iter()
+					.map(|r#type| self.settle(r#type))
+					.collect(),
+				returns: Box::new(self.settle(&returns)),
+			},
+			r#type => r#type,
+		}
+	}
+
+	/// Makes a type parameter of each type variable in a type that stands for
+	/// nothing, naming them `T`, `U`, `V` and so on in order of appearance, and
+	/// skipping the names of the type and size parameters of the function.
+	fn generalise(&mut self, r#type: &Type)
+	{
+		match self.resolve(r#type).0
+		{
+			r#type if variable(&r#type).is_some() =>
+			{
+				let index = variable(&r#type).unwrap_or_default();
+
+				if !self.names.contains_key(&index)
+				{
+					let name = (0..)
+						.map(|count| match PARAMETERS.get(count)
+						{
+							Some(name) => name.to_string(),
+							None => format!("T{}", count - PARAMETERS.len() + 1),
+						})
+						.find(|name| {
+							!self.generics.contains(name)
+								&& !self.names.values().any(|used| used == name)
+						})
+						.unwrap_or_default();
+
+					self.names.insert(index, name);
+				}
+			}
+			Type::Tuple(types) =>
+			{
+				for r#type in &types
+				{
+					self.generalise(r#type);
+				}
+			}
+			Type::List(r#type) => self.generalise(&r#type),
+			Type::Function {
+				parameters,
+				returns,
+			} =>
+			{
+				for r#type in &parameters
+				{
+					self.generalise(r#type);
+				}
+
+				self.generalise(&returns);
+			}
+			_ => (),
+		}
+	}
+
+	/// Checks that the values whose type is now known have the members used on
+	/// them.
+	///
+	/// ### Errors
+	/// * If the type of a value has no member that is used on it.
+	fn check(&self) -> Result<()>
+	{
+		for requirement in &self.requirements
+		{
+			let name = &requirement.member;
+
+			let error = match self.settle(&requirement.r#type)
+			{
+				Type::Unit | Type::Variable(_) => continue,
+				Type::Named(r#type) => match self.symbol.field(&r#type, name)
+				{
+					Ok(_) => continue,
+					Err(error) => error.to_string(),
+				},
+				r#type if member::lookup(&r#type, name).is_some() => continue,
+				r#type => format!("`{type}` has no member `{name}`"),
+			};
+
+			let mut diagnostic = Diagnostic::new(requirement.span, error);
+
+			if requirement.origin != requirement.span
+			{
+				let note = format!("`{name}` is used here");
+				diagnostic = diagnostic.relate(requirement.origin, note);
+			}
+
+			return Err(diagnostic.into());
+		}
+
+		Ok(())
+	}
+
+	/// Requires of the arguments of a call the members that the body of the
+	/// function called uses on values of its type parameters.
+	///
+	/// ### Parameters
+	/// * `name` - The name of the function.
+	/// * `fresh` - The type variable each type parameter was replaced with.
+	/// * `span` - The span of the call.
+	fn inherit(&mut self, name: &str, fresh: &HashMap<String, Type>, span: Span)
+	{
+		if self.scopes.iter().any(|scope| scope.contains_key(name))
+		{
+			return;
+		}
+
+		let Some(requirements) = self.symbol.requirements.get(name)
+		else
+		{
+			return;
+		};
+
+		for (parameter, member, origin) in requirements
+		{
+			if let Some(r#type) = fresh.get(parameter)
+			{
+				self.requirements.push(Requirement {
+					r#type: r#type.clone(),
+					member: member.clone(),
+					span,
+					origin: *origin,
+				});
+			}
+		}
+	}
+
+	/// Determines if a type variable occurs within a type, so that binding it
+	/// to the type would make an infinite type.
+	fn occurs(&self, index: usize, r#type: &Type) -> bool
+	{
+		match self.resolve(r#type).0
+		{
+			r#type if variable(&r#type).is_some() => variable(&r#type) == Some(index),
+			Type::Tuple(types) =>
+			{
+				types.iter().any(|r#type| self.occurs(index, r#type))
+			}
+			Type::List(r#type) => self.occurs(index, &r#type),
+			Type::Function {
+				parameters,
+				returns,
+			} =>
+			{
+				parameters.iter().any(|r#type| self.occurs(index, r#type))
+					|| self.occurs(index, &returns)
+			}
+			_ => false,
+		}
+	}
+
+	/// Constrains two types to be the same.
+	///
+	/// ### Parameters
+	/// * `expected` - The type the code needs.
+	/// * `found` - The type of the code.
+	/// * `span` - The span of the code.
+	///
+	/// ### Errors
+	/// * If the types cannot be the same.
+	fn unify(&mut self, expected: &Type, found: &Type, span: Span) -> Result<()>
+	{
+		let (expected, expected_origin) = self.resolve(expected);
+		let (found, found_origin) = self.resolve(found);
+
+		if expected == found || expected == Type::Unit || found == Type::Unit
+		{
+			return Ok(());
+		}
+
+		if let Some(index) = variable(&expected)
+		{
+			self.bind(index, found, span);
+			return Ok(());
+		}
+
+		if let Some(index) = variable(&found)
+		{
+			self.bind(index, expected, span);
+			return Ok(());
+		}
+
+		match (&expected, &found)
+		{
+			(Type::Tuple(expected), Type::Tuple(found))
+				if expected.len() == found.len() =>
+			{
+				expected
+					.iter()
+					.zip(found)
+					.try_for_each(|(expected, found)| self.unify(expected, found, span))
+			}
+			(Type::List(expected), Type::List(found)) =>
+			{
+				self.unify(expected, found, span)
+			}
+			(
+				Type::Function {
+					parameters: expected_parameters,
+					returns: expected_returns,
+				},
+				Type::Function {
+					parameters: found_parameters,
+					returns: found_returns,
+				},
+			) if expected_parameters.len() == found_parameters.len() =>
+			{
+				for (expected, found) in
+					expected_parameters.iter().zip(found_parameters)
+				{
+					self.unify(expected, found, span)?;
+				}
+
+				self.unify(expected_returns, found_returns, span)
+			}
+			(Type::Matrix(_), Type::Matrix(_)) if expected.accepts(&found) => Ok(()),
+			_ =>
+			{
+				let expected = self.settle(&expected);
+				let found = self.settle(&found);
+
+				let mut diagnostic = Diagnostic::new(
+					span,
+					format!("Mismatched types: expected `{expected}`, found `{found}`"),
+				);
+
+				for (r#type, origin) in
+					[(expected, expected_origin), (found, found_origin)]
+				{
+					if let Some(origin) = origin.filter(|&origin| origin != span)
+					{
+						let note = format!("`{type}` is inferred from this");
+						diagnostic = diagnostic.relate(origin, note);
+					}
+				}
+
+				Err(diagnostic.into())
+			}
+		}
+	}
+
+	/// Binds a type variable to the type it stands for, unless that would make
+	/// an infinite type.
+	///
+	/// ### Parameters
+	/// * `index` - The type variable.
+	/// * `type` - The type.
+	/// * `span` - The span of the code the type was found from.
+	fn bind(&mut self, index: usize, r#type: Type, span: Span)
+	{
+		if !self.occurs(index, &r#type)
+		{
+			self.bindings[index] = Some((r#type, span));
+		}
+	}
+
+	/// Infers the types within a block, whose declarations go out of scope at
+	/// its end.
+	fn block(&mut self, block: &Block) -> Result<()>
+	{
+		self.scopes.push(HashMap::new());
+
+		// The functions of the block may be called before their declaration.
+		for statement in block.statements.iter().flatten()
+		{
+			if let statement::Kind::Declaration(Declaration {
+				kind: declaration::Kind::Function(function),
+				..
+			}) = &statement.kind
+			{
+				self.declare(&function.name, function.r#type());
+			}
+		}
+
+		for statement in block.statements.iter().flatten()
+		{
+			self.statement(statement)?;
+		}
+
+		self.scopes.pop();
+		Ok(())
+	}
+
+	/// Infers the types within a statement.
+	fn statement(&mut self, statement: &Statement) -> Result<()>
+	{
+		use statement::Kind::*;
+
+		match &statement.kind
+		{
+			Declaration(declaration) => self.declaration(declaration),
+
+			Expression(expression) => self.expression(expression).map(|_| ()),
+
+			FunctionCall(call) => self
+				.call(&call.name, &call.arguments, call.span)
+				.map(|_| ()),
+
+			Echo(echo) => echo
+				.arguments
+				.iter()
+				.try_for_each(|argument| self.expression(argument).map(|_| ())),
+
+			// A variable keeps the type of its initialiser, as at the top level,
+			// so assigning it a value of another type does not constrain it.
+			Assignment(assignment) =>
+			{
+				self.expression(&assignment.expression)?;
+
+				match &assignment.subscript
+				{
+					Some(subscript) => self.subscript(subscript),
+					None => Ok(()),
+				}
+			}
+
+			If(statement) =>
+			{
+				self.condition(&statement.condition)?;
+				self.block(&statement.body)?;
+
+				for else_if in &statement.else_ifs
+				{
+					self.condition(&else_if.condition)?;
+					self.block(&else_if.body)?;
+				}
+
+				match &statement.else_body
+				{
+					Some(body) => self.block(body),
+					None => Ok(()),
+				}
+			}
+
+			While(statement) =>
+			{
+				self.condition(&statement.condition)?;
+				self.block(&statement.body)
+			}
+
+			Match(statement) => self.r#match(statement),
+
+			Return(statement) =>
+			{
+				let r#type = self.expression(&statement.expression)?;
+				let returns = self.returns.clone();
+				self.unify(&returns, &r#type, statement.expression.span)
+			}
+
+			Break(_) | Continue(_) | Import(_) | Use(_) => Ok(()),
+		}
+	}
+
+	/// Infers the types of the names a declaration in the body declares.
+	fn declaration(&mut self, declaration: &Declaration) -> Result<()>
+	{
+		match &declaration.kind
+		{
+			declaration::Kind::Variable { name, value, .. } =>
+			{
+				let r#type = self.expression(value)?;
+				self.declare(name, r#type.clone());
+				self.declarations.push((declaration.span, r#type));
+			}
+
+			declaration::Kind::Destructuring { names, value, .. } =>
+			{
+				let r#type = self.expression(value)?;
+
+				let types = match self.resolve(&r#type).0
+				{
+					Type::Tuple(types) if types.len() == names.len() => types,
+					r#type if variable(&r#type).is_some() =>
+					{
+						let types: Vec<_> = names.iter().map(|_| self.fresh()).collect();
+						self.unify(&r#type, &Type::Tuple(types.clone()), value.span)?;
+						types
+					}
+					_ => vec![Type::Unit; names.len()],
+				};
+
+				for (name, r#type) in names.iter().zip(&types)
+				{
+					self.declare(name, r#type.clone());
+				}
+
+				self
+					.declarations
+					.push((declaration.span, Type::Tuple(types)));
+			}
+
+			// The body of a nested function is not inferred with the body it
+			// is nested in.
+			declaration::Kind::Function(function) =>
+			{
+				self.declare(&function.name, function.r#type());
+			}
+
+			declaration::Kind::Struct(_) | declaration::Kind::Enum(_) => (),
+		}
+
+		Ok(())
+	}
+
+	/// Infers the types within a match statement, whose patterns constrain the
+	/// type of the matched value.
+	fn r#match(&mut self, statement: &MatchStmt) -> Result<()>
+	{
+		let r#type = self.expression(&statement.scrutinee)?;
+
+		for arm in &statement.arms
+		{
+			self.scopes.push(HashMap::new());
+
+			for pattern in &arm.patterns
+			{
+				if let Some(matched) = self.pattern(pattern)
+				{
+					self.unify(&r#type, &matched, pattern.span)?;
+				}
+			}
+
+			if let Some(guard) = &arm.guard
+			{
+				self.condition(guard)?;
+			}
+
+			self.block(&arm.body)?;
+			self.scopes.pop();
+		}
+
+		Ok(())
+	}
+
+	/// Declares the names a pattern binds.
+	///
+	/// ### Returns
+	/// * The type of the values the pattern matches, or `None` if it matches any
+	///   value.
+	fn pattern(&mut self, pattern: &Pattern) -> Option<Type>
+	{
+		match &pattern.kind
+		{
+			pattern::Kind::Wildcard => None,
+
+			pattern::Kind::Literal(token) => Some(match token.kind
+			{
+				token::Kind::Boolean(_) => Type::Boolean,
+				token::Kind::String(_) => Type::String,
+				_ => Type::Number,
+			}),
+
+			pattern::Kind::Range { .. } => Some(Type::Number),
+
+			pattern::Kind::Variant {
+				r#enum,
+				name,
+				bindings,
+			} =>
+			{
+				let r#enum = r#enum.identifier().unwrap_or_default();
+				let name = name.identifier().unwrap_or_default();
+
+				let payload = self
+					.symbol
+					.variant(r#enum, name)
+					.map(|variant| variant.payload.clone())
+					.unwrap_or_default();
+
+				for (index, binding) in bindings.iter().enumerate()
+				{
+					let r#type = payload.get(index).cloned().unwrap_or(Type::Unit);
+					self.declare(binding.identifier().unwrap_or("_"), r#type);
+				}
+
+				Some(Type::Named(r#enum.to_string()))
+			}
+		}
+	}
+
+	/// Constrains a condition to be a `Bool`.
+	fn condition(&mut self, condition: &Expression) -> Result<()>
+	{
+		let r#type = self.expression(condition)?;
+		self.unify(&Type::Boolean, &r#type, condition.span)
+	}
+
+	/// Infers the types of the positions and bounds of a subscript.
+	fn subscript(&mut self, subscript: &Subscript) -> Result<()>
+	{
+		for index in &subscript.indices
+		{
+			match index
+			{
+				Index::Position(position) =>
+				{
+					self.expression(position)?;
+				}
+				Index::Slice { start, end, .. } =>
+				{
+					for bound in start.iter().chain(end)
+					{
+						self.expression(bound)?;
+					}
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Infers the type of a call, constraining the types of its arguments by
+	/// the types of the parameters.  A name that is not known to be a function
+	/// is constrained to be one.
+	///
+	/// ### Parameters
+	/// * `name` - The name of what is called.
+	/// * `arguments` - The arguments.
+	/// * `span` - The span of the call.
+	///
+	/// ### Returns
+	/// * The type of the value the call returns.
+	fn call(
+		&mut self,
+		name: &str,
+		arguments: &Option<Items>,
+		span: Span,
+	) -> Result<Type>
+	{
+		let arguments: Vec<_> = arguments
+			.iter()
+			.flat_map(|arguments| &arguments.expressions)
+			.collect();
+
+		let types = arguments
+			.iter()
+			.map(|argument| self.expression(argument))
+			.collect::<Result<Vec<_>>>()?;
+
+		let mut fresh = HashMap::new();
+		let callee = self.lookup(name, span, &mut fresh)?;
+
+		match self.resolve(&callee).0
+		{
+			Type::Function {
+				parameters,
+				returns,
+			} =>
+			{
+				for ((parameter, r#type), argument) in
+					parameters.iter().zip(&types).zip(&arguments)
+				{
+					self.unify(parameter, r#type, argument.span)?;
+				}
+
+				self.inherit(name, &fresh, span);
+				Ok(*returns)
+			}
+			callee if variable(&callee).is_some() =>
+			{
+				let returns = self.fresh();
+				let function = Type::Function {
+					parameters: types,
+					returns: Box::new(returns.clone()),
+				};
+
+				self.unify(&callee, &function, span)?;
+				Ok(returns)
+			}
+			_ => Ok(Type::Unit),
+		}
+	}
+
+	/// Infers the type of a lambda from how its body uses its parameters, as
+	/// the type of a function is inferred from its body.
+	///
+	/// ### Returns
+	/// * The function type of the lambda, whose parameters that are not annotated
+	///   and whose result start as type variables.
+	fn lambda(&mut self, lambda: &Lambda) -> Result<Type>
+	{
+		self.scopes.push(HashMap::new());
+
+		let parameters: Vec<_> = lambda
+			.parameters
+			.iter()
+			.flat_map(|parameters| &parameters.parameters)
+			.map(|parameter| {
+				let r#type = match &parameter.r#type
+				{
+					Type::Unit => self.fresh(),
+					r#type => r#type.clone(),
+				};
+
+				self.declare(&parameter.name, r#type.clone());
+				r#type
+			})
+			.collect();
+
+		// A `return` in the body returns from the lambda rather than from the
+		// function around it.
+		let returns = match &lambda.body
+		{
+			Body::Expression(expression) => self.expression(expression),
+			Body::Block(block) =>
+			{
+				let outer = std::mem::replace(&mut self.returns, Type::Unit);
+				self.returns = self.fresh();
+
+				let result = self.block(block);
+				let returns = std::mem::replace(&mut self.returns, outer);
+				result.map(|_| returns)
+			}
+		};
+
+		self.scopes.pop();
+
+		Ok(Type::Function {
+			parameters,
+			returns: Box::new(returns?),
+		})
+	}
+
+	/// Infers the type of an expression.
+	fn expression(&mut self, expression: &Expression) -> Result<Type>
+	{
+		match &expression.kind
+		{
+			Kind::Identifier(token) =>
+			{
+				let name = token.identifier().unwrap_or_default();
+				self.lookup(name, expression.span, &mut HashMap::new())
+			}
+
+			Kind::Literal(token) => Ok(match token.kind
+			{
+				token::Kind::Boolean(_) => Type::Boolean,
+				token::Kind::Number(_) => Type::Number,
+				token::Kind::String(_) => Type::String,
+				_ => Type::Unit,
+			}),
+
+			Kind::Parenthesised(expression) => self.expression(expression),
+
+			Kind::Tuple(items) => items
+				.expressions
+				.iter()
+				.map(|expression| self.expression(expression))
+				.collect::<Result<_>>()
+				.map(Type::Tuple),
+
+			// The items of a list are all of one type.  The type of a matrix
+			// has sizes rather than a type of items, so it is not inferred.
+			Kind::List(rows) =>
+			{
+				let item = self.fresh();
+
+				for expression in rows.iter().flatten().flat_map(|row| &row.expressions)
+				{
+					let r#type = self.expression(expression)?;
+					self.unify(&item, &r#type, expression.span)?;
+				}
+
+				Ok(Type::List(Box::new(item)))
+			}
+
+			Kind::Matrix(rows) =>
+			{
+				for item in rows.iter().flatten().flat_map(|row| &row.expressions)
+				{
+					self.expression(item)?;
+				}
+
+				Ok(Type::Unit)
+			}
+
+			Kind::Prefix { operator, operand } =>
+			{
+				let r#type = self.expression(operand)?;
+
+				let expected = match operator.kind
+				{
+					token::Kind::Not => Type::Boolean,
+					_ => Type::Number,
+				};
+
+				self.unify(&expected, &r#type, operand.span)?;
+				Ok(expected)
+			}
+
+			Kind::Infix {
+				left,
+				operator,
+				right,
+			} => self.infix(left, &operator.kind, right),
+
+			Kind::FunctionCall(call) =>
+			{
+				self.call(&call.name, &call.arguments, expression.span)
+			}
+
+			Kind::Index { target, subscript } =>
+			{
+				let r#type = self.expression(target)?;
+				self.subscript(subscript)?;
+
+				let is_position =
+					matches!(subscript.indices.as_slice(), [Index::Position(_)]);
+
+				Ok(match self.resolve(&r#type).0
+				{
+					Type::String => Type::String,
+					Type::Tuple(types) => subscript.element(&types).unwrap_or(Type::Unit),
+					Type::List(r#type) if is_position => *r#type,
+					Type::List(r#type) => Type::List(r#type),
+					_ => Type::Unit,
+				})
+			}
+
+			Kind::Member { target, name } =>
+			{
+				let r#type = self.expression(target)?;
+				let name = name.identifier().unwrap_or_default();
+
+				Ok(match self.resolve(&r#type).0
+				{
+					Type::Named(r#type) => self
+						.symbol
+						.field(&r#type, name)
+						.map_err(|error| Diagnostic::attach(error, expression.span))?,
+					r#type if variable(&r#type).is_some() =>
+					{
+						self.require(r#type, name, expression.span);
+						Type::Unit
+					}
+					r#type => member::lookup(&r#type, name)
+						.map_or(Type::Unit, |member| member.r#type.clone()),
+				})
+			}
+
+			Kind::MethodCall {
+				target,
+				name,
+				arguments,
+			} =>
+			{
+				let r#type = self.expression(target)?;
+				let name = name.identifier().unwrap_or_default();
+
+				let member = match self.resolve(&r#type).0
+				{
+					r#type if variable(&r#type).is_some() =>
+					{
+						self.require(r#type, name, expression.span);
+						None
+					}
+					r#type => member::lookup(&r#type, name),
+				};
+
+				let parameters = member
+					.and_then(|member| member.parameters)
+					.unwrap_or_default();
+
+				for (index, argument) in arguments
+					.iter()
+					.flat_map(|items| &items.expressions)
+					.enumerate()
+				{
+					let r#type = self.expression(argument)?;
+
+					if let Some(parameter) = parameters.get(index)
+					{
+						self.unify(parameter, &r#type, argument.span)?;
+					}
+				}
+
+				Ok(member.map_or(Type::Unit, |member| member.r#type.clone()))
+			}
+
+			Kind::Lambda(lambda) => self.lambda(lambda),
+
+			Kind::Variant {
+				r#enum,
+				name,
+				arguments,
+			} =>
+			{
+				let r#enum = r#enum.identifier().unwrap_or_default();
+				let name = name.identifier().unwrap_or_default();
+
+				let payload = self
+					.symbol
+					.variant(r#enum, name)
+					.map(|variant| variant.payload.clone())
+					.unwrap_or_default();
+
+				for (index, argument) in arguments
+					.iter()
+					.flat_map(|items| &items.expressions)
+					.enumerate()
+				{
+					let r#type = self.expression(argument)?;
+
+					if let Some(parameter) = payload.get(index)
+					{
+						self.unify(parameter, &r#type, argument.span)?;
+					}
+				}
+
+				Ok(Type::Named(r#enum.to_string()))
+			}
+
+			Kind::Interpolation(parts) =>
+			{
+				for part in parts
+				{
+					if let Part::Expression(expression) = part
+					{
+						self.expression(expression)?;
+					}
+				}
+
+				Ok(Type::String)
+			}
+		}
+	}
+
+	/// Requires a value whose type is not known yet to have a member.
+	///
+	/// ### Parameters
+	/// * `type` - The type variable of the value.
+	/// * `member` - The name of the member.
+	/// * `span` - The span of the code that uses the member.
+	fn require(&mut self, r#type: Type, member: &str, span: Span)
+	{
+		self.requirements.push(Requirement {
+			r#type,
+			member: member.to_string(),
+			span,
+			origin: span,
+		});
+	}
+
+	/// Infers the type of an infix expression, constraining its operands.
+	///
+	/// `+` adds numbers or joins strings, so it only constrains its operands to
+	/// be of the same type, and to be numbers if they are of any other known
+	/// type.  `==` and `!=` only constrain their operands to be of the same
+	/// type.
+	fn infix(
+		&mut self,
+		left: &Expression,
+		operator: &token::Kind,
+		right: &Expression,
+	) -> Result<Type>
+	{
+		use token::Kind::*;
+
+		let left_type = self.expression(left)?;
+		let right_type = self.expression(right)?;
+
+		let (operands, result) = match operator
+		{
+			Plus =>
+			{
+				self.unify(&left_type, &right_type, right.span)?;
+
+				let (r#type, span) = match self.resolve(&left_type).0
+				{
+					Type::Unit => (right_type, right.span),
+					_ => (left_type, left.span),
+				};
+
+				return match self.resolve(&r#type).0
+				{
+					Type::Number | Type::String | Type::Unit => Ok(r#type),
+					found if variable(&found).is_some() => Ok(r#type),
+					_ => self.unify(&Type::Number, &r#type, span).map(|_| r#type),
+				};
+			}
+			EqualEqual | ExclaimEqual =>
+			{
+				self.unify(&left_type, &right_type, right.span)?;
+				return Ok(Type::Boolean);
+			}
+			And | Or | Xor => (Type::Boolean, Type::Boolean),
+			Greater | GreaterEqual | Less | LessEqual =>
+			{
+				(Type::Number, Type::Boolean)
+			}
+			_ => (Type::Number, Type::Number),
+		};
+
+		self.unify(&operands, &left_type, left.span)?;
+		self.unify(&operands, &right_type, right.span)?;
+		Ok(result)
+	}
+}
+
+/// The names given to the type parameters that type variables become, before
+/// falling back on numbered names.
+const PARAMETERS: [&str; 4] = ["T", "U", "V", "W"];
+
+/// Gets the index of a type variable.
+///
+/// ### Returns
+/// * The index if the type is a type variable rather than a type parameter.
+/// * `None` otherwise.
+fn variable(r#type: &Type) -> Option<usize>
+{
+	match r#type
+	{
+		Type::Variable(name) => name.strip_prefix('\'')?.parse().ok(),
+		_ => None,
+	}
+}
@@ -1,4 +1,8 @@
 mod analyse;
+pub mod flow;
+mod generic;
+mod infer;
 mod rules;
+mod shape;
 pub mod table;
 mod r#type;
@@ -0,0 +1,379 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, ensure, Result};
+
+use crate::compiler::semanter::table::{Binder, Binding, Table};
+use crate::language::grammar::pattern::{Kind, Pattern};
+use crate::language::grammar::MatchStmt;
+use crate::language::lexicon::{token, Token};
+use crate::language::semantics::r#type::Type;
+
+/// The most missing values an exhaustiveness error lists.
+const MISSING: usize = 4;
+
+impl MatchStmt
+{
+	/// Semantically analyses the match statement.
+	///
+	/// ### Errors
+	/// * If a pattern cannot match the type of the matched value.
+	/// * If patterns joined with `|` bind names.
+	/// * If a guard is not a `Bool`.
+	/// * If a match over a `Bool`, an enum or bit-strings is not exhaustive.
+	/// * If there are semantic errors in the body of an arm.
+	pub fn analyse(&self, symbol: &Table) -> Result<()>
+	{
+		let r#type = self.scrutinee.r#type(symbol)?;
+
+		for arm in &self.arms
+		{
+			let mut bindings = Vec::new();
+
+			for pattern in &arm.patterns
+			{
+				let names = pattern.analyse(&r#type, symbol)?;
+
+				bindings.extend(names.into_iter().map(|(name, r#type)| {
+					let binder = Binder::Pattern;
+					let span = pattern.span;
+					(
+						name,
+						Binding {
+							r#type,
+							binder,
+							span,
+						},
+					)
+				}));
+			}
+
+			ensure!(
+				arm.patterns.len() == 1 || bindings.is_empty(),
+				"Patterns joined with `|` cannot bind names"
+			);
+
+			let mut scope = symbol.scope();
+
+			for (name, binding) in bindings
+			{
+				scope.shapes.remove(&name);
+				scope.widths.remove(&name);
+				scope.bindings.insert(name, binding);
+			}
+
+			if let Some(guard) = &arm.guard
+			{
+				ensure!(guard.r#type(&scope)? == Type::Boolean, "Invalid condition");
+			}
+
+			arm.body.analyse(&scope)?;
+		}
+
+		self.exhaust(&r#type, symbol)
+	}
+
+	/// Checks that a match over a `Bool`, an enum or bit-strings has an arm for
+	/// every value.
+	///
+	/// Arms with a guard are not counted, since their guard may not hold.  The
+	/// values of bit-strings are only known when the matched value has a
+	/// known width, so a match over any other value with bit-string patterns
+	/// needs a `_` arm.
+	///
+	/// ### Parameters
+	/// * `type` - The type of the matched value.
+	/// * `symbol` - The symbol table.
+	///
+	/// ### Errors
+	/// * If a value or variant has no arm.
+	/// * If the bit-string patterns do not have the width of the matched value.
+	fn exhaust(&self, r#type: &Type, symbol: &Table) -> Result<()>
+	{
+		let patterns: Vec<_> = self
+			.arms
+			.iter()
+			.flat_map(|arm| &arm.patterns)
+			.filter(|pattern| pattern.kind != Kind::Wildcard)
+			.collect();
+
+		let mut covered = self
+			.arms
+			.iter()
+			.filter(|arm| arm.guard.is_none())
+			.flat_map(|arm| &arm.patterns);
+
+		if covered
+			.clone()
+			.any(|pattern| pattern.kind == Kind::Wildcard)
+		{
+			return Ok(());
+		}
+
+		// The enum of the matched value, which is known from the patterns when
+		// the type of the value is not.
+		let r#enum = match r#type
+		{
+			Type::Named(name) => symbol.enums.get(name),
+			_ => patterns.iter().find_map(|pattern| match &pattern.kind
+			{
+				Kind::Variant { r#enum, .. } =>
+				{
+					symbol.enums.get(r#enum.identifier().unwrap_or_default())
+				}
+				_ => None,
+			}),
+		};
+
+		let width = self.scrutinee.width(symbol);
+
+		let missing: Vec<_> = match (r#enum, width)
+		{
+			_ if *r#type == Type::Boolean => [true, false]
+				.into_iter()
+				.filter(|&value| {
+					!covered.any(|pattern| match &pattern.kind
+					{
+						Kind::Literal(Token {
+							kind: token::Kind::Boolean(boolean),
+							..
+						}) => *boolean == value,
+						_ => false,
+					})
+				})
+				.map(|value| value.to_string())
+				.collect(),
+
+			(Some(r#enum), _) => r#enum
+				.variants
+				.iter()
+				.filter(|variant| {
+					!covered.clone().any(|pattern| match &pattern.kind
+					{
+						Kind::Variant { name, .. } =>
+						{
+							name.identifier() == Some(variant.name.as_str())
+						}
+						_ => false,
+					})
+				})
+				.map(|variant| {
+					let bindings = vec!["_"; variant.payload.len()];
+					match bindings.is_empty()
+					{
+						true => format!("{}::{}", r#enum.name, variant.name),
+						false => format!(
+							"{}::{}({})",
+							r#enum.name,
+							variant.name,
+							bindings.join(", ")
+						),
+					}
+				})
+				.collect(),
+
+			(None, Some(width))
+				if patterns.iter().all(|pattern| pattern.width().is_some()) =>
+			{
+				ensure!(
+					patterns
+						.iter()
+						.all(|pattern| pattern.width() == Some(width)),
+					"Bit-string patterns must have the width of the matched value, \
+					 {width} bit{}",
+					if width == 1 { "" } else { "s" }
+				);
+
+				let values: HashSet<_> = covered.filter_map(Pattern::bits).collect();
+				let count = 1u128.checked_shl(width as u32).unwrap_or(u128::MAX);
+
+				(0..count)
+					.filter(|value| !values.contains(value))
+					.take(MISSING + 1)
+					.map(|value| format!("0b{value:0width$b}"))
+					.collect()
+			}
+
+			_ if patterns.iter().any(|pattern| pattern.width().is_some()) =>
+			{
+				vec![String::from("_")]
+			}
+
+			_ => return Ok(()),
+		};
+
+		match missing.as_slice()
+		{
+			[] => Ok(()),
+			[value] => bail!("Match is not exhaustive; add an arm for `{value}`"),
+			values =>
+			{
+				let mut list: Vec<_> = values
+					.iter()
+					.take(MISSING)
+					.map(|value| format!("`{value}`"))
+					.collect();
+
+				if values.len() > MISSING
+				{
+					list.push(String::from("…"));
+				}
+
+				bail!("Match is not exhaustive; add arms for {}", list.join(", "))
+			}
+		}
+	}
+}
+
+impl Pattern
+{
+	/// Semantically analyses the pattern.
+	///
+	/// ### Parameters
+	/// * `type` - The type of the matched value.
+	/// * `symbol` - The symbol table.
+	///
+	/// ### Returns
+	/// * The names the pattern binds, with the types of their values.
+	///
+	/// ### Errors
+	/// * If the pattern cannot match a value of the type.
+	/// * If the pattern is a range that is empty or not over real numbers.
+	/// * If the pattern is a variant that does not exist, or binds more or fewer
+	///   values than its payload holds.
+	fn analyse(
+		&self,
+		r#type: &Type,
+		symbol: &Table,
+	) -> Result<Vec<(String, Type)>>
+	{
+		let mut bindings = Vec::new();
+
+		let pattern = match &self.kind
+		{
+			Kind::Wildcard => return Ok(bindings),
+
+			Kind::Literal(token) => match token.kind
+			{
+				token::Kind::Boolean(_) => Type::Boolean,
+				token::Kind::String(_) => Type::String,
+				_ => Type::Number,
+			},
+
+			Kind::Range {
+				start,
+				end,
+				is_inclusive,
+			} =>
+			{
+				let (token::Kind::Number(start), token::Kind::Number(end)) =
+					(&start.kind, &end.kind)
+				else
+				{
+					unreachable!()
+				};
+
+				let (Some(low), Some(high)) = (real(start), real(end))
+				else
+				{
+					bail!("Range bounds must be real numbers")
+				};
+
+				let operator = if *is_inclusive { "..=" } else { ".." };
+
+				ensure!(
+					low < high || (low == high && *is_inclusive),
+					"Range `{start}{operator}{end}` is empty"
+				);
+
+				Type::Number
+			}
+
+			Kind::Variant {
+				r#enum,
+				name,
+				bindings: names,
+			} =>
+			{
+				let r#enum = r#enum.identifier().unwrap_or_default();
+				let name = name.identifier().unwrap_or_default();
+				let variant = symbol.variant(r#enum, name)?;
+				let count = variant.payload.len();
+
+				ensure!(
+					names.len() == count,
+					"`{enum}::{name}` carries {count} value{} but the pattern binds {}",
+					if count == 1 { "" } else { "s" },
+					names.len()
+				);
+
+				for (binding, r#type) in names.iter().zip(&variant.payload)
+				{
+					match binding.identifier()
+					{
+						Some("_") | None => (),
+						Some(binding) =>
+						{
+							bindings.push((binding.to_string(), r#type.clone()))
+						}
+					}
+				}
+
+				Type::Named(r#enum.to_string())
+			}
+		};
+
+		ensure!(
+			*r#type == Type::Unit || *r#type == pattern,
+			"A `{pattern}` pattern cannot match a value of type `{type}`"
+		);
+
+		Ok(bindings)
+	}
+
+	/// Gets the value of a bit-string pattern.
+	///
+	/// ### Returns
+	/// * The value of the pattern if it is a bit-string that fits in 128 bits.
+	/// * `None` otherwise.
+	fn bits(&self) -> Option<u128>
+	{
+		let Kind::Literal(Token {
+			kind: token::Kind::Number(number),
+			..
+		}) = &self.kind
+		else
+		{
+			return None;
+		};
+
+		u128::from_str_radix(number.strip_prefix("0b")?, 2).ok()
+	}
+}
+
+/// Gets the value of a real number literal.
+///
+/// ### Parameters
+/// * `number` - The number, as a Python literal with an optional sign.
+///
+/// ### Returns
+/// * The value of the number.
+/// * `None` if the number is imaginary.
+fn real(number: &str) -> Option<f64>
+{
+	let (sign, digits) = match number.strip_prefix('-')
+	{
+		Some(digits) => (-1.0, digits),
+		None => (1.0, number),
+	};
+
+	let radix = match digits.get(..2)
+	{
+		Some("0x") => 16,
+		Some("0o") => 8,
+		Some("0b") => 2,
+		_ => return digits.parse::<f64>().ok().map(|value| sign * value),
+	};
+
+	let value = u128::from_str_radix(&digits[2..], radix).ok()?;
+	Some(sign * value as f64)
+}
@@ -1,8 +1,20 @@
-use anyhow::{bail, Result};
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, ensure, Result};
 
 use crate::{
-	compiler::semanter::table::Table,
-	language::grammar::declaration::{Declaration, Kind::*},
+	compiler::{
+		semanter::{
+			generic::Bindings,
+			table::{Binder, Binding, Table},
+		},
+		Diagnostic,
+	},
+	language::{
+		grammar::declaration::{Declaration, Kind::*},
+		semantics::{member, r#type::Type},
+		utils::Span,
+	},
 };
 
 impl Declaration
@@ -21,37 +33,249 @@ impl Declaration
 				is_mutable,
 			} =>
 			{
-				if *is_mutable
-					&& symbol
-						.variables
-						.insert(name.clone(), value.r#type(symbol)?)
-						.is_some()
+				let r#type = value.r#type(symbol)?;
+				declare(symbol, name, r#type, *is_mutable, self.span)?;
+
+				match value.shape(symbol)
 				{
-					bail!("Variable '{}' already declared", name)
-				}
+					Some(shape) => symbol.shapes.insert(name.clone(), shape),
+					None => symbol.shapes.remove(name),
+				};
+
+				match value.width(symbol).filter(|_| !is_mutable)
+				{
+					Some(width) => symbol.widths.insert(name.clone(), width),
+					None => symbol.widths.remove(name),
+				};
+			}
+
+			Destructuring {
+				names,
+				value,
+				is_mutable,
+			} =>
+			{
+				let r#type = value.r#type(symbol)?;
+
+				let types = match r#type
+				{
+					Type::Tuple(types) if types.len() == names.len() => types,
+					Type::Unit => vec![Type::Unit; names.len()],
+					_ => bail!(
+						"Cannot destructure a `{type}` into {} name{}",
+						names.len(),
+						if names.len() == 1 { "" } else { "s" }
+					),
+				};
 
-				if !is_mutable
-					&& symbol
-						.constants
-						.insert(name.clone(), value.r#type(symbol)?)
-						.is_some()
+				for (name, r#type) in names.iter().zip(types)
 				{
-					bail!("Constant '{}' already declared", name)
+					if name != "_"
+					{
+						declare(symbol, name, r#type, *is_mutable, self.span)?;
+						symbol.shapes.remove(name);
+						symbol.widths.remove(name);
+					}
 				}
 			}
 
 			Function(function) =>
 			{
+				let mut generics = HashSet::new();
+
+				for generic in &function.generics
+				{
+					ensure!(
+						generics.insert(generic),
+						"Type parameter '{generic}' already declared in function '{}'",
+						function.name
+					);
+				}
+
+				let mut parameters = HashMap::new();
+
+				for parameter in function.parameters.iter().flat_map(|p| &p.parameters)
+				{
+					let name = &parameter.name;
+
+					if let Some(first) = parameters.insert(name, parameter.span)
+					{
+						let message = format!(
+							"Parameter '{name}' already declared in function '{}'",
+							function.name
+						);
+						let note = format!("`{name}` is first declared here");
+						let diagnostic = Diagnostic::new(parameter.span, message);
+						return Err(diagnostic.relate(first, note).into());
+					}
+				}
+
+				symbol.resolve(&function.r#type(), &function.generics)?;
+
+				// The function is declared with the types it is annotated with
+				// before its body is inferred, so that it may call itself.
+				let is_provisional = symbol.provisional.remove(&function.name);
+
 				if symbol
 					.functions
-					.insert(function.name.clone(), function.return_type)
+					.insert(function.name.clone(), function.r#type())
 					.is_some()
+					&& !is_provisional
 				{
 					bail!("Function '{}' already declared", function.name)
 				}
+
+				let r#type = function.infer(symbol)?;
+				symbol.functions.insert(function.name.clone(), r#type);
+
+				// The body is analysed with the types inferred for the parameters.
+				// A type parameter may stand for any type, as `Unit` does.
+				let mut scope = symbol.scope();
+				scope.in_loop = false;
+				scope.function = Some(self.span);
+
+				for parameter in function.parameters.iter().flat_map(|p| &p.parameters)
+				{
+					let r#type = symbol
+						.inferred
+						.get(&parameter.span)
+						.map_or(Type::Unit, |r#type| {
+							Bindings::default().substitute(r#type)
+						});
+
+					let binding = Binding {
+						r#type,
+						binder: Binder::Parameter,
+						span: parameter.span,
+					};
+
+					scope.shapes.remove(&parameter.name);
+					scope.widths.remove(&parameter.name);
+					scope.bindings.insert(parameter.name.clone(), binding);
+				}
+
+				function.body.analyse(&scope)?;
+			}
+
+			Struct(r#struct) =>
+			{
+				let name = &r#struct.name;
+
+				// The struct is declared before its fields are resolved, so that a
+				// field may hold a value of the struct itself.
+				ensure!(
+					!symbol.enums.contains_key(name)
+						&& symbol
+							.structs
+							.insert(name.clone(), r#struct.clone())
+							.is_none()
+						&& symbol
+							.functions
+							.insert(name.clone(), r#struct.constructor())
+							.is_none(),
+					"Struct '{name}' already declared"
+				);
+
+				let mut names = HashSet::new();
+
+				for field in &r#struct.fields
+				{
+					ensure!(
+						names.insert(&field.name),
+						"Field '{}' already declared in struct '{name}'",
+						field.name
+					);
+
+					// Synthesis turns built-in properties into Python expressions
+					// by name alone, so a field must not share a name with one.
+					ensure!(
+						member::find(&field.name)
+							.is_none_or(|member| member.parameters.is_some()),
+						"Field '{}' would hide the built-in property `{}`",
+						field.name,
+						field.name
+					);
+
+					symbol.resolve(&field.r#type, &[])?;
+				}
+			}
+
+			Enum(r#enum) =>
+			{
+				let name = &r#enum.name;
+
+				ensure!(
+					!symbol.structs.contains_key(name)
+						&& symbol.enums.insert(name.clone(), r#enum.clone()).is_none(),
+					"Enum '{name}' already declared"
+				);
+
+				let mut names = HashSet::new();
+
+				for variant in &r#enum.variants
+				{
+					ensure!(
+						names.insert(&variant.name),
+						"Variant '{}' already declared in enum '{name}'",
+						variant.name
+					);
+
+					for r#type in &variant.payload
+					{
+						symbol.resolve(r#type, &[])?;
+					}
+				}
 			}
 		};
 
 		Ok(())
 	}
 }
+
+/// Declares a variable, or a constant if it is not mutable.
+///
+/// ### Parameters
+/// * `symbol` - The symbol table.
+/// * `name` - The name of the variable.
+/// * `type` - The type of its value.
+/// * `is_mutable` - Whether the variable is mutable.
+/// * `span` - The span of the declaration.
+///
+/// ### Errors
+/// * If a variable or constant of the same name is already declared in the same
+///   scope.  The error relates the span of the earlier declaration.
+fn declare(
+	symbol: &mut Table,
+	name: &str,
+	r#type: Type,
+	is_mutable: bool,
+	span: Span,
+) -> Result<()>
+{
+	let (binder, kind) = match is_mutable
+	{
+		true => (Binder::Var, "Variable"),
+		false => (Binder::Let, "Constant"),
+	};
+
+	let binding = Binding {
+		r#type,
+		binder,
+		span,
+	};
+
+	let is_shadowing = symbol.enclosing.remove(name);
+
+	if let Some(earlier) = symbol
+		.bindings
+		.insert(name.to_string(), binding)
+		.filter(|_| !is_shadowing)
+	{
+		let diagnostic =
+			Diagnostic::new(span, format!("{kind} '{name}' already declared"));
+		let note = format!("`{name}` is first declared here");
+		return Err(diagnostic.relate(earlier.span, note).into());
+	}
+
+	Ok(())
+}
@@ -1,10 +1,12 @@
-use anyhow::{ensure, Result};
+use anyhow::{bail, ensure, Result};
 
-use crate::language::lexicon::token::Kind::*;
 use crate::{
-	compiler::semanter::table::Table,
+	compiler::semanter::{generic::Bindings, table::Table},
 	language::{
-		grammar::expression::{Expression, Kind::*},
+		grammar::{
+			expression::{Expression, Items, Kind, Kind::*, Part},
+			FunctionCall,
+		},
 		semantics::r#type::Type,
 	},
 };
@@ -19,54 +21,92 @@ impl Expression
 	{
 		match &self.kind
 		{
-			FunctionCall(function) =>
+			FunctionCall(function) => function.analyse(symbol),
+
+			// The types of the operands are inferred with the expression.
+			Prefix { operand, .. } => operand.analyse(symbol),
+
+			Infix { left, right, .. } =>
 			{
-				ensure!(
-					symbol.functions.contains_key(&function.name),
-					"Invalid function call"
-				);
-				Ok(())
+				left.analyse(symbol)?;
+				right.analyse(symbol)
 			}
 
-			Prefix { operator, operand } => match operator.kind
+			Kind::Index { target, subscript } =>
 			{
-				Plus | Minus =>
+				match target.r#type(symbol)?
 				{
-					ensure!(operand.r#type(symbol)? == Type::Number, "Invalid operand");
-					Ok(())
+					Type::String | Type::Unit | Type::List(_) | Type::Matrix(_) => (),
+					Type::Tuple(types) => return subscript.element(&types).map(|_| ()),
+					r#type => bail!("Cannot index a value of type `{type}`"),
 				}
-				Not =>
+
+				let shape = target.shape(symbol);
+				subscript.analyse(shape.as_deref(), symbol)
+			}
+
+			Kind::Member { target, name } =>
+			{
+				if let Type::Named(r#type) = target.r#type(symbol)?
 				{
-					ensure!(operand.r#type(symbol)? == Type::Boolean, "Invalid operand");
-					Ok(())
+					symbol.field(&r#type, name.identifier().unwrap_or_default())?;
+					return Ok(());
 				}
-				_ => unreachable!(),
-			},
 
-			Infix {
-				operator,
-				left,
-				right,
+				let member = target.member(name, symbol)?;
+				ensure!(
+					member.parameters.is_none(),
+					"`{}` is a method; call it with `{}()`",
+					member.name,
+					member.name
+				);
+				Ok(())
+			}
+
+			Kind::MethodCall {
+				target,
+				name,
+				arguments,
 			} =>
 			{
-				let left = left.r#type(symbol)?;
-				let right = right.r#type(symbol)?;
-
-				match operator.kind
+				let member = target.member(name, symbol)?;
+				let parameters = match member.parameters
 				{
-					Plus => ensure!(
-						left == Type::Number || left == Type::String || left == Type::Unit,
-						"Invalid left operand"
-					),
-					Minus | Asterisk | Slash | Greater | GreaterEqual | Less | LessEqual | EqualEqual | ExclaimEqual => ensure!(left == Type::Number || left == Type::Unit, "Invalid left operand"),
-					And | Or | Xor =>
+					Some(parameters) => parameters,
+					None =>
 					{
-						ensure!(left == Type::Boolean, "Invalid left operand")
+						bail!("`{}` is a property; remove the parentheses", member.name)
 					}
-					_ => unreachable!(),
 				};
 
-				ensure!(left == right, "Invalid right operand");
+				self::arguments(member.name, parameters, arguments, symbol)
+			}
+
+			Kind::Lambda(lambda) => lambda.analyse(symbol),
+
+			Kind::Variant {
+				r#enum,
+				name,
+				arguments,
+			} =>
+			{
+				let r#enum = r#enum.identifier().unwrap_or_default();
+				let name = name.identifier().unwrap_or_default();
+				let variant = symbol.variant(r#enum, name)?;
+				let name = format!("{enum}::{name}");
+
+				self::arguments(&name, &variant.payload, arguments, symbol)
+			}
+
+			Kind::Interpolation(parts) =>
+			{
+				for part in parts
+				{
+					if let Part::Expression(expression) = part
+					{
+						expression.r#type(symbol)?;
+					}
+				}
 				Ok(())
 			}
 
@@ -74,3 +114,74 @@ impl Expression
 		}
 	}
 }
+
+impl FunctionCall
+{
+	/// Semantically analyses the call, as an expression or as a statement.
+	///
+	/// ### Errors
+	/// * If the name cannot be called.
+	/// * If the arguments do not fit the parameters of what is called.
+	pub fn analyse(&self, symbol: &Table) -> Result<()>
+	{
+		let parameters = match symbol.callable(&self.name)
+		{
+			Some(Type::Function { parameters, .. }) => parameters,
+			_ => bail!("Invalid function call"),
+		};
+
+		arguments(&self.name, parameters, &self.arguments, symbol)
+	}
+}
+
+/// Checks the arguments of a call against the parameters of the function,
+/// method, constructor or variant called.
+///
+/// ### Parameters
+/// * `name` - The name of what is called.
+/// * `parameters` - The types of the parameters.
+/// * `arguments` - The arguments.
+/// * `symbol` - The symbol table.
+///
+/// ### Errors
+/// * If there are more or fewer arguments than parameters.
+/// * If an argument is not of the type of its parameter.
+/// * If a type or size parameter of a generic function would stand for two
+///   different types or sizes.
+fn arguments(
+	name: &str,
+	parameters: &[Type],
+	arguments: &Option<Items>,
+	symbol: &Table,
+) -> Result<()>
+{
+	let count = arguments
+		.iter()
+		.map(|arguments| arguments.expressions.len())
+		.sum();
+
+	arity(name, parameters.len(), count)?;
+	Bindings::new(name, parameters, arguments, symbol).map(|_| ())
+}
+
+/// Checks that a function or method is called with as many arguments as it
+/// has parameters.
+///
+/// ### Parameters
+/// * `name` - The name of the function or method.
+/// * `parameters` - The number of parameters.
+/// * `arguments` - The number of arguments.
+///
+/// ### Errors
+/// * If the numbers differ.
+fn arity(name: &str, parameters: usize, arguments: usize) -> Result<()>
+{
+	ensure!(
+		arguments == parameters,
+		"`{name}` takes {parameters} argument{} but {arguments} {} given",
+		if parameters == 1 { "" } else { "s" },
+		if arguments == 1 { "was" } else { "were" }
+	);
+
+	Ok(())
+}
@@ -0,0 +1,381 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Result};
+
+use crate::{
+	compiler::{
+		semanter::table::{Binder, Binding, Table},
+		Diagnostic,
+	},
+	language::{
+		grammar::{
+			declaration::{self, Declaration},
+			expression::{self, Expression},
+			functions::{Parameter, Params},
+			pattern::{self, Pattern},
+			visit::{self, Visit},
+			Arm, AssignmentStmt, Block, BreakStmt, ContinueStmt, FunctionDclr,
+			Lambda, ReturnStmt, WhileStmt,
+		},
+		lexicon::token,
+		semantics::{prelude, r#type::Type},
+		utils::Span,
+	},
+};
+
+impl Lambda
+{
+	/// Semantically analyses the lambda.
+	///
+	/// The body is not type checked, since its parameters are untyped, but the
+	/// names it captures from the enclosing scope must be `let` bindings or
+	/// functions.  A captured `var` could change after the lambda is created.
+	///
+	/// ### Parameters
+	/// * `symbol` - The symbol table of the enclosing scope.
+	///
+	/// ### Errors
+	/// * If the lambda captures a `var` or an unknown name.
+	/// * If the lambda assigns to an immutable binding.
+	/// * If the lambda uses `break` or `continue` outside of a loop.
+	pub fn analyse(&self, symbol: &Table) -> Result<()>
+	{
+		let mut immutable = Immutable::new(symbol);
+		immutable.visit_lambda(self);
+
+		let mut jumps = Jumps::default();
+		jumps.visit_lambda(self);
+
+		if let Some(error) = immutable.error.or(jumps.error)
+		{
+			return Err(error);
+		}
+
+		let mut captures = Captures::default();
+		captures.visit_lambda(self);
+
+		for name in captures.used
+		{
+			let binding = symbol.bindings.get(name);
+
+			if captures.bound.contains(name)
+				|| binding.is_some_and(|binding| binding.binder != Binder::Var)
+				|| symbol.functions.contains_key(name)
+				|| prelude::lookup(name).is_some()
+			{
+				continue;
+			}
+
+			if binding.is_some()
+			{
+				bail!(
+					"Closures can only capture `let` bindings; `{name}` is declared with \
+					 `var`"
+				)
+			}
+
+			bail!("Unknown identifier `{name}`")
+		}
+
+		Ok(())
+	}
+}
+
+/// A visitor that collects the names a lambda uses and the names it binds
+/// itself, as parameters, declarations or in patterns.
+#[derive(Default)]
+struct Captures<'ast>
+{
+	/// The names the lambda binds.
+	bound: HashSet<&'ast str>,
+
+	/// The names the lambda uses, in order.
+	used: Vec<&'ast str>,
+}
+
+impl<'ast> Visit<'ast> for Captures<'ast>
+{
+	fn visit_parameter(&mut self, node: &'ast Parameter)
+	{
+		self.bound.insert(&node.name);
+	}
+
+	fn visit_declaration(&mut self, node: &'ast Declaration)
+	{
+		let names = match &node.kind
+		{
+			declaration::Kind::Variable { name, .. } => vec![name],
+			declaration::Kind::Destructuring { names, .. } => names.iter().collect(),
+			declaration::Kind::Function(function) => vec![&function.name],
+			declaration::Kind::Struct(r#struct) => vec![&r#struct.name],
+			declaration::Kind::Enum(r#enum) => vec![&r#enum.name],
+		};
+
+		self.bound.extend(names.into_iter().map(String::as_str));
+
+		visit::walk_declaration(self, node);
+	}
+
+	fn visit_pattern(&mut self, node: &'ast Pattern)
+	{
+		if let pattern::Kind::Variant { bindings, .. } = &node.kind
+		{
+			for binding in bindings
+			{
+				if let token::Kind::Identifier(name) = &binding.kind
+				{
+					self.bound.insert(name);
+				}
+			}
+		}
+	}
+
+	fn visit_assignment(&mut self, node: &'ast AssignmentStmt)
+	{
+		self.used.push(&node.identifier);
+		visit::walk_assignment(self, node);
+	}
+
+	fn visit_expression(&mut self, node: &'ast Expression)
+	{
+		if let expression::Kind::Identifier(token) = &node.kind
+		{
+			if let token::Kind::Identifier(name) = &token.kind
+			{
+				self.used.push(name);
+			}
+		}
+
+		visit::walk_expression(self, node);
+	}
+}
+
+/// A visitor that checks that the assignments within the body of a lambda,
+/// which is not otherwise analysed, only assign to names declared with `var`.
+struct Immutable<'ast>
+{
+	/// The symbol table of the scope the lambda is in.
+	symbol: &'ast Table,
+
+	/// The names bound within the lambda, innermost scope last.
+	scopes: Vec<HashMap<&'ast str, Binding>>,
+
+	/// The first assignment to an immutable binding, if any.
+	error: Option<anyhow::Error>,
+}
+
+impl<'ast> Immutable<'ast>
+{
+	/// Creates a visitor for a lambda in a scope.
+	fn new(symbol: &'ast Table) -> Self
+	{
+		Self {
+			symbol,
+			scopes: vec![HashMap::new()],
+			error: None,
+		}
+	}
+
+	/// Binds a name in the innermost scope.
+	fn bind(&mut self, name: &'ast str, binder: Binder, span: Span)
+	{
+		let binding = Binding {
+			r#type: Type::Unit,
+			binder,
+			span,
+		};
+
+		let scope = self.scopes.last_mut().expect("scope");
+		scope.insert(name, binding);
+	}
+
+	/// Binds the parameters of a function or lambda in a new scope.
+	fn parameters(&mut self, parameters: &'ast Option<Params>)
+	{
+		self.scopes.push(HashMap::new());
+
+		for parameter in parameters.iter().flat_map(|p| &p.parameters)
+		{
+			self.bind(&parameter.name, Binder::Parameter, parameter.span);
+		}
+	}
+}
+
+impl<'ast> Visit<'ast> for Immutable<'ast>
+{
+	fn visit_block(&mut self, node: &'ast Block)
+	{
+		self.scopes.push(HashMap::new());
+		visit::walk_block(self, node);
+		self.scopes.pop();
+	}
+
+	fn visit_declaration(&mut self, node: &'ast Declaration)
+	{
+		visit::walk_declaration(self, node);
+
+		let binder = |is_mutable| match is_mutable
+		{
+			true => Binder::Var,
+			false => Binder::Let,
+		};
+
+		match &node.kind
+		{
+			declaration::Kind::Variable {
+				name, is_mutable, ..
+			} => self.bind(name, binder(*is_mutable), node.span),
+			declaration::Kind::Destructuring {
+				names, is_mutable, ..
+			} =>
+			{
+				for name in names
+				{
+					self.bind(name, binder(*is_mutable), node.span);
+				}
+			}
+			_ => (),
+		}
+	}
+
+	fn visit_function(&mut self, node: &'ast FunctionDclr)
+	{
+		self.parameters(&node.parameters);
+		visit::walk_function(self, node);
+		self.scopes.pop();
+	}
+
+	fn visit_lambda(&mut self, node: &'ast Lambda)
+	{
+		self.parameters(&node.parameters);
+		visit::walk_lambda(self, node);
+		self.scopes.pop();
+	}
+
+	fn visit_arm(&mut self, node: &'ast Arm)
+	{
+		self.scopes.push(HashMap::new());
+		visit::walk_arm(self, node);
+		self.scopes.pop();
+	}
+
+	fn visit_pattern(&mut self, node: &'ast Pattern)
+	{
+		if let pattern::Kind::Variant { bindings, .. } = &node.kind
+		{
+			for binding in bindings
+			{
+				if let Some(name) = binding.identifier()
+				{
+					self.bind(name, Binder::Pattern, node.span);
+				}
+			}
+		}
+	}
+
+	fn visit_assignment(&mut self, node: &'ast AssignmentStmt)
+	{
+		let name = node.identifier.as_str();
+
+		let binding = self
+			.scopes
+			.iter()
+			.rev()
+			.find_map(|scope| scope.get(name))
+			.or_else(|| self.symbol.bindings.get(name));
+
+		if let Some(binding) = binding
+		{
+			if let Err(error) = binding.assign(name, node.span)
+			{
+				self.error.get_or_insert(error);
+			}
+		}
+
+		visit::walk_assignment(self, node);
+	}
+}
+
+/// A visitor that checks that `break` and `continue` within the body of a
+/// lambda are only used within a loop of the lambda, and `return` only within
+/// a function or lambda.
+#[derive(Default)]
+struct Jumps
+{
+	/// The number of loops around the node being visited, within the
+	/// innermost function.
+	loops: usize,
+
+	/// The number of functions and lambdas around the node being visited.
+	functions: usize,
+
+	/// The first misplaced statement, if any.
+	error: Option<anyhow::Error>,
+}
+
+impl Jumps
+{
+	/// Reports a statement used where it cannot be.
+	fn misplace(&mut self, span: Span, message: &str)
+	{
+		let diagnostic = Diagnostic::new(span, message.to_string());
+		self.error.get_or_insert(diagnostic.into());
+	}
+
+	/// Visits the body of a function or lambda, which is outside of the loops
+	/// around it.
+	fn function(&mut self, walk: impl FnOnce(&mut Self))
+	{
+		let loops = std::mem::take(&mut self.loops);
+		self.functions += 1;
+		walk(self);
+		self.functions -= 1;
+		self.loops = loops;
+	}
+}
+
+impl<'ast> Visit<'ast> for Jumps
+{
+	fn visit_while(&mut self, node: &'ast WhileStmt)
+	{
+		self.loops += 1;
+		visit::walk_while(self, node);
+		self.loops -= 1;
+	}
+
+	fn visit_function(&mut self, node: &'ast FunctionDclr)
+	{
+		self.function(|jumps| visit::walk_function(jumps, node));
+	}
+
+	fn visit_lambda(&mut self, node: &'ast Lambda)
+	{
+		self.function(|jumps| visit::walk_lambda(jumps, node));
+	}
+
+	fn visit_break(&mut self, node: &'ast BreakStmt)
+	{
+		if self.loops == 0
+		{
+			self.misplace(node.span, "`break` outside of a loop");
+		}
+	}
+
+	fn visit_continue(&mut self, node: &'ast ContinueStmt)
+	{
+		if self.loops == 0
+		{
+			self.misplace(node.span, "`continue` outside of a loop");
+		}
+	}
+
+	fn visit_return(&mut self, node: &'ast ReturnStmt)
+	{
+		if self.functions == 0
+		{
+			self.misplace(node.span, "`return` outside of a function");
+		}
+
+		visit::walk_return(self, node);
+	}
+}
@@ -1,4 +1,7 @@
+mod controlflow;
 mod declaration;
 mod expression;
+mod lambda;
 mod programme;
 mod statement;
+mod subscript;
@@ -1,22 +1,39 @@
+use std::collections::HashMap;
+
 use anyhow::Result;
 
-use crate::{compiler::semanter::table::Table, language::grammar::Programme};
+use crate::compiler::semanter::table::Table;
+use crate::compiler::Diagnostic;
+use crate::language::grammar::Programme;
 
 impl Programme
 {
 	/// Semantically analyses the abstract syntax tree.
 	///
+	/// ### Parameters
+	/// * `modules` - The exported symbols of the modules this programme may
+	///   import, keyed by module name.
+	///
+	/// ### Returns
+	/// * The symbol table of the programme's top-level scope.
+	///
 	/// ### Errors
 	/// * If there are semantic errors in the abstract syntax tree.
-	pub fn analyse(&self) -> Result<()>
+	pub fn analyse(&self, modules: HashMap<String, Table>) -> Result<Table>
 	{
-		let mut symbol: Table = Table::new();
+		let mut symbol = Table {
+			modules,
+			..Table::new()
+		};
+		symbol.hoist(&self.statements);
 
 		for statement in &self.statements
 		{
-			statement.analyse(&mut symbol)?;
+			statement
+				.analyse(&mut symbol)
+				.map_err(|error| Diagnostic::attach(error, statement.span))?;
 		}
 
-		Ok(())
+		Ok(symbol)
 	}
 }
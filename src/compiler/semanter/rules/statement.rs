@@ -1,11 +1,13 @@
-use anyhow::{ensure, Result};
+use anyhow::{bail, ensure, Result};
 
 use crate::{
-	compiler::semanter::table::Table,
+	compiler::{semanter::table::Table, Diagnostic},
 	language::{
 		grammar::{
 			statement::{Kind::*, Statement},
-			IfStmt, WhileStmt,
+			visit::{self, Visit},
+			AssignmentStmt, Block, BreakStmt, ContinueStmt, FunctionDclr, IfStmt,
+			Lambda, ReturnStmt, UseStmt, WhileStmt,
 		},
 		semantics::r#type::Type,
 	},
@@ -13,33 +15,267 @@ use crate::{
 
 impl Statement
 {
-	/// Semantically analyses the statement.
+	/// Semantically analyses the statement, and the statements of its bodies
+	/// each in a scope of their own.
 	///
 	/// ### Errors
 	/// * If there are semantic errors in the statement.
 	pub fn analyse(&self, symbol: &mut Table) -> Result<()>
 	{
+		// The bodies of the statement may run any number of times, so the
+		// shapes of the variables they reassign are not known within them nor
+		// after them.
+		if !matches!(self.kind, Assignment(_))
+		{
+			let mut assigned = Assigned::default();
+			assigned.visit_statement(self);
+
+			for name in assigned.names
+			{
+				symbol.shapes.remove(name);
+			}
+		}
+
 		match &self.kind
 		{
 			Declaration(declaration) => declaration.analyse(symbol),
-			Expression(expression) => expression.analyse(symbol),
-			If(IfStmt { condition, .. }) | While(WhileStmt { condition, .. }) =>
+			Expression(expression) => expression.r#type(symbol).map(|_| ()),
+			If(IfStmt {
+				condition,
+				body,
+				else_ifs,
+				else_body,
+				..
+			}) =>
+			{
+				let conditions = else_ifs.iter().map(|else_if| &else_if.condition);
+
+				for condition in std::iter::once(condition).chain(conditions)
+				{
+					ensure!(
+						condition.r#type(symbol)? == Type::Boolean,
+						"Invalid condition"
+					);
+				}
+
+				let bodies = else_ifs.iter().map(|else_if| &else_if.body);
+
+				for body in std::iter::once(body).chain(bodies).chain(else_body)
+				{
+					body.analyse(symbol)?;
+				}
+				Ok(())
+			}
+			Match(match_) => match_.analyse(symbol),
+			While(WhileStmt {
+				condition, body, ..
+			}) =>
 			{
 				ensure!(
 					condition.r#type(symbol)? == Type::Boolean,
 					"Invalid condition"
 				);
-				Ok(())
+
+				let mut scope = symbol.scope();
+				scope.in_loop = true;
+				body.analyse(&scope)
 			}
 			Assignment(assignment) =>
+			{
+				let name = &assignment.identifier;
+
+				let Some(binding) = symbol.bindings.get(name)
+				else
+				{
+					ensure!(
+						!symbol.functions.contains_key(name),
+						"Cannot assign to function `{name}`"
+					);
+					bail!("Unknown identifier `{name}`")
+				};
+
+				binding.assign(name, assignment.span)?;
+
+				// Assigning to an element changes the value in place, which Python
+				// allows from within a function.
+				let function =
+					symbol.function.filter(|_| assignment.subscript.is_none());
+
+				if let Some(function) = function
+				{
+					if !function.contains(&binding.span)
+					{
+						let message = format!(
+							"Functions cannot assign to `{name}`, which is declared \
+							 outside of them"
+						);
+						let diagnostic = Diagnostic::new(assignment.span, message);
+						let note = format!("`{name}` is declared here");
+						return Err(diagnostic.relate(binding.span, note).into());
+					}
+				}
+
+				assignment.expression.r#type(symbol)?;
+				let r#type = &binding.r#type;
+
+				match &assignment.subscript
+				{
+					Some(subscript) =>
+					{
+						ensure!(
+							matches!(r#type, Type::Unit | Type::List(_) | Type::Matrix(_)),
+							"Cannot assign to elements of a value of type `{type}`"
+						);
+
+						let shape = symbol.shapes.get(name).map(Vec::as_slice);
+						subscript.analyse(shape, symbol)
+					}
+
+					None =>
+					{
+						match assignment.expression.shape(symbol)
+						{
+							Some(shape) => symbol.shapes.insert(name.clone(), shape),
+							None => symbol.shapes.remove(name),
+						};
+						Ok(())
+					}
+				}
+			}
+			Import(import) =>
+			{
+				ensure!(
+					symbol.modules.contains_key(&import.name),
+					"Module `{}` could not be resolved",
+					import.path
+				);
+				Ok(())
+			}
+			Use(UseStmt { module, name, .. }) =>
+			{
+				let Some(exports) = symbol.modules.get(module)
+				else
+				{
+					bail!("Module `{module}` has not been imported")
+				};
+
+				if let Some(r#struct) = exports.structs.get(name)
+				{
+					symbol.structs.insert(name.clone(), r#struct.clone());
+					symbol.functions.insert(name.clone(), r#struct.constructor());
+				}
+				else if let Some(r#enum) = exports.enums.get(name)
+				{
+					symbol.enums.insert(name.clone(), r#enum.clone());
+				}
+				else if let Some(r#type) = exports.functions.get(name)
+				{
+					symbol.functions.insert(name.clone(), r#type.clone());
+
+					if let Some(requirements) = exports.requirements.get(name)
+					{
+						symbol
+							.requirements
+							.insert(name.clone(), requirements.clone());
+					}
+				}
+				else if let Some(binding) = exports.bindings.get(name)
+				{
+					symbol.bindings.insert(name.clone(), binding.clone());
+				}
+				else
+				{
+					bail!("`{name}` is not exported by module `{module}`")
+				}
+
+				Ok(())
+			}
+			Break(BreakStmt { span }) =>
 			{
 				ensure!(
-					symbol.variables.contains_key(&assignment.identifier),
-					"Invalid assignment"
+					symbol.in_loop,
+					Diagnostic::new(*span, "`break` outside of a loop".into())
 				);
 				Ok(())
 			}
-			Echo(_) | Break(_) | Continue(_) | Return(_) | FunctionCall(_) => Ok(()),
+			Continue(ContinueStmt { span }) =>
+			{
+				ensure!(
+					symbol.in_loop,
+					Diagnostic::new(*span, "`continue` outside of a loop".into())
+				);
+				Ok(())
+			}
+			Return(ReturnStmt { span, expression }) =>
+			{
+				ensure!(
+					symbol.function.is_some(),
+					Diagnostic::new(*span, "`return` outside of a function".into())
+				);
+				expression.r#type(symbol).map(|_| ())
+			}
+			Echo(echo) => echo
+				.arguments
+				.iter()
+				.try_for_each(|argument| argument.r#type(symbol).map(|_| ())),
+			FunctionCall(call) => call.analyse(symbol),
+		}?;
+
+		// The statement as a whole is then held to the constraints that the
+		// statements of a function body are inferred with.
+		self.infer(symbol)
+	}
+}
+
+/// A visitor that collects the names of the variables that are assigned a new
+/// value as a whole.
+#[derive(Default)]
+struct Assigned<'ast>
+{
+	/// The names of the assigned variables.
+	names: Vec<&'ast str>,
+}
+
+impl<'ast> Visit<'ast> for Assigned<'ast>
+{
+	fn visit_assignment(&mut self, node: &'ast AssignmentStmt)
+	{
+		if node.subscript.is_none()
+		{
+			self.names.push(&node.identifier);
 		}
+
+		visit::walk_assignment(self, node);
+	}
+
+	// Functions and lambdas cannot assign to the names bound outside of them.
+	fn visit_function(&mut self, _: &'ast FunctionDclr) {}
+
+	fn visit_lambda(&mut self, _: &'ast Lambda) {}
+}
+
+impl Block
+{
+	/// Semantically analyses the statements of the block in a scope of their
+	/// own, nested in the scope of the block.
+	///
+	/// ### Parameters
+	/// * `symbol` - The symbol table of the scope of the block.
+	///
+	/// ### Errors
+	/// * If there are semantic errors in the statements.
+	pub fn analyse(&self, symbol: &Table) -> Result<()>
+	{
+		let mut scope = symbol.scope();
+		scope.hoist(self.statements.iter().flatten());
+
+		for statement in self.statements.iter().flatten()
+		{
+			statement
+				.analyse(&mut scope)
+				.map_err(|error| Diagnostic::attach(error, statement.span))?;
+		}
+
+		Ok(())
 	}
 }
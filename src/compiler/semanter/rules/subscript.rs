@@ -0,0 +1,163 @@
+use anyhow::{bail, ensure, Result};
+
+use crate::compiler::semanter::table::Table;
+use crate::language::grammar::subscript::{Index, Subscript};
+use crate::language::grammar::Expression;
+use crate::language::semantics::r#type::Type;
+
+impl Subscript
+{
+	/// Semantically analyses the subscript.
+	///
+	/// ### Parameters
+	/// * `shape` - The shape of the subscripted value, if it is known.
+	/// * `symbol` - The symbols in scope.
+	///
+	/// ### Errors
+	/// * If an index is not a number.
+	/// * If there are more indices than the value has dimensions.
+	/// * If an integer literal index is out of bounds.
+	pub fn analyse(&self, shape: Option<&[usize]>, symbol: &Table) -> Result<()>
+	{
+		for index in &self.indices
+		{
+			let bounds = match index
+			{
+				Index::Position(position) => vec![position],
+				Index::Slice { start, end, .. } => start.iter().chain(end).collect(),
+			};
+
+			for bound in bounds
+			{
+				let r#type = bound.r#type(symbol)?;
+				ensure!(
+					r#type == Type::Number || r#type == Type::Unit,
+					"Index must be a `Number`, not `{type}`"
+				);
+			}
+		}
+
+		let Some(shape) = shape
+		else
+		{
+			return Ok(());
+		};
+
+		ensure!(
+			self.indices.len() <= shape.len(),
+			"Too many indices for a value with {} {}",
+			shape.len(),
+			if shape.len() == 1
+			{
+				"dimension"
+			}
+			else
+			{
+				"dimensions"
+			}
+		);
+
+		for (index, &length) in self.indices.iter().zip(shape)
+		{
+			match index
+			{
+				Index::Position(position) =>
+				{
+					bound(position, length, false, "Index")?;
+				}
+
+				Index::Slice {
+					start,
+					end,
+					is_inclusive,
+					..
+				} =>
+				{
+					if let Some(start) = start
+					{
+						bound(start, length, true, "Slice bound")?;
+					}
+
+					if let Some(end) = end
+					{
+						bound(end, length, !is_inclusive, "Slice bound")?;
+					}
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Gets the type of the value of a tuple the subscript selects.  A tuple
+	/// is indexed by a single integer literal, so that the type is known.
+	///
+	/// ### Parameters
+	/// * `types` - The types of the values of the tuple.
+	///
+	/// ### Returns
+	/// * The type of the value.
+	///
+	/// ### Errors
+	/// * If the subscript is not a single integer literal in bounds.
+	pub fn element(&self, types: &[Type]) -> Result<Type>
+	{
+		let index = match self.indices.as_slice()
+		{
+			[Index::Position(position)] => position.integer(),
+			_ => None,
+		};
+
+		let length = types.len() as i128;
+
+		match index
+		{
+			Some(index) if -length <= index && index < length =>
+			{
+				Ok(types[index.rem_euclid(length) as usize].clone())
+			}
+			_ => bail!(
+				"A tuple of {length} values is indexed by an integer from {} to {}",
+				-length,
+				length - 1
+			),
+		}
+	}
+}
+
+/// Checks that an integer literal index fits in a dimension.
+///
+/// An index of `-n` counts `n` positions back from the end of the dimension.
+///
+/// ### Parameters
+/// * `index` - The index, which is only checked if it is an integer literal.
+/// * `length` - The length of the dimension.
+/// * `can_end` - Whether the index may be the length itself, as the start or
+///   exclusive end of a slice may be.
+/// * `what` - What the index is, for the error message.
+///
+/// ### Errors
+/// * If the index is out of bounds.
+fn bound(
+	index: &Expression,
+	length: usize,
+	can_end: bool,
+	what: &str,
+) -> Result<()>
+{
+	let Some(value) = index.integer()
+	else
+	{
+		return Ok(());
+	};
+
+	let length = length as i128;
+	let end = if can_end { length } else { length - 1 };
+
+	ensure!(
+		-length <= value && value <= end,
+		"{what} `{value}` is out of bounds for a dimension of length {length}"
+	);
+
+	Ok(())
+}
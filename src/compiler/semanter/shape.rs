@@ -0,0 +1,179 @@
+use super::table::Table;
+use crate::language::grammar::expression::{Expression, Items, Kind};
+use crate::language::grammar::subscript::Index;
+use crate::language::lexicon::token;
+use crate::language::semantics::r#type::Type;
+
+impl Expression
+{
+	/// Gets the shape of a list or matrix.
+	///
+	/// ### Parameters
+	/// * `symbol` - The symbols in scope.
+	///
+	/// ### Returns
+	/// * The length of each dimension of the value, outermost first.
+	/// * `None` if the value is not a list or matrix of known shape.
+	pub fn shape(&self, symbol: &Table) -> Option<Vec<usize>>
+	{
+		match &self.kind
+		{
+			Kind::Identifier(token) => match &token.kind
+			{
+				token::Kind::Identifier(name) => symbol.shapes.get(name).cloned(),
+				_ => unreachable!(),
+			},
+
+			Kind::Parenthesised(expression) => expression.shape(symbol),
+
+			Kind::List(rows) =>
+			{
+				let row = rows.first()?;
+				Some(vec![length(row.as_ref(), symbol)?])
+			}
+
+			Kind::Matrix(rows) =>
+			{
+				let lengths = rows
+					.iter()
+					.map(|row| length(row.as_ref(), symbol))
+					.collect::<Option<Vec<_>>>()?;
+
+				let &columns = lengths.first()?;
+
+				match lengths.iter().all(|&length| length == columns)
+				{
+					true => Some(vec![rows.len(), columns]),
+					false => None,
+				}
+			}
+
+			Kind::Index { target, subscript } =>
+			{
+				let shape = target.shape(symbol)?;
+				let rest = shape.get(subscript.indices.len()..)?.to_vec();
+
+				let mut dimensions = subscript
+					.indices
+					.iter()
+					.zip(shape)
+					.filter_map(|(index, length)| match index
+					{
+						Index::Position(_) => None,
+						Index::Slice { .. } => Some(slice(index, length)),
+					})
+					.collect::<Option<Vec<_>>>()?;
+
+				dimensions.extend(rest);
+				Some(dimensions)
+			}
+
+			_ => None,
+		}
+	}
+
+	/// Gets the width of a bit-string.
+	///
+	/// ### Parameters
+	/// * `symbol` - The symbols in scope.
+	///
+	/// ### Returns
+	/// * The number of bits of the value.
+	/// * `None` if the value is not a bit-string of known width.
+	pub fn width(&self, symbol: &Table) -> Option<usize>
+	{
+		match &self.kind
+		{
+			Kind::Literal(token) => match &token.kind
+			{
+				token::Kind::Number(number) => number.strip_prefix("0b").map(str::len),
+				_ => None,
+			},
+
+			Kind::Identifier(token) => match &token.kind
+			{
+				token::Kind::Identifier(name) => symbol.widths.get(name).copied(),
+				_ => unreachable!(),
+			},
+
+			Kind::Parenthesised(expression) => expression.width(symbol),
+
+			_ => None,
+		}
+	}
+}
+
+/// Gets the number of items in a row of a list or matrix, provided every item
+/// is a single value rather than a nested list or matrix.
+///
+/// ### Parameters
+/// * `row` - The items of the row, or `None` if the row is empty.
+/// * `symbol` - The symbols in scope.
+///
+/// ### Returns
+/// * The number of items in the row.
+/// * `None` if an item may not be a single value.
+fn length(row: Option<&Items>, symbol: &Table) -> Option<usize>
+{
+	let Some(items) = row
+	else
+	{
+		return Some(0);
+	};
+
+	items
+		.expressions
+		.iter()
+		.all(|item| !matches!(item.r#type(symbol), Ok(Type::Unit) | Err(_)))
+		.then_some(items.expressions.len())
+}
+
+/// Gets the number of positions a slice takes from a dimension.
+///
+/// ### Parameters
+/// * `index` - The slice.
+/// * `length` - The length of the dimension.
+///
+/// ### Returns
+/// * The number of positions, following Python's slicing rules.
+/// * `None` if a bound of the slice is not an integer literal.
+pub fn slice(index: &Index, length: usize) -> Option<usize>
+{
+	let Index::Slice {
+		start,
+		end,
+		is_inclusive,
+		..
+	} = index
+	else
+	{
+		return None;
+	};
+
+	let length = length as i128;
+
+	let position = |bound: i128| match bound < 0
+	{
+		true => (bound + length).max(0),
+		false => bound.min(length),
+	};
+
+	let start = match start
+	{
+		Some(start) => position(start.integer()?),
+		None => 0,
+	};
+
+	let end = match (end, is_inclusive)
+	{
+		(None, _) => length,
+		(Some(end), false) => position(end.integer()?),
+		(Some(end), true) => match end.integer()?
+		{
+			-1 => length,
+			end => position(end + 1),
+		},
+	};
+
+	Some((end - start).max(0) as usize)
+}
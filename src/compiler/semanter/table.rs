@@ -1,12 +1,136 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::language::semantics::r#type::Type;
+use anyhow::{bail, ensure, Result};
 
+use crate::compiler::Diagnostic;
+use crate::language::grammar::declaration::{self, Declaration};
+use crate::language::grammar::statement::{self, Statement};
+use crate::language::grammar::types::Variant;
+use crate::language::grammar::{EnumDclr, StructDclr};
+use crate::language::semantics::r#type::{Size, Type};
+use crate::language::utils::Span;
+
+/// What binds a name to a value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Binder
+{
+	/// A `let` declaration, whose name cannot be assigned a new value.
+	Let,
+
+	/// A `var` declaration, whose name can be assigned a new value.
+	Var,
+
+	/// A parameter of a function or lambda.
+	Parameter,
+
+	/// A pattern of a `match` arm.
+	Pattern,
+}
+
+/// A name bound to a value.
+#[derive(Clone, Debug)]
+pub struct Binding
+{
+	/// The type of the value.
+	pub r#type: Type,
+
+	/// What binds the name.
+	pub binder: Binder,
+
+	/// The span of the code that binds the name.
+	pub span: Span,
+}
+
+impl Binding
+{
+	/// Checks that the name may be assigned a new value, which only names
+	/// declared with `var` may.
+	///
+	/// ### Parameters
+	/// * `name` - The name.
+	/// * `span` - The span of the assignment.
+	///
+	/// ### Errors
+	/// * If the name is not declared with `var`.  The error relates the span that
+	///   binds the name.
+	pub fn assign(&self, name: &str, span: Span) -> Result<()>
+	{
+		let note = match self.binder
+		{
+			Binder::Var => return Ok(()),
+			Binder::Let =>
+			{
+				"is declared here; change `let` to `var` to make it mutable"
+			}
+			Binder::Parameter => "is a parameter declared here",
+			Binder::Pattern => "is bound by the pattern here",
+		};
+
+		let message = format!("Cannot assign to immutable binding `{name}`");
+		let diagnostic = Diagnostic::new(span, message);
+		Err(
+			diagnostic
+				.relate(self.span, format!("`{name}` {note}"))
+				.into(),
+		)
+	}
+}
+
+#[derive(Clone, Default)]
 pub struct Table
 {
-	pub constants: HashMap<String, Type>,
-	pub variables: HashMap<String, Type>,
+	/// The names bound to values, keyed by name.  A name is bound at most once
+	/// in a scope, whether or not it is mutable.
+	pub bindings: HashMap<String, Binding>,
+
 	pub functions: HashMap<String, Type>,
+
+	/// The declared structs, keyed by name.  The constructor of each struct is
+	/// also one of the functions.
+	pub structs: HashMap<String, StructDclr>,
+
+	/// The declared enums, keyed by name.
+	pub enums: HashMap<String, EnumDclr>,
+
+	/// The dimensions of the lists and matrices whose shape is known, keyed by
+	/// name.
+	pub shapes: HashMap<String, Vec<usize>>,
+
+	/// The widths of the bit-strings bound to constants, keyed by name.  Only
+	/// a constant keeps the width of the bit-string it is declared with.
+	pub widths: HashMap<String, usize>,
+
+	/// The exported symbols of each imported module, keyed by module name.
+	pub modules: HashMap<String, Table>,
+
+	/// The types inferred within function bodies, keyed by the span of what
+	/// they were inferred for: a function declaration, a parameter or a
+	/// declaration in the body.
+	pub inferred: HashMap<Span, Type>,
+
+	/// The members that the body of each generic function uses on values of its
+	/// type parameters, keyed by function name, as the type parameter, the name
+	/// of the member and the span of the code that uses it.
+	pub requirements: HashMap<String, Vec<(String, String, Span)>>,
+
+	/// The names bound in enclosing scopes, which a declaration in this scope
+	/// may bind again to shadow them.
+	pub enclosing: HashSet<String>,
+
+	/// The names of the functions a declaration in this scope may declare
+	/// again: those of enclosing scopes, and those of this scope, which are
+	/// declared with their annotated types ahead of their declaration so that
+	/// they may be called before it.
+	pub provisional: HashSet<String>,
+
+	/// Whether the scope is within a loop of the innermost function, where
+	/// `break` and `continue` may be used.
+	pub in_loop: bool,
+
+	/// The span of the innermost function the scope is within, where `return`
+	/// may be used.  The function cannot assign to the names bound outside of
+	/// it, since Python would bind them anew within the function.
+	pub function: Option<Span>,
 }
 
 impl Table
@@ -14,9 +138,213 @@ impl Table
 	pub fn new() -> Self
 	{
 		Self {
-			constants: HashMap::new(),
-			variables: HashMap::new(),
+			bindings: HashMap::new(),
 			functions: HashMap::new(),
+			structs: HashMap::new(),
+			enums: HashMap::new(),
+			shapes: HashMap::new(),
+			widths: HashMap::new(),
+			modules: HashMap::new(),
+			inferred: HashMap::new(),
+			requirements: HashMap::new(),
+			enclosing: HashSet::new(),
+			provisional: HashSet::new(),
+			in_loop: false,
+			function: None,
+		}
+	}
+
+	/// Creates the table of a scope nested in this one, such as the body of an
+	/// `if`, in which the names of this scope stay bound but may be shadowed.
+	///
+	/// ### Returns
+	/// * The table of the nested scope.
+	pub fn scope(&self) -> Self
+	{
+		let mut scope = self.clone();
+		scope.enclosing.extend(self.bindings.keys().cloned());
+		scope.provisional.extend(self.functions.keys().cloned());
+		scope
+	}
+
+	/// Declares the functions of a scope with their annotated types, so that
+	/// they may be called before their declaration.
+	///
+	/// ### Parameters
+	/// * `statements` - The statements of the scope.
+	pub fn hoist<'a>(
+		&mut self,
+		statements: impl IntoIterator<Item = &'a Statement>,
+	)
+	{
+		for statement in statements
+		{
+			let statement::Kind::Declaration(Declaration {
+				kind: declaration::Kind::Function(function),
+				..
+			}) = &statement.kind
+			else
+			{
+				continue;
+			};
+
+			let name = &function.name;
+
+			// A second declaration in the scope is reported when it is reached.
+			if !self.functions.contains_key(name) || self.provisional.contains(name)
+			{
+				self.functions.insert(name.clone(), function.r#type());
+				self.provisional.insert(name.clone());
+			}
+		}
+	}
+
+	/// Looks up the type of a name that can be called, which is either a
+	/// function or a binding holding a function value.
+	///
+	/// ### Parameters
+	/// * `name` - The name.
+	///
+	/// ### Returns
+	/// * The function type, or `None` if the name cannot be called.
+	pub fn callable(&self, name: &str) -> Option<&Type>
+	{
+		self
+			.functions
+			.get(name)
+			.or_else(|| self.bindings.get(name).map(|binding| &binding.r#type))
+			.filter(|r#type| matches!(r#type, Type::Function { .. }))
+	}
+
+	/// Checks that a type, and each type within it, names a declared struct or
+	/// enum, if it names one at all, and that each size parameter of a matrix
+	/// type is declared.
+	///
+	/// ### Parameters
+	/// * `type` - The type.
+	/// * `generics` - The type and size parameters in scope.
+	///
+	/// ### Errors
+	/// * If the type is named after neither a built-in type nor a declared struct
+	///   or enum.
+	/// * If a matrix type has a size parameter that is not in scope.
+	pub fn resolve(&self, r#type: &Type, generics: &[String]) -> Result<()>
+	{
+		match r#type
+		{
+			Type::Named(name)
+				if !self.structs.contains_key(name)
+					&& !self.enums.contains_key(name) =>
+			{
+				bail!("Unknown type `{name}`")
+			}
+			Type::Tuple(types) => types
+				.iter()
+				.try_for_each(|r#type| self.resolve(r#type, generics)),
+			Type::List(r#type) => self.resolve(r#type, generics),
+			Type::Matrix(sizes) =>
+			{
+				for size in sizes.iter()
+				{
+					if let Size::Parameter(name) = size
+					{
+						ensure!(generics.contains(name), "Unknown size `{name}`");
+					}
+				}
+				Ok(())
+			}
+			Type::Function {
+				parameters,
+				returns,
+			} =>
+			{
+				parameters
+					.iter()
+					.try_for_each(|r#type| self.resolve(r#type, generics))?;
+				self.resolve(returns, generics)
+			}
+			_ => Ok(()),
+		}
+	}
+
+	/// Looks up the type of a field of a struct.
+	///
+	/// ### Parameters
+	/// * `type` - The name of the struct.
+	/// * `name` - The name of the field.
+	///
+	/// ### Returns
+	/// * The type of the field.
+	///
+	/// ### Errors
+	/// * If the type is an enum, or a struct without the field.
+	pub fn field(&self, r#type: &str, name: &str) -> Result<Type>
+	{
+		if self.enums.contains_key(r#type)
+		{
+			bail!(
+				"`{type}` is an enum; use `match` to read the payload of its variants"
+			)
+		}
+
+		let field = self.structs.get(r#type).and_then(|r#struct| {
+			r#struct.fields.iter().find(|field| field.name == name)
+		});
+
+		match field
+		{
+			Some(field) => Ok(field.r#type.clone()),
+			None => bail!("`{type}` has no field `{name}`"),
+		}
+	}
+
+	/// Looks up a variant of an enum.
+	///
+	/// ### Parameters
+	/// * `enum` - The name of the enum.
+	/// * `name` - The name of the variant.
+	///
+	/// ### Returns
+	/// * The variant.
+	///
+	/// ### Errors
+	/// * If there is no such enum, or it has no such variant.
+	pub fn variant(&self, r#enum: &str, name: &str) -> Result<&Variant>
+	{
+		let Some(declaration) = self.enums.get(r#enum)
+		else
+		{
+			bail!("Unknown enum `{enum}`")
+		};
+
+		match declaration.variant(name)
+		{
+			Some(variant) => Ok(variant),
+			None => bail!("`{enum}` has no variant `{name}`"),
+		}
+	}
+
+	/// Creates the table of symbols other modules may `use` from this one.
+	///
+	/// Only functions, structs, enums and `let` constants are exported; mutable
+	/// variables stay private to the module that declares them.
+	///
+	/// ### Returns
+	/// * The exported symbols.
+	pub fn exports(&self) -> Self
+	{
+		Self {
+			bindings: self
+				.bindings
+				.iter()
+				.filter(|(_, binding)| binding.binder != Binder::Var)
+				.map(|(name, binding)| (name.clone(), binding.clone()))
+				.collect(),
+			functions: self.functions.clone(),
+			requirements: self.requirements.clone(),
+			structs: self.structs.clone(),
+			enums: self.enums.clone(),
+			..Self::new()
 		}
 	}
 }
@@ -1,56 +1,64 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 
-use crate::language::lexicon::token;
+use crate::language::lexicon::{token, Token};
 use crate::language::{
-	grammar::expression::{Expression, Kind::*},
-	semantics::r#type::Type,
+	grammar::expression::Expression,
+	semantics::{member, r#type::Type},
 };
 
 use super::table::Table;
 
 impl Expression
 {
+	/// Gets the type of the expression, after analysing it.
+	///
+	/// ### Parameters
+	/// * `symbol` - The symbol table.
+	///
+	/// ### Returns
+	/// * The type of the expression, which is `Unit` where it is not known.
+	///
+	/// ### Errors
+	/// * If there are semantic errors in the expression.
 	pub fn r#type(&self, symbol: &Table) -> Result<Type>
 	{
 		self.analyse(symbol)?;
+		self.infer(symbol)
+	}
 
-		match &self.kind
+	/// Looks up a member of the type of the expression.
+	///
+	/// ### Parameters
+	/// * `name` - The name of the member.
+	/// * `symbol` - The symbol table.
+	///
+	/// ### Returns
+	/// * The member.
+	///
+	/// ### Errors
+	/// * If the type of the expression has no member with the name.
+	pub fn member(
+		&self,
+		name: &Token,
+		symbol: &Table,
+	) -> Result<&'static member::Member>
+	{
+		let name = match &name.kind
 		{
-			Identifier(token) =>
-			{
-				let name = match &token.kind
-				{
-					token::Kind::Identifier(name) => name.clone(),
-					_ => unreachable!(),
-				};
+			token::Kind::Identifier(name) => name,
+			_ => unreachable!(),
+		};
 
-				if symbol.constants.contains_key(&name)
-				{
-					Ok(symbol.constants[&name])
-				}
-				else
-				{
-					Ok(symbol.variables[&name])
-				}
-			}
+		let r#type = self.r#type(symbol)?;
 
-			Literal(token) => match token.kind
+		match member::lookup(&r#type, name)
+		{
+			Some(member) => Ok(member),
+			None if r#type == Type::Unit =>
 			{
-				token::Kind::Boolean(_) => Ok(Type::Boolean),
-				token::Kind::Number(_) => Ok(Type::Number),
-				token::Kind::String(_) => Ok(Type::String),
-				_ => unreachable!(),
-			},
-
-			Parenthesised(expression) => expression.r#type(symbol),
-
-			Prefix { operand, .. } => operand.r#type(symbol),
-
-			Infix { left, .. } => left.r#type(symbol),
-
-			FunctionCall(function) => Ok(symbol.functions[&function.name]),
-
-			_ => Ok(Type::Unit),
+				bail!("Lists and matrices have no member `{name}`")
+			}
+			None => bail!("`{type}` has no member `{name}`"),
 		}
 	}
 }
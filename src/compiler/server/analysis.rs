@@ -0,0 +1,817 @@
+use std::path::Path;
+
+use crate::compiler::lexer::Lex;
+use crate::compiler::parser::{Parse, Tree};
+use crate::compiler::semanter::table::Table;
+use crate::compiler::{Diagnostic, Levels, Lint, Project};
+use crate::language::grammar::declaration::{self, Declaration};
+use crate::language::grammar::functions::{Body, Lambda};
+use crate::language::grammar::pattern;
+use crate::language::grammar::statement::Kind::*;
+use crate::language::grammar::visit::{self, Visit};
+use crate::language::grammar::{Arm, Block, Statement};
+use crate::language::lexicon::token::{self, Segment};
+use crate::language::lexicon::Token;
+use crate::language::semantics::r#type::Type;
+use crate::language::utils::{FileId, Position, SourceMap, Span};
+
+/// The kind of a symbol declared in a document.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SymbolKind
+{
+	/// A `let` constant.
+	Constant,
+
+	/// A `var` variable.
+	Variable,
+
+	/// A function.
+	Function,
+
+	/// A struct.
+	Struct,
+
+	/// An enum.
+	Enum,
+
+	/// A field of a struct.
+	Field,
+
+	/// A variant of an enum.
+	Variant,
+
+	/// A parameter of a function or lambda, or a name bound by a pattern.
+	Parameter,
+
+	/// An imported module.
+	Module,
+
+	/// A name brought in from a module by `use`.
+	Use,
+}
+
+/// A symbol declared in a document.
+#[derive(Clone, Debug)]
+pub struct Symbol
+{
+	/// The name of the symbol.
+	pub name: String,
+
+	/// The kind of the symbol.
+	pub kind: SymbolKind,
+
+	/// The span of the name where the symbol is declared.
+	pub span: Span,
+
+	/// The span of the code the symbol is visible in.
+	pub scope: Span,
+
+	/// The declaration of the symbol as it would be written in Quark, with its
+	/// type where it is known.
+	pub detail: String,
+
+	/// The documentation comments of the symbol.
+	pub docs: Vec<String>,
+
+	/// The name of the function the symbol is declared in, if any.
+	pub container: Option<String>,
+}
+
+/// An error found in a document.
+#[derive(Clone, Debug)]
+pub struct Problem
+{
+	/// The span of the error, if it lies in the document.
+	pub span: Option<Span>,
+
+	/// The error message.
+	pub message: String,
+
+	/// The spans in the document of code related to the error, each with a
+	/// note on how it is related.
+	pub related: Vec<(Span, String)>,
+
+	/// The lint that found the problem, or `None` if it is an error.
+	pub lint: Option<Lint>,
+}
+
+/// Everything the language server knows about an open document.
+pub struct Analysis
+{
+	/// The source map holding the text of the document.
+	pub source: SourceMap,
+
+	/// The document.
+	pub file: FileId,
+
+	/// The errors in the document.
+	pub problems: Vec<Problem>,
+
+	/// The symbols declared in the document.
+	pub symbols: Vec<Symbol>,
+
+	/// The identifiers of the document, each with the symbol it refers to.
+	pub references: Vec<(Span, String, Option<usize>)>,
+}
+
+impl Analysis
+{
+	/// Analyses the text of a document.
+	///
+	/// ### Parameters
+	/// * `path` - The path of the document.
+	/// * `text` - The text of the document.
+	///
+	/// ### Returns
+	/// * The analysis of the document.
+	pub fn new(path: &Path, text: String) -> Self
+	{
+		let mut source = SourceMap::new();
+		let file = source.add(path, text.clone());
+
+		// The entry file is the first file of a project's source map, so its
+		// spans carry the same file identifier as `file`.
+		let problems = match Project::load_source(path, text)
+		{
+			Ok(project) => project
+				.lint(&Levels::default())
+				.unwrap_or_default()
+				.into_iter()
+				.filter(|warning| warning.span.file() == file)
+				.map(|warning| Problem {
+					span: Some(warning.span),
+					message: warning.message,
+					related: Vec::new(),
+					lint: Some(warning.lint),
+				})
+				.collect(),
+			Err(error) => vec![match error.downcast_ref::<Diagnostic>()
+			{
+				Some(diagnostic) if diagnostic.span.file() == file => Problem {
+					span: Some(diagnostic.span),
+					message: diagnostic.message.clone(),
+					related: diagnostic
+						.related
+						.iter()
+						.filter(|(span, _)| span.file() == file)
+						.cloned()
+						.collect(),
+					lint: None,
+				},
+				_ => Problem {
+					span: None,
+					message: error.to_string(),
+					related: Vec::new(),
+					lint: None,
+				},
+			}],
+		};
+
+		let mut analysis = Self {
+			source,
+			file,
+			problems,
+			symbols: Vec::new(),
+			references: Vec::new(),
+		};
+
+		analysis.index();
+		analysis
+	}
+
+	/// Indexes the symbols of the document and the identifiers that refer to
+	/// them.  Nothing is indexed if the document cannot be parsed.
+	fn index(&mut self)
+	{
+		let Ok(tokens) = self.file.lex(&self.source)
+		else
+		{
+			return;
+		};
+
+		let Ok(Tree(programme)) = tokens.clone().parse(&self.source)
+		else
+		{
+			return;
+		};
+
+		// Analyse each statement on its own, so that one error does not hide
+		// the types of everything after it.
+		let mut table = Table::new();
+
+		for statement in &programme.statements
+		{
+			let _ = statement.analyse(&mut table);
+		}
+
+		let text = &self.source.file(self.file).text;
+		let scope = Span {
+			start: Position {
+				file: self.file,
+				offset: 0,
+			},
+			end: Position {
+				file: self.file,
+				offset: text.len(),
+			},
+		};
+
+		let mut indexer = Indexer {
+			tokens: &tokens,
+			table: &table,
+			symbols: Vec::new(),
+			container: None,
+		};
+
+		indexer.statements(programme.statements.iter(), scope);
+		self.symbols = indexer.symbols;
+
+		for token in flatten(&tokens)
+		{
+			if let token::Kind::Identifier(name) = &token.kind
+			{
+				let symbol = self.resolve(name, token.span.start.offset);
+				self.references.push((token.span, name.clone(), symbol));
+			}
+		}
+	}
+
+	/// Finds the symbol a name refers to at an offset.
+	///
+	/// ### Parameters
+	/// * `name` - The name.
+	/// * `offset` - The byte offset of the name.
+	///
+	/// ### Returns
+	/// * The index of the symbol, or `None` if no symbol of that name is visible.
+	pub fn resolve(&self, name: &str, offset: usize) -> Option<usize>
+	{
+		let visible: Vec<_> = self
+			.symbols
+			.iter()
+			.enumerate()
+			.filter(|(_, symbol)| {
+				symbol.name == name && contains(symbol.scope, offset)
+			})
+			.collect();
+
+		let innermost =
+			visible.iter().map(|(_, symbol)| size(symbol.scope)).min()?;
+
+		let candidates: Vec<_> = visible
+			.into_iter()
+			.filter(|(_, symbol)| size(symbol.scope) == innermost)
+			.collect();
+
+		candidates
+			.iter()
+			.rev()
+			.find(|(_, symbol)| symbol.span.start.offset <= offset)
+			.or(candidates.first())
+			.map(|&(index, _)| index)
+	}
+
+	/// Finds the identifier at an offset.
+	///
+	/// ### Parameters
+	/// * `offset` - The byte offset.
+	///
+	/// ### Returns
+	/// * The span and name of the identifier, and the symbol it refers to.
+	pub fn reference(
+		&self,
+		offset: usize,
+	) -> Option<&(Span, String, Option<usize>)>
+	{
+		self.references.iter().find(|(span, name, _)| {
+			let start = span.start.offset;
+			(start..=start + name.len()).contains(&offset)
+		})
+	}
+
+	/// Gets the symbols visible at an offset.
+	///
+	/// ### Parameters
+	/// * `offset` - The byte offset.
+	///
+	/// ### Returns
+	/// * The visible symbols, with inner declarations shadowing outer ones.
+	pub fn visible(&self, offset: usize) -> Vec<&Symbol>
+	{
+		let mut names: Vec<_> = self
+			.symbols
+			.iter()
+			.filter(|symbol| {
+				let is_hoisted = matches!(
+					symbol.kind,
+					SymbolKind::Function
+						| SymbolKind::Struct
+						| SymbolKind::Enum
+						| SymbolKind::Module
+				);
+				contains(symbol.scope, offset)
+					&& (is_hoisted || symbol.span.start.offset < offset)
+			})
+			.map(|symbol| symbol.name.as_str())
+			.collect();
+
+		names.sort_unstable();
+		names.dedup();
+
+		names
+			.into_iter()
+			.filter_map(|name| self.resolve(name, offset))
+			.map(|index| &self.symbols[index])
+			.collect()
+	}
+}
+
+/// A walker that collects the symbols declared by a programme.
+struct Indexer<'a>
+{
+	/// The tokens of the document, used to find the spans of names.
+	tokens: &'a [Token],
+
+	/// The symbol table of the programme's top-level scope.
+	table: &'a Table,
+
+	/// The symbols found so far.
+	symbols: Vec<Symbol>,
+
+	/// The name of the function being walked, if any.
+	container: Option<String>,
+}
+
+/// A visitor that collects the lambdas of a statement, without descending into
+/// blocks, whose statements are indexed on their own.
+#[derive(Default)]
+struct Lambdas<'ast>
+{
+	/// The lambdas found so far.
+	found: Vec<&'ast Lambda>,
+}
+
+impl<'ast> Visit<'ast> for Lambdas<'ast>
+{
+	fn visit_block(&mut self, _node: &'ast Block) {}
+
+	fn visit_lambda(&mut self, node: &'ast Lambda)
+	{
+		self.found.push(node);
+		visit::walk_lambda(self, node);
+	}
+}
+
+impl Indexer<'_>
+{
+	/// Collects the symbols declared by some statements.
+	///
+	/// ### Parameters
+	/// * `statements` - The statements.
+	/// * `scope` - The span of the code the statements' symbols are visible in.
+	fn statements<'s>(
+		&mut self,
+		statements: impl Iterator<Item = &'s Statement>,
+		scope: Span,
+	)
+	{
+		for statement in statements
+		{
+			let mut lambdas = Lambdas::default();
+			lambdas.visit_statement(statement);
+
+			for lambda in lambdas.found
+			{
+				self.lambda(lambda);
+			}
+
+			match &statement.kind
+			{
+				Declaration(declaration) => self.declaration(declaration, scope),
+
+				If(statement) =>
+				{
+					self.block(&statement.body);
+
+					for else_if in &statement.else_ifs
+					{
+						self.block(&else_if.body);
+					}
+
+					if let Some(body) = &statement.else_body
+					{
+						self.block(body);
+					}
+				}
+
+				While(statement) => self.block(&statement.body),
+
+				Match(statement) =>
+				{
+					for arm in &statement.arms
+					{
+						self.arm(arm);
+					}
+				}
+
+				Import(import) => self.push(
+					&import.name,
+					SymbolKind::Module,
+					import.span,
+					scope,
+					format!("import \"{}\"", import.path),
+					Vec::new(),
+				),
+
+				Use(statement) =>
+				{
+					let span = self.name(&statement.name, statement.span, true);
+
+					let detail = match self.table.functions.get(&statement.name)
+					{
+						Some(Type::Function { returns, .. }) =>
+						{
+							format!("func {}(…) -> {returns}", statement.name)
+						}
+						_ => format!("use {}::{}", statement.module, statement.name),
+					};
+
+					self.push(
+						&statement.name,
+						SymbolKind::Use,
+						span,
+						scope,
+						detail,
+						Vec::new(),
+					);
+				}
+
+				_ => (),
+			}
+		}
+	}
+
+	/// Collects the symbols declared in a block.
+	///
+	/// ### Parameters
+	/// * `block` - The block.
+	fn block(&mut self, block: &Block)
+	{
+		self.statements(block.statements.iter().flatten(), block.span);
+	}
+
+	/// Collects the symbols of a declaration.
+	///
+	/// ### Parameters
+	/// * `declaration` - The declaration.
+	/// * `scope` - The span of the code the declaration is visible in.
+	fn declaration(&mut self, declaration: &Declaration, scope: Span)
+	{
+		let docs = declaration.docs.clone();
+
+		match &declaration.kind
+		{
+			declaration::Kind::Variable {
+				name,
+				value,
+				is_mutable,
+			} =>
+			{
+				let (kind, keyword) = match is_mutable
+				{
+					true => (SymbolKind::Variable, "var"),
+					false => (SymbolKind::Constant, "let"),
+				};
+
+				// A declaration in a function body is only typed by inference.
+				let r#type = match self.table.inferred.get(&declaration.span)
+				{
+					Some(r#type) => Ok(r#type.clone()),
+					None => value.r#type(self.table),
+				};
+
+				let detail = match r#type
+				{
+					Ok(r#type) => format!("{keyword} {name}: {type}"),
+					Err(_) => format!("{keyword} {name}"),
+				};
+
+				let span = self.name(name, declaration.span, false);
+				self.push(name, kind, span, scope, detail, docs);
+			}
+
+			declaration::Kind::Destructuring {
+				names,
+				value,
+				is_mutable,
+			} =>
+			{
+				let (kind, keyword) = match is_mutable
+				{
+					true => (SymbolKind::Variable, "var"),
+					false => (SymbolKind::Constant, "let"),
+				};
+
+				let r#type = match self.table.inferred.get(&declaration.span)
+				{
+					Some(r#type) => Ok(r#type.clone()),
+					None => value.r#type(self.table),
+				};
+
+				let types = match r#type
+				{
+					Ok(Type::Tuple(types)) => types,
+					_ => Vec::new(),
+				};
+
+				for (index, name) in names.iter().enumerate()
+				{
+					if name == "_"
+					{
+						continue;
+					}
+
+					let detail = match types.get(index)
+					{
+						Some(r#type) => format!("{keyword} {name}: {type}"),
+						None => format!("{keyword} {name}"),
+					};
+
+					let span = self.name(name, declaration.span, false);
+					let docs = docs.clone();
+					self.push(name, kind, span, scope, detail, docs);
+				}
+			}
+
+			declaration::Kind::Function(function) =>
+			{
+				let span = self.name(&function.name, declaration.span, false);
+				let detail = match self.table.inferred.get(&function.span)
+				{
+					Some(r#type) => function.signature_of(r#type),
+					None => function.signature(),
+				};
+
+				self.push(
+					&function.name,
+					SymbolKind::Function,
+					span,
+					scope,
+					detail,
+					docs,
+				);
+
+				let outer = self.container.replace(function.name.clone());
+
+				for parameter in function.parameters.iter().flat_map(|p| &p.parameters)
+				{
+					self.push(
+						&parameter.name,
+						SymbolKind::Parameter,
+						parameter.span,
+						function.span,
+						match self.table.inferred.get(&parameter.span)
+						{
+							Some(r#type) => parameter.signature_of(r#type),
+							None => parameter.signature(),
+						},
+						Vec::new(),
+					);
+				}
+
+				self.block(&function.body);
+				self.container = outer;
+			}
+
+			declaration::Kind::Struct(r#struct) =>
+			{
+				let span = self.name(&r#struct.name, declaration.span, false);
+				let detail = r#struct.signature();
+				let kind = SymbolKind::Struct;
+				self.push(&r#struct.name, kind, span, scope, detail, docs);
+
+				let outer = self.container.replace(r#struct.name.clone());
+
+				for field in &r#struct.fields
+				{
+					let span = self.name(&field.name, field.span, false);
+					let detail = format!("{}: {}", field.name, field.r#type);
+					let kind = SymbolKind::Field;
+					let scope = r#struct.span;
+					self.push(&field.name, kind, span, scope, detail, Vec::new());
+				}
+
+				self.container = outer;
+			}
+
+			declaration::Kind::Enum(r#enum) =>
+			{
+				let span = self.name(&r#enum.name, declaration.span, false);
+				let detail = r#enum.signature();
+				let kind = SymbolKind::Enum;
+				self.push(&r#enum.name, kind, span, scope, detail, docs);
+
+				let outer = self.container.replace(r#enum.name.clone());
+
+				for variant in &r#enum.variants
+				{
+					let span = self.name(&variant.name, variant.span, false);
+					let detail = variant.signature();
+					let kind = SymbolKind::Variant;
+					let scope = r#enum.span;
+					self.push(&variant.name, kind, span, scope, detail, Vec::new());
+				}
+
+				self.container = outer;
+			}
+		}
+	}
+
+	/// Collects the names the patterns of a match arm bind and the symbols
+	/// declared in its body.
+	///
+	/// ### Parameters
+	/// * `arm` - The arm.
+	fn arm(&mut self, arm: &Arm)
+	{
+		for pattern in &arm.patterns
+		{
+			let pattern::Kind::Variant {
+				r#enum,
+				name,
+				bindings,
+			} = &pattern.kind
+			else
+			{
+				continue;
+			};
+
+			let payload = self
+				.table
+				.variant(
+					r#enum.identifier().unwrap_or_default(),
+					name.identifier().unwrap_or_default(),
+				)
+				.map(|variant| variant.payload.clone())
+				.unwrap_or_default();
+
+			for (index, binding) in bindings.iter().enumerate()
+			{
+				let name = match binding.identifier()
+				{
+					Some("_") | None => continue,
+					Some(name) => name,
+				};
+
+				let detail = match payload.get(index)
+				{
+					Some(r#type) => format!("{name}: {type}"),
+					None => name.to_string(),
+				};
+
+				self.push(
+					name,
+					SymbolKind::Parameter,
+					binding.span,
+					arm.span,
+					detail,
+					Vec::new(),
+				);
+			}
+		}
+
+		self.block(&arm.body);
+	}
+
+	/// Collects the parameters of a lambda and the symbols declared in its
+	/// body.
+	///
+	/// ### Parameters
+	/// * `lambda` - The lambda.
+	fn lambda(&mut self, lambda: &Lambda)
+	{
+		for parameter in lambda.parameters.iter().flat_map(|p| &p.parameters)
+		{
+			self.push(
+				&parameter.name,
+				SymbolKind::Parameter,
+				parameter.span,
+				lambda.span,
+				parameter.signature(),
+				Vec::new(),
+			);
+		}
+
+		if let Body::Block(body) = &lambda.body
+		{
+			self.block(body);
+		}
+	}
+
+	/// Adds a symbol.
+	///
+	/// ### Parameters
+	/// * `name` - The name of the symbol.
+	/// * `kind` - The kind of the symbol.
+	/// * `span` - The span of its name.
+	/// * `scope` - The span of the code it is visible in.
+	/// * `detail` - Its declaration.
+	/// * `docs` - Its documentation comments.
+	fn push(
+		&mut self,
+		name: &str,
+		kind: SymbolKind,
+		span: Span,
+		scope: Span,
+		detail: String,
+		docs: Vec<String>,
+	)
+	{
+		self.symbols.push(Symbol {
+			name: name.to_string(),
+			kind,
+			span,
+			scope,
+			detail,
+			docs,
+			container: self.container.clone(),
+		});
+	}
+
+	/// Finds the span of a name inside a statement.
+	///
+	/// ### Parameters
+	/// * `name` - The name.
+	/// * `within` - The span of the statement.
+	/// * `last` - Whether to take the last occurrence rather than the first.
+	///
+	/// ### Returns
+	/// * The span of the name, or of the statement if the name is not found.
+	fn name(&self, name: &str, within: Span, last: bool) -> Span
+	{
+		let mut matches = self.tokens.iter().filter(|token| {
+			contains(within, token.span.start.offset)
+				&& matches!(&token.kind, token::Kind::Identifier(text) if text == name)
+		});
+
+		let token = match last
+		{
+			true => matches.next_back(),
+			false => matches.next(),
+		};
+
+		token.map_or(within, |token| token.span)
+	}
+}
+
+/// Flattens tokens along with the tokens embedded in their interpolations.
+///
+/// ### Parameters
+/// * `tokens` - The tokens.
+///
+/// ### Returns
+/// * Every token, in source order.
+fn flatten(tokens: &[Token]) -> Vec<&Token>
+{
+	tokens
+		.iter()
+		.flat_map(|token| match &token.kind
+		{
+			token::Kind::Interpolation(segments) => segments
+				.iter()
+				.flat_map(|segment| match segment
+				{
+					Segment::Code(tokens) => flatten(tokens),
+					Segment::Text(_) => Vec::new(),
+				})
+				.collect(),
+			_ => vec![token],
+		})
+		.collect()
+}
+
+/// Determines if a span contains an offset.
+///
+/// ### Parameters
+/// * `span` - The span.
+/// * `offset` - The byte offset.
+///
+/// ### Returns
+/// * `true` if the offset lies within the span.
+/// * `false` otherwise.
+fn contains(span: Span, offset: usize) -> bool
+{
+	(span.start.offset..=span.end.offset).contains(&offset)
+}
+
+/// Measures a span.
+///
+/// ### Parameters
+/// * `span` - The span.
+///
+/// ### Returns
+/// * The number of bytes between the start and end of the span.
+fn size(span: Span) -> usize
+{
+	span.end.offset - span.start.offset
+}
@@ -0,0 +1,14 @@
+/// An error message indicating a message without a `Content-Length` header.
+pub const CONTENT_LENGTH: &str = "Expected a `Content-Length` header";
+
+/// An error message indicating a message whose body is not valid JSON.
+pub const JSON: &str = "Expected the message body to be valid JSON";
+
+/// An error message indicating a request for an unknown method.
+pub const METHOD: &str = "This method is not supported";
+
+/// An error message indicating a request about a document that is not open.
+pub const DOCUMENT: &str = "This document is not open";
+
+/// An error message indicating a request sent after the server shut down.
+pub const SHUT_DOWN: &str = "The server has shut down";
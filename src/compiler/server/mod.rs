@@ -0,0 +1,6 @@
+mod analysis;
+mod error;
+mod protocol;
+pub mod serve;
+
+pub use serve::*;
@@ -0,0 +1,183 @@
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+
+use super::*;
+use crate::language::utils::{SourceFile, Span};
+
+/// Reads the next message sent by the client.
+///
+/// ### Parameters
+/// * `input` - The stream the client writes to.
+///
+/// ### Returns
+/// * The message, or `None` if the stream has ended.
+///
+/// ### Errors
+/// * If the message is not framed by a `Content-Length` header.
+/// * If the body of the message is not valid JSON.
+pub fn read(input: &mut impl BufRead) -> Result<Option<Value>>
+{
+	let mut length = None;
+
+	loop
+	{
+		let mut header = String::new();
+
+		if input.read_line(&mut header)? == 0
+		{
+			return Ok(None);
+		}
+
+		let header = header.trim_end();
+
+		if header.is_empty()
+		{
+			break;
+		}
+
+		if let Some(value) = header.strip_prefix("Content-Length:")
+		{
+			length = Some(value.trim().parse::<usize>()?);
+		}
+	}
+
+	let mut body = vec![0; length.context(error::CONTENT_LENGTH)?];
+	input.read_exact(&mut body)?;
+
+	serde_json::from_slice(&body).context(error::JSON).map(Some)
+}
+
+/// Writes a message to the client.
+///
+/// ### Parameters
+/// * `output` - The stream the client reads from.
+/// * `message` - The message.
+///
+/// ### Errors
+/// * If the message cannot be written.
+pub fn write(output: &mut impl Write, message: &Value) -> Result<()>
+{
+	let body = message.to_string();
+	write!(output, "Content-Length: {}\r\n\r\n{body}", body.len())?;
+	output.flush()?;
+	Ok(())
+}
+
+/// Converts a `file://` URI into a path.
+///
+/// ### Parameters
+/// * `uri` - The URI of a document.
+///
+/// ### Returns
+/// * The path of the document.
+pub fn path(uri: &str) -> PathBuf
+{
+	let path = uri.strip_prefix("file://").unwrap_or(uri);
+	let mut bytes = Vec::with_capacity(path.len());
+	let mut rest = path.as_bytes();
+
+	while let Some((&byte, tail)) = rest.split_first()
+	{
+		let escaped = tail
+			.get(..2)
+			.filter(|_| byte == b'%')
+			.and_then(|hex| std::str::from_utf8(hex).ok())
+			.and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+		match escaped
+		{
+			Some(byte) =>
+			{
+				bytes.push(byte);
+				rest = &tail[2..];
+			}
+			None =>
+			{
+				bytes.push(byte);
+				rest = tail;
+			}
+		}
+	}
+
+	PathBuf::from(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Converts a byte offset into a protocol position, which counts UTF-16 code
+/// units from the start of the line.
+///
+/// ### Parameters
+/// * `file` - The source file.
+/// * `offset` - The byte offset.
+///
+/// ### Returns
+/// * The position.
+pub fn position(file: &SourceFile, offset: usize) -> Value
+{
+	let line = file.location(offset).line;
+	let start = file.line_start(line).min(offset);
+
+	let character = file
+		.text
+		.get(start..offset)
+		.map_or(0, |text| text.encode_utf16().count());
+
+	json!({ "line": line, "character": character })
+}
+
+/// Converts a span into a protocol range, whose end is exclusive.
+///
+/// ### Parameters
+/// * `file` - The source file.
+/// * `span` - The span.
+///
+/// ### Returns
+/// * The range.
+pub fn range(file: &SourceFile, span: Span) -> Value
+{
+	let last = file.text[span.end.offset..]
+		.chars()
+		.next()
+		.map_or(0, char::len_utf8);
+
+	json!({
+		"start": position(file, span.start.offset),
+		"end": position(file, span.end.offset + last),
+	})
+}
+
+/// Converts a protocol position into a byte offset.
+///
+/// ### Parameters
+/// * `file` - The source file.
+/// * `position` - The position.
+///
+/// ### Returns
+/// * The byte offset, clamped to the end of its line.
+pub fn offset(file: &SourceFile, position: &Value) -> usize
+{
+	let line = position["line"].as_u64().unwrap_or_default() as usize;
+	let character = position["character"].as_u64().unwrap_or_default() as usize;
+
+	if line >= file.line_count()
+	{
+		return file.text.len();
+	}
+
+	let start = file.line_start(line);
+	let mut units = 0;
+
+	for (index, symbol) in file.line(line).char_indices()
+	{
+		if units >= character
+		{
+			return start + index;
+		}
+
+		units += symbol.len_utf16();
+	}
+
+	start + file.line(line).len()
+}
@@ -0,0 +1,523 @@
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+
+use super::analysis::{Analysis, Symbol, SymbolKind};
+use super::*;
+use crate::language::lexicon::token;
+use crate::language::semantics::member::{AGGREGATE, NUMBER, STRING};
+use crate::language::semantics::prelude::{self, PRELUDE};
+
+/// The protocol error code for a request of an unknown method.
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// The protocol error code for a request the server cannot accept.
+const INVALID_REQUEST: i64 = -32600;
+
+/// The protocol error code for a request with invalid parameters.
+const INVALID_PARAMS: i64 = -32602;
+
+/// A language server for Quark.
+///
+/// The server keeps the text of every open document and re-analyses a document
+/// whenever it changes, publishing its errors as diagnostics.
+struct Server
+{
+	/// The analysis of each open document, keyed by its URI.
+	documents: HashMap<String, Analysis>,
+
+	/// Whether the client has asked the server to shut down.
+	is_shut_down: bool,
+}
+
+/// Serves the Language Server Protocol until the client exits.
+///
+/// ### Parameters
+/// * `input` - The stream the client writes to.
+/// * `output` - The stream the client reads from.
+///
+/// ### Errors
+/// * If a message cannot be read or written.
+pub fn serve(mut input: impl BufRead, mut output: impl Write) -> Result<()>
+{
+	let mut server = Server {
+		documents: HashMap::new(),
+		is_shut_down: false,
+	};
+
+	while let Some(message) = protocol::read(&mut input)?
+	{
+		let method = message["method"].as_str().unwrap_or_default();
+
+		if method == "exit"
+		{
+			break;
+		}
+
+		let params = &message["params"];
+
+		match message.get("id")
+		{
+			Some(id) =>
+			{
+				let response = match server.request(method, params)
+				{
+					Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+					Err((code, message)) => json!({
+						"jsonrpc": "2.0",
+						"id": id,
+						"error": { "code": code, "message": message },
+					}),
+				};
+
+				protocol::write(&mut output, &response)?;
+			}
+
+			None =>
+			{
+				for notification in server.notify(method, params)
+				{
+					protocol::write(&mut output, &notification)?;
+				}
+			}
+		}
+	}
+
+	Ok(())
+}
+
+impl Server
+{
+	/// Answers a request from the client.
+	///
+	/// ### Parameters
+	/// * `method` - The method of the request.
+	/// * `params` - The parameters of the request.
+	///
+	/// ### Returns
+	/// * The result of the request.
+	///
+	/// ### Errors
+	/// * The error code and message if the request cannot be answered.
+	fn request(
+		&mut self,
+		method: &str,
+		params: &Value,
+	) -> Result<Value, (i64, String)>
+	{
+		if self.is_shut_down
+		{
+			return Err((INVALID_REQUEST, error::SHUT_DOWN.to_string()));
+		}
+
+		match method
+		{
+			"initialize" => Ok(json!({
+				"capabilities": {
+					"textDocumentSync": 1,
+					"hoverProvider": true,
+					"definitionProvider": true,
+					"referencesProvider": true,
+					"documentSymbolProvider": true,
+					"completionProvider": {},
+				},
+				"serverInfo": {
+					"name": "quark",
+					"version": env!("CARGO_PKG_VERSION"),
+				},
+			})),
+
+			"shutdown" =>
+			{
+				self.is_shut_down = true;
+				Ok(Value::Null)
+			}
+
+			"textDocument/hover" => self.hover(params),
+			"textDocument/definition" => self.definition(params),
+			"textDocument/references" => self.references(params),
+			"textDocument/documentSymbol" => self.symbols(params),
+			"textDocument/completion" => self.completion(params),
+
+			_ => Err((METHOD_NOT_FOUND, format!("{}: {method}", error::METHOD))),
+		}
+	}
+
+	/// Handles a notification from the client.
+	///
+	/// ### Parameters
+	/// * `method` - The method of the notification.
+	/// * `params` - The parameters of the notification.
+	///
+	/// ### Returns
+	/// * The notifications to send back to the client.
+	fn notify(&mut self, method: &str, params: &Value) -> Vec<Value>
+	{
+		let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+
+		let text = match method
+		{
+			"textDocument/didOpen" => params["textDocument"]["text"].as_str(),
+
+			// Only full synchronisation is offered, so the last change holds the
+			// whole text of the document.
+			"textDocument/didChange" => params["contentChanges"]
+				.as_array()
+				.and_then(|changes| changes.last())
+				.and_then(|change| change["text"].as_str()),
+
+			"textDocument/didClose" =>
+			{
+				self.documents.remove(uri);
+				return vec![diagnostics(uri, Vec::new())];
+			}
+
+			_ => None,
+		};
+
+		let Some(text) = text
+		else
+		{
+			return Vec::new();
+		};
+
+		let analysis = Analysis::new(&protocol::path(uri), text.to_string());
+		let file = analysis.source.file(analysis.file);
+
+		let problems = analysis
+			.problems
+			.iter()
+			.map(|problem| {
+				let range = match problem.span
+				{
+					Some(span) => protocol::range(file, span),
+					None => json!({
+						"start": { "line": 0, "character": 0 },
+						"end": { "line": 0, "character": 0 },
+					}),
+				};
+
+				let related: Vec<_> = problem
+					.related
+					.iter()
+					.map(|(span, message)| {
+						json!({
+							"location": {
+								"uri": uri,
+								"range": protocol::range(file, *span),
+							},
+							"message": message,
+						})
+					})
+					.collect();
+
+				let mut diagnostic = json!({
+					"range": range,
+					"severity": 1,
+					"source": "quark",
+					"message": problem.message,
+					"relatedInformation": related,
+				});
+
+				// Warnings are published with the code of the lint that found
+				// them.
+				if let Some(lint) = problem.lint
+				{
+					diagnostic["severity"] = 2.into();
+					diagnostic["code"] = lint.code().into();
+				}
+
+				diagnostic
+			})
+			.collect();
+
+		self.documents.insert(uri.to_string(), analysis);
+		vec![diagnostics(uri, problems)]
+	}
+
+	/// Gets the analysis of the document a request is about, and the offset of
+	/// the position the request is at.
+	///
+	/// ### Parameters
+	/// * `params` - The parameters of the request.
+	///
+	/// ### Returns
+	/// * The analysis and the offset.
+	///
+	/// ### Errors
+	/// * If the document is not open.
+	fn document(
+		&self,
+		params: &Value,
+	) -> Result<(&Analysis, usize), (i64, String)>
+	{
+		let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+
+		let analysis = self
+			.documents
+			.get(uri)
+			.context(error::DOCUMENT)
+			.map_err(|error| (INVALID_PARAMS, error.to_string()))?;
+
+		let file = analysis.source.file(analysis.file);
+		let offset = protocol::offset(file, &params["position"]);
+
+		Ok((analysis, offset))
+	}
+
+	/// Describes the symbol under the cursor.
+	fn hover(&self, params: &Value) -> Result<Value, (i64, String)>
+	{
+		let (analysis, offset) = self.document(params)?;
+
+		let Some((span, name, symbol)) = analysis.reference(offset)
+		else
+		{
+			return Ok(Value::Null);
+		};
+
+		let (detail, docs) = match symbol
+		{
+			Some(index) =>
+			{
+				let symbol = &analysis.symbols[*index];
+				(symbol.detail.clone(), symbol.docs.join("\n"))
+			}
+
+			None => match prelude::lookup(name)
+			{
+				Some(constant) =>
+				{
+					(format!("let {name}: {}", constant.r#type), String::new())
+				}
+				None => return Ok(Value::Null),
+			},
+		};
+
+		let mut value = format!("```quark\n{detail}\n```");
+
+		if !docs.is_empty()
+		{
+			value = format!("{value}\n\n{docs}");
+		}
+
+		let file = analysis.source.file(analysis.file);
+
+		Ok(json!({
+			"contents": { "kind": "markdown", "value": value },
+			"range": protocol::range(file, *span),
+		}))
+	}
+
+	/// Finds the declaration of the symbol under the cursor.
+	fn definition(&self, params: &Value) -> Result<Value, (i64, String)>
+	{
+		let (analysis, offset) = self.document(params)?;
+
+		let Some(&(_, _, Some(index))) = analysis.reference(offset)
+		else
+		{
+			return Ok(Value::Null);
+		};
+
+		let uri = &params["textDocument"]["uri"];
+		let file = analysis.source.file(analysis.file);
+		let span = analysis.symbols[index].span;
+
+		Ok(json!({ "uri": uri, "range": protocol::range(file, span) }))
+	}
+
+	/// Finds every use of the symbol under the cursor.
+	fn references(&self, params: &Value) -> Result<Value, (i64, String)>
+	{
+		let (analysis, offset) = self.document(params)?;
+
+		let Some(&(_, _, Some(index))) = analysis.reference(offset)
+		else
+		{
+			return Ok(json!([]));
+		};
+
+		let uri = &params["textDocument"]["uri"];
+		let file = analysis.source.file(analysis.file);
+		let declaration = analysis.symbols[index].span;
+		let include = params["context"]["includeDeclaration"].as_bool();
+
+		let locations: Vec<_> = analysis
+			.references
+			.iter()
+			.filter(|(_, _, symbol)| *symbol == Some(index))
+			.map(|(span, ..)| *span)
+			.filter(|span| include != Some(false) || *span != declaration)
+			.map(|span| json!({ "uri": uri, "range": protocol::range(file, span) }))
+			.collect();
+
+		Ok(json!(locations))
+	}
+
+	/// Lists the symbols declared in a document.
+	fn symbols(&self, params: &Value) -> Result<Value, (i64, String)>
+	{
+		let (analysis, _) = self.document(params)?;
+
+		let uri = &params["textDocument"]["uri"];
+		let file = analysis.source.file(analysis.file);
+
+		let symbols: Vec<_> = analysis
+			.symbols
+			.iter()
+			.map(|symbol| {
+				json!({
+					"name": symbol.name,
+					"kind": kind(symbol),
+					"location": {
+						"uri": uri,
+						"range": protocol::range(file, symbol.span),
+					},
+					"containerName": symbol.container,
+				})
+			})
+			.collect();
+
+		Ok(json!(symbols))
+	}
+
+	/// Suggests the keywords and names that may be written at the cursor.
+	fn completion(&self, params: &Value) -> Result<Value, (i64, String)>
+	{
+		let (analysis, offset) = self.document(params)?;
+
+		let file = analysis.source.file(analysis.file);
+		if file.text[..offset].ends_with('.')
+		{
+			return Ok(members(&analysis.symbols));
+		}
+
+		let keywords = token::Kind::KEYWORDS
+			.into_iter()
+			.map(|keyword| json!({ "label": keyword, "kind": 14 }));
+
+		let constants = PRELUDE.iter().map(|constant| {
+			json!({
+				"label": constant.name,
+				"kind": 21,
+				"detail": format!("let {}: {}", constant.name, constant.r#type),
+			})
+		});
+
+		let names = analysis.visible(offset).into_iter().map(|symbol| {
+			json!({
+				"label": symbol.name,
+				"kind": completion(symbol),
+				"detail": symbol.detail,
+			})
+		});
+
+		Ok(json!(keywords
+			.chain(constants)
+			.chain(names)
+			.collect::<Vec<_>>()))
+	}
+}
+
+/// Suggests the members of the built-in types and the fields of the structs
+/// of a document, once for each name.
+///
+/// ### Parameters
+/// * `symbols` - The symbols of the document.
+///
+/// ### Returns
+/// * The completion items.
+fn members(symbols: &[Symbol]) -> Value
+{
+	let mut items: Vec<Value> = Vec::new();
+
+	for symbol in symbols.iter().filter(|s| s.kind == SymbolKind::Field)
+	{
+		if items.iter().all(|item| item["label"] != symbol.name)
+		{
+			items.push(json!({
+				"label": symbol.name,
+				"kind": completion(symbol),
+				"detail": symbol.detail,
+			}));
+		}
+	}
+
+	for member in STRING.iter().chain(&NUMBER).chain(&AGGREGATE)
+	{
+		if items.iter().all(|item| item["label"] != member.name)
+		{
+			items.push(json!({
+				"label": member.name,
+				"kind": if member.parameters.is_some() { 2 } else { 10 },
+				"detail": member.signature(),
+			}));
+		}
+	}
+
+	json!(items)
+}
+
+/// Creates a notification publishing the diagnostics of a document.
+///
+/// ### Parameters
+/// * `uri` - The URI of the document.
+/// * `diagnostics` - The diagnostics.
+///
+/// ### Returns
+/// * The notification.
+fn diagnostics(uri: &str, diagnostics: Vec<Value>) -> Value
+{
+	json!({
+		"jsonrpc": "2.0",
+		"method": "textDocument/publishDiagnostics",
+		"params": { "uri": uri, "diagnostics": diagnostics },
+	})
+}
+
+/// Gets the protocol kind of a symbol.
+///
+/// ### Parameters
+/// * `symbol` - The symbol.
+///
+/// ### Returns
+/// * The symbol kind as defined by the protocol.
+fn kind(symbol: &Symbol) -> u8
+{
+	match symbol.kind
+	{
+		SymbolKind::Module => 2,
+		SymbolKind::Function => 12,
+		SymbolKind::Struct => 23,
+		SymbolKind::Enum => 10,
+		SymbolKind::Field => 8,
+		SymbolKind::Variant => 22,
+		SymbolKind::Variable | SymbolKind::Parameter | SymbolKind::Use => 13,
+		SymbolKind::Constant => 14,
+	}
+}
+
+/// Gets the protocol completion kind of a symbol.
+///
+/// ### Parameters
+/// * `symbol` - The symbol.
+///
+/// ### Returns
+/// * The completion item kind as defined by the protocol.
+fn completion(symbol: &Symbol) -> u8
+{
+	match symbol.kind
+	{
+		SymbolKind::Module => 9,
+		SymbolKind::Function => 3,
+		SymbolKind::Struct => 22,
+		SymbolKind::Enum => 13,
+		SymbolKind::Field => 5,
+		SymbolKind::Variant => 20,
+		SymbolKind::Variable | SymbolKind::Parameter | SymbolKind::Use => 6,
+		SymbolKind::Constant => 21,
+	}
+}
@@ -0,0 +1,178 @@
+//! Hoisting of lambdas with block bodies into nested functions, since a
+//! Python `lambda` can only hold an expression.
+
+use super::lower::identifier;
+use crate::language::grammar::declaration::{self, Declaration};
+use crate::language::grammar::expression::{self, Expression};
+use crate::language::grammar::fold::{self, Fold};
+use crate::language::grammar::functions::{Body, Lambda};
+use crate::language::grammar::statement::{self, Block, Statement};
+use crate::language::grammar::{FunctionDclr, Programme, ReturnStmt};
+use crate::language::semantics::r#type::Type;
+
+/// The prefix of the names of the functions that lambdas are hoisted into.
+pub const PREFIX: &str = "__quark_lambda";
+
+/// A fold that replaces every lambda with a block body by the name of a
+/// function declared just before the statement that holds it.
+///
+/// A lambda with an expression body stays a `lambda`, unless it holds a hoisted
+/// lambda itself, in which case it is hoisted too so that the inner function
+/// can still see its parameters.
+#[derive(Default)]
+pub struct Hoist
+{
+	/// The number of functions hoisted so far, used to name the next one.
+	count: usize,
+
+	/// The functions hoisted out of the statement being folded.
+	hoisted: Vec<Statement>,
+}
+
+impl Fold for Hoist
+{
+	fn fold_programme(&mut self, node: Programme) -> Programme
+	{
+		Programme {
+			span: node.span,
+			statements: self.statements(node.statements),
+		}
+	}
+
+	fn fold_block(&mut self, node: Block) -> Block
+	{
+		let statements = node
+			.statements
+			.into_iter()
+			.flat_map(|statement| match statement
+			{
+				Some(statement) => self
+					.statements(vec![statement])
+					.into_iter()
+					.map(Some)
+					.collect(),
+				None => vec![None],
+			})
+			.collect();
+
+		Block {
+			span: node.span,
+			statements,
+		}
+	}
+
+	fn fold_expression(&mut self, node: Expression) -> Expression
+	{
+		let span = node.span;
+
+		let lambda = match node.kind
+		{
+			expression::Kind::Lambda(lambda) => *lambda,
+			kind => return fold::walk_expression(self, Expression { span, kind }),
+		};
+
+		let body = match lambda.body
+		{
+			Body::Block(body) => self.fold_block(body),
+
+			Body::Expression(body) =>
+			{
+				let outer = std::mem::take(&mut self.hoisted);
+				let body = self.fold_expression(*body);
+				let inner = std::mem::replace(&mut self.hoisted, outer);
+
+				if inner.is_empty()
+				{
+					let kind = expression::Kind::Lambda(Box::new(Lambda {
+						body: Body::Expression(Box::new(body)),
+						..lambda
+					}));
+
+					return Expression { span, kind };
+				}
+
+				let kind = statement::Kind::Return(ReturnStmt {
+					span: body.span,
+					expression: body,
+				});
+
+				let statements = inner
+					.into_iter()
+					.chain([Statement { span, kind }])
+					.map(Some)
+					.collect();
+
+				Block { span, statements }
+			}
+		};
+
+		let name = format!("{PREFIX}_{}", self.count);
+		self.count += 1;
+
+		let function = FunctionDclr {
+			span,
+			name: name.clone(),
+			generics: Vec::new(),
+			body,
+			return_type: Type::Unit,
+			parameters: lambda.parameters,
+		};
+
+		let declaration = Declaration {
+			span,
+			kind: declaration::Kind::Function(function),
+			docs: Vec::new(),
+		};
+
+		let kind = statement::Kind::Declaration(declaration);
+		self.hoisted.push(Statement { span, kind });
+
+		identifier(&name, span)
+	}
+}
+
+impl Hoist
+{
+	/// Folds a list of statements, declaring the functions hoisted out of each
+	/// statement just before it.
+	///
+	/// ### Parameters
+	/// * `statements` - The statements.
+	///
+	/// ### Returns
+	/// * The folded statements.
+	pub fn statements(&mut self, statements: Vec<Statement>) -> Vec<Statement>
+	{
+		statements
+			.into_iter()
+			.flat_map(|statement| {
+				let outer = std::mem::take(&mut self.hoisted);
+				let statement = self.fold_statement(statement);
+				let mut statements = std::mem::replace(&mut self.hoisted, outer);
+
+				statements.push(statement);
+				statements
+			})
+			.collect()
+	}
+
+	/// Folds an expression on its own, such as one typed into the REPL.
+	///
+	/// ### Parameters
+	/// * `expression` - The expression.
+	///
+	/// ### Returns
+	/// * The declarations of the functions hoisted out of the expression, and the
+	///   folded expression.
+	pub fn expression(
+		&mut self,
+		expression: Expression,
+	) -> (Vec<Statement>, Expression)
+	{
+		let outer = std::mem::take(&mut self.hoisted);
+		let expression = self.fold_expression(expression);
+		let statements = std::mem::replace(&mut self.hoisted, outer);
+
+		(statements, expression)
+	}
+}
@@ -0,0 +1,472 @@
+//! Lowering of `match` statements into `if` chains, for versions of Python
+//! that have no `match` statement.
+
+use super::rules::types::field;
+use crate::language::grammar::declaration::{self, Declaration};
+use crate::language::grammar::expression::{self, Expression, Items};
+use crate::language::grammar::fold::{self, Fold};
+use crate::language::grammar::pattern::{self, Pattern};
+use crate::language::grammar::statement::{self, Block, Statement};
+use crate::language::grammar::{
+	Arm, ElseIf, FunctionCall, IfStmt, MatchStmt, Programme,
+};
+use crate::language::lexicon::{token, Token};
+use crate::language::utils::Span;
+
+/// The name of the variable that holds a matched value while the arms test it.
+pub const SUBJECT: &str = "__quark_match";
+
+/// A fold that replaces every `match` statement with an `if` chain.
+pub struct Lower;
+
+impl Fold for Lower
+{
+	fn fold_programme(&mut self, node: Programme) -> Programme
+	{
+		Programme {
+			span: node.span,
+			statements: self.statements(node.statements),
+		}
+	}
+
+	fn fold_block(&mut self, node: Block) -> Block
+	{
+		let statements = node
+			.statements
+			.into_iter()
+			.flat_map(|statement| match statement
+			{
+				Some(statement) => self
+					.statements(vec![statement])
+					.into_iter()
+					.map(Some)
+					.collect(),
+				None => vec![None],
+			})
+			.collect();
+
+		Block {
+			span: node.span,
+			statements,
+		}
+	}
+}
+
+impl Lower
+{
+	/// Folds a list of statements, expanding each `match` statement into the
+	/// statements that replace it.
+	///
+	/// ### Parameters
+	/// * `statements` - The statements.
+	///
+	/// ### Returns
+	/// * The folded statements.
+	fn statements(&mut self, statements: Vec<Statement>) -> Vec<Statement>
+	{
+		statements
+			.into_iter()
+			.flat_map(|statement| match statement.kind
+			{
+				statement::Kind::Match(match_) => self.lower(statement.span, match_),
+				_ => vec![self.fold_statement(statement)],
+			})
+			.collect()
+	}
+
+	/// Lowers a `match` statement into an `if` chain.
+	///
+	/// The matched value is first stored in a variable, unless it is a name or
+	/// literal already.  An arm without patterns to test or a guard becomes the
+	/// `else` branch, and the arms after it are dropped since they never run.
+	/// The names a variant pattern binds are declared at the start of the body
+	/// of its arm.
+	///
+	/// ### Parameters
+	/// * `span` - The span of the statement.
+	/// * `match_` - The `match` statement.
+	///
+	/// ### Returns
+	/// * The statements that replace the `match` statement.
+	fn lower(&mut self, span: Span, match_: MatchStmt) -> Vec<Statement>
+	{
+		let mut statements = Vec::new();
+
+		let subject = match match_.scrutinee.kind
+		{
+			expression::Kind::Identifier(_) | expression::Kind::Literal(_) =>
+			{
+				match_.scrutinee
+			}
+			_ =>
+			{
+				let kind = declaration::Kind::Variable {
+					name: SUBJECT.to_string(),
+					value: match_.scrutinee,
+					is_mutable: true,
+				};
+
+				let declaration = Declaration {
+					span,
+					kind,
+					docs: Vec::new(),
+				};
+
+				let kind = statement::Kind::Declaration(declaration);
+				statements.push(Statement { span, kind });
+				identifier(SUBJECT, span)
+			}
+		};
+
+		let mut if_: Option<IfStmt> = None;
+
+		for arm in match_.arms
+		{
+			let condition = condition(&arm, &subject);
+
+			let bindings =
+				bindings(&arm, &subject).into_iter().map(|(name, value)| {
+					let span = value.span;
+					let kind = declaration::Kind::Variable {
+						name,
+						value,
+						is_mutable: false,
+					};
+
+					let declaration = Declaration {
+						span,
+						kind,
+						docs: Vec::new(),
+					};
+
+					let kind = statement::Kind::Declaration(declaration);
+					Some(Statement { span, kind })
+				});
+
+			let mut body = self.fold_block(arm.body);
+			body.statements.splice(0..0, bindings);
+
+			match (&mut if_, condition)
+			{
+				(None, Some(condition)) =>
+				{
+					if_ = Some(IfStmt {
+						span,
+						condition,
+						body,
+						else_ifs: Vec::new(),
+						else_body: None,
+					});
+				}
+
+				(Some(if_), Some(condition)) => if_.else_ifs.push(ElseIf {
+					span: arm.span,
+					condition,
+					body,
+				}),
+
+				(None, None) =>
+				{
+					statements.extend(body.statements.into_iter().flatten());
+					return statements;
+				}
+
+				(Some(if_), None) =>
+				{
+					if_.else_body = Some(body);
+					break;
+				}
+			}
+		}
+
+		let kind = statement::Kind::If(if_.expect("Match arm"));
+		statements.push(Statement { span, kind });
+		statements
+	}
+}
+
+/// Creates the condition under which an arm of a `match` statement runs.
+///
+/// ### Parameters
+/// * `arm` - The arm.
+/// * `subject` - The expression holding the matched value.
+///
+/// ### Returns
+/// * The condition, as a Quark expression.
+/// * `None` if the arm always runs.
+pub fn condition(arm: &Arm, subject: &Expression) -> Option<Expression>
+{
+	let is_wildcard = arm
+		.patterns
+		.iter()
+		.any(|pattern| pattern.kind == pattern::Kind::Wildcard);
+
+	let test = match is_wildcard
+	{
+		true => None,
+		false => arm
+			.patterns
+			.iter()
+			.map(|pattern| {
+				let test = test(pattern, subject);
+				match arm.patterns.len()
+				{
+					1 => test,
+					_ => parenthesise(test),
+				}
+			})
+			.reduce(|left, right| infix(left, token::Kind::Or, right)),
+	};
+
+	// The guard runs before the names the pattern binds are declared, so it
+	// reads their values from the matched value instead.
+	let guard = arm.guard.clone().map(|guard| {
+		let bindings = bindings(arm, subject);
+		Bind(&bindings).fold_expression(guard)
+	});
+
+	match (test, guard)
+	{
+		(None, guard) => guard,
+		(test, None) => test,
+		(Some(test), Some(guard)) => Some(infix(
+			parenthesise(test),
+			token::Kind::And,
+			parenthesise(guard),
+		)),
+	}
+}
+
+/// Gets the names the patterns of an arm bind.
+///
+/// ### Parameters
+/// * `arm` - The arm.
+/// * `subject` - The expression holding the matched value.
+///
+/// ### Returns
+/// * Each name, with the expression that reads the value bound to it from the
+///   matched value.
+fn bindings(arm: &Arm, subject: &Expression) -> Vec<(String, Expression)>
+{
+	let mut bindings = Vec::new();
+
+	for pattern in &arm.patterns
+	{
+		let pattern::Kind::Variant {
+			bindings: names, ..
+		} = &pattern.kind
+		else
+		{
+			continue;
+		};
+
+		for (index, binding) in names.iter().enumerate()
+		{
+			let name = match binding.identifier()
+			{
+				Some("_") | None => continue,
+				Some(name) => name.to_string(),
+			};
+
+			let field = Token {
+				span: binding.span,
+				kind: token::Kind::Identifier(field(index)),
+			};
+
+			let kind = expression::Kind::Member {
+				target: Box::new(subject.clone()),
+				name: field,
+			};
+
+			let value = Expression {
+				span: binding.span,
+				kind,
+			};
+
+			bindings.push((name, value));
+		}
+	}
+
+	bindings
+}
+
+/// A fold that replaces the names a pattern binds with the expressions that
+/// read their values.
+struct Bind<'a>(&'a [(String, Expression)]);
+
+impl Fold for Bind<'_>
+{
+	fn fold_expression(&mut self, node: Expression) -> Expression
+	{
+		if let expression::Kind::Identifier(token) = &node.kind
+		{
+			let value = self
+				.0
+				.iter()
+				.find(|(name, _)| token.identifier() == Some(name.as_str()));
+
+			if let Some((_, value)) = value
+			{
+				return value.clone();
+			}
+		}
+
+		fold::walk_expression(self, node)
+	}
+}
+
+/// Creates the test of whether a value matches a literal, range or variant
+/// pattern.
+///
+/// ### Parameters
+/// * `pattern` - The pattern, which must not be a wildcard.
+/// * `subject` - The expression holding the value.
+///
+/// ### Returns
+/// * The test, as a Quark expression.
+fn test(pattern: &Pattern, subject: &Expression) -> Expression
+{
+	match &pattern.kind
+	{
+		pattern::Kind::Wildcard => unreachable!(),
+
+		pattern::Kind::Literal(token) =>
+		{
+			let literal = Expression {
+				span: token.span,
+				kind: expression::Kind::Literal(token.clone()),
+			};
+			infix(subject.clone(), token::Kind::EqualEqual, literal)
+		}
+
+		pattern::Kind::Range {
+			start,
+			end,
+			is_inclusive,
+		} =>
+		{
+			let start = Expression {
+				span: start.span,
+				kind: expression::Kind::Literal(start.clone()),
+			};
+
+			let end = Expression {
+				span: end.span,
+				kind: expression::Kind::Literal(end.clone()),
+			};
+
+			let operator = match is_inclusive
+			{
+				true => token::Kind::LessEqual,
+				false => token::Kind::Less,
+			};
+
+			infix(
+				infix(start, token::Kind::LessEqual, subject.clone()),
+				token::Kind::And,
+				infix(subject.clone(), operator, end),
+			)
+		}
+
+		pattern::Kind::Variant { r#enum, name, .. } =>
+		{
+			// The class of the variant is named as Python names it, since a
+			// member access would turn a variant named after a built-in property,
+			// such as `H`, into that property.
+			let r#enum = r#enum.identifier().unwrap_or_default();
+			let name = name.identifier().unwrap_or_default();
+			let class = identifier(&format!("{enum}.{name}"), pattern.span);
+
+			let arguments = Items {
+				span: pattern.span,
+				expressions: vec![subject.clone(), class],
+			};
+
+			let call = FunctionCall {
+				span: pattern.span,
+				name: String::from("isinstance"),
+				arguments: Some(arguments),
+			};
+
+			Expression {
+				span: pattern.span,
+				kind: expression::Kind::FunctionCall(call),
+			}
+		}
+	}
+}
+
+/// Creates a reference to a name.
+///
+/// ### Parameters
+/// * `name` - The name.
+/// * `span` - The span to give the reference.
+///
+/// ### Returns
+/// * The identifier expression.
+pub fn identifier(name: &str, span: Span) -> Expression
+{
+	let token = Token {
+		span,
+		kind: token::Kind::Identifier(name.to_string()),
+	};
+
+	Expression {
+		span,
+		kind: expression::Kind::Identifier(token),
+	}
+}
+
+/// Creates an infix expression.
+///
+/// ### Parameters
+/// * `left` - The left operand.
+/// * `operator` - The kind of the operator.
+/// * `right` - The right operand.
+///
+/// ### Returns
+/// * The infix expression, spanning both operands.
+fn infix(
+	left: Expression,
+	operator: token::Kind,
+	right: Expression,
+) -> Expression
+{
+	let span = Span {
+		start: left.span.start,
+		end: right.span.end,
+	};
+
+	let operator = Token {
+		span,
+		kind: operator,
+	};
+
+	Expression {
+		span,
+		kind: expression::Kind::Infix {
+			left: Box::new(left),
+			operator,
+			right: Box::new(right),
+		},
+	}
+}
+
+/// Wraps an infix expression in parentheses, so that it can be an operand.
+///
+/// ### Parameters
+/// * `expression` - The expression.
+///
+/// ### Returns
+/// * The expression, in parentheses if it is an infix expression.
+fn parenthesise(expression: Expression) -> Expression
+{
+	match expression.kind
+	{
+		expression::Kind::Infix { .. } => Expression {
+			span: expression.span,
+			kind: expression::Kind::Parenthesised(Box::new(expression)),
+		},
+		_ => expression,
+	}
+}
@@ -1,4 +1,8 @@
+pub(crate) mod hoist;
+mod lower;
 mod rules;
 pub mod synthesis;
+pub mod version;
 
 pub use synthesis::*;
+pub use version::Version;
@@ -1,7 +1,10 @@
 use super::Synthesis;
+use crate::compiler::synthesiser::lower::{condition, identifier, SUBJECT};
 use crate::language::grammar::controlflow::{
-	BreakStmt, ContinueStmt, IfStmt, WhileStmt,
+	Arm, BreakStmt, ContinueStmt, IfStmt, MatchStmt, WhileStmt,
 };
+use crate::language::grammar::expression::{self, Expression};
+use crate::language::grammar::pattern::{self, Pattern};
 
 impl Synthesis for WhileStmt
 {
@@ -26,6 +29,15 @@ impl Synthesis for IfStmt
 		output.push_str(self.body.synthesise().as_str());
 		output.push('}');
 
+		for else_if in self.else_ifs
+		{
+			output.push_str("elif ");
+			output.push_str(else_if.condition.synthesise().as_str());
+			output.push_str(":{");
+			output.push_str(else_if.body.synthesise().as_str());
+			output.push('}');
+		}
+
 		if let Some(else_body) = self.else_body
 		{
 			output.push_str("else:{");
@@ -36,6 +48,127 @@ impl Synthesis for IfStmt
 	}
 }
 
+impl Synthesis for MatchStmt
+{
+	fn synthesise(self) -> String
+	{
+		let has_range = self
+			.arms
+			.iter()
+			.flat_map(|arm| &arm.patterns)
+			.any(|pattern| matches!(pattern.kind, pattern::Kind::Range { .. }));
+
+		// Python has no range patterns, so arms with ranges test the matched
+		// value in a guard, which needs the value to have a name.
+		let (subject, name) = match self.scrutinee.kind
+		{
+			expression::Kind::Identifier(_) | expression::Kind::Literal(_) =>
+			{
+				(self.scrutinee.clone().synthesise(), self.scrutinee)
+			}
+			_ =>
+			{
+				let name = identifier(SUBJECT, self.scrutinee.span);
+				let value = self.scrutinee.synthesise();
+
+				match has_range
+				{
+					true => (format!("({SUBJECT} := {value})"), name),
+					false => (value, name),
+				}
+			}
+		};
+
+		let mut output = format!("match {subject}:{{");
+
+		for arm in self.arms
+		{
+			output.push_str(&arm.synthesise_case(&name));
+		}
+
+		output.push('}');
+		output
+	}
+}
+
+impl Arm
+{
+	/// Synthesises the arm as a `case` of a Python `match` statement.
+	///
+	/// ### Parameters
+	/// * `subject` - The expression holding the matched value.
+	///
+	/// ### Returns
+	/// * The `case` clause.
+	fn synthesise_case(self, subject: &Expression) -> String
+	{
+		let has_range = self
+			.patterns
+			.iter()
+			.any(|pattern| matches!(pattern.kind, pattern::Kind::Range { .. }));
+
+		let case = match has_range
+		{
+			true => match condition(&self, subject)
+			{
+				Some(condition) => format!("_ if {}", condition.synthesise()),
+				None => String::from("_"),
+			},
+			false =>
+			{
+				let patterns = self
+					.patterns
+					.into_iter()
+					.map(Pattern::synthesise)
+					.collect::<Vec<_>>()
+					.join(" | ");
+
+				match self.guard
+				{
+					Some(guard) => format!("{patterns} if {}", guard.synthesise()),
+					None => patterns,
+				}
+			}
+		};
+
+		format!("case {case}:{{{}}}", self.body.synthesise())
+	}
+}
+
+impl Synthesis for Pattern
+{
+	fn synthesise(self) -> String
+	{
+		match self.kind
+		{
+			pattern::Kind::Wildcard => String::from("_"),
+			pattern::Kind::Literal(token) => Expression {
+				span: self.span,
+				kind: expression::Kind::Literal(token),
+			}
+			.synthesise(),
+			pattern::Kind::Range { .. } => unreachable!(),
+			pattern::Kind::Variant {
+				r#enum,
+				name,
+				bindings,
+			} =>
+			{
+				let bindings = bindings
+					.iter()
+					.map(|binding| binding.identifier().unwrap_or_default())
+					.collect::<Vec<_>>()
+					.join(", ");
+
+				let r#enum = r#enum.identifier().unwrap_or_default();
+				let name = name.identifier().unwrap_or_default();
+
+				format!("{enum}.{name}({bindings})")
+			}
+		}
+	}
+}
+
 impl Synthesis for ContinueStmt
 {
 	fn synthesise(self) -> String
@@ -14,7 +14,20 @@ impl Synthesis for Declaration
 				format!("{name} = {value};")
 			}
 
+			Kind::Destructuring { names, value, .. } =>
+			{
+				let value = value.synthesise();
+
+				match names.as_slice()
+				{
+					[name] => format!("{name}, = {value};"),
+					names => format!("{} = {value};", names.join(", ")),
+				}
+			}
+
 			Kind::Function(function) => function.synthesise(),
+			Kind::Struct(r#struct) => r#struct.synthesise(),
+			Kind::Enum(r#enum) => r#enum.synthesise(),
 		}
 	}
 }
@@ -1,6 +1,8 @@
 use super::Synthesis;
-use crate::language::grammar::expression::{Expression, Kind};
-use crate::language::lexicon::token::Kind::*;
+use crate::language::grammar::expression::{Expression, Kind, Part};
+use crate::language::grammar::functions::Body;
+use crate::language::lexicon::token::{Kind::*, Token};
+use crate::language::semantics::member;
 
 impl Synthesis for Expression
 {
@@ -17,7 +19,7 @@ impl Synthesis for Expression
 			Kind::Literal(token) => match token.kind
 			{
 				Number(value) => value,
-				String(value) => format!("'{value}'"),
+				String(value) => format!("'{}'", escape(&value, '\'')),
 				Boolean(true) => "True".to_string(),
 				Boolean(false) => "False".to_string(),
 				_ => unreachable!(),
@@ -29,6 +31,23 @@ impl Synthesis for Expression
 				format!("({inner})")
 			}
 
+			Kind::Tuple(items) =>
+			{
+				let single = items.expressions.len() == 1;
+				let items = items
+					.expressions
+					.into_iter()
+					.map(|expr| expr.synthesise())
+					.collect::<Vec<_>>()
+					.join(", ");
+
+				match single
+				{
+					true => format!("({items},)"),
+					false => format!("({items})"),
+				}
+			}
+
 			Kind::List(mut structure) =>
 			{
 				assert_eq!(structure.len(), 1);
@@ -56,6 +75,111 @@ impl Synthesis for Expression
 
 			Kind::FunctionCall(function_call) => function_call.synthesise(),
 
+			Kind::Index { target, subscript } =>
+			{
+				format!("{}{}", target.synthesise(), subscript.synthesise())
+			}
+
+			Kind::Member { target, name } => access(*target, name, None),
+
+			Kind::MethodCall {
+				target,
+				name,
+				arguments,
+			} =>
+			{
+				let arguments = arguments
+					.into_iter()
+					.flat_map(|arguments| arguments.expressions)
+					.map(|argument| argument.synthesise())
+					.collect::<Vec<_>>()
+					.join(", ");
+
+				access(*target, name, Some(arguments))
+			}
+
+			Kind::Lambda(lambda) =>
+			{
+				let parameters = match lambda.parameters
+				{
+					Some(parameters) => format!(" {}", parameters.synthesise()),
+					None => std::string::String::new(),
+				};
+
+				match lambda.body
+				{
+					Body::Expression(body) =>
+					{
+						format!("lambda{parameters}: {}", body.synthesise())
+					}
+					Body::Block(_) =>
+					{
+						unreachable!("Lambdas with block bodies are hoisted into functions")
+					}
+				}
+			}
+
+			Kind::Variant {
+				r#enum,
+				name,
+				arguments,
+			} =>
+			{
+				let arguments = arguments
+					.into_iter()
+					.flat_map(|arguments| arguments.expressions)
+					.map(|argument| argument.synthesise())
+					.collect::<Vec<_>>()
+					.join(", ");
+
+				let r#enum = r#enum.identifier().unwrap_or_default();
+				let name = name.identifier().unwrap_or_default();
+
+				format!("{enum}.{name}({arguments})")
+			}
+
+			Kind::Interpolation(parts) =>
+			{
+				let mut inline = std::string::String::new();
+				let mut template = std::string::String::new();
+				let mut arguments = Vec::new();
+
+				for part in parts
+				{
+					match part
+					{
+						Part::Text(text) =>
+						{
+							let text =
+								escape(&text, '"').replace('{', "{{").replace('}', "}}");
+							inline.push_str(&text);
+							template.push_str(&text);
+						}
+						Part::Expression(expression) =>
+						{
+							let expression = expression.synthesise();
+							inline.push_str(&format!("{{{expression}}}"));
+							template.push_str("{}");
+							arguments.push(expression);
+						}
+					}
+				}
+
+				// Before Python 3.12, the expressions of an f-string can hold
+				// neither a backslash nor the quote of the f-string, so such
+				// strings are formatted by `str.format` instead.
+				if arguments
+					.iter()
+					.any(|argument| argument.contains(['\\', '"']))
+				{
+					format!("\"{template}\".format({})", arguments.join(", "))
+				}
+				else
+				{
+					format!("f\"{inline}\"")
+				}
+			}
+
 			Kind::Matrix(items_list) =>
 			{
 				let mut output = "np.array([".to_string();
@@ -115,7 +239,7 @@ impl Synthesis for Expression
 					Caret => "**",
 					And => "and",
 					Or => "or",
-					Equal => "==",
+					EqualEqual => "==",
 					ExclaimEqual => "!=",
 					Less => "<",
 					LessEqual => "<=",
@@ -131,3 +255,85 @@ impl Synthesis for Expression
 		}
 	}
 }
+
+/// Creates the Python equivalent of a member access or method call.
+///
+/// ### Parameters
+/// * `target` - The expression whose member is used.
+/// * `name` - The name of the member.
+/// * `arguments` - The synthesised arguments of a method call, or `None` for a
+///   member access.
+///
+/// ### Returns
+/// * The Python expression the member is defined as, or a plain attribute or
+///   method if no built-in type has a property or method of that name, such as
+///   a field of a struct.
+fn access(
+	target: Expression,
+	name: Token,
+	arguments: Option<std::string::String>,
+) -> std::string::String
+{
+	let name = match name.kind
+	{
+		Identifier(name) => name,
+		_ => unreachable!(),
+	};
+
+	let target = target.synthesise();
+
+	match (member::find(&name), arguments)
+	{
+		(Some(member), arguments)
+			if member.parameters.is_some() == arguments.is_some() =>
+		{
+			member
+				.python
+				.split("{self}")
+				.map(|part| {
+					part.replace("{arguments}", arguments.as_deref().unwrap_or(""))
+				})
+				.collect::<Vec<_>>()
+				.join(&target)
+		}
+		(_, Some(arguments)) => format!("{target}.{name}({arguments})"),
+		(_, None) => format!("{target}.{name}"),
+	}
+}
+
+/// Escapes the contents of a Python string literal.
+///
+/// ### Parameters
+/// * `value` - The contents of the string.
+/// * `quote` - The quote character delimiting the literal.
+///
+/// ### Returns
+/// * The contents with every character that cannot appear verbatim inside
+///   the literal escaped.
+fn escape(value: &str, quote: char) -> std::string::String
+{
+	let mut output = std::string::String::new();
+
+	for character in value.chars()
+	{
+		match character
+		{
+			'\\' => output.push_str("\\\\"),
+			'\n' => output.push_str("\\n"),
+			'\t' => output.push_str("\\t"),
+			'\r' => output.push_str("\\r"),
+			_ if character == quote =>
+			{
+				output.push('\\');
+				output.push(character);
+			}
+			_ if character.is_control() =>
+			{
+				output.push_str(&format!("\\u{:04x}", u32::from(character)));
+			}
+			_ => output.push(character),
+		}
+	}
+
+	output
+}
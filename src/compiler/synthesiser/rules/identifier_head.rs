@@ -6,7 +6,16 @@ impl Synthesis for AssignmentStmt
 {
 	fn synthesise(self) -> std::string::String
 	{
-		format!("{} = {}", self.identifier, self.expression.synthesise())
+		let subscript = self
+			.subscript
+			.map(|subscript| subscript.synthesise())
+			.unwrap_or_default();
+
+		format!(
+			"{}{subscript} = {}",
+			self.identifier,
+			self.expression.synthesise()
+		)
 	}
 }
 
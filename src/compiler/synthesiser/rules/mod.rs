@@ -3,7 +3,11 @@ mod declaration;
 mod expression;
 mod functions;
 mod identifier_head;
+mod module;
+mod prelude;
 mod programme;
 mod statement;
+mod subscript;
+pub(crate) mod types;
 
 pub use super::*;
@@ -0,0 +1,18 @@
+use super::Synthesis;
+use crate::language::grammar::{ImportStmt, UseStmt};
+
+impl Synthesis for ImportStmt
+{
+	fn synthesise(self) -> String
+	{
+		format!("import {} as {}", self.module, self.name)
+	}
+}
+
+impl Synthesis for UseStmt
+{
+	fn synthesise(self) -> String
+	{
+		format!("{} = {}.{}", self.name, self.module, self.name)
+	}
+}
@@ -0,0 +1,216 @@
+use super::DATACLASS;
+use crate::language::grammar::declaration::{self, Declaration};
+use crate::language::grammar::expression::{self, Expression, Items, Part};
+use crate::language::grammar::functions::Body;
+use crate::language::grammar::statement::{self, Statement};
+use crate::language::grammar::subscript::{Index, Subscript};
+use crate::language::grammar::visit::{self, Visit};
+use crate::language::grammar::{Block, Programme};
+use crate::language::lexicon::token;
+use crate::language::semantics::prelude::PRELUDE;
+
+impl Programme
+{
+	/// Creates the Python definitions of the prelude constants the programme
+	/// refers to, and the import the classes of its structs and enums need.
+	///
+	/// ### Returns
+	/// * The definitions, one statement per constant.
+	pub fn prelude(&self) -> String
+	{
+		let mut records = Records(false);
+		records.visit_programme(self);
+
+		let import = match records.0
+		{
+			true => DATACLASS,
+			false => "",
+		};
+
+		let constants: String = PRELUDE
+			.iter()
+			.filter(|constant| {
+				self
+					.statements
+					.iter()
+					.any(|statement| statement.mentions(constant.name))
+			})
+			.map(|constant| format!("{} = {};", constant.name, constant.python))
+			.collect();
+
+		format!("{import}{constants}")
+	}
+}
+
+/// Finds out whether a programme declares a struct or an enum.
+struct Records(bool);
+
+impl<'ast> Visit<'ast> for Records
+{
+	fn visit_declaration(&mut self, node: &'ast Declaration)
+	{
+		match &node.kind
+		{
+			declaration::Kind::Struct(_) | declaration::Kind::Enum(_) =>
+			{
+				self.0 = true
+			}
+			_ => visit::walk_declaration(self, node),
+		}
+	}
+}
+
+impl Statement
+{
+	/// Determines if the statement refers to a name.
+	fn mentions(&self, name: &str) -> bool
+	{
+		use statement::Kind::*;
+
+		match &self.kind
+		{
+			Declaration(declaration) => declaration.mentions(name),
+			Expression(expression) => expression.mentions(name),
+			If(if_) =>
+			{
+				if_.condition.mentions(name)
+					|| if_.body.mentions(name)
+					|| if_.else_ifs.iter().any(|else_if| {
+						else_if.condition.mentions(name) || else_if.body.mentions(name)
+					})
+					|| if_.else_body.iter().any(|body| body.mentions(name))
+			}
+			While(while_) =>
+			{
+				while_.condition.mentions(name) || while_.body.mentions(name)
+			}
+			Match(match_) =>
+			{
+				match_.scrutinee.mentions(name)
+					|| match_.arms.iter().any(|arm| {
+						arm.guard.iter().any(|guard| guard.mentions(name))
+							|| arm.body.mentions(name)
+					})
+			}
+			Return(return_) => return_.expression.mentions(name),
+			Assignment(assignment) =>
+			{
+				assignment.expression.mentions(name)
+					|| assignment.subscript.iter().any(|sub| sub.mentions(name))
+			}
+			Echo(echo) => echo.arguments.iter().any(|echo| echo.mentions(name)),
+			FunctionCall(call) => call.arguments.iter().any(|items| items.mentions(name)),
+			Break(_) | Continue(_) | Import(_) | Use(_) => false,
+		}
+	}
+}
+
+impl Declaration
+{
+	/// Determines if the declaration refers to a name.
+	fn mentions(&self, name: &str) -> bool
+	{
+		match &self.kind
+		{
+			declaration::Kind::Variable { value, .. }
+			| declaration::Kind::Destructuring { value, .. } => value.mentions(name),
+			declaration::Kind::Function(function) => function.body.mentions(name),
+			declaration::Kind::Struct(_) | declaration::Kind::Enum(_) => false,
+		}
+	}
+}
+
+impl Block
+{
+	/// Determines if the block refers to a name.
+	fn mentions(&self, name: &str) -> bool
+	{
+		self
+			.statements
+			.iter()
+			.flatten()
+			.any(|statement| statement.mentions(name))
+	}
+}
+
+impl Items
+{
+	/// Determines if the items refer to a name.
+	fn mentions(&self, name: &str) -> bool
+	{
+		self
+			.expressions
+			.iter()
+			.any(|expression| expression.mentions(name))
+	}
+}
+
+impl Subscript
+{
+	/// Determines if the subscript refers to a name.
+	fn mentions(&self, name: &str) -> bool
+	{
+		self.indices.iter().any(|index| match index
+		{
+			Index::Position(position) => position.mentions(name),
+			Index::Slice { start, end, .. } =>
+			{
+				start.iter().chain(end).any(|bound| bound.mentions(name))
+			}
+		})
+	}
+}
+
+impl Expression
+{
+	/// Determines if the expression refers to a name.
+	fn mentions(&self, name: &str) -> bool
+	{
+		use expression::Kind::*;
+
+		match &self.kind
+		{
+			Identifier(token) => match &token.kind
+			{
+				token::Kind::Identifier(identifier) => identifier == name,
+				_ => false,
+			},
+			Literal(_) => false,
+			Parenthesised(expression) => expression.mentions(name),
+			Tuple(items) => items.mentions(name),
+			List(structure) | Matrix(structure) =>
+			{
+				structure.iter().flatten().any(|items| items.mentions(name))
+			}
+			Prefix { operand, .. } => operand.mentions(name),
+			Infix { left, right, .. } => left.mentions(name) || right.mentions(name),
+			FunctionCall(call) => call.arguments.iter().any(|items| items.mentions(name)),
+			Index { target, subscript } =>
+			{
+				target.mentions(name) || subscript.mentions(name)
+			}
+			Member { target, .. } => target.mentions(name),
+			Lambda(lambda) => match &lambda.body
+			{
+				Body::Expression(body) => body.mentions(name),
+				Body::Block(body) => body.mentions(name),
+			},
+			MethodCall {
+				target, arguments, ..
+			} =>
+			{
+				target.mentions(name)
+					|| arguments.iter().any(|items| items.mentions(name))
+			}
+			Variant { arguments, .. } =>
+			{
+				arguments.iter().any(|items| items.mentions(name))
+			}
+			Interpolation(parts) => parts.iter().any(|part| match part
+			{
+				Part::Text(_) => false,
+				Part::Expression(expression) => expression.mentions(name),
+			}),
+		}
+	}
+}
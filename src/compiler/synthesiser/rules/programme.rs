@@ -5,11 +5,15 @@ impl Synthesis for Programme
 {
 	fn synthesise(self) -> String
 	{
-		self
+		let prelude = self.prelude();
+
+		let statements = self
 			.statements
 			.into_iter()
 			.map(|statement| statement.synthesise())
 			.collect::<Vec<_>>()
-			.join("")
+			.join("");
+
+		format!("{prelude}{statements}")
 	}
 }
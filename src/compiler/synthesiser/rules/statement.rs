@@ -12,6 +12,7 @@ impl Synthesis for Statement
 		{
 			Kind::If(if_) => if_.synthesise(),
 			Kind::While(while_) => while_.synthesise(),
+			Kind::Match(match_) => match_.synthesise(),
 			Kind::Declaration(declaration) => (declaration.synthesise()),
 
 			Kind::Expression(expression) =>
@@ -26,6 +27,8 @@ impl Synthesis for Statement
 			{
 				format!("{}{}", function_call.synthesise(), ";")
 			}
+			Kind::Import(import) => format!("{}{}", import.synthesise(), ";"),
+			Kind::Use(use_) => format!("{}{}", use_.synthesise(), ";"),
 			Kind::Assignment(assignment) =>
 			{
 				format!("{}{}", assignment.synthesise(), ";")
@@ -38,13 +41,20 @@ impl Synthesis for Block
 {
 	fn synthesise(self) -> std::string::String
 	{
-		self
+		let body = self
 			.statements
 			.into_iter()
 			.flatten()
 			.map(|statement| statement.synthesise())
 			.collect::<Vec<_>>()
-			.join("")
+			.join("");
+
+		// Python has no empty blocks.
+		match body.is_empty()
+		{
+			true => String::from("pass;"),
+			false => body,
+		}
 	}
 }
 
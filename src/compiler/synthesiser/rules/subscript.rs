@@ -0,0 +1,68 @@
+use super::Synthesis;
+use crate::language::grammar::expression::{Expression, Kind};
+use crate::language::grammar::subscript::{Index, Subscript};
+
+impl Synthesis for Subscript
+{
+	fn synthesise(self) -> String
+	{
+		let indices = self
+			.indices
+			.into_iter()
+			.map(Index::synthesise)
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		format!("[{indices}]")
+	}
+}
+
+impl Synthesis for Index
+{
+	fn synthesise(self) -> String
+	{
+		let (start, end, is_inclusive) = match self
+		{
+			Self::Position(position) => return position.synthesise(),
+			Self::Slice {
+				start,
+				end,
+				is_inclusive,
+				..
+			} => (start, end, is_inclusive),
+		};
+
+		let start = start.map(Expression::synthesise).unwrap_or_default();
+
+		let end = match (end, is_inclusive)
+		{
+			(None, _) => String::new(),
+			(Some(end), false) => end.synthesise(),
+			(Some(end), true) => inclusive(end),
+		};
+
+		format!("{start}:{end}")
+	}
+}
+
+/// Synthesises the end of an inclusive slice as the exclusive end Python
+/// expects.
+///
+/// ### Parameters
+/// * `end` - The inclusive end of the slice.
+///
+/// ### Returns
+/// * The exclusive end, which is empty when the slice ends at the last element.
+fn inclusive(end: Expression) -> String
+{
+	match end.integer()
+	{
+		Some(-1) => String::new(),
+		Some(end) => (end + 1).to_string(),
+		None if matches!(end.kind, Kind::Infix { .. }) =>
+		{
+			format!("({}) + 1", end.synthesise())
+		}
+		None => format!("{} + 1", end.synthesise()),
+	}
+}
@@ -0,0 +1,106 @@
+use super::Synthesis;
+use crate::language::grammar::types::{EnumDclr, StructDclr};
+use crate::language::semantics::r#type::Type;
+
+impl Synthesis for StructDclr
+{
+	fn synthesise(self) -> String
+	{
+		let fields = self
+			.fields
+			.into_iter()
+			.map(|field| (field.name, field.r#type))
+			.collect();
+
+		dataclass(&self.name, fields)
+	}
+}
+
+impl Synthesis for EnumDclr
+{
+	fn synthesise(self) -> String
+	{
+		let mut body: String = self
+			.variants
+			.into_iter()
+			.map(|variant| {
+				let fields = variant
+					.payload
+					.into_iter()
+					.enumerate()
+					.map(|(index, r#type)| (field(index), r#type))
+					.collect();
+
+				dataclass(&variant.name, fields)
+			})
+			.collect();
+
+		if body.is_empty()
+		{
+			body.push_str("pass;");
+		}
+
+		format!("class {}:{{{body}}}", self.name)
+	}
+}
+
+/// Gets the name of the Python attribute holding a value of the payload of an
+/// enum variant.
+///
+/// ### Parameters
+/// * `index` - The position of the value in the payload.
+///
+/// ### Returns
+/// * The name of the attribute, such as `_0`.
+pub fn field(index: usize) -> String
+{
+	format!("_{index}")
+}
+
+/// Creates a Python dataclass.
+///
+/// ### Parameters
+/// * `name` - The name of the class.
+/// * `fields` - The names and types of the fields, in order.
+///
+/// ### Returns
+/// * The class definition.
+fn dataclass(name: &str, fields: Vec<(String, Type)>) -> String
+{
+	let mut body: String = fields
+		.into_iter()
+		.map(|(name, r#type)| format!("{name}: {};", annotation(&r#type)))
+		.collect();
+
+	if body.is_empty()
+	{
+		body.push_str("pass;");
+	}
+
+	format!("@dataclass;class {name}:{{{body}}}")
+}
+
+/// Creates the Python annotation of a type.
+///
+/// ### Parameters
+/// * `type` - The type.
+///
+/// ### Returns
+/// * The annotation.  Structs and enums are annotated by name in quotes, since
+///   they may be declared after the class that refers to them.
+fn annotation(r#type: &Type) -> String
+{
+	match r#type
+	{
+		Type::Boolean => String::from("bool"),
+		Type::String => String::from("str"),
+		Type::Number => String::from("complex"),
+		Type::Unit
+		| Type::Function { .. }
+		| Type::Variable(_)
+		| Type::Matrix(_) => String::from("object"),
+		Type::Named(name) => format!("'{name}'"),
+		Type::Tuple(_) => String::from("tuple"),
+		Type::List(_) => String::from("list"),
+	}
+}
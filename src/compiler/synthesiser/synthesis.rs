@@ -1,12 +1,21 @@
+use super::hoist::Hoist;
+use super::lower::Lower;
+use super::Version;
 use crate::compiler::parser::parse::Tree;
+use crate::language::grammar::Fold;
 
 const TABS: &str = "    ";
 
+/// The Python code every synthesised programme starts with.
+pub const HEADER: &str = "import numpy as np\n";
+
+/// The import the classes of structs and enums are declared with.
+pub const DATACLASS: &str = "from dataclasses import dataclass;";
+
 /// Types that can be synthesised into the target language.
 ///
 /// This trait is used to translate Quark's linguistic structures into the
 /// target language.
-
 pub trait Synthesis
 {
 	/// Creates a valid string representation of this structure into the target
@@ -21,21 +30,77 @@ impl Synthesis for Tree
 {
 	fn synthesise(self) -> String
 	{
-		let Tree(programme) = self;
+		self.synthesise_for(Version::default())
+	}
+}
+
+impl Tree
+{
+	/// Synthesises the programme into Python code for a version of Python.
+	///
+	/// ### Parameters
+	/// * `version` - The version of Python the code must run on.
+	///
+	/// ### Returns
+	/// * The Python code.  Before Python 3.10, `match` statements become `if`
+	///   chains.  Lambdas with block bodies become nested functions.
+	pub fn synthesise_for(self, version: Version) -> String
+	{
+		let Tree(mut programme) = self;
+
+		if version < Version::MATCH
+		{
+			programme = Lower.fold_programme(programme);
+		}
+
+		programme = Hoist::default().fold_programme(programme);
+
 		python(programme.synthesise())
 	}
 }
 
 fn python(syntax: String) -> String
+{
+	format!("{HEADER}{}", layout(syntax)).trim_end().to_string()
+}
+
+/// Lays out synthesised code as Python, turning braces and semicolons into
+/// indented lines.
+///
+/// ### Parameters
+/// * `syntax` - The synthesised code.
+///
+/// ### Returns
+/// * The Python code.
+pub fn layout(syntax: String) -> String
 {
 	let mut indent_level = 0;
 	let mut output = String::new();
-	output.push_str("import numpy as np\n");
 
-	for char in syntax.chars()
+	// The quote character of the string literal being copied, if any.
+	let mut quote = None;
+	let mut characters = syntax.chars();
+
+	while let Some(char) = characters.next()
 	{
 		match char
 		{
+			'\\' if quote.is_some() =>
+			{
+				output.push(char);
+				output.extend(characters.next());
+			}
+			_ if quote == Some(char) =>
+			{
+				quote = None;
+				output.push(char);
+			}
+			_ if quote.is_some() => output.push(char),
+			'\'' | '"' =>
+			{
+				quote = Some(char);
+				output.push(char);
+			}
 			'{' =>
 			{
 				indent_level += 1;
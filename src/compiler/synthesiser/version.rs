@@ -0,0 +1,57 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A version of Python that synthesised code targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version
+{
+	/// The major version, such as `3` in `3.10`.
+	pub major: u32,
+
+	/// The minor version, such as `10` in `3.10`.
+	pub minor: u32,
+}
+
+impl Version
+{
+	/// The first version with the `match` statement.
+	pub const MATCH: Self = Self {
+		major: 3,
+		minor: 10,
+	};
+}
+
+impl Default for Version
+{
+	fn default() -> Self
+	{
+		Self::MATCH
+	}
+}
+
+impl FromStr for Version
+{
+	type Err = String;
+
+	fn from_str(version: &str) -> Result<Self, Self::Err>
+	{
+		let invalid = || {
+			format!("Invalid Python version `{version}`; expected one like `3.10`")
+		};
+
+		let (major, minor) = version.split_once('.').ok_or_else(invalid)?;
+
+		Ok(Self {
+			major: major.parse().map_err(|_| invalid())?,
+			minor: minor.parse().map_err(|_| invalid())?,
+		})
+	}
+}
+
+impl Display for Version
+{
+	fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result
+	{
+		write!(formatter, "{}.{}", self.major, self.minor)
+	}
+}
@@ -0,0 +1,142 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::language::lexicon::{Lexeme, Trivia};
+
+/// A concrete syntax tree.
+///
+/// Unlike the abstract syntax tree, the concrete syntax tree keeps every
+/// token, whitespace and comment of the source, so printing it reproduces the
+/// source text exactly.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyntaxTree
+{
+	/// The programme node, which holds every token of the source.
+	pub root: Node,
+
+	/// The trivia after the last token.
+	pub end: Vec<Trivia>,
+}
+
+/// A node of a concrete syntax tree.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Node
+{
+	/// The kind of the node.
+	pub kind: Kind,
+
+	/// The nodes and tokens of the node, in source order.
+	pub children: Vec<Element>,
+}
+
+/// A child of a concrete syntax tree node.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Element
+{
+	/// A nested node.
+	Node(Node),
+
+	/// A token with its trivia.
+	Lexeme(Lexeme),
+}
+
+/// The kind of a concrete syntax tree node.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Kind
+{
+	/// A whole source file.
+	///
+	/// ### Rule
+	/// * _programme_ -> _statement_*
+	Programme,
+
+	/// A statement, which ends with a `;` or a block that is not followed by
+	/// `else`.
+	///
+	/// ### Rule
+	/// * _statement_ -> { _token_ | _group_ }* { `;` | _block_ }
+	Statement,
+
+	/// A block of statements.
+	///
+	/// ### Rule
+	/// * _block_ -> `{` _statement_* `}`
+	Block,
+
+	/// The fields of a struct or the variants of an enum.
+	///
+	/// ### Rule
+	/// * _members_ -> `{` { _token_ | _group_ }* `}`
+	Members,
+
+	/// A parenthesised group of tokens.
+	///
+	/// ### Rule
+	/// * _parenthesised_ -> `(` { _token_ | _group_ }* `)`
+	Parenthesised,
+
+	/// A bracketed group of tokens, such as a list or matrix.
+	///
+	/// ### Rule
+	/// * _brackets_ -> `[` { _token_ | _group_ }* { `]` | `]m` | `]a` }
+	Brackets,
+}
+
+impl Node
+{
+	/// Gets the tokens of the node and all of its descendants.
+	///
+	/// ### Returns
+	/// * The tokens, in source order.
+	pub fn lexemes(&self) -> Vec<&Lexeme>
+	{
+		self
+			.children
+			.iter()
+			.flat_map(|child| match child
+			{
+				Element::Node(node) => node.lexemes(),
+				Element::Lexeme(lexeme) => vec![lexeme],
+			})
+			.collect()
+	}
+}
+
+impl Display for SyntaxTree
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		write!(formatter, "{}", self.root)?;
+
+		self
+			.end
+			.iter()
+			.try_for_each(|trivia| write!(formatter, "{}", trivia.text))
+	}
+}
+
+impl Display for Node
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		self
+			.children
+			.iter()
+			.try_for_each(|child| write!(formatter, "{child}"))
+	}
+}
+
+impl Display for Element
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		match self
+		{
+			Self::Node(node) => write!(formatter, "{node}"),
+			Self::Lexeme(lexeme) => write!(formatter, "{lexeme}"),
+		}
+	}
+}
@@ -1,11 +1,12 @@
-use super::{Block, Expression};
+use super::{Block, Expression, Pattern};
 use crate::language::utils::Span;
 
 /// A If statement in a Quark programme.
 ///
 /// ### Rule
-/// * _statement_ -> { _declaration_ | _expression_ } `;`
+/// * _if_ -> `if` _expression_ _block_ _else_if_* { `else` _block_ }?
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IfStmt
 {
 	/// The span of the statement.
@@ -15,14 +16,75 @@ pub struct IfStmt
 	/// The body of the if statement
 	pub body: Block,
 
+	/// The `else if` branches of the if statement, in order.
+	pub else_ifs: Vec<ElseIf>,
+
 	/// The else of the if statement
 	pub else_body: Option<Block>,
 }
 
+/// An `else if` branch of an If statement.
+///
+/// ### Rule
+/// * _else_if_ -> `else` `if` _expression_ _block_
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElseIf
+{
+	/// The span of the branch, from its `else` keyword.
+	pub span: Span,
+
+	/// The condition expression
+	pub condition: Expression,
+
+	/// The body of the branch
+	pub body: Block,
+}
+
+/// A Match statement in a Quark programme.
+///
+/// ### Rule
+/// * _match_ -> `match` _expression_ `{` _arm_* `}`
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MatchStmt
+{
+	/// The span of the statement.
+	pub span: Span,
+
+	/// The expression whose value is matched.
+	pub scrutinee: Expression,
+
+	/// The arms of the statement, in the order they are tried.
+	pub arms: Vec<Arm>,
+}
+
+/// An arm of a Match statement.
+///
+/// ### Rule
+/// * _arm_ -> _pattern_ { `|` _pattern_ }* { `if` _expression_ }? `=>` _block_
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Arm
+{
+	/// The span of the arm.
+	pub span: Span,
+
+	/// The patterns of the arm, any of which selects it.
+	pub patterns: Vec<Pattern>,
+
+	/// The condition that must also hold for the arm to be selected.
+	pub guard: Option<Expression>,
+
+	/// The body of the arm.
+	pub body: Block,
+}
+
 /// A While statement in a Quark programme.
 ///
 /// ### Rule
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WhileStmt
 {
 	/// The span of the statement.
@@ -37,6 +99,7 @@ pub struct WhileStmt
 ///
 /// ### Rule
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BreakStmt
 {
 	/// The span of the statement.
@@ -47,6 +110,7 @@ pub struct BreakStmt
 ///
 /// ### Rule
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContinueStmt
 {
 	/// The span of the statement.
@@ -1,11 +1,13 @@
-use super::{Expression, FunctionDclr};
+use super::{EnumDclr, Expression, FunctionDclr, StructDclr};
 use crate::language::utils::Span;
 
 /// A declaration in a Quark programme.
 ///
 /// ### Rule
-/// * _declaration_ -> _function_ | _variable_
+/// * _declaration_ -> _function_ | _variable_ | _destructuring_ | _struct_ |
+///   _enum_
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Declaration
 {
 	/// The span of the declaration.
@@ -13,15 +15,25 @@ pub struct Declaration
 
 	/// The kind of the declaration.
 	pub kind: Kind,
+
+	/// The lines of the documentation comments preceding the declaration.
+	pub docs: Vec<String>,
 }
 
 /// The kind of a declaration.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Kind
 {
 	/// A function declaration.
 	Function(FunctionDclr),
 
+	/// A struct declaration.
+	Struct(StructDclr),
+
+	/// An enum declaration.
+	Enum(EnumDclr),
+
 	/// A variable declaration.
 	///
 	/// ### Rule
@@ -37,4 +49,22 @@ pub enum Kind
 		/// Whether the variable is mutable.
 		is_mutable: bool,
 	},
+
+	/// A declaration of a variable for each value of a tuple.  A name of `_`
+	/// declares nothing.
+	///
+	/// ### Rule
+	/// * _destructuring_ -> { `let` | `var` } `(` _identifier_ { `,`
+	///   _identifier_ }* `,`? `)` `=` _expression_
+	Destructuring
+	{
+		/// The names of the variables, in the order of the values.
+		names: Vec<String>,
+
+		/// The tuple the values are taken from.
+		value: Expression,
+
+		/// Whether the variables are mutable.
+		is_mutable: bool,
+	},
 }
@@ -1,13 +1,15 @@
-use crate::language::lexicon::Token;
+use crate::language::lexicon::{token, Token};
 use crate::language::utils::Span;
 
 /// An expression in a Quark programme.
 ///
 /// ### Rule
-/// * _expression_ -> _primary_ | _prefix_ | _infix_
+/// * _expression_ -> _primary_ | _prefix_ | _infix_ | _index_ | _member_ |
+///   _method_ | _lambda_ | _variant_
 /// * _primary_ -> _literal_ | _identifier_ | _parenthesised_ | _list_ |
 ///   _matrix_
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Expression
 {
 	/// The span of the expression.
@@ -17,8 +19,50 @@ pub struct Expression
 	pub kind: Kind,
 }
 
+impl Expression
+{
+	/// Gets the value of an integer literal, such as `3`, `0x1F` or `-1`.
+	///
+	/// ### Returns
+	/// * The value of the literal.
+	/// * `None` if the expression is not an integer literal.
+	pub fn integer(&self) -> Option<i128>
+	{
+		match &self.kind
+		{
+			Kind::Literal(Token {
+				kind: token::Kind::Number(number),
+				..
+			}) =>
+			{
+				let (radix, digits) = match number.get(..2)
+				{
+					Some("0x") => (16, &number[2..]),
+					Some("0o") => (8, &number[2..]),
+					Some("0b") => (2, &number[2..]),
+					_ => (10, number.as_str()),
+				};
+
+				i128::from_str_radix(&digits.replace('_', ""), radix).ok()
+			}
+
+			Kind::Prefix { operator, operand } => match operator.kind
+			{
+				token::Kind::Minus => operand.integer()?.checked_neg(),
+				token::Kind::Plus => operand.integer(),
+				_ => None,
+			},
+
+			Kind::Parenthesised(expression) => expression.integer(),
+
+			_ => None,
+		}
+	}
+}
+
 /// The kind of an expression.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Kind
 {
 	/// An identifier expression.
@@ -41,6 +85,14 @@ pub enum Kind
 	/// * _parenthesised_ -> `(` _expression_ `)`
 	Parenthesised(Box<Expression>),
 
+	/// A tuple expression.  A comma tells a tuple from a parenthesised
+	/// expression, so a tuple of one value ends with a comma.
+	///
+	/// ### Rule
+	/// * _tuple_ -> `(` _expression_ `,` { _expression_ { `,` _expression_ }*
+	///   `,`? }? `)`
+	Tuple(Items),
+
 	/// A list expression.
 	///
 	/// ### Rule
@@ -80,6 +132,82 @@ pub enum Kind
 	},
 
 	FunctionCall(super::FunctionCall),
+
+	/// An index expression, which selects elements of a list or matrix.
+	///
+	/// ### Rule
+	/// * _index_ -> _primary_ _subscript_+
+	Index
+	{
+		/// The indexed expression.
+		target: Box<Expression>,
+		/// The subscript selecting the elements.
+		subscript: super::Subscript,
+	},
+
+	/// A member access expression, which reads a property of a value.
+	///
+	/// ### Rule
+	/// * _member_ -> _primary_ `.` _identifier_
+	Member
+	{
+		/// The expression whose property is read.
+		target: Box<Expression>,
+		/// The name of the property.
+		name: Token,
+	},
+
+	/// A method call expression, which calls a method of a value.
+	///
+	/// ### Rule
+	/// * _method_ -> _primary_ `.` _identifier_ `(` _items_? `)`
+	MethodCall
+	{
+		/// The expression whose method is called.
+		target: Box<Expression>,
+		/// The name of the method.
+		name: Token,
+		/// The arguments of the method.
+		arguments: Option<Items>,
+	},
+
+	/// An anonymous function expression.
+	///
+	/// ### Rule
+	/// * _lambda_ -> `|` _params_? `|` ( _expression_ | _block_ )
+	Lambda(Box<super::Lambda>),
+
+	/// An enum variant expression, which creates a value of an enum.
+	///
+	/// ### Rule
+	/// * _variant_ -> _identifier_ `::` _identifier_ { `(` _items_? `)` }?
+	Variant
+	{
+		/// The name of the enum.
+		r#enum: Box<Token>,
+		/// The name of the variant.
+		name: Box<Token>,
+		/// The values of the payload of the variant.
+		arguments: Option<Items>,
+	},
+
+	/// An interpolated string expression.
+	///
+	/// ### Rule
+	/// * _interpolation_ -> `"` { _character_ | `{` _expression_ `}` }* `"`
+	Interpolation(Vec<Part>),
+}
+
+/// A part of an interpolated string expression.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Part
+{
+	/// Literal text.
+	Text(String),
+
+	/// An embedded expression.
+	Expression(Expression),
 }
 
 /// An list of items in a Quark programme.
@@ -87,6 +215,7 @@ pub enum Kind
 /// ### Rule
 /// * _items_ -> _expression_ { `,` _expression_ }*
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Items
 {
 	/// The span of the expression.
@@ -0,0 +1,613 @@
+//! Tree-to-tree transformation of the syntax tree.
+//!
+//! Each method of [`Fold`] takes a node by value and returns the node that
+//! replaces it.  By default it rebuilds the node from its folded children
+//! through the `walk_*` function of the same name, so an implementation only
+//! overrides the methods for the nodes it transforms.
+
+use super::declaration::{self, Declaration};
+use super::expression::{self, Expression, Items, Part};
+use super::functions::{Body, Parameter, Params};
+use super::pattern::Pattern;
+use super::statement::{self, Block, EchoStmt, Statement};
+use super::subscript::Index;
+use super::*;
+use crate::language::lexicon::Token;
+
+/// A transformation from one syntax tree to another.
+pub trait Fold
+{
+	/// Folds a programme.
+	fn fold_programme(&mut self, node: Programme) -> Programme
+	{
+		walk_programme(self, node)
+	}
+
+	/// Folds a statement.
+	fn fold_statement(&mut self, node: Statement) -> Statement
+	{
+		walk_statement(self, node)
+	}
+
+	/// Folds a block.
+	fn fold_block(&mut self, node: Block) -> Block
+	{
+		walk_block(self, node)
+	}
+
+	/// Folds a declaration.
+	fn fold_declaration(&mut self, node: Declaration) -> Declaration
+	{
+		walk_declaration(self, node)
+	}
+
+	/// Folds a function declaration.
+	fn fold_function(&mut self, node: FunctionDclr) -> FunctionDclr
+	{
+		walk_function(self, node)
+	}
+
+	/// Folds the parameter list of a function declaration.
+	fn fold_params(&mut self, node: Params) -> Params
+	{
+		walk_params(self, node)
+	}
+
+	/// Folds a parameter of a function declaration.
+	fn fold_parameter(&mut self, node: Parameter) -> Parameter
+	{
+		node
+	}
+
+	/// Folds an `if` statement.
+	fn fold_if(&mut self, node: IfStmt) -> IfStmt
+	{
+		walk_if(self, node)
+	}
+
+	/// Folds an `else if` branch of an `if` statement.
+	fn fold_else_if(&mut self, node: ElseIf) -> ElseIf
+	{
+		walk_else_if(self, node)
+	}
+
+	/// Folds a `match` statement.
+	fn fold_match(&mut self, node: MatchStmt) -> MatchStmt
+	{
+		walk_match(self, node)
+	}
+
+	/// Folds an arm of a `match` statement.
+	fn fold_arm(&mut self, node: Arm) -> Arm
+	{
+		walk_arm(self, node)
+	}
+
+	/// Folds a pattern of a `match` arm.
+	fn fold_pattern(&mut self, node: Pattern) -> Pattern
+	{
+		node
+	}
+
+	/// Folds a `while` statement.
+	fn fold_while(&mut self, node: WhileStmt) -> WhileStmt
+	{
+		walk_while(self, node)
+	}
+
+	/// Folds a `break` statement.
+	fn fold_break(&mut self, node: BreakStmt) -> BreakStmt
+	{
+		node
+	}
+
+	/// Folds a `continue` statement.
+	fn fold_continue(&mut self, node: ContinueStmt) -> ContinueStmt
+	{
+		node
+	}
+
+	/// Folds a `return` statement.
+	fn fold_return(&mut self, node: ReturnStmt) -> ReturnStmt
+	{
+		walk_return(self, node)
+	}
+
+	/// Folds an assignment statement.
+	fn fold_assignment(&mut self, node: AssignmentStmt) -> AssignmentStmt
+	{
+		walk_assignment(self, node)
+	}
+
+	/// Folds an `echo` statement.
+	fn fold_echo(&mut self, node: EchoStmt) -> EchoStmt
+	{
+		walk_echo(self, node)
+	}
+
+	/// Folds an `import` statement.
+	fn fold_import(&mut self, node: ImportStmt) -> ImportStmt
+	{
+		node
+	}
+
+	/// Folds a `use` statement.
+	fn fold_use(&mut self, node: UseStmt) -> UseStmt
+	{
+		node
+	}
+
+	/// Folds a function call.
+	fn fold_function_call(&mut self, node: FunctionCall) -> FunctionCall
+	{
+		walk_function_call(self, node)
+	}
+
+	/// Folds a lambda.
+	fn fold_lambda(&mut self, node: Lambda) -> Lambda
+	{
+		walk_lambda(self, node)
+	}
+
+	/// Folds an expression.
+	fn fold_expression(&mut self, node: Expression) -> Expression
+	{
+		walk_expression(self, node)
+	}
+
+	/// Folds a comma separated list of expressions.
+	fn fold_items(&mut self, node: Items) -> Items
+	{
+		walk_items(self, node)
+	}
+
+	/// Folds a part of an interpolated string.
+	fn fold_part(&mut self, node: Part) -> Part
+	{
+		walk_part(self, node)
+	}
+
+	/// Folds the subscript of an index expression or assignment.
+	fn fold_subscript(&mut self, node: Subscript) -> Subscript
+	{
+		walk_subscript(self, node)
+	}
+
+	/// Folds an index of a subscript.
+	fn fold_index(&mut self, node: Index) -> Index
+	{
+		walk_index(self, node)
+	}
+
+	/// Folds the token of an identifier, literal or operator.
+	fn fold_token(&mut self, node: Token) -> Token
+	{
+		node
+	}
+}
+
+/// Rebuilds a programme from its folded statements.
+pub fn walk_programme<F>(folder: &mut F, node: Programme) -> Programme
+where F: Fold + ?Sized
+{
+	Programme {
+		span: node.span,
+		statements: node
+			.statements
+			.into_iter()
+			.map(|statement| folder.fold_statement(statement))
+			.collect(),
+	}
+}
+
+/// Rebuilds a statement from the folded node it holds.
+pub fn walk_statement<F>(folder: &mut F, node: Statement) -> Statement
+where F: Fold + ?Sized
+{
+	use statement::Kind::*;
+
+	let kind = match node.kind
+	{
+		Declaration(node) => Declaration(folder.fold_declaration(node)),
+		Expression(node) => Expression(folder.fold_expression(node)),
+		If(node) => If(folder.fold_if(node)),
+		While(node) => While(folder.fold_while(node)),
+		Match(node) => Match(folder.fold_match(node)),
+		Break(node) => Break(folder.fold_break(node)),
+		Continue(node) => Continue(folder.fold_continue(node)),
+		Return(node) => Return(folder.fold_return(node)),
+		Assignment(node) => Assignment(folder.fold_assignment(node)),
+		Echo(node) => Echo(folder.fold_echo(node)),
+		Import(node) => Import(folder.fold_import(node)),
+		Use(node) => Use(folder.fold_use(node)),
+		FunctionCall(node) => FunctionCall(folder.fold_function_call(node)),
+	};
+
+	Statement {
+		span: node.span,
+		kind,
+	}
+}
+
+/// Rebuilds a block from its folded statements.
+pub fn walk_block<F>(folder: &mut F, node: Block) -> Block
+where F: Fold + ?Sized
+{
+	Block {
+		span: node.span,
+		statements: node
+			.statements
+			.into_iter()
+			.map(|statement| {
+				statement.map(|statement| folder.fold_statement(statement))
+			})
+			.collect(),
+	}
+}
+
+/// Rebuilds a declaration from its folded function or value.
+pub fn walk_declaration<F>(folder: &mut F, node: Declaration) -> Declaration
+where F: Fold + ?Sized
+{
+	let kind = match node.kind
+	{
+		declaration::Kind::Function(function) =>
+		{
+			declaration::Kind::Function(folder.fold_function(function))
+		}
+
+		declaration::Kind::Variable {
+			name,
+			value,
+			is_mutable,
+		} => declaration::Kind::Variable {
+			name,
+			value: folder.fold_expression(value),
+			is_mutable,
+		},
+
+		declaration::Kind::Destructuring {
+			names,
+			value,
+			is_mutable,
+		} => declaration::Kind::Destructuring {
+			names,
+			value: folder.fold_expression(value),
+			is_mutable,
+		},
+
+		kind @ (declaration::Kind::Struct(_) | declaration::Kind::Enum(_)) => kind,
+	};
+
+	Declaration { kind, ..node }
+}
+
+/// Rebuilds a function declaration from its folded parameters and body.
+pub fn walk_function<F>(folder: &mut F, node: FunctionDclr) -> FunctionDclr
+where F: Fold + ?Sized
+{
+	FunctionDclr {
+		parameters: node
+			.parameters
+			.map(|parameters| folder.fold_params(parameters)),
+		body: folder.fold_block(node.body),
+		..node
+	}
+}
+
+/// Rebuilds a parameter list from its folded parameters.
+pub fn walk_params<F>(folder: &mut F, node: Params) -> Params
+where F: Fold + ?Sized
+{
+	Params {
+		span: node.span,
+		parameters: node
+			.parameters
+			.into_iter()
+			.map(|parameter| folder.fold_parameter(parameter))
+			.collect(),
+	}
+}
+
+/// Rebuilds an `if` statement from its folded condition and every branch.
+pub fn walk_if<F>(folder: &mut F, node: IfStmt) -> IfStmt
+where F: Fold + ?Sized
+{
+	IfStmt {
+		span: node.span,
+		condition: folder.fold_expression(node.condition),
+		body: folder.fold_block(node.body),
+		else_ifs: node
+			.else_ifs
+			.into_iter()
+			.map(|else_if| folder.fold_else_if(else_if))
+			.collect(),
+		else_body: node.else_body.map(|body| folder.fold_block(body)),
+	}
+}
+
+/// Rebuilds an `else if` branch from its folded condition and body.
+pub fn walk_else_if<F>(folder: &mut F, node: ElseIf) -> ElseIf
+where F: Fold + ?Sized
+{
+	ElseIf {
+		span: node.span,
+		condition: folder.fold_expression(node.condition),
+		body: folder.fold_block(node.body),
+	}
+}
+
+/// Rebuilds a `match` statement from its folded expression and arms.
+pub fn walk_match<F>(folder: &mut F, node: MatchStmt) -> MatchStmt
+where F: Fold + ?Sized
+{
+	MatchStmt {
+		span: node.span,
+		scrutinee: folder.fold_expression(node.scrutinee),
+		arms: node
+			.arms
+			.into_iter()
+			.map(|arm| folder.fold_arm(arm))
+			.collect(),
+	}
+}
+
+/// Rebuilds a `match` arm from its folded patterns, guard and body.
+pub fn walk_arm<F>(folder: &mut F, node: Arm) -> Arm
+where F: Fold + ?Sized
+{
+	Arm {
+		span: node.span,
+		patterns: node
+			.patterns
+			.into_iter()
+			.map(|pattern| folder.fold_pattern(pattern))
+			.collect(),
+		guard: node.guard.map(|guard| folder.fold_expression(guard)),
+		body: folder.fold_block(node.body),
+	}
+}
+
+/// Rebuilds a `while` statement from its folded condition and body.
+pub fn walk_while<F>(folder: &mut F, node: WhileStmt) -> WhileStmt
+where F: Fold + ?Sized
+{
+	WhileStmt {
+		span: node.span,
+		condition: folder.fold_expression(node.condition),
+		body: folder.fold_block(node.body),
+	}
+}
+
+/// Rebuilds a `return` statement from its folded value.
+pub fn walk_return<F>(folder: &mut F, node: ReturnStmt) -> ReturnStmt
+where F: Fold + ?Sized
+{
+	ReturnStmt {
+		span: node.span,
+		expression: folder.fold_expression(node.expression),
+	}
+}
+
+/// Rebuilds an assignment statement from its folded subscript and value.
+pub fn walk_assignment<F>(
+	folder: &mut F,
+	node: AssignmentStmt,
+) -> AssignmentStmt
+where
+	F: Fold + ?Sized,
+{
+	AssignmentStmt {
+		subscript: node
+			.subscript
+			.map(|subscript| folder.fold_subscript(subscript)),
+		expression: folder.fold_expression(node.expression),
+		..node
+	}
+}
+
+/// Rebuilds an `echo` statement from its folded arguments.
+pub fn walk_echo<F>(folder: &mut F, node: EchoStmt) -> EchoStmt
+where F: Fold + ?Sized
+{
+	EchoStmt {
+		span: node.span,
+		arguments: node
+			.arguments
+			.into_iter()
+			.map(|argument| folder.fold_expression(argument))
+			.collect(),
+	}
+}
+
+/// Rebuilds a function call from its folded arguments.
+pub fn walk_function_call<F>(
+	folder: &mut F,
+	node: FunctionCall,
+) -> FunctionCall
+where
+	F: Fold + ?Sized,
+{
+	FunctionCall {
+		arguments: node.arguments.map(|arguments| folder.fold_items(arguments)),
+		..node
+	}
+}
+
+/// Rebuilds an expression from its folded tokens and operands.
+pub fn walk_expression<F>(folder: &mut F, node: Expression) -> Expression
+where F: Fold + ?Sized
+{
+	use expression::Kind::*;
+
+	let mut rows = |rows: Vec<Option<Items>>| {
+		rows
+			.into_iter()
+			.map(|items| items.map(|items| folder.fold_items(items)))
+			.collect()
+	};
+
+	let kind = match node.kind
+	{
+		Identifier(token) => Identifier(folder.fold_token(token)),
+		Literal(token) => Literal(folder.fold_token(token)),
+		List(items) => List(rows(items)),
+		Matrix(items) => Matrix(rows(items)),
+
+		Parenthesised(expression) =>
+		{
+			Parenthesised(Box::new(folder.fold_expression(*expression)))
+		}
+
+		Tuple(items) => Tuple(folder.fold_items(items)),
+
+		Prefix { operator, operand } => Prefix {
+			operator: folder.fold_token(operator),
+			operand: Box::new(folder.fold_expression(*operand)),
+		},
+
+		Infix {
+			left,
+			operator,
+			right,
+		} => Infix {
+			left: Box::new(folder.fold_expression(*left)),
+			operator: folder.fold_token(operator),
+			right: Box::new(folder.fold_expression(*right)),
+		},
+
+		FunctionCall(call) => FunctionCall(folder.fold_function_call(call)),
+
+		Index { target, subscript } => Index {
+			target: Box::new(folder.fold_expression(*target)),
+			subscript: folder.fold_subscript(subscript),
+		},
+
+		Member { target, name } => Member {
+			target: Box::new(folder.fold_expression(*target)),
+			name,
+		},
+
+		MethodCall {
+			target,
+			name,
+			arguments,
+		} => MethodCall {
+			target: Box::new(folder.fold_expression(*target)),
+			name,
+			arguments: arguments.map(|arguments| folder.fold_items(arguments)),
+		},
+
+		Lambda(lambda) => Lambda(Box::new(folder.fold_lambda(*lambda))),
+
+		Variant {
+			r#enum,
+			name,
+			arguments,
+		} => Variant {
+			r#enum,
+			name,
+			arguments: arguments.map(|arguments| folder.fold_items(arguments)),
+		},
+
+		Interpolation(parts) => Interpolation(
+			parts
+				.into_iter()
+				.map(|part| folder.fold_part(part))
+				.collect(),
+		),
+	};
+
+	Expression {
+		span: node.span,
+		kind,
+	}
+}
+
+/// Rebuilds a lambda from its folded parameters and body.
+pub fn walk_lambda<F>(folder: &mut F, node: Lambda) -> Lambda
+where F: Fold + ?Sized
+{
+	let body = match node.body
+	{
+		Body::Expression(expression) =>
+		{
+			Body::Expression(Box::new(folder.fold_expression(*expression)))
+		}
+		Body::Block(block) => Body::Block(folder.fold_block(block)),
+	};
+
+	Lambda {
+		span: node.span,
+		parameters: node
+			.parameters
+			.map(|parameters| folder.fold_params(parameters)),
+		body,
+	}
+}
+
+/// Rebuilds a list of items from its folded expressions.
+pub fn walk_items<F>(folder: &mut F, node: Items) -> Items
+where F: Fold + ?Sized
+{
+	Items {
+		span: node.span,
+		expressions: node
+			.expressions
+			.into_iter()
+			.map(|expression| folder.fold_expression(expression))
+			.collect(),
+	}
+}
+
+/// Rebuilds a part of an interpolated string from its folded expression.
+pub fn walk_part<F>(folder: &mut F, node: Part) -> Part
+where F: Fold + ?Sized
+{
+	match node
+	{
+		Part::Expression(expression) =>
+		{
+			Part::Expression(folder.fold_expression(expression))
+		}
+		Part::Text(text) => Part::Text(text),
+	}
+}
+
+/// Rebuilds a subscript from its folded indices.
+pub fn walk_subscript<F>(folder: &mut F, node: Subscript) -> Subscript
+where F: Fold + ?Sized
+{
+	Subscript {
+		span: node.span,
+		indices: node
+			.indices
+			.into_iter()
+			.map(|index| folder.fold_index(index))
+			.collect(),
+	}
+}
+
+/// Rebuilds an index from its folded position or bounds.
+pub fn walk_index<F>(folder: &mut F, node: Index) -> Index
+where F: Fold + ?Sized
+{
+	match node
+	{
+		Index::Position(position) =>
+		{
+			Index::Position(folder.fold_expression(position))
+		}
+		Index::Slice {
+			span,
+			start,
+			end,
+			is_inclusive,
+		} => Index::Slice {
+			span,
+			start: start.map(|start| folder.fold_expression(start)),
+			end: end.map(|end| folder.fold_expression(end)),
+			is_inclusive,
+		},
+	}
+}
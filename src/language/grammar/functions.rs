@@ -3,6 +3,7 @@ use crate::language::utils::Span;
 use super::{semantics::r#type::Type, Block, Expression};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReturnStmt
 {
 	pub span: Span,
@@ -10,25 +11,193 @@ pub struct ReturnStmt
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FunctionDclr
 {
 	pub span: Span,
 	pub name: String,
+	/// The names of the type and size parameters of a generic function, as in
+	/// `func first<T>(xs: List<T>) -> T`.
+	pub generics: Vec<String>,
 	pub body: Block,
 	pub return_type: Type,
 	pub parameters: Option<Params>,
 }
 
+/// An anonymous function expression, such as `|x| x * 2`.
+///
+/// ### Rule
+/// * _lambda_ -> `|` _params_? `|` ( _expression_ | _block_ )
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lambda
+{
+	/// The span of the lambda.
+	pub span: Span,
+
+	/// The parameters of the lambda.
+	pub parameters: Option<Params>,
+
+	/// The body of the lambda.
+	pub body: Body,
+}
+
+/// The body of a lambda.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Body
+{
+	/// An expression whose value the lambda returns.
+	Expression(Box<Expression>),
+
+	/// A block of statements that returns with `return`.
+	Block(Block),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Parameter
 {
 	pub span: Span,
 	pub name: String,
+	/// The type the parameter is annotated with, or `Unit` if it is not.
+	pub r#type: Type,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Params
 {
 	pub span: Span,
 	pub parameters: Vec<Parameter>,
 }
+
+impl FunctionDclr
+{
+	/// Creates the signature of the function as it would be written in Quark.
+	///
+	/// ### Returns
+	/// * The signature, such as `func add(a, b) -> Number` or of a generic
+	///   function, such as `func first<T>(xs: List<T>) -> T`.
+	pub fn signature(&self) -> String
+	{
+		self.signature_of(&self.r#type())
+	}
+
+	/// Creates the signature of the function with the types of its parameters
+	/// and result taken from a function type, such as one inferred from its
+	/// body.
+	///
+	/// ### Parameters
+	/// * `type` - The type of the function.
+	///
+	/// ### Returns
+	/// * The signature, such as `func double(x: Number) -> Number`.
+	pub fn signature_of(&self, r#type: &Type) -> String
+	{
+		let (types, returns) = match r#type
+		{
+			Type::Function {
+				parameters,
+				returns,
+			} => (parameters.clone(), returns.as_ref().clone()),
+			_ => (types(&self.parameters), self.return_type.clone()),
+		};
+
+		let parameters = self
+			.parameters
+			.iter()
+			.flat_map(|parameters| &parameters.parameters)
+			.zip(types.iter().chain(std::iter::repeat(&Type::Unit)))
+			.map(|(parameter, r#type)| parameter.signature_of(r#type))
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		let name = match self.generics.is_empty()
+		{
+			true => self.name.clone(),
+			false => format!("{}<{}>", self.name, self.generics.join(", ")),
+		};
+
+		match returns
+		{
+			Type::Unit => format!("func {name}({parameters})"),
+			r#type => format!("func {name}({parameters}) -> {type}"),
+		}
+	}
+
+	/// Gets the type of the function as a value.
+	///
+	/// ### Returns
+	/// * The function type, with a `Unit` parameter for each parameter that is
+	///   not annotated.
+	pub fn r#type(&self) -> Type
+	{
+		Type::Function {
+			parameters: types(&self.parameters),
+			returns: Box::new(self.return_type.clone()),
+		}
+	}
+}
+
+impl Parameter
+{
+	/// Creates the parameter as it would be written in Quark.
+	///
+	/// ### Returns
+	/// * The name of the parameter, followed by its type if it is annotated, such
+	///   as `xs: List<T>`.
+	pub fn signature(&self) -> String
+	{
+		self.signature_of(&self.r#type)
+	}
+
+	/// Creates the parameter as it would be written with a type, such as one
+	/// inferred from the body of its function.
+	///
+	/// ### Parameters
+	/// * `type` - The type of the parameter.
+	///
+	/// ### Returns
+	/// * The name of the parameter, followed by the type unless it is `Unit`.
+	pub fn signature_of(&self, r#type: &Type) -> String
+	{
+		match r#type
+		{
+			Type::Unit => self.name.clone(),
+			r#type => format!("{}: {type}", self.name),
+		}
+	}
+}
+
+impl Lambda
+{
+	/// Gets the type of the lambda as a value.
+	///
+	/// ### Returns
+	/// * The function type, with a `Unit` parameter for each parameter that is
+	///   not annotated and a `Unit` result since it cannot be annotated.
+	pub fn r#type(&self) -> Type
+	{
+		Type::Function {
+			parameters: types(&self.parameters),
+			returns: Box::new(Type::Unit),
+		}
+	}
+}
+
+/// Gets the types of the parameters of a function or lambda.
+///
+/// ### Parameters
+/// * `parameters` - The parameters, if there are any.
+///
+/// ### Returns
+/// * The type of each parameter, in order.
+fn types(parameters: &Option<Params>) -> Vec<Type>
+{
+	parameters
+		.iter()
+		.flat_map(|parameters| &parameters.parameters)
+		.map(|parameter| parameter.r#type.clone())
+		.collect()
+}
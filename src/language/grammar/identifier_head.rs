@@ -1,16 +1,27 @@
 use crate::language::utils::Span;
 
-use super::{expression::Items, Expression};
+use super::{expression::Items, Expression, Subscript};
 
+/// An assignment to a variable or to elements of it.
+///
+/// ### Rule
+/// * _assignment_ -> _identifier_ _subscript_? `=` _expression_
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AssignmentStmt
 {
 	pub span: Span,
 	pub identifier: String,
+
+	/// The subscript selecting the assigned elements, or `None` when the
+	/// whole variable is assigned.
+	pub subscript: Option<Subscript>,
+
 	pub expression: Expression,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FunctionCall
 {
 	pub span: Span,
@@ -1,24 +1,46 @@
+pub mod concrete;
 pub mod controlflow;
 pub mod declaration;
 pub mod expression;
+pub mod fold;
 pub mod functions;
 pub mod identifier_head;
+pub mod module;
+pub mod pattern;
 pub mod programme;
 pub mod statement;
+pub mod subscript;
+pub mod types;
+pub mod visit;
+pub mod visit_mut;
 
 pub use super::*;
 
+pub use concrete::SyntaxTree;
+pub use controlflow::Arm;
 pub use controlflow::BreakStmt;
 pub use controlflow::ContinueStmt;
+pub use controlflow::ElseIf;
 pub use controlflow::IfStmt;
+pub use controlflow::MatchStmt;
 pub use controlflow::WhileStmt;
 pub use declaration::Declaration;
 pub use expression::Expression;
+pub use fold::Fold;
 pub use functions::FunctionDclr;
+pub use functions::Lambda;
 pub use functions::ReturnStmt;
 pub use identifier_head::AssignmentStmt;
 pub use identifier_head::FunctionCall;
+pub use module::ImportStmt;
+pub use module::UseStmt;
+pub use pattern::Pattern;
 pub use programme::Programme;
 pub use statement::Block;
 pub use statement::EchoStmt;
 pub use statement::Statement;
+pub use subscript::Subscript;
+pub use types::EnumDclr;
+pub use types::StructDclr;
+pub use visit::Visit;
+pub use visit_mut::VisitMut;
@@ -0,0 +1,65 @@
+use crate::language::utils::Span;
+
+/// An import statement in a Quark programme.
+///
+/// ### Rule
+/// * _import_ -> `import` _string_ `;`
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImportStmt
+{
+	/// The span of the statement.
+	pub span: Span,
+
+	/// The path of the imported file, relative to the importing file.
+	pub path: String,
+
+	/// The name the imported module is bound to (the file stem).
+	pub name: String,
+
+	/// The dotted Python module path of the imported file.
+	pub module: String,
+}
+
+/// A use statement in a Quark programme.
+///
+/// ### Rule
+/// * _use_ -> `use` _identifier_ `::` _identifier_ `;`
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UseStmt
+{
+	/// The span of the statement.
+	pub span: Span,
+
+	/// The name of the module the item is taken from.
+	pub module: String,
+
+	/// The name of the item brought into scope.
+	pub name: String,
+}
+
+impl ImportStmt
+{
+	/// Creates an import statement from the path of the imported file.
+	///
+	/// ### Parameters
+	/// * `span` - The span of the statement.
+	/// * `path` - The path of the imported file.
+	///
+	/// ### Returns
+	/// * The import statement, with its module path derived from `path`.
+	pub fn new(span: Span, path: String) -> Self
+	{
+		let stem = path.strip_suffix(".q").unwrap_or(&path);
+		let module = stem.replace('/', ".");
+		let name = module.rsplit('.').next().unwrap_or_default().to_string();
+
+		Self {
+			span,
+			path,
+			name,
+			module,
+		}
+	}
+}
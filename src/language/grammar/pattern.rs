@@ -0,0 +1,138 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::language::lexicon::{token, Token};
+use crate::language::utils::Span;
+
+/// A pattern in an arm of a match statement.
+///
+/// ### Rule
+/// * _pattern_ -> `_` | _literal_ | _bound_ { `..` | `..=` } _bound_ |
+///   _variant_
+/// * _bound_ -> `-`? _number_
+/// * _variant_ -> _identifier_ `::` _identifier_ { `(` _identifier_ { `,`
+///   _identifier_ }* `)` }?
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pattern
+{
+	/// The span of the pattern.
+	pub span: Span,
+
+	/// The kind of the pattern.
+	pub kind: Kind,
+}
+
+/// The kind of a pattern.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Kind
+{
+	/// The `_` pattern, which matches any value.
+	Wildcard,
+
+	/// A literal pattern, which matches a value equal to the literal.
+	///
+	/// A binary number literal such as `0b01` is a bit-string pattern, whose
+	/// width is its number of digits.
+	Literal(Token),
+
+	/// A range pattern, which matches a number from the start of the range up
+	/// to its end.
+	Range
+	{
+		/// The number the range starts at.
+		start: Token,
+
+		/// The number the range ends at.
+		end: Token,
+
+		/// Whether the range includes its end.
+		is_inclusive: bool,
+	},
+
+	/// A variant pattern, which matches a value of an enum that is the variant
+	/// and binds the values of its payload to names.
+	Variant
+	{
+		/// The name of the enum.
+		r#enum: Token,
+
+		/// The name of the variant.
+		name: Token,
+
+		/// The names the values of the payload are bound to, in order, where
+		/// `_` binds nothing.
+		bindings: Vec<Token>,
+	},
+}
+
+impl Pattern
+{
+	/// Gets the width of a bit-string pattern.
+	///
+	/// ### Returns
+	/// * The number of digits of the pattern if it is a bit-string, such as `2`
+	///   for `0b01`.
+	/// * `None` otherwise.
+	pub fn width(&self) -> Option<usize>
+	{
+		match &self.kind
+		{
+			Kind::Literal(Token {
+				kind: token::Kind::Number(number),
+				..
+			}) => number.strip_prefix("0b").map(str::len),
+			_ => None,
+		}
+	}
+}
+
+impl Display for Pattern
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		let text = |token: &Token| match &token.kind
+		{
+			token::Kind::Number(number) => number.clone(),
+			token::Kind::String(string) => format!("{string:?}"),
+			token::Kind::Boolean(boolean) => boolean.to_string(),
+			_ => unreachable!(),
+		};
+
+		match &self.kind
+		{
+			Kind::Wildcard => write!(formatter, "_"),
+			Kind::Literal(token) => write!(formatter, "{}", text(token)),
+			Kind::Range {
+				start,
+				end,
+				is_inclusive,
+			} =>
+			{
+				let operator = if *is_inclusive { "..=" } else { ".." };
+				write!(formatter, "{}{operator}{}", text(start), text(end))
+			}
+			Kind::Variant {
+				r#enum,
+				name,
+				bindings,
+			} =>
+			{
+				let r#enum = r#enum.identifier().unwrap_or_default();
+				let name = name.identifier().unwrap_or_default();
+				write!(formatter, "{enum}::{name}")?;
+
+				if !bindings.is_empty()
+				{
+					let bindings: Vec<_> = bindings
+						.iter()
+						.map(|binding| binding.identifier().unwrap_or_default())
+						.collect();
+					write!(formatter, "({})", bindings.join(", "))?;
+				}
+
+				Ok(())
+			}
+		}
+	}
+}
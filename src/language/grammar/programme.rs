@@ -5,6 +5,8 @@ use crate::language::utils::Span;
 ///
 /// ### Rule
 /// * _programme_ -> _statement_*
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Programme
 {
 	/// The span of the programme.
@@ -7,6 +7,7 @@ use super::Expression;
 /// ### Rule
 /// * _statement_ -> { _declaration_ | _expression_ } `;`
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Statement
 {
 	/// The span of the statement.
@@ -18,6 +19,7 @@ pub struct Statement
 
 /// The kind of a statement.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Kind
 {
 	/// A declaration statement.
@@ -32,6 +34,9 @@ pub enum Kind
 	/// A `While` Conditional statemtent
 	While(super::WhileStmt),
 
+	/// A `match` statement.
+	Match(super::MatchStmt),
+
 	/// A break statement
 	Break(super::BreakStmt),
 
@@ -44,16 +49,24 @@ pub enum Kind
 
 	Echo(EchoStmt),
 
+	/// An `import` statement.
+	Import(super::ImportStmt),
+
+	/// A `use` statement.
+	Use(super::UseStmt),
+
 	FunctionCall(super::FunctionCall),
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Block
 {
 	pub span: Span,
 	pub statements: Vec<Option<Statement>>,
 }
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EchoStmt
 {
 	pub span: Span,
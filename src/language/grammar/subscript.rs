@@ -0,0 +1,64 @@
+use super::Expression;
+use crate::language::utils::Span;
+
+/// A subscript that selects elements of a list, a matrix or a register.
+///
+/// ### Rule
+/// * _subscript_ -> `[` _index_ { `,` _index_ }* `]`
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Subscript
+{
+	/// The span of the subscript, from `[` to `]`.
+	pub span: Span,
+
+	/// The indices of the subscript, one per dimension.
+	pub indices: Vec<Index>,
+}
+
+/// An index of a subscript.
+///
+/// ### Rule
+/// * _index_ -> _expression_ | `:` | _expression_? { `..` | `..=` }
+///   _expression_?
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Index
+{
+	/// A single position along a dimension.
+	Position(Expression),
+
+	/// A range of positions along a dimension.
+	///
+	/// `:` and `..` take every position.
+	Slice
+	{
+		/// The span of the slice.
+		span: Span,
+
+		/// The position the slice starts at, or `None` to start at the first.
+		start: Option<Expression>,
+
+		/// The position the slice ends at, or `None` to end after the last.
+		end: Option<Expression>,
+
+		/// Whether the slice includes its end.
+		is_inclusive: bool,
+	},
+}
+
+impl Index
+{
+	/// Gets the span of the index.
+	///
+	/// ### Returns
+	/// * The span of the position or slice.
+	pub fn span(&self) -> Span
+	{
+		match self
+		{
+			Self::Position(expression) => expression.span,
+			Self::Slice { span, .. } => *span,
+		}
+	}
+}
@@ -0,0 +1,176 @@
+use crate::language::semantics::r#type::Type;
+use crate::language::utils::Span;
+
+/// A struct declaration, which declares a record type with named fields.
+///
+/// ### Rule
+/// * _struct_ -> `struct` _identifier_ `{` { _field_ { `,` _field_ }* `,`? }?
+///   `}`
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StructDclr
+{
+	/// The span of the declaration.
+	pub span: Span,
+
+	/// The name of the struct.
+	pub name: String,
+
+	/// The fields of the struct, in the order its constructor takes them.
+	pub fields: Vec<Field>,
+}
+
+/// A field of a struct.
+///
+/// ### Rule
+/// * _field_ -> _identifier_ `:` _identifier_
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Field
+{
+	/// The span of the field.
+	pub span: Span,
+
+	/// The name of the field.
+	pub name: String,
+
+	/// The type of the field.
+	pub r#type: Type,
+}
+
+/// An enum declaration, which declares a sum type whose values are one of its
+/// variants.
+///
+/// ### Rule
+/// * _enum_ -> `enum` _identifier_ `{` { _variant_ { `,` _variant_ }* `,`? }?
+///   `}`
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnumDclr
+{
+	/// The span of the declaration.
+	pub span: Span,
+
+	/// The name of the enum.
+	pub name: String,
+
+	/// The variants of the enum.
+	pub variants: Vec<Variant>,
+}
+
+/// A variant of an enum, which may carry a payload.
+///
+/// ### Rule
+/// * _variant_ -> _identifier_ { `(` _identifier_ { `,` _identifier_ }* `)` }?
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Variant
+{
+	/// The span of the variant.
+	pub span: Span,
+
+	/// The name of the variant.
+	pub name: String,
+
+	/// The types of the values of the payload, in order.
+	pub payload: Vec<Type>,
+}
+
+impl StructDclr
+{
+	/// Creates the signature of the struct as it would be written in Quark.
+	///
+	/// ### Returns
+	/// * The signature, such as `struct Run { shots: Number, label: String }`.
+	pub fn signature(&self) -> String
+	{
+		let fields = self
+			.fields
+			.iter()
+			.map(|field| format!("{}: {}", field.name, field.r#type))
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		match fields.is_empty()
+		{
+			true => format!("struct {} {{}}", self.name),
+			false => format!("struct {} {{ {fields} }}", self.name),
+		}
+	}
+
+	/// Gets the type of the constructor of the struct, which takes a value for
+	/// each field.
+	///
+	/// ### Returns
+	/// * The function type.
+	pub fn constructor(&self) -> Type
+	{
+		Type::Function {
+			parameters: self
+				.fields
+				.iter()
+				.map(|field| field.r#type.clone())
+				.collect(),
+			returns: Box::new(Type::Named(self.name.clone())),
+		}
+	}
+}
+
+impl EnumDclr
+{
+	/// Creates the signature of the enum as it would be written in Quark.
+	///
+	/// ### Returns
+	/// * The signature, such as `enum Outcome { Zero, Mixed(Number) }`.
+	pub fn signature(&self) -> String
+	{
+		let variants = self
+			.variants
+			.iter()
+			.map(Variant::signature)
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		match variants.is_empty()
+		{
+			true => format!("enum {} {{}}", self.name),
+			false => format!("enum {} {{ {variants} }}", self.name),
+		}
+	}
+
+	/// Looks up a variant of the enum.
+	///
+	/// ### Parameters
+	/// * `name` - The name of the variant.
+	///
+	/// ### Returns
+	/// * The variant, or `None` if the enum has no variant of that name.
+	pub fn variant(&self, name: &str) -> Option<&Variant>
+	{
+		self.variants.iter().find(|variant| variant.name == name)
+	}
+}
+
+impl Variant
+{
+	/// Creates the signature of the variant as it would be written in Quark.
+	///
+	/// ### Returns
+	/// * The signature, such as `Mixed(Number, Number)` or `Zero`.
+	pub fn signature(&self) -> String
+	{
+		if self.payload.is_empty()
+		{
+			return self.name.clone();
+		}
+
+		let payload = self
+			.payload
+			.iter()
+			.map(ToString::to_string)
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		format!("{}({payload})", self.name)
+	}
+}
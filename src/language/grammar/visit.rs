@@ -0,0 +1,498 @@
+//! Read-only traversal of the syntax tree.
+//!
+//! Each method of [`Visit`] is called on one kind of node and, by default,
+//! walks into the node's children through the `walk_*` function of the same
+//! name.  An implementation overrides the methods for the nodes it cares about
+//! and calls the `walk_*` function from them to keep descending.
+
+use super::declaration::{self, Declaration};
+use super::expression::{self, Expression, Items, Part};
+use super::functions::{Body, Parameter, Params};
+use super::pattern::Pattern;
+use super::statement::{self, Block, EchoStmt, Statement};
+use super::subscript::Index;
+use super::*;
+use crate::language::lexicon::Token;
+
+/// A read-only visitor of the syntax tree.
+///
+/// The `'ast` lifetime lets an implementation keep references to the nodes it
+/// visits.
+pub trait Visit<'ast>
+{
+	/// Visits a programme.
+	fn visit_programme(&mut self, node: &'ast Programme)
+	{
+		walk_programme(self, node)
+	}
+
+	/// Visits a statement.
+	fn visit_statement(&mut self, node: &'ast Statement)
+	{
+		walk_statement(self, node)
+	}
+
+	/// Visits a block.
+	fn visit_block(&mut self, node: &'ast Block)
+	{
+		walk_block(self, node)
+	}
+
+	/// Visits a declaration.
+	fn visit_declaration(&mut self, node: &'ast Declaration)
+	{
+		walk_declaration(self, node)
+	}
+
+	/// Visits a function declaration.
+	fn visit_function(&mut self, node: &'ast FunctionDclr)
+	{
+		walk_function(self, node)
+	}
+
+	/// Visits the parameter list of a function declaration.
+	fn visit_params(&mut self, node: &'ast Params)
+	{
+		walk_params(self, node)
+	}
+
+	/// Visits a parameter of a function declaration.
+	fn visit_parameter(&mut self, _node: &'ast Parameter) {}
+
+	/// Visits an `if` statement.
+	fn visit_if(&mut self, node: &'ast IfStmt)
+	{
+		walk_if(self, node)
+	}
+
+	/// Visits an `else if` branch of an `if` statement.
+	fn visit_else_if(&mut self, node: &'ast ElseIf)
+	{
+		walk_else_if(self, node)
+	}
+
+	/// Visits a `match` statement.
+	fn visit_match(&mut self, node: &'ast MatchStmt)
+	{
+		walk_match(self, node)
+	}
+
+	/// Visits an arm of a `match` statement.
+	fn visit_arm(&mut self, node: &'ast Arm)
+	{
+		walk_arm(self, node)
+	}
+
+	/// Visits a pattern of a `match` arm.
+	fn visit_pattern(&mut self, _node: &'ast Pattern) {}
+
+	/// Visits a `while` statement.
+	fn visit_while(&mut self, node: &'ast WhileStmt)
+	{
+		walk_while(self, node)
+	}
+
+	/// Visits a `break` statement.
+	fn visit_break(&mut self, _node: &'ast BreakStmt) {}
+
+	/// Visits a `continue` statement.
+	fn visit_continue(&mut self, _node: &'ast ContinueStmt) {}
+
+	/// Visits a `return` statement.
+	fn visit_return(&mut self, node: &'ast ReturnStmt)
+	{
+		walk_return(self, node)
+	}
+
+	/// Visits an assignment statement.
+	fn visit_assignment(&mut self, node: &'ast AssignmentStmt)
+	{
+		walk_assignment(self, node)
+	}
+
+	/// Visits an `echo` statement.
+	fn visit_echo(&mut self, node: &'ast EchoStmt)
+	{
+		walk_echo(self, node)
+	}
+
+	/// Visits an `import` statement.
+	fn visit_import(&mut self, _node: &'ast ImportStmt) {}
+
+	/// Visits a `use` statement.
+	fn visit_use(&mut self, _node: &'ast UseStmt) {}
+
+	/// Visits a function call.
+	fn visit_function_call(&mut self, node: &'ast FunctionCall)
+	{
+		walk_function_call(self, node)
+	}
+
+	/// Visits a lambda.
+	fn visit_lambda(&mut self, node: &'ast Lambda)
+	{
+		walk_lambda(self, node)
+	}
+
+	/// Visits an expression.
+	fn visit_expression(&mut self, node: &'ast Expression)
+	{
+		walk_expression(self, node)
+	}
+
+	/// Visits a comma separated list of expressions.
+	fn visit_items(&mut self, node: &'ast Items)
+	{
+		walk_items(self, node)
+	}
+
+	/// Visits a part of an interpolated string.
+	fn visit_part(&mut self, node: &'ast Part)
+	{
+		walk_part(self, node)
+	}
+
+	/// Visits the subscript of an index expression or assignment.
+	fn visit_subscript(&mut self, node: &'ast Subscript)
+	{
+		walk_subscript(self, node)
+	}
+
+	/// Visits an index of a subscript.
+	fn visit_index(&mut self, node: &'ast Index)
+	{
+		walk_index(self, node)
+	}
+
+	/// Visits the token of an identifier, literal or operator.
+	fn visit_token(&mut self, _node: &'ast Token) {}
+}
+
+/// Walks the statements of a programme.
+pub fn walk_programme<'ast, V>(visitor: &mut V, node: &'ast Programme)
+where V: Visit<'ast> + ?Sized
+{
+	for statement in &node.statements
+	{
+		visitor.visit_statement(statement);
+	}
+}
+
+/// Walks the node a statement holds.
+pub fn walk_statement<'ast, V>(visitor: &mut V, node: &'ast Statement)
+where V: Visit<'ast> + ?Sized
+{
+	match &node.kind
+	{
+		statement::Kind::Declaration(node) => visitor.visit_declaration(node),
+		statement::Kind::Expression(node) => visitor.visit_expression(node),
+		statement::Kind::If(node) => visitor.visit_if(node),
+		statement::Kind::While(node) => visitor.visit_while(node),
+		statement::Kind::Match(node) => visitor.visit_match(node),
+		statement::Kind::Break(node) => visitor.visit_break(node),
+		statement::Kind::Continue(node) => visitor.visit_continue(node),
+		statement::Kind::Return(node) => visitor.visit_return(node),
+		statement::Kind::Assignment(node) => visitor.visit_assignment(node),
+		statement::Kind::Echo(node) => visitor.visit_echo(node),
+		statement::Kind::Import(node) => visitor.visit_import(node),
+		statement::Kind::Use(node) => visitor.visit_use(node),
+		statement::Kind::FunctionCall(node) => visitor.visit_function_call(node),
+	}
+}
+
+/// Walks the statements of a block.
+pub fn walk_block<'ast, V>(visitor: &mut V, node: &'ast Block)
+where V: Visit<'ast> + ?Sized
+{
+	for statement in node.statements.iter().flatten()
+	{
+		visitor.visit_statement(statement);
+	}
+}
+
+/// Walks the function or value of a declaration.
+pub fn walk_declaration<'ast, V>(visitor: &mut V, node: &'ast Declaration)
+where V: Visit<'ast> + ?Sized
+{
+	match &node.kind
+	{
+		declaration::Kind::Function(node) => visitor.visit_function(node),
+		declaration::Kind::Variable { value, .. }
+		| declaration::Kind::Destructuring { value, .. } =>
+		{
+			visitor.visit_expression(value)
+		}
+		declaration::Kind::Struct(_) | declaration::Kind::Enum(_) => (),
+	}
+}
+
+/// Walks the parameters and body of a function declaration.
+pub fn walk_function<'ast, V>(visitor: &mut V, node: &'ast FunctionDclr)
+where V: Visit<'ast> + ?Sized
+{
+	if let Some(parameters) = &node.parameters
+	{
+		visitor.visit_params(parameters);
+	}
+
+	visitor.visit_block(&node.body);
+}
+
+/// Walks the parameters of a parameter list.
+pub fn walk_params<'ast, V>(visitor: &mut V, node: &'ast Params)
+where V: Visit<'ast> + ?Sized
+{
+	for parameter in &node.parameters
+	{
+		visitor.visit_parameter(parameter);
+	}
+}
+
+/// Walks the condition and every branch of an `if` statement.
+pub fn walk_if<'ast, V>(visitor: &mut V, node: &'ast IfStmt)
+where V: Visit<'ast> + ?Sized
+{
+	visitor.visit_expression(&node.condition);
+	visitor.visit_block(&node.body);
+
+	for else_if in &node.else_ifs
+	{
+		visitor.visit_else_if(else_if);
+	}
+
+	if let Some(body) = &node.else_body
+	{
+		visitor.visit_block(body);
+	}
+}
+
+/// Walks the condition and body of an `else if` branch.
+pub fn walk_else_if<'ast, V>(visitor: &mut V, node: &'ast ElseIf)
+where V: Visit<'ast> + ?Sized
+{
+	visitor.visit_expression(&node.condition);
+	visitor.visit_block(&node.body);
+}
+
+/// Walks the matched expression and arms of a `match` statement.
+pub fn walk_match<'ast, V>(visitor: &mut V, node: &'ast MatchStmt)
+where V: Visit<'ast> + ?Sized
+{
+	visitor.visit_expression(&node.scrutinee);
+
+	for arm in &node.arms
+	{
+		visitor.visit_arm(arm);
+	}
+}
+
+/// Walks the patterns, guard and body of a `match` arm.
+pub fn walk_arm<'ast, V>(visitor: &mut V, node: &'ast Arm)
+where V: Visit<'ast> + ?Sized
+{
+	for pattern in &node.patterns
+	{
+		visitor.visit_pattern(pattern);
+	}
+
+	if let Some(guard) = &node.guard
+	{
+		visitor.visit_expression(guard);
+	}
+
+	visitor.visit_block(&node.body);
+}
+
+/// Walks the condition and body of a `while` statement.
+pub fn walk_while<'ast, V>(visitor: &mut V, node: &'ast WhileStmt)
+where V: Visit<'ast> + ?Sized
+{
+	visitor.visit_expression(&node.condition);
+	visitor.visit_block(&node.body);
+}
+
+/// Walks the value of a `return` statement.
+pub fn walk_return<'ast, V>(visitor: &mut V, node: &'ast ReturnStmt)
+where V: Visit<'ast> + ?Sized
+{
+	visitor.visit_expression(&node.expression);
+}
+
+/// Walks the subscript and value of an assignment statement.
+pub fn walk_assignment<'ast, V>(visitor: &mut V, node: &'ast AssignmentStmt)
+where V: Visit<'ast> + ?Sized
+{
+	if let Some(subscript) = &node.subscript
+	{
+		visitor.visit_subscript(subscript);
+	}
+
+	visitor.visit_expression(&node.expression);
+}
+
+/// Walks the arguments of an `echo` statement.
+pub fn walk_echo<'ast, V>(visitor: &mut V, node: &'ast EchoStmt)
+where V: Visit<'ast> + ?Sized
+{
+	for argument in &node.arguments
+	{
+		visitor.visit_expression(argument);
+	}
+}
+
+/// Walks the arguments of a function call.
+pub fn walk_function_call<'ast, V>(visitor: &mut V, node: &'ast FunctionCall)
+where V: Visit<'ast> + ?Sized
+{
+	if let Some(arguments) = &node.arguments
+	{
+		visitor.visit_items(arguments);
+	}
+}
+
+/// Walks the parameters and body of a lambda.
+pub fn walk_lambda<'ast, V>(visitor: &mut V, node: &'ast Lambda)
+where V: Visit<'ast> + ?Sized
+{
+	if let Some(parameters) = &node.parameters
+	{
+		visitor.visit_params(parameters);
+	}
+
+	match &node.body
+	{
+		Body::Expression(expression) => visitor.visit_expression(expression),
+		Body::Block(block) => visitor.visit_block(block),
+	}
+}
+
+/// Walks the tokens and operands of an expression.
+pub fn walk_expression<'ast, V>(visitor: &mut V, node: &'ast Expression)
+where V: Visit<'ast> + ?Sized
+{
+	match &node.kind
+	{
+		expression::Kind::Identifier(token) | expression::Kind::Literal(token) =>
+		{
+			visitor.visit_token(token)
+		}
+
+		expression::Kind::Parenthesised(expression) =>
+		{
+			visitor.visit_expression(expression)
+		}
+
+		expression::Kind::Tuple(items) => visitor.visit_items(items),
+
+		expression::Kind::List(rows) | expression::Kind::Matrix(rows) =>
+		{
+			for items in rows.iter().flatten()
+			{
+				visitor.visit_items(items);
+			}
+		}
+
+		expression::Kind::Prefix { operator, operand } =>
+		{
+			visitor.visit_token(operator);
+			visitor.visit_expression(operand);
+		}
+
+		expression::Kind::Infix {
+			left,
+			operator,
+			right,
+		} =>
+		{
+			visitor.visit_expression(left);
+			visitor.visit_token(operator);
+			visitor.visit_expression(right);
+		}
+
+		expression::Kind::FunctionCall(call) => visitor.visit_function_call(call),
+
+		expression::Kind::Index { target, subscript } =>
+		{
+			visitor.visit_expression(target);
+			visitor.visit_subscript(subscript);
+		}
+
+		expression::Kind::Member { target, .. } => visitor.visit_expression(target),
+
+		expression::Kind::MethodCall {
+			target, arguments, ..
+		} =>
+		{
+			visitor.visit_expression(target);
+			if let Some(arguments) = arguments
+			{
+				visitor.visit_items(arguments);
+			}
+		}
+
+		expression::Kind::Lambda(lambda) => visitor.visit_lambda(lambda),
+
+		expression::Kind::Variant { arguments, .. } =>
+		{
+			if let Some(arguments) = arguments
+			{
+				visitor.visit_items(arguments);
+			}
+		}
+
+		expression::Kind::Interpolation(parts) =>
+		{
+			for part in parts
+			{
+				visitor.visit_part(part);
+			}
+		}
+	}
+}
+
+/// Walks the expressions of a list of items.
+pub fn walk_items<'ast, V>(visitor: &mut V, node: &'ast Items)
+where V: Visit<'ast> + ?Sized
+{
+	for expression in &node.expressions
+	{
+		visitor.visit_expression(expression);
+	}
+}
+
+/// Walks the expression embedded in a part of an interpolated string.
+pub fn walk_part<'ast, V>(visitor: &mut V, node: &'ast Part)
+where V: Visit<'ast> + ?Sized
+{
+	if let Part::Expression(expression) = node
+	{
+		visitor.visit_expression(expression);
+	}
+}
+
+/// Walks the indices of a subscript.
+pub fn walk_subscript<'ast, V>(visitor: &mut V, node: &'ast Subscript)
+where V: Visit<'ast> + ?Sized
+{
+	for index in &node.indices
+	{
+		visitor.visit_index(index);
+	}
+}
+
+/// Walks the position or bounds of an index.
+pub fn walk_index<'ast, V>(visitor: &mut V, node: &'ast Index)
+where V: Visit<'ast> + ?Sized
+{
+	match node
+	{
+		Index::Position(position) => visitor.visit_expression(position),
+		Index::Slice { start, end, .. } =>
+		{
+			for bound in start.iter().chain(end)
+			{
+				visitor.visit_expression(bound);
+			}
+		}
+	}
+}
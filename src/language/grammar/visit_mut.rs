@@ -0,0 +1,504 @@
+//! In-place rewriting of the syntax tree.
+//!
+//! [`VisitMut`] mirrors [`Visit`](super::Visit), but hands each node over
+//! mutably so that an implementation can change it where it stands.  Each
+//! method walks into the node's children by default through the `walk_*`
+//! function of the same name.
+
+use super::declaration::{self, Declaration};
+use super::expression::{self, Expression, Items, Part};
+use super::functions::{Body, Parameter, Params};
+use super::pattern::Pattern;
+use super::statement::{self, Block, EchoStmt, Statement};
+use super::subscript::Index;
+use super::*;
+use crate::language::lexicon::Token;
+
+/// A visitor that can change the syntax tree in place.
+pub trait VisitMut
+{
+	/// Visits a programme.
+	fn visit_programme_mut(&mut self, node: &mut Programme)
+	{
+		walk_programme(self, node)
+	}
+
+	/// Visits a statement.
+	fn visit_statement_mut(&mut self, node: &mut Statement)
+	{
+		walk_statement(self, node)
+	}
+
+	/// Visits a block.
+	fn visit_block_mut(&mut self, node: &mut Block)
+	{
+		walk_block(self, node)
+	}
+
+	/// Visits a declaration.
+	fn visit_declaration_mut(&mut self, node: &mut Declaration)
+	{
+		walk_declaration(self, node)
+	}
+
+	/// Visits a function declaration.
+	fn visit_function_mut(&mut self, node: &mut FunctionDclr)
+	{
+		walk_function(self, node)
+	}
+
+	/// Visits the parameter list of a function declaration.
+	fn visit_params_mut(&mut self, node: &mut Params)
+	{
+		walk_params(self, node)
+	}
+
+	/// Visits a parameter of a function declaration.
+	fn visit_parameter_mut(&mut self, _node: &mut Parameter) {}
+
+	/// Visits an `if` statement.
+	fn visit_if_mut(&mut self, node: &mut IfStmt)
+	{
+		walk_if(self, node)
+	}
+
+	/// Visits an `else if` branch of an `if` statement.
+	fn visit_else_if_mut(&mut self, node: &mut ElseIf)
+	{
+		walk_else_if(self, node)
+	}
+
+	/// Visits a `match` statement.
+	fn visit_match_mut(&mut self, node: &mut MatchStmt)
+	{
+		walk_match(self, node)
+	}
+
+	/// Visits an arm of a `match` statement.
+	fn visit_arm_mut(&mut self, node: &mut Arm)
+	{
+		walk_arm(self, node)
+	}
+
+	/// Visits a pattern of a `match` arm.
+	fn visit_pattern_mut(&mut self, _node: &mut Pattern) {}
+
+	/// Visits a `while` statement.
+	fn visit_while_mut(&mut self, node: &mut WhileStmt)
+	{
+		walk_while(self, node)
+	}
+
+	/// Visits a `break` statement.
+	fn visit_break_mut(&mut self, _node: &mut BreakStmt) {}
+
+	/// Visits a `continue` statement.
+	fn visit_continue_mut(&mut self, _node: &mut ContinueStmt) {}
+
+	/// Visits a `return` statement.
+	fn visit_return_mut(&mut self, node: &mut ReturnStmt)
+	{
+		walk_return(self, node)
+	}
+
+	/// Visits an assignment statement.
+	fn visit_assignment_mut(&mut self, node: &mut AssignmentStmt)
+	{
+		walk_assignment(self, node)
+	}
+
+	/// Visits an `echo` statement.
+	fn visit_echo_mut(&mut self, node: &mut EchoStmt)
+	{
+		walk_echo(self, node)
+	}
+
+	/// Visits an `import` statement.
+	fn visit_import_mut(&mut self, _node: &mut ImportStmt) {}
+
+	/// Visits a `use` statement.
+	fn visit_use_mut(&mut self, _node: &mut UseStmt) {}
+
+	/// Visits a function call.
+	fn visit_function_call_mut(&mut self, node: &mut FunctionCall)
+	{
+		walk_function_call(self, node)
+	}
+
+	/// Visits a lambda.
+	fn visit_lambda_mut(&mut self, node: &mut Lambda)
+	{
+		walk_lambda(self, node)
+	}
+
+	/// Visits an expression.
+	fn visit_expression_mut(&mut self, node: &mut Expression)
+	{
+		walk_expression(self, node)
+	}
+
+	/// Visits a comma separated list of expressions.
+	fn visit_items_mut(&mut self, node: &mut Items)
+	{
+		walk_items(self, node)
+	}
+
+	/// Visits a part of an interpolated string.
+	fn visit_part_mut(&mut self, node: &mut Part)
+	{
+		walk_part(self, node)
+	}
+
+	/// Visits the subscript of an index expression or assignment.
+	fn visit_subscript_mut(&mut self, node: &mut Subscript)
+	{
+		walk_subscript(self, node)
+	}
+
+	/// Visits an index of a subscript.
+	fn visit_index_mut(&mut self, node: &mut Index)
+	{
+		walk_index(self, node)
+	}
+
+	/// Visits the token of an identifier, literal or operator.
+	fn visit_token_mut(&mut self, _node: &mut Token) {}
+}
+
+/// Walks the statements of a programme.
+pub fn walk_programme<V>(visitor: &mut V, node: &mut Programme)
+where V: VisitMut + ?Sized
+{
+	for statement in &mut node.statements
+	{
+		visitor.visit_statement_mut(statement);
+	}
+}
+
+/// Walks the node a statement holds.
+pub fn walk_statement<V>(visitor: &mut V, node: &mut Statement)
+where V: VisitMut + ?Sized
+{
+	match &mut node.kind
+	{
+		statement::Kind::Declaration(node) => visitor.visit_declaration_mut(node),
+		statement::Kind::Expression(node) => visitor.visit_expression_mut(node),
+		statement::Kind::If(node) => visitor.visit_if_mut(node),
+		statement::Kind::While(node) => visitor.visit_while_mut(node),
+		statement::Kind::Match(node) => visitor.visit_match_mut(node),
+		statement::Kind::Break(node) => visitor.visit_break_mut(node),
+		statement::Kind::Continue(node) => visitor.visit_continue_mut(node),
+		statement::Kind::Return(node) => visitor.visit_return_mut(node),
+		statement::Kind::Assignment(node) => visitor.visit_assignment_mut(node),
+		statement::Kind::Echo(node) => visitor.visit_echo_mut(node),
+		statement::Kind::Import(node) => visitor.visit_import_mut(node),
+		statement::Kind::Use(node) => visitor.visit_use_mut(node),
+		statement::Kind::FunctionCall(node) =>
+		{
+			visitor.visit_function_call_mut(node)
+		}
+	}
+}
+
+/// Walks the statements of a block.
+pub fn walk_block<V>(visitor: &mut V, node: &mut Block)
+where V: VisitMut + ?Sized
+{
+	for statement in node.statements.iter_mut().flatten()
+	{
+		visitor.visit_statement_mut(statement);
+	}
+}
+
+/// Walks the function or value of a declaration.
+pub fn walk_declaration<V>(visitor: &mut V, node: &mut Declaration)
+where V: VisitMut + ?Sized
+{
+	match &mut node.kind
+	{
+		declaration::Kind::Function(node) => visitor.visit_function_mut(node),
+		declaration::Kind::Variable { value, .. }
+		| declaration::Kind::Destructuring { value, .. } =>
+		{
+			visitor.visit_expression_mut(value)
+		}
+		declaration::Kind::Struct(_) | declaration::Kind::Enum(_) => (),
+	}
+}
+
+/// Walks the parameters and body of a function declaration.
+pub fn walk_function<V>(visitor: &mut V, node: &mut FunctionDclr)
+where V: VisitMut + ?Sized
+{
+	if let Some(parameters) = &mut node.parameters
+	{
+		visitor.visit_params_mut(parameters);
+	}
+
+	visitor.visit_block_mut(&mut node.body);
+}
+
+/// Walks the parameters of a parameter list.
+pub fn walk_params<V>(visitor: &mut V, node: &mut Params)
+where V: VisitMut + ?Sized
+{
+	for parameter in &mut node.parameters
+	{
+		visitor.visit_parameter_mut(parameter);
+	}
+}
+
+/// Walks the condition and every branch of an `if` statement.
+pub fn walk_if<V>(visitor: &mut V, node: &mut IfStmt)
+where V: VisitMut + ?Sized
+{
+	visitor.visit_expression_mut(&mut node.condition);
+	visitor.visit_block_mut(&mut node.body);
+
+	for else_if in &mut node.else_ifs
+	{
+		visitor.visit_else_if_mut(else_if);
+	}
+
+	if let Some(body) = &mut node.else_body
+	{
+		visitor.visit_block_mut(body);
+	}
+}
+
+/// Walks the condition and body of an `else if` branch.
+pub fn walk_else_if<V>(visitor: &mut V, node: &mut ElseIf)
+where V: VisitMut + ?Sized
+{
+	visitor.visit_expression_mut(&mut node.condition);
+	visitor.visit_block_mut(&mut node.body);
+}
+
+/// Walks the matched expression and arms of a `match` statement.
+pub fn walk_match<V>(visitor: &mut V, node: &mut MatchStmt)
+where V: VisitMut + ?Sized
+{
+	visitor.visit_expression_mut(&mut node.scrutinee);
+
+	for arm in &mut node.arms
+	{
+		visitor.visit_arm_mut(arm);
+	}
+}
+
+/// Walks the patterns, guard and body of a `match` arm.
+pub fn walk_arm<V>(visitor: &mut V, node: &mut Arm)
+where V: VisitMut + ?Sized
+{
+	for pattern in &mut node.patterns
+	{
+		visitor.visit_pattern_mut(pattern);
+	}
+
+	if let Some(guard) = &mut node.guard
+	{
+		visitor.visit_expression_mut(guard);
+	}
+
+	visitor.visit_block_mut(&mut node.body);
+}
+
+/// Walks the condition and body of a `while` statement.
+pub fn walk_while<V>(visitor: &mut V, node: &mut WhileStmt)
+where V: VisitMut + ?Sized
+{
+	visitor.visit_expression_mut(&mut node.condition);
+	visitor.visit_block_mut(&mut node.body);
+}
+
+/// Walks the value of a `return` statement.
+pub fn walk_return<V>(visitor: &mut V, node: &mut ReturnStmt)
+where V: VisitMut + ?Sized
+{
+	visitor.visit_expression_mut(&mut node.expression);
+}
+
+/// Walks the subscript and value of an assignment statement.
+pub fn walk_assignment<V>(visitor: &mut V, node: &mut AssignmentStmt)
+where V: VisitMut + ?Sized
+{
+	if let Some(subscript) = &mut node.subscript
+	{
+		visitor.visit_subscript_mut(subscript);
+	}
+
+	visitor.visit_expression_mut(&mut node.expression);
+}
+
+/// Walks the arguments of an `echo` statement.
+pub fn walk_echo<V>(visitor: &mut V, node: &mut EchoStmt)
+where V: VisitMut + ?Sized
+{
+	for argument in &mut node.arguments
+	{
+		visitor.visit_expression_mut(argument);
+	}
+}
+
+/// Walks the arguments of a function call.
+pub fn walk_function_call<V>(visitor: &mut V, node: &mut FunctionCall)
+where V: VisitMut + ?Sized
+{
+	if let Some(arguments) = &mut node.arguments
+	{
+		visitor.visit_items_mut(arguments);
+	}
+}
+
+/// Walks the parameters and body of a lambda.
+pub fn walk_lambda<V>(visitor: &mut V, node: &mut Lambda)
+where V: VisitMut + ?Sized
+{
+	if let Some(parameters) = &mut node.parameters
+	{
+		visitor.visit_params_mut(parameters);
+	}
+
+	match &mut node.body
+	{
+		Body::Expression(expression) => visitor.visit_expression_mut(expression),
+		Body::Block(block) => visitor.visit_block_mut(block),
+	}
+}
+
+/// Walks the tokens and operands of an expression.
+pub fn walk_expression<V>(visitor: &mut V, node: &mut Expression)
+where V: VisitMut + ?Sized
+{
+	match &mut node.kind
+	{
+		expression::Kind::Identifier(token) | expression::Kind::Literal(token) =>
+		{
+			visitor.visit_token_mut(token)
+		}
+
+		expression::Kind::Parenthesised(expression) =>
+		{
+			visitor.visit_expression_mut(expression)
+		}
+
+		expression::Kind::Tuple(items) => visitor.visit_items_mut(items),
+
+		expression::Kind::List(rows) | expression::Kind::Matrix(rows) =>
+		{
+			for items in rows.iter_mut().flatten()
+			{
+				visitor.visit_items_mut(items);
+			}
+		}
+
+		expression::Kind::Prefix { operator, operand } =>
+		{
+			visitor.visit_token_mut(operator);
+			visitor.visit_expression_mut(operand);
+		}
+
+		expression::Kind::Infix {
+			left,
+			operator,
+			right,
+		} =>
+		{
+			visitor.visit_expression_mut(left);
+			visitor.visit_token_mut(operator);
+			visitor.visit_expression_mut(right);
+		}
+
+		expression::Kind::FunctionCall(call) =>
+		{
+			visitor.visit_function_call_mut(call)
+		}
+
+		expression::Kind::Index { target, subscript } =>
+		{
+			visitor.visit_expression_mut(target);
+			visitor.visit_subscript_mut(subscript);
+		}
+
+		expression::Kind::Member { target, .. } =>
+		{
+			visitor.visit_expression_mut(target)
+		}
+
+		expression::Kind::MethodCall {
+			target, arguments, ..
+		} =>
+		{
+			visitor.visit_expression_mut(target);
+			if let Some(arguments) = arguments
+			{
+				visitor.visit_items_mut(arguments);
+			}
+		}
+
+		expression::Kind::Lambda(lambda) => visitor.visit_lambda_mut(lambda),
+
+		expression::Kind::Variant { arguments, .. } =>
+		{
+			if let Some(arguments) = arguments
+			{
+				visitor.visit_items_mut(arguments);
+			}
+		}
+
+		expression::Kind::Interpolation(parts) =>
+		{
+			for part in parts
+			{
+				visitor.visit_part_mut(part);
+			}
+		}
+	}
+}
+
+/// Walks the expressions of a list of items.
+pub fn walk_items<V>(visitor: &mut V, node: &mut Items)
+where V: VisitMut + ?Sized
+{
+	for expression in &mut node.expressions
+	{
+		visitor.visit_expression_mut(expression);
+	}
+}
+
+/// Walks the expression embedded in a part of an interpolated string.
+pub fn walk_part<V>(visitor: &mut V, node: &mut Part)
+where V: VisitMut + ?Sized
+{
+	if let Part::Expression(expression) = node
+	{
+		visitor.visit_expression_mut(expression);
+	}
+}
+
+/// Walks the indices of a subscript.
+pub fn walk_subscript<V>(visitor: &mut V, node: &mut Subscript)
+where V: VisitMut + ?Sized
+{
+	for index in &mut node.indices
+	{
+		visitor.visit_index_mut(index);
+	}
+}
+
+/// Walks the position or bounds of an index.
+pub fn walk_index<V>(visitor: &mut V, node: &mut Index)
+where V: VisitMut + ?Sized
+{
+	match node
+	{
+		Index::Position(position) => visitor.visit_expression_mut(position),
+		Index::Slice { start, end, .. } =>
+		{
+			for bound in start.iter_mut().chain(end)
+			{
+				visitor.visit_expression_mut(bound);
+			}
+		}
+	}
+}
@@ -1,5 +1,7 @@
 pub mod symbol;
 pub mod token;
+pub mod trivia;
 
 pub use symbol::*;
 pub use token::*;
+pub use trivia::{Lexeme, Trivia};
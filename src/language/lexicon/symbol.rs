@@ -20,15 +20,13 @@ impl Symbol
 	/// Creates a new symbol.
 	///
 	/// ### Parameters
-	/// * `line` - The line of the symbol in source code.
-	/// * `column` - The column of the symbol in source code.
+	/// * `position` - The position of the symbol in source code.
 	/// * `character` - The raw `char` character of the symbol.
 	///
 	/// ### Returns
-	/// * The new symbol created from the given line, column, and character.
-	pub fn new(line: usize, column: usize, character: char) -> Self
+	/// * The new symbol created from the given position and character.
+	pub fn new(position: Position, character: char) -> Self
 	{
-		let position = Position { line, column };
 		Self {
 			position,
 			character,
@@ -7,6 +7,7 @@ use crate::language::utils::Span;
 /// A token is a word or symbol that represents a unit of meaning in the
 /// language.
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Token
 {
 	/// The span of the token in the source file.
@@ -16,6 +17,22 @@ pub struct Token
 	pub kind: Kind,
 }
 
+impl Token
+{
+	/// Gets the name an identifier token holds.
+	///
+	/// ### Returns
+	/// * The name, or `None` if the token is not an identifier.
+	pub fn identifier(&self) -> Option<&str>
+	{
+		match &self.kind
+		{
+			Kind::Identifier(name) => Some(name),
+			_ => None,
+		}
+	}
+}
+
 impl Debug for Token
 {
 	fn fmt(&self, formatter: &mut Formatter) -> Result
@@ -25,11 +42,25 @@ impl Debug for Token
 	}
 }
 
+/// A segment of an interpolated string literal token.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Segment
+{
+	/// Literal text, with its escape sequences already resolved.
+	Text(String),
+
+	/// The tokens of an embedded expression, without the enclosing braces.
+	Code(Vec<Token>),
+}
+
 /// The kind of lexical token.
 ///
 /// The kind of token is a classification of the token into categories defined
-/// by the language specification.
+/// by the language specification.  Kinds own their text: identifiers and
+/// strings are copied out of the source, so tokens can outlive it.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Kind
 {
 	/// An identifier token.
@@ -40,18 +71,39 @@ pub enum Kind
 	/// * _digit_ -> `0`..`9`
 	Identifier(String),
 
-	/// A string literal token.
+	/// A string literal token, holding the string with its escape sequences
+	/// already resolved.
 	///
 	/// ### Rule
-	/// * _string_ -> `"` { Σ \ `"` }* `"`
+	/// * _string_ -> `"` { _character_ | _escape_ }* `"`
+	/// * _character_ -> Σ \ { `"` | `\` | `{` }
+	/// * _escape_ -> `\` { `n` | `t` | `r` | `0` | `\` | `"` | `'` | `{` | `}` }
+	///   | `\u{` _hex_digit_+ `}`
 	String(String),
 
-	/// A number literal token.
+	/// An interpolated string literal token.
+	///
+	/// ### Rule
+	/// * _interpolation_ -> `"` { _character_ | _escape_ | `{` _token_* `}` }*
+	///   `"`
+	Interpolation(Vec<Segment>),
+
+	/// A number literal token, holding the number as a Python literal.
 	///
 	/// ### Rule
-	/// * _number_ -> _digit_* `.`? _digit_+ `i`?
+	/// * _number_ -> _decimal_ | _radix_
+	/// * _decimal_ -> _digits_? { `.` _digits_ }? _exponent_? `i`?
+	/// * _exponent_ -> { `e` | `E` } { `+` | `-` }? _digits_
+	/// * _radix_ -> `0` { `x` | `o` | `b` } _digits_
+	/// * _digits_ -> _digit_ { `_`? _digit_ }*
 	Number(String),
 
+	/// A documentation comment token, holding the text of the comment.
+	///
+	/// ### Rule
+	/// * _doc_ -> `///` { Σ \ `\n` }*
+	Doc(String),
+
 	/// A boolean literal token.
 	///
 	/// ### Rule
@@ -70,6 +122,12 @@ pub enum Kind
 	/// The `echo` declarator token.
 	Echo,
 
+	/// The `struct` declarator token.
+	Struct,
+
+	/// The `enum` declarator token.
+	Enum,
+
 	/// The `proc` declarator token.
 	// Procedure,
 
@@ -82,6 +140,9 @@ pub enum Kind
 	/// The lexical token for the `while` keyword.
 	While,
 
+	/// The lexical token for the `match` keyword.
+	Match,
+
 	/// The lexical token for the `for` keyword.
 	For,
 
@@ -97,6 +158,12 @@ pub enum Kind
 	/// The lexical token for the `continue` keyword.
 	Continue,
 
+	/// The lexical token for the `import` keyword.
+	Import,
+
+	/// The lexical token for the `use` keyword.
+	Use,
+
 	/// The `and` operator.
 	And,
 
@@ -133,6 +200,9 @@ pub enum Kind
 	/// The `->` operator.
 	ArrowRight,
 
+	/// The `=>` operator.
+	FatArrow,
+
 	/// The `+=` operator.
 	PlusEqual,
 
@@ -196,12 +266,21 @@ pub enum Kind
 	/// The lexical token for the `.` symbol.
 	Dot,
 
+	/// The lexical token for the `..` symbol.
+	DotDot,
+
+	/// The lexical token for the `..=` symbol.
+	DotDotEqual,
+
 	/// The lexical token for the `,` symbol.
 	Comma,
 
 	/// The lexical token for the `:` symbol.
 	Colon,
 
+	/// The lexical token for the `::` symbol.
+	ColonColon,
+
 	/// The lexical token for the `;` symbol.
 	Semicolon,
 
@@ -0,0 +1,92 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::language::utils::Span;
+
+use super::Token;
+
+/// A piece of source text that carries no meaning for the compiler.
+///
+/// Trivia is the whitespace and comments between tokens.  It is discarded by
+/// the ordinary lexer but kept by the lossless lexer, so that tools can
+/// reproduce the exact source text.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trivia
+{
+	/// The span of the trivia in the source file.
+	pub span: Span,
+
+	/// The kind of the trivia.
+	pub kind: Kind,
+
+	/// The exact source text of the trivia.
+	pub text: String,
+}
+
+/// The kind of trivia.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Kind
+{
+	/// A run of whitespace that contains no line break.
+	Whitespace,
+
+	/// A single line break, either `\n` or `\r\n`.
+	Newline,
+
+	/// An end-of-line comment.
+	///
+	/// ### Rule
+	/// * _comment_ -> `//` { Σ \ `\n` }*
+	Comment,
+
+	/// A block comment, which may contain other block comments.
+	///
+	/// ### Rule
+	/// * _block_comment_ -> `/*` { Σ | _block_comment_ }* `*/`
+	BlockComment,
+}
+
+/// A token together with its exact source text and surrounding trivia.
+///
+/// The trivia up to and excluding the first line break after a token trails
+/// it; everything else leads the next token.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lexeme
+{
+	/// The trivia before the token.
+	pub leading: Vec<Trivia>,
+
+	/// The token itself.
+	pub token: Token,
+
+	/// The exact source text of the token.
+	pub text: String,
+
+	/// The trivia after the token on the same line.
+	pub trailing: Vec<Trivia>,
+}
+
+impl Display for Lexeme
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		let Self {
+			leading,
+			text,
+			trailing,
+			..
+		} = self;
+
+		leading
+			.iter()
+			.try_for_each(|trivia| write!(formatter, "{}", trivia.text))?;
+
+		write!(formatter, "{text}")?;
+
+		trailing
+			.iter()
+			.try_for_each(|trivia| write!(formatter, "{}", trivia.text))
+	}
+}
@@ -0,0 +1,274 @@
+use super::r#type::Type;
+
+/// A member of a built-in type, which is either a property such as `m.shape`
+/// or a method such as `s.len()`.
+pub struct Member
+{
+	/// The name of the member.
+	pub name: &'static str,
+
+	/// The types of the parameters of a method, or `None` for a property. A
+	/// parameter of type `Unit` accepts a value of any type.
+	pub parameters: Option<&'static [Type]>,
+
+	/// The type of the value the member evaluates to.
+	pub r#type: Type,
+
+	/// The Python expression the member is defined as, where `{self}` stands
+	/// for the value and `{arguments}` for the arguments of a method. Members
+	/// of different types that share a name share the expression.
+	pub python: &'static str,
+}
+
+impl Member
+{
+	/// Creates the signature of the member as it would be written in Quark.
+	///
+	/// ### Returns
+	/// * The signature, such as `replace(String, String) -> String` for a method
+	///   or `shape: Unit` for a property.
+	pub fn signature(&self) -> String
+	{
+		match self.parameters
+		{
+			Some(parameters) =>
+			{
+				let parameters = parameters
+					.iter()
+					.map(ToString::to_string)
+					.collect::<Vec<_>>()
+					.join(", ");
+
+				format!("{}({parameters}) -> {}", self.name, self.r#type)
+			}
+			None => format!("{}: {}", self.name, self.r#type),
+		}
+	}
+}
+
+/// The members of strings.
+pub const STRING: [Member; 8] = [
+	Member {
+		name: "len",
+		parameters: Some(&[]),
+		r#type: Type::Number,
+		python: "len({self})",
+	},
+	Member {
+		name: "upper",
+		parameters: Some(&[]),
+		r#type: Type::String,
+		python: "{self}.upper()",
+	},
+	Member {
+		name: "lower",
+		parameters: Some(&[]),
+		r#type: Type::String,
+		python: "{self}.lower()",
+	},
+	Member {
+		name: "trim",
+		parameters: Some(&[]),
+		r#type: Type::String,
+		python: "{self}.strip()",
+	},
+	Member {
+		name: "contains",
+		parameters: Some(&[Type::String]),
+		r#type: Type::Boolean,
+		python: "({arguments} in {self})",
+	},
+	Member {
+		name: "starts_with",
+		parameters: Some(&[Type::String]),
+		r#type: Type::Boolean,
+		python: "{self}.startswith({arguments})",
+	},
+	Member {
+		name: "ends_with",
+		parameters: Some(&[Type::String]),
+		r#type: Type::Boolean,
+		python: "{self}.endswith({arguments})",
+	},
+	Member {
+		name: "replace",
+		parameters: Some(&[Type::String, Type::String]),
+		r#type: Type::String,
+		python: "{self}.replace({arguments})",
+	},
+];
+
+/// The members of numbers.
+pub const NUMBER: [Member; 8] = [
+	Member {
+		name: "abs",
+		parameters: Some(&[]),
+		r#type: Type::Number,
+		python: "np.abs({self})",
+	},
+	Member {
+		name: "sqrt",
+		parameters: Some(&[]),
+		r#type: Type::Number,
+		python: "np.sqrt({self})",
+	},
+	Member {
+		name: "floor",
+		parameters: Some(&[]),
+		r#type: Type::Number,
+		python: "np.floor({self})",
+	},
+	Member {
+		name: "ceil",
+		parameters: Some(&[]),
+		r#type: Type::Number,
+		python: "np.ceil({self})",
+	},
+	Member {
+		name: "round",
+		parameters: Some(&[]),
+		r#type: Type::Number,
+		python: "np.round({self})",
+	},
+	Member {
+		name: "conj",
+		parameters: Some(&[]),
+		r#type: Type::Number,
+		python: "np.conj({self})",
+	},
+	Member {
+		name: "real",
+		parameters: None,
+		r#type: Type::Number,
+		python: "np.real({self})",
+	},
+	Member {
+		name: "imag",
+		parameters: None,
+		r#type: Type::Number,
+		python: "np.imag({self})",
+	},
+];
+
+/// The members of lists and matrices, whose elements may be the amplitudes of
+/// a quantum state.
+pub const AGGREGATE: [Member; 11] = [
+	Member {
+		name: "len",
+		parameters: Some(&[]),
+		r#type: Type::Number,
+		python: "len({self})",
+	},
+	Member {
+		name: "shape",
+		parameters: None,
+		r#type: Type::Unit,
+		python: "np.shape({self})",
+	},
+	Member {
+		name: "T",
+		parameters: None,
+		r#type: Type::Unit,
+		python: "np.transpose({self})",
+	},
+	Member {
+		name: "H",
+		parameters: None,
+		r#type: Type::Unit,
+		python: "np.conj(np.transpose({self}))",
+	},
+	Member {
+		name: "abs",
+		parameters: Some(&[]),
+		r#type: Type::Unit,
+		python: "np.abs({self})",
+	},
+	Member {
+		name: "conj",
+		parameters: Some(&[]),
+		r#type: Type::Unit,
+		python: "np.conj({self})",
+	},
+	Member {
+		name: "sum",
+		parameters: Some(&[]),
+		r#type: Type::Number,
+		python: "np.sum({self})",
+	},
+	Member {
+		name: "norm",
+		parameters: Some(&[]),
+		r#type: Type::Number,
+		python: "np.linalg.norm({self})",
+	},
+	Member {
+		name: "dot",
+		parameters: Some(&[Type::Unit]),
+		r#type: Type::Unit,
+		python: "np.dot({self}, {arguments})",
+	},
+	Member {
+		name: "normalised",
+		parameters: Some(&[]),
+		r#type: Type::Unit,
+		python: "({self} / np.linalg.norm({self}))",
+	},
+	Member {
+		name: "probabilities",
+		parameters: Some(&[]),
+		r#type: Type::Unit,
+		python: "(np.abs({self}) ** 2)",
+	},
+];
+
+/// Gets the members of a type.
+///
+/// ### Parameters
+/// * `type` - The type. `Unit` stands for lists and matrices.
+///
+/// ### Returns
+/// * The members of the type.
+pub fn members(r#type: &Type) -> &'static [Member]
+{
+	match r#type
+	{
+		Type::String => &STRING,
+		Type::Number => &NUMBER,
+		Type::Boolean
+		| Type::Function { .. }
+		| Type::Named(_)
+		| Type::Tuple(_)
+		| Type::Variable(_) => &[],
+		Type::Unit | Type::List(_) | Type::Matrix(_) => &AGGREGATE,
+	}
+}
+
+/// Looks up a member of a type.
+///
+/// ### Parameters
+/// * `type` - The type. `Unit` stands for lists and matrices.
+/// * `name` - The name of the member.
+///
+/// ### Returns
+/// * The member if the type has it.
+pub fn lookup(r#type: &Type, name: &str) -> Option<&'static Member>
+{
+	members(r#type).iter().find(|member| member.name == name)
+}
+
+/// Looks up a member of any type by its name alone.
+///
+/// ### Parameters
+/// * `name` - The name of the member.
+///
+/// ### Returns
+/// * The first member with the name, whose Python expression every member with
+///   the name shares.
+pub fn find(name: &str) -> Option<&'static Member>
+{
+	STRING
+		.iter()
+		.chain(&NUMBER)
+		.chain(&AGGREGATE)
+		.find(|member| member.name == name)
+}
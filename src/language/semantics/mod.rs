@@ -1 +1,3 @@
+pub mod member;
+pub mod prelude;
 pub mod r#type;
@@ -0,0 +1,45 @@
+use super::r#type::Type;
+
+/// A named constant that every Quark programme can use without declaring it.
+pub struct Constant
+{
+	/// The name of the constant.
+	pub name: &'static str,
+
+	/// The type of the constant.
+	pub r#type: Type,
+
+	/// The Python expression the constant is defined as.
+	pub python: &'static str,
+}
+
+/// The constants of the prelude.
+pub const PRELUDE: [Constant; 3] = [
+	Constant {
+		name: "pi",
+		r#type: Type::Number,
+		python: "np.pi",
+	},
+	Constant {
+		name: "tau",
+		r#type: Type::Number,
+		python: "2 * np.pi",
+	},
+	Constant {
+		name: "e",
+		r#type: Type::Number,
+		python: "np.e",
+	},
+];
+
+/// Looks up a constant of the prelude.
+///
+/// ### Parameters
+/// * `name` - The name of the constant.
+///
+/// ### Returns
+/// * The constant if the prelude defines it.
+pub fn lookup(name: &str) -> Option<&'static Constant>
+{
+	PRELUDE.iter().find(|constant| constant.name == name)
+}
@@ -1,8 +1,221 @@
-#[derive(Debug, PartialEq, Clone, Copy)]
+use std::fmt::{Display, Formatter, Result};
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type
 {
 	Boolean,
 	String,
 	Number,
 	Unit,
+
+	/// The type of a function value, such as a named function or a lambda.
+	/// A parameter of type `Unit` accepts a value of any type.
+	Function
+	{
+		/// The types of the parameters.
+		parameters: Vec<Type>,
+		/// The type of the value the function returns.
+		returns: Box<Type>,
+	},
+
+	/// A struct or enum type, by the name it is declared with.
+	Named(String),
+
+	/// The type of a tuple, with the type of each of its values in order.
+	Tuple(Vec<Type>),
+
+	/// A type parameter of a generic function, such as `T` in
+	/// `func first<T>(xs: List<T>) -> T`.
+	Variable(String),
+
+	/// A list whose items are all of a type, as in `List<T>`.
+	List(Box<Type>),
+
+	/// A matrix with a number of rows and columns, as in `Matrix<n, n>`.
+	Matrix(Box<[Size; 2]>),
+}
+
+/// The length of a dimension of a matrix type.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Size
+{
+	/// A length known when the function is declared.
+	Fixed(usize),
+
+	/// A size parameter of a generic function, such as `n` in
+	/// `func trace<n>(m: Matrix<n, n>)`.
+	Parameter(String),
+}
+
+impl Type
+{
+	/// Gets the type a name stands for in a type annotation.
+	///
+	/// ### Parameters
+	/// * `name` - The name, such as `Number` or the name of a struct.
+	///
+	/// ### Returns
+	/// * The built-in type of that name, or the struct or enum type of that name
+	///   otherwise.
+	pub fn from_name(name: &str) -> Self
+	{
+		match name
+		{
+			"Number" => Self::Number,
+			"String" => Self::String,
+			"Bool" => Self::Boolean,
+			"Unit" => Self::Unit,
+			_ => Self::Named(name.to_string()),
+		}
+	}
+
+	/// Turns the names of the type parameters of a generic function into type
+	/// variables wherever they are used as a type.
+	///
+	/// ### Parameters
+	/// * `generics` - The names of the type and size parameters.
+	///
+	/// ### Returns
+	/// * The type, with a `Variable` in place of each parameter named.
+	pub fn generalise(self, generics: &[String]) -> Self
+	{
+		match self
+		{
+			Self::Named(name) if generics.contains(&name) => Self::Variable(name),
+			Self::Tuple(types) => Self::Tuple(
+				types
+					.into_iter()
+					.map(|r#type| r#type.generalise(generics))
+					.collect(),
+			),
+			Self::List(r#type) => Self::List(Box::new(r#type.generalise(generics))),
+			Self::Function {
+				parameters,
+				returns,
+			} => Self::Function {
+				parameters: parameters
+					.into_iter()
+					.map(|r#type| r#type.generalise(generics))
+					.collect(),
+				returns: Box::new(returns.generalise(generics)),
+			},
+			r#type => r#type,
+		}
+	}
+
+	/// Determines if a value of a type may be given where this type is
+	/// expected.  `Unit` stands for a type that is not known, so it accepts and
+	/// is accepted by any type, including within a tuple.  A type variable
+	/// accepts any type, since it is bound at the call rather than here.
+	///
+	/// ### Parameters
+	/// * `other` - The type of the value.
+	///
+	/// ### Returns
+	/// * `true` if the value is accepted.
+	/// * `false` otherwise.
+	pub fn accepts(&self, other: &Type) -> bool
+	{
+		match (self, other)
+		{
+			(Self::Unit | Self::Variable(_), _) | (_, Self::Unit) => true,
+			(Self::Tuple(expected), Self::Tuple(actual)) =>
+			{
+				expected.len() == actual.len()
+					&& expected.iter().zip(actual).all(|(a, b)| a.accepts(b))
+			}
+			(Self::List(expected), Self::List(actual)) => expected.accepts(actual),
+			(
+				Self::Function {
+					parameters: expected,
+					returns: expected_returns,
+				},
+				Self::Function {
+					parameters: actual,
+					returns: actual_returns,
+				},
+			) =>
+			{
+				expected.len() == actual.len()
+					&& expected.iter().zip(actual).all(|(a, b)| a.accepts(b))
+					&& expected_returns.accepts(actual_returns)
+			}
+			// A size parameter accepts any length, as a type variable does.
+			(Self::Matrix(expected), Self::Matrix(actual)) =>
+			{
+				expected.iter().zip(actual.iter()).all(|sizes| match sizes
+				{
+					(Size::Fixed(a), Size::Fixed(b)) => a == b,
+					_ => true,
+				})
+			}
+			_ => self == other,
+		}
+	}
+}
+
+impl Display for Type
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		let name = match self
+		{
+			Self::Boolean => "Bool",
+			Self::String => "String",
+			Self::Number => "Number",
+			Self::Unit => "Unit",
+			Self::Function {
+				parameters,
+				returns,
+			} =>
+			{
+				let parameters = parameters
+					.iter()
+					.map(ToString::to_string)
+					.collect::<Vec<_>>()
+					.join(", ");
+
+				return write!(formatter, "|{parameters}| -> {returns}");
+			}
+			Self::Named(name) => name,
+			// A tuple of one value keeps its comma, as in Python.
+			Self::Tuple(types) if types.len() == 1 =>
+			{
+				return write!(formatter, "({},)", types[0]);
+			}
+			Self::Tuple(types) =>
+			{
+				let types = types
+					.iter()
+					.map(ToString::to_string)
+					.collect::<Vec<_>>()
+					.join(", ");
+
+				return write!(formatter, "({types})");
+			}
+			Self::Variable(name) => name,
+			Self::List(r#type) => return write!(formatter, "List<{type}>"),
+			Self::Matrix(sizes) =>
+			{
+				let [rows, columns] = sizes.as_ref();
+				return write!(formatter, "Matrix<{rows}, {columns}>");
+			}
+		};
+
+		write!(formatter, "{name}")
+	}
+}
+
+impl Display for Size
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		match self
+		{
+			Self::Fixed(length) => write!(formatter, "{length}"),
+			Self::Parameter(name) => write!(formatter, "{name}"),
+		}
+	}
 }
@@ -1,5 +1,7 @@
 pub mod position;
+pub mod source;
 pub mod span;
 
 pub use position::*;
+pub use source::*;
 pub use span::*;
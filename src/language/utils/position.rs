@@ -1,12 +1,27 @@
 use std::fmt::{Debug, Formatter, Result};
 
+use super::FileId;
+
 /// The position of a symbol in a source file.
 ///
-/// The position is given by a line number and a column number. The line number
-/// is the 0-based index of the line in the source file. The column number is
-/// the 0-based index of the column in the line.
-#[derive(Clone, Copy, PartialEq)]
+/// The position is given by the file it belongs to and the 0-based byte offset
+/// of the symbol in that file.  Line and column numbers are computed on demand
+/// through [`SourceFile::location`](super::SourceFile::location).
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Position
+{
+	/// The source file the position belongs to.
+	pub file: FileId,
+
+	/// The 0-based byte offset of the position in the source file.
+	pub offset: usize,
+}
+
+/// The line and column of a position in a source file.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Location
 {
 	/// The 0-based index of the line in the source file.
 	pub line: usize,
@@ -16,6 +31,14 @@ pub struct Position
 }
 
 impl Debug for Position
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		write!(formatter, "[{}]", self.offset)
+	}
+}
+
+impl Debug for Location
 {
 	fn fmt(&self, formatter: &mut Formatter) -> Result
 	{
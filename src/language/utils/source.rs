@@ -0,0 +1,145 @@
+use std::path::PathBuf;
+
+use super::Location;
+
+/// The identifier of a source file in a [`SourceMap`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FileId(pub usize);
+
+/// The database of every source file taking part in a compilation.
+///
+/// Each phase of the compiler looks up the text of a file through its
+/// [`FileId`], so spans from any file can be reported against the right
+/// source.
+#[derive(Debug, Default)]
+pub struct SourceMap
+{
+	/// The source files, indexed by their identifiers.
+	files: Vec<SourceFile>,
+}
+
+/// A single source file.
+#[derive(Debug)]
+pub struct SourceFile
+{
+	/// The path of the file, as it should be shown in diagnostics.
+	pub path: PathBuf,
+
+	/// The full text of the file.
+	pub text: String,
+
+	/// The byte offset at which each line of the file starts.
+	lines: Vec<usize>,
+}
+
+impl SourceMap
+{
+	/// Creates an empty source map.
+	pub fn new() -> Self
+	{
+		Self { files: Vec::new() }
+	}
+
+	/// Adds a source file to the map.
+	///
+	/// ### Parameters
+	/// * `path` - The path of the file, as it should be shown in diagnostics.
+	/// * `text` - The contents of the file.
+	///
+	/// ### Returns
+	/// * The identifier of the new file.
+	pub fn add(&mut self, path: impl Into<PathBuf>, text: String) -> FileId
+	{
+		let lines = std::iter::once(0)
+			.chain(text.match_indices('\n').map(|(offset, _)| offset + 1))
+			.filter(|&offset| offset < text.len())
+			.collect();
+
+		let file = SourceFile {
+			path: path.into(),
+			text,
+			lines,
+		};
+
+		self.files.push(file);
+		FileId(self.files.len() - 1)
+	}
+
+	/// Looks up a source file.
+	///
+	/// ### Parameters
+	/// * `id` - The identifier of the file.
+	///
+	/// ### Returns
+	/// * The source file.
+	///
+	/// ### Panics
+	/// * If the file is not part of this map.
+	pub fn file(&self, FileId(id): FileId) -> &SourceFile
+	{
+		&self.files[id]
+	}
+}
+
+impl SourceFile
+{
+	/// Gets the number of lines in the file.
+	pub fn line_count(&self) -> usize
+	{
+		self.lines.len()
+	}
+
+	/// Gets the text of a line, without its line terminator.
+	///
+	/// ### Parameters
+	/// * `line` - The 0-based index of the line.
+	///
+	/// ### Returns
+	/// * The text of the line, or an empty string past the end of the file.
+	pub fn line(&self, line: usize) -> &str
+	{
+		let Some(&start) = self.lines.get(line)
+		else
+		{
+			return "";
+		};
+
+		let end = self.lines.get(line + 1).copied().unwrap_or(self.text.len());
+
+		self.text[start..end].trim_end_matches(['\n', '\r'])
+	}
+
+	/// Gets the byte offset at which a line starts.
+	///
+	/// ### Parameters
+	/// * `line` - The 0-based index of the line.
+	///
+	/// ### Returns
+	/// * The byte offset of the start of the line.
+	pub fn line_start(&self, line: usize) -> usize
+	{
+		self.lines.get(line).copied().unwrap_or(self.text.len())
+	}
+
+	/// Computes the line and column of a byte offset in the file.
+	///
+	/// ### Parameters
+	/// * `offset` - The byte offset.
+	///
+	/// ### Returns
+	/// * The 0-based line and column (counted in characters) of the offset.
+	pub fn location(&self, offset: usize) -> Location
+	{
+		let line = self.lines.partition_point(|&start| start <= offset);
+		let line = line.saturating_sub(1);
+		let start = self.line_start(line).min(offset);
+
+		let column = self
+			.text
+			.get(start..offset)
+			.map_or(offset - start, |text| text.chars().count());
+
+		Location { line, column }
+	}
+}
@@ -5,7 +5,8 @@ use super::*;
 /// A span of symbols in a source file.
 ///
 /// The span is given by the start and end positions of the span.
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Span
 {
 	/// The start position of the span.
@@ -15,6 +16,30 @@ pub struct Span
 	pub end: Position,
 }
 
+impl Span
+{
+	/// Gets the source file the span belongs to.
+	pub fn file(&self) -> FileId
+	{
+		self.start.file
+	}
+
+	/// Checks whether another span lies within the span.
+	///
+	/// ### Parameters
+	/// * `other` - The other span.
+	///
+	/// ### Returns
+	/// * Whether the other span is in the same file and between the start and
+	///   end of the span.
+	pub fn contains(&self, other: &Span) -> bool
+	{
+		self.file() == other.file()
+			&& self.start.offset <= other.start.offset
+			&& other.end.offset <= self.end.offset
+	}
+}
+
 impl Debug for Span
 {
 	fn fmt(&self, formatter: &mut Formatter) -> Result
@@ -0,0 +1,186 @@
+use quark::compiler::{Compile, Diagnostic};
+
+#[test]
+fn testing_reassignment()
+{
+	let valid = "var x = 1;
+x = 2;
+while x < 5 { x = x + 1; }
+func f(n) { var total = 0; total = n; let x = 3; return x + total; }
+if true { var y = 1; y = 2; }"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let cases = [
+		(
+			"let x = 1;\nx = 2;",
+			"Cannot assign to immutable binding `x`",
+		),
+		(
+			"let x = 1;\nwhile true { x = 2; break; }",
+			"Cannot assign to immutable binding `x`",
+		),
+		(
+			"let xs = [1, 2];\nxs[0] = 3;",
+			"Cannot assign to immutable binding `xs`",
+		),
+		(
+			"var x = 1;\nfunc f() { let x = 2; x = 3; }",
+			"Cannot assign to immutable binding `x`",
+		),
+		(
+			"func f(a) { a = 2; }",
+			"Cannot assign to immutable binding `a`",
+		),
+		(
+			"let (a, b) = (1, 2);\nif true { b = 3; }",
+			"Cannot assign to immutable binding `b`",
+		),
+		("func f() { }\nf = 1;", "Cannot assign to function `f`"),
+		("y = 1;", "Unknown identifier `y`"),
+		("var x = 1;\nlet x = 2;", "Constant 'x' already declared"),
+		("let x = 1;\nvar x = 2;", "Variable 'x' already declared"),
+	];
+
+	for (input, message) in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+}
+
+#[test]
+fn testing_scopes()
+{
+	let valid = "let x = 1;
+if x > 0 { let x = \"a\"; echo x + \"b\"; }
+while x < 0 { var x = true; x = false; }
+func f(n) { let x = n; return g(x); }
+func g(n) { return n; }
+echo f(x) + x;"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let cases = [
+		(
+			"if true { let x = 1; let x = 2; }",
+			"Constant 'x' already declared",
+		),
+		(
+			"if true { func f() { } func f() { } }",
+			"Function 'f' already declared",
+		),
+		(
+			"if true { let y = 1; }\nlet z = y;",
+			"Unknown identifier `y`",
+		),
+		("if true { let y = 1; }\necho y;", "Unknown identifier `y`"),
+		(
+			"func f() { let y = 1; }\nlet z = y;",
+			"Unknown identifier `y`",
+		),
+	];
+
+	for (input, message) in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+}
+
+#[test]
+fn testing_assignments_in_functions()
+{
+	let valid = "var xs = [1];
+func f() { xs[0] = 2; var ys = [1]; ys = [1, 2]; }
+f();"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let message =
+		"Functions cannot assign to `x`, which is declared outside of them";
+	let cases = [
+		"var x = [1];\nfunc f() { x = [1, 2, 3]; }",
+		"var x = 1;\nfunc f() { if true { x = 2; } }",
+		"func f() { var x = 1; func g() { x = 2; } }",
+	];
+
+	for input in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+
+	// Only the name bound outside of the function has its shape unknown.
+	let input = "let xs = [1];
+func f() { var xs = [1, 2]; xs = [1, 2, 3]; }
+echo xs[2];";
+	let error = input.to_string().compile().unwrap_err();
+	let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+	assert_eq!(
+		diagnostic.message,
+		"Index `2` is out of bounds for a dimension of length 1"
+	);
+}
+
+#[test]
+fn testing_duplicate_parameters()
+{
+	let input = "func f(x, x) { }";
+	let error = input.to_string().compile().unwrap_err();
+	let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+
+	assert_eq!(
+		diagnostic.message,
+		"Parameter 'x' already declared in function 'f'"
+	);
+	assert_eq!(diagnostic.span.start.offset, 10);
+	assert_eq!(diagnostic.related[0].0.start.offset, 7);
+}
+
+#[test]
+fn testing_reassignment_relates_the_declaration()
+{
+	let input = "let x = 1;\nif true { x = 2; }";
+	let error = input.to_string().compile().unwrap_err();
+	let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+
+	assert_eq!(diagnostic.span.start.offset, 21);
+
+	let [(span, note)] = diagnostic.related.as_slice()
+	else
+	{
+		panic!("{:?}", diagnostic.related);
+	};
+	assert_eq!(span.start.offset, 0);
+	assert_eq!(
+		note,
+		"`x` is declared here; change `let` to `var` to make it mutable"
+	);
+
+	let input = "var x = 1;\nlet x = 2;";
+	let error = input.to_string().compile().unwrap_err();
+	let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+
+	assert_eq!(diagnostic.span.start.offset, 11);
+	assert_eq!(diagnostic.related[0].0.start.offset, 0);
+}
+
+#[test]
+fn testing_reassignment_report()
+{
+	let input = "let x = 1;\nif true { x = 2; }";
+	let error = input.to_string().compile().unwrap_err().to_string();
+	let expected = "--> <source>:2:11
+2 | if true { x = 2; }
+              ^^^^^
+Cannot assign to immutable binding `x`
+--> <source>:1:1
+1 | let x = 1;
+    ^^^^^^^^^
+`x` is declared here; change `let` to `var` to make it mutable";
+	assert_eq!(error, expected);
+}
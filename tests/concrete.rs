@@ -0,0 +1,101 @@
+use std::fs;
+
+use quark::compiler::lexer::Lex;
+use quark::language::grammar::concrete::{Element, Kind, SyntaxTree};
+use quark::language::lexicon::token;
+use quark::language::lexicon::trivia;
+use quark::language::utils::SourceMap;
+
+/// Builds the concrete syntax tree of a piece of source code.
+fn tree(text: &str) -> anyhow::Result<SyntaxTree>
+{
+	let mut source = SourceMap::new();
+	let file = source.add("test.q", text.to_string());
+	SyntaxTree::try_from_file(file, &source)
+}
+
+#[test]
+fn testing_example_files_round_trip()
+{
+	let examples = [
+		"test.q",
+		"tests/docs/main.q",
+		"tests/modules/project/main.q",
+		"tests/modules/project/lib/gates.q",
+	];
+
+	for path in examples
+	{
+		let text = fs::read_to_string(path).unwrap();
+		assert_eq!(tree(&text).unwrap().to_string(), text, "{path}");
+	}
+
+	let readme = fs::read_to_string("README.md").unwrap();
+	let (_, example) = readme.split_once("```nim\n").unwrap();
+	let (example, _) = example.split_once("```").unwrap();
+	assert_eq!(tree(example).unwrap().to_string(), example);
+}
+
+#[test]
+fn testing_trivia_round_trip()
+{
+	let text = "\t/* a /* nested */ comment */ let x = 1;  // one\r\n\n\
+	            /// Doc.\nfunc f(a) -> Number { return a * [1, 2 | 3, 4]m; }\n\n";
+	assert_eq!(tree(text).unwrap().to_string(), text);
+}
+
+#[test]
+fn testing_trivia_attachment()
+{
+	let mut source = SourceMap::new();
+	let text = "let x = 1; // one\n// two\nx = 2;\n";
+	let file = source.add("test.q", text.to_string());
+
+	let (lexemes, end) = file.lex_lossless(&source).unwrap();
+
+	let semicolon = &lexemes[4];
+	assert_eq!(semicolon.token.kind, token::Kind::Semicolon);
+	let kinds: Vec<_> = semicolon.trailing.iter().map(|t| t.kind).collect();
+	assert_eq!(kinds, [trivia::Kind::Whitespace, trivia::Kind::Comment]);
+
+	let x = &lexemes[5];
+	let texts: Vec<_> = x.leading.iter().map(|t| t.text.as_str()).collect();
+	assert_eq!(texts, ["\n", "// two", "\n"]);
+
+	assert!(lexemes[7].trailing.is_empty());
+	assert_eq!(end.len(), 1);
+	assert_eq!(end[0].kind, trivia::Kind::Newline);
+}
+
+#[test]
+fn testing_statement_structure()
+{
+	let text = "if x { y = 1; } else { y = 2; }\necho y;";
+	let tree = tree(text).unwrap();
+
+	assert_eq!(tree.root.kind, Kind::Programme);
+	assert_eq!(tree.root.children.len(), 2);
+
+	let Element::Node(statement) = &tree.root.children[0]
+	else
+	{
+		panic!("Expected a statement");
+	};
+	let blocks = statement
+		.children
+		.iter()
+		.filter(|child| {
+			matches!(child, Element::Node(node) if node.kind == Kind::Block)
+		})
+		.count();
+	assert_eq!(blocks, 2);
+}
+
+#[test]
+fn testing_unmatched_delimiters()
+{
+	assert!(tree("echo (1;").is_err());
+	assert!(tree("echo 1);").is_err());
+	assert!(tree("while true { echo 1;").is_err());
+	assert!(tree("echo [1 };").is_err());
+}
@@ -0,0 +1,180 @@
+use std::path::Path;
+
+use quark::compiler::{Compile, Diagnostic, Graph, Project};
+use quark::language::grammar::declaration::{self, Declaration};
+use quark::language::grammar::statement::{self, Statement};
+use quark::language::grammar::{FunctionDclr, Programme};
+
+fn parse(code: &str) -> Programme
+{
+	let project = Project::load_source(Path::new("main.q"), code.into()).unwrap();
+	let (_, programme) = project.modules().last().unwrap();
+	programme.clone()
+}
+
+fn function(statement: &Statement) -> &FunctionDclr
+{
+	match &statement.kind
+	{
+		statement::Kind::Declaration(Declaration {
+			kind: declaration::Kind::Function(function),
+			..
+		}) => function,
+		_ => panic!("{statement:?}"),
+	}
+}
+
+/// Finds the block whose first statement is of a kind.
+fn block(graph: &Graph, is_kind: fn(&statement::Kind) -> bool) -> usize
+{
+	graph
+		.blocks
+		.iter()
+		.position(|block| {
+			block.statements.first().is_some_and(|s| is_kind(&s.kind))
+		})
+		.unwrap()
+}
+
+#[test]
+fn testing_misplaced_jumps()
+{
+	let valid = "var i = 0;
+while i < 3 { if i == 1 { i = i + 2; continue; } i = i + 1; break; }
+func f(n) { while n > 0 { return n; } return 0; }
+let g = |x| { return x + f(1); };
+match i { 1 => { } _ => { while true { break; } } }"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let cases = [
+		("break;", "`break` outside of a loop"),
+		("continue;", "`continue` outside of a loop"),
+		("if true { break; }", "`break` outside of a loop"),
+		("return 1;", "`return` outside of a function"),
+		("while true { return 1; }", "`return` outside of a function"),
+		(
+			"while true { func f() { break; } }",
+			"`break` outside of a loop",
+		),
+		(
+			"while true { let g = |x| { continue; }; break; }",
+			"`continue` outside of a loop",
+		),
+		("match 1 { _ => { break; } }", "`break` outside of a loop"),
+	];
+
+	for (input, message) in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+
+	let input = "echo 1;\nif true { break; }";
+	let error = input.to_string().compile().unwrap_err();
+	let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+	assert_eq!(diagnostic.span.start.offset, 18);
+
+	let expected = "--> <source>:2:11
+2 | if true { break; }
+              ^^^^^
+`break` outside of a loop";
+	assert_eq!(error.to_string(), expected);
+}
+
+#[test]
+fn testing_function_graph()
+{
+	let programme = parse(
+		"func f(n)
+{
+	var i = n;
+	while i > 0
+	{
+		if i == 3 { return i; }
+		i = i - 1;
+	}
+	return 0;
+	echo i;
+}
+echo f(4);",
+	);
+	let graph = function(&programme.statements[0]).graph();
+
+	assert_eq!(
+		graph.blocks[Graph::ENTRY].statements[0].span.start.offset,
+		13
+	);
+	assert!(graph.blocks[Graph::EXIT].statements.is_empty());
+
+	// The loop header is entered from before the loop and again from the end
+	// of its body.
+	let header = block(&graph, |kind| matches!(kind, statement::Kind::While(_)));
+	let end = block(&graph, |kind| {
+		matches!(kind, statement::Kind::Assignment(_))
+	});
+	assert_eq!(graph.predecessors(header), [Graph::ENTRY, end]);
+
+	// Both returns go to the exit, and so does the end of the body.
+	let returns: Vec<_> = graph
+		.predecessors(Graph::EXIT)
+		.into_iter()
+		.filter(|&id| {
+			let last = graph.blocks[id].statements.last();
+			last.is_some_and(|s| matches!(s.kind, statement::Kind::Return(_)))
+		})
+		.collect();
+	assert_eq!(returns.len(), 2);
+	assert_eq!(graph.predecessors(Graph::EXIT).len(), 3);
+
+	let unreachable: Vec<_> = graph.unreachable().collect();
+	assert_eq!(unreachable.len(), 1);
+	assert!(matches!(unreachable[0].kind, statement::Kind::Echo(_)));
+	assert!(programme.graph().unreachable().next().is_none());
+}
+
+#[test]
+fn testing_branch_graphs()
+{
+	let programme = parse(
+		"func f(n)
+{
+	if n > 0 { return 1; } else if n < 0 { return 2; } else { return 3; }
+	echo n;
+}
+func g(n)
+{
+	if n > 0 { return 1; }
+	echo n;
+	match n { 1 => { return 1; } _ => { return 2; } }
+	echo n;
+}
+func h(n)
+{
+	match n { 1 => { return 1; } 2 => { return 2; } }
+	while n > 0 { break; echo n; }
+	return n;
+}
+echo f(1) + g(1) + h(1);",
+	);
+
+	let unreachable = |index: usize| -> Vec<usize> {
+		let graph = function(&programme.statements[index]).graph();
+		graph
+			.unreachable()
+			.map(|statement| statement.span.start.offset)
+			.collect()
+	};
+
+	// The statement after an `if` whose every branch returns never runs.
+	assert_eq!(unreachable(0), [84]);
+
+	// Without an `else` it may run, but not after a `match` with a catch-all
+	// arm whose every arm returns.
+	assert_eq!(unreachable(1), [191]);
+
+	// A `match` without a catch-all arm may match nothing, and nothing after a
+	// `break` in the same block runs.
+	assert_eq!(unreachable(2), [286]);
+}
@@ -0,0 +1,74 @@
+use std::path::Path;
+
+use quark::compiler::{Compile, Document, Format, Project};
+const HEADER: &str = "import numpy as np\n";
+
+#[test]
+fn testing_nested_block_comments()
+{
+	let source = "/* outer /* inner */ still a comment */\necho 1;";
+	let output = source.to_string().compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, "print(1)"));
+}
+
+#[test]
+fn testing_unterminated_nested_block_comment()
+{
+	let source = "/* outer /* inner */\nprint(1);";
+	assert!(source.to_string().compile().is_err());
+}
+
+#[test]
+fn testing_doc_comments_are_not_code()
+{
+	let source = "/// The answer.\nlet x = 42;\n//// Not docs.\necho x;";
+	let output = source.to_string().compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, "x = 42\nprint(x)"));
+}
+
+#[test]
+fn testing_markdown_documentation()
+{
+	let project = Project::load(Path::new("tests/docs/main.q")).unwrap();
+	let output = project.document(Format::Markdown);
+
+	let expected = "# `lib/gates.q`
+
+## `func hello(name)`
+
+Says hi.
+
+# `main.q`
+
+## `let answer`
+
+The answer.
+
+Used <everywhere>.
+
+## `func double(x) -> Number`
+
+Doubles a number.
+
+## `struct Run { distance: Number }`
+
+A measured run.
+
+## `enum Shot { Miss, Hit(Number) }`
+
+What a shot did.
+";
+	assert_eq!(output, expected);
+}
+
+#[test]
+fn testing_html_documentation()
+{
+	let project = Project::load(Path::new("tests/docs/main.q")).unwrap();
+	let output = project.document(Format::Html);
+
+	assert!(output.contains("<h1><code>main.q</code></h1>"));
+	assert!(output.contains("<p>Used &lt;everywhere&gt;.</p>"));
+	assert!(output.contains("<h2><code>func double(x) -&gt; Number</code></h2>"));
+	assert!(!output.contains("hidden"));
+}
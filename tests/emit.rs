@@ -0,0 +1,130 @@
+use quark::compiler::{emit, Compile, Stage};
+#[cfg(feature = "serde")]
+use quark::compiler::{Syntax, SCHEMA_VERSION};
+#[cfg(feature = "serde")]
+use serde_json::Value;
+
+#[test]
+fn testing_emit_tokens()
+{
+	let output = emit("let x = \"a{x}\";", Stage::Tokens, false).unwrap();
+
+	let expected = "Tokens [1; 1]--[1; 15]
+  Constant [1; 1]--[1; 3]
+  Identifier \"x\" [1; 5]--[1; 5]
+  Equal [1; 7]--[1; 7]
+  Interpolation [1; 9]--[1; 14]
+    Text \"a\" [1; 9]--[1; 14]
+    Code [1; 12]--[1; 12]
+      Identifier \"x\" [1; 12]--[1; 12]
+  Semicolon [1; 15]--[1; 15]";
+	assert_eq!(output, expected);
+}
+
+#[test]
+fn testing_emit_ast()
+{
+	let code = "let x = -1 + 2;\nif x > 0 { echo [1, 2 | 3, 4]m; }";
+	let output = emit(code, Stage::Ast, false).unwrap();
+
+	let expected = "Programme [1; 1]--[2; 33]
+  Constant x [1; 1]--[1; 14]
+    Infix + [1; 9]--[1; 14]
+      Prefix - [1; 9]--[1; 10]
+        Literal 1 [1; 10]--[1; 10]
+      Literal 2 [1; 14]--[1; 14]
+  If [2; 1]--[2; 33]
+    Infix > [2; 4]--[2; 8]
+      Identifier x [2; 4]--[2; 4]
+      Literal 0 [2; 8]--[2; 8]
+    Then [2; 10]--[2; 33]
+      Echo [2; 12]--[2; 31]
+        Matrix [2; 17]--[2; 30]
+          Row [2; 18]--[2; 21]
+            Literal 1 [2; 18]--[2; 18]
+            Literal 2 [2; 21]--[2; 21]
+          Row [2; 25]--[2; 28]
+            Literal 3 [2; 25]--[2; 25]
+            Literal 4 [2; 28]--[2; 28]";
+	assert_eq!(output, expected);
+}
+
+#[test]
+fn testing_emit_typed_ast()
+{
+	let code = "let x = 1;
+func f(a) -> Number { let y = \"s\"; return a; }
+if true { let z = x; echo z; }
+echo y;";
+	let output = emit(code, Stage::TypedAst, false).unwrap();
+
+	let x = "  Constant x [1; 1]--[1; 9]\n    Literal 1: Number";
+	assert!(output.contains(x));
+	assert!(output.contains("Literal \"s\": String"));
+	assert!(output.contains("Identifier a: Number [2; 43]--[2; 43]"));
+	assert!(output.contains("Function func f(a: Number) -> Number"));
+	assert!(output.contains("Parameter a: Number [2; 8]--[2; 8]"));
+	assert!(output.contains("Identifier z: Number"));
+
+	// Declarations in a block go out of scope at its end.
+	assert!(
+		output.ends_with("Echo [4; 1]--[4; 7]\n    Identifier y [4; 6]--[4; 6]")
+	);
+
+	let output = emit("let xs = [1, 2];", Stage::TypedAst, false).unwrap();
+	assert!(output.contains("List: List<Number> [1; 10]--[1; 15]"));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn testing_emit_json()
+{
+	let output = emit("echo 1 + 2;", Stage::Ast, true).unwrap();
+	let tree: Value = serde_json::from_str(&output).unwrap();
+
+	assert_eq!(tree["version"], SCHEMA_VERSION);
+	let syntax = Syntax::new("echo 1 + 2;").unwrap();
+	assert_eq!(Syntax::from_json(&output).unwrap(), syntax);
+
+	let echo = &tree["programme"]["statements"][0];
+	assert_eq!(echo["span"]["end"]["offset"], 10);
+	assert!(echo["kind"]["Echo"].is_object());
+
+	let output = emit("echo 1;", Stage::Tokens, true).unwrap();
+	let tokens: Value = serde_json::from_str(&output).unwrap();
+	assert_eq!(tokens["version"], SCHEMA_VERSION);
+	assert_eq!(tokens["tokens"][1]["kind"]["Number"], "1");
+
+	assert!(emit("echo 1 + 2;", Stage::TypedAst, true).is_err());
+
+	let code = "let x = 1;\necho x;";
+	let output = emit(code, Stage::Py, true).unwrap();
+	let python: Value = serde_json::from_str(&output).unwrap();
+	assert_eq!(python, code.to_string().compile().unwrap());
+}
+
+#[test]
+#[cfg(not(feature = "serde"))]
+fn testing_emit_json_without_serde()
+{
+	assert!(emit("echo 1;", Stage::Ast, true).is_err());
+	assert!(emit("echo 1;", Stage::Py, true).is_err());
+}
+
+#[test]
+fn testing_emit_python()
+{
+	let code = "let x = 1;\necho x;";
+	let output = emit(code, Stage::Py, false).unwrap();
+	assert_eq!(output, code.to_string().compile().unwrap());
+}
+
+#[test]
+fn testing_emit_errors()
+{
+	assert!(emit("let x = \"a", Stage::Tokens, false).is_err());
+	assert!(emit("let x = ;", Stage::Tokens, false).is_ok());
+	assert!(emit("let x = ;", Stage::Ast, false).is_err());
+	assert!(emit("echo y;", Stage::TypedAst, false).is_ok());
+	assert!(emit("let x = y;", Stage::Py, false).is_err());
+}
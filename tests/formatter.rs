@@ -0,0 +1,184 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use quark::compiler::formatter::equivalent;
+use quark::compiler::{format, try_format};
+
+/// Collects the Quark files in a directory and its subdirectories.
+fn files(directory: &Path, files: &mut Vec<PathBuf>)
+{
+	for entry in fs::read_dir(directory).unwrap()
+	{
+		let path = entry.unwrap().path();
+
+		if path.is_dir()
+		{
+			self::files(&path, files);
+		}
+		else if path.extension().is_some_and(|extension| extension == "q")
+		{
+			files.push(path);
+		}
+	}
+}
+
+/// Collects every example program: the README example, `test.q` and the test
+/// fixtures that parse.
+fn examples() -> Vec<(String, String)>
+{
+	let readme = fs::read_to_string("README.md").unwrap();
+	let (_, example) = readme.split_once("```nim\n").unwrap();
+	let (example, _) = example.split_once("```").unwrap();
+
+	let mut paths = vec![PathBuf::from("test.q")];
+	files(Path::new("tests"), &mut paths);
+
+	let mut examples = vec![("README.md".to_string(), example.to_string())];
+
+	for path in paths
+	{
+		let text = fs::read_to_string(&path).unwrap();
+
+		if equivalent(&text, &text).is_ok()
+		{
+			examples.push((path.display().to_string(), text));
+		}
+	}
+
+	examples
+}
+
+#[test]
+fn testing_examples_are_idempotent()
+{
+	for (name, text) in examples()
+	{
+		let once = try_format(&text).unwrap();
+		let twice = try_format(&once).unwrap();
+		assert_eq!(once, twice, "{name}");
+	}
+}
+
+#[test]
+fn testing_examples_keep_their_syntax_tree()
+{
+	for (name, text) in examples()
+	{
+		let formatted = try_format(&text).unwrap();
+		assert!(equivalent(&text, &formatted).unwrap(), "{name}");
+	}
+}
+
+#[test]
+fn testing_canonical_style()
+{
+	let source = "func   double( x )->Number{return x*2;}
+if x>=0 and not y
+{
+  x = -x+1;
+}
+else { echo(x); }";
+
+	let expected = "func double(x) -> Number {
+    return x * 2;
+}
+if x >= 0 and not y {
+    x = -x + 1;
+} else {
+    echo (x);
+}
+";
+	assert_eq!(format(source), expected);
+}
+
+#[test]
+fn testing_comments_are_preserved()
+{
+	let source = "// header
+
+
+/// Docs.
+let x = 1; // trailing
+while x < 2 { /* block */
+// inside
+x = x + 1;
+}
+if x { // c1
+echo 1; } // c2
+else { echo 2; }
+// footer
+";
+
+	let expected = "// header
+
+/// Docs.
+let x = 1; // trailing
+while x < 2 { /* block */
+    // inside
+    x = x + 1;
+}
+if x { // c1
+    echo 1;
+} // c2
+else {
+    echo 2;
+}
+// footer
+";
+	assert_eq!(format(source), expected);
+}
+
+#[test]
+fn testing_matrix_rows_are_aligned()
+{
+	let source = "let m = [1, -2 |\n30,4|-5, 600];\nlet n = [1,2|3,4];";
+
+	let expected = "let m = [ 1,  -2 |
+         30,   4 |
+         -5, 600];
+let n = [1, 2 | 3, 4];
+";
+	assert_eq!(format(source), expected);
+}
+
+#[test]
+fn testing_unparsable_source_is_unchanged()
+{
+	let source = "let x = ;";
+	assert!(try_format(source).is_err());
+	assert_eq!(format(source), source);
+}
+
+#[test]
+fn testing_subscripts_are_tight()
+{
+	let source = "let y = m [ 1 .. 3 , : ] [0];\nm [ ..= 2 ] = 1;";
+	let expected = "let y = m[1..3, :][0];\nm[..=2] = 1;\n";
+	assert_eq!(format(source), expected);
+}
+
+#[test]
+fn testing_lambdas_are_tight()
+{
+	let source = "let f = | x ,y |x*2;\nlet z = | | 0;\nlet m = [1, 2 |3, 4];";
+	let expected = "let f = |x, y| x * 2;\nlet z = || 0;\nlet m = [1, 2 | 3, 4];\n";
+	assert_eq!(format(source), expected);
+}
+
+#[test]
+fn testing_records_have_a_member_per_line()
+{
+	let source = "struct Run{a:Number, // metres\nb: String}\nenum E { A, B(Number,Run), }\nenum F {}";
+	let expected = "struct Run {\n    a: Number, // metres\n    b: String\n}\nenum E {\n    A,\n    B(Number, Run),\n}\nenum F {}\n";
+	assert_eq!(format(source), expected);
+	assert_eq!(format(expected), expected);
+}
+
+#[test]
+fn testing_type_arguments_hug_their_brackets()
+{
+	let source = "func first < T > (xs: List < T >) -> T { return xs[0]; }\nstruct G { m: Matrix<2,n>, l: List<Number> }\nlet f = |xs: List<Number>| xs[0] < 1;";
+	let expected = "func first<T>(xs: List<T>) -> T {\n    return xs[0];\n}\nstruct G {\n    m: Matrix<2, n>,\n    l: List<Number>\n}\nlet f = |xs: List<Number>| xs[0] < 1;\n";
+	assert_eq!(format(source), expected);
+	assert_eq!(format(expected), expected);
+}
@@ -1,4 +1,4 @@
-use quark::compiler::Compile;
+use quark::compiler::{Compile, Diagnostic};
 const HEADER: &str = "import numpy as np\n";
 
 #[test]
@@ -74,7 +74,7 @@ fn nasty_fibonacci()
     if n < 1 {
         return n;
     } else {
-    let x = x(3, 3) + 3;
+    let x = fibonacci(3) + 3;
     return fibonacci(n - 1) + fibonacci(n - 2);
   }
 }"
@@ -85,9 +85,83 @@ fn nasty_fibonacci()
         return n
         
     else:
-        x = x(3, 3) + 3
+        x = fibonacci(3) + 3
         return fibonacci(n - 1) + fibonacci(n - 2)"
 		.to_string();
 	let output = input.compile().unwrap();
 	assert_eq!(output, format!("{}{}", HEADER, expected));
 }
+
+#[test]
+fn testing_else_if_chain()
+{
+	let input = "let x = 2;
+if x == 1 {
+    echo 1;
+} else if x == 2 {
+    echo 2;
+} else if x > 2 {
+    echo 3;
+} else {
+    echo 4;
+}"
+	.to_string();
+
+	let expected = "x = 2
+if x == 1:
+    print(1)
+    
+elif x == 2:
+    print(2)
+    
+elif x > 2:
+    print(3)
+    
+else:
+    print(4)"
+		.to_string();
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_else_if_conditions()
+{
+	let valid = "if true { echo 1; } else if 1 < 2 { echo 2; }".to_string();
+	assert!(valid.compile().is_ok());
+
+	let invalid = "if true { echo 1; } else if 1 { echo 2; }".to_string();
+	assert!(invalid.compile().is_err());
+
+	let invalid = "if true { } else if false { } else if \"a\" { }".to_string();
+	assert!(invalid.compile().is_err());
+
+	let missing = "if true { echo 1; } else if { echo 2; }".to_string();
+	assert!(missing.compile().is_err());
+
+	let missing = "if true { echo 1; } else if true echo 2;".to_string();
+	assert!(missing.compile().is_err());
+
+	let nested = [
+		"while true { if true { } else if 1 { } break; }",
+		"if true { if 1 { } }",
+		"if true { } else { if false { } else if 1 { } }",
+		"match 1 { _ => { if true { } else if 1 { } } }",
+	];
+
+	for input in nested
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, "Invalid condition", "{input}");
+	}
+
+	// Within a function, the condition is constrained as its type is inferred.
+	let nested = "func f() { if true { } else if 1 { } }".to_string();
+	let error = nested.compile().unwrap_err();
+	let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+	assert_eq!(
+		diagnostic.message,
+		"Mismatched types: expected `Bool`, found `Number`"
+	);
+}
@@ -0,0 +1,175 @@
+use quark::compiler::{Compile, Diagnostic};
+use quark::language::semantics::r#type::{Size, Type};
+const HEADER: &str = "import numpy as np\n";
+
+#[test]
+fn testing_generic_functions()
+{
+	let input = "func first<T>(xs: List<T>) -> T
+{
+	return xs[0];
+}
+func trace<n>(m: Matrix<n, n>) -> Number
+{
+	return m[0, 0];
+}
+let x = first([1, 2]) + 1;
+let t = trace([1, 2 | 3, 4]);"
+		.to_string();
+
+	let expected = "def first(xs):
+    return xs[0]
+    
+def trace(m):
+    return m[0, 0]
+    
+x = first([1, 2]) + 1
+t = trace(np.array([[1, 2],[3, 4],]))";
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_generic_types()
+{
+	let list = Type::List(Box::new(Type::Variable("T".into())));
+	assert_eq!(list.to_string(), "List<T>");
+
+	let sizes = [Size::Parameter("n".into()), Size::Fixed(2)];
+	let matrix = Type::Matrix(Box::new(sizes));
+	assert_eq!(matrix.to_string(), "Matrix<n, 2>");
+
+	let named = Type::Tuple(vec![Type::Named("T".into()), Type::Number]);
+	let generic = Type::Tuple(vec![Type::Variable("T".into()), Type::Number]);
+	assert_eq!(named.generalise(&["T".to_string()]), generic);
+
+	let fixed = Type::Matrix(Box::new([Size::Fixed(3), Size::Fixed(2)]));
+	let square = Type::Matrix(Box::new([Size::Fixed(2), Size::Fixed(2)]));
+	assert!(matrix.accepts(&fixed));
+	assert!(!fixed.accepts(&square));
+}
+
+#[test]
+fn testing_inference()
+{
+	let valid = "func pick<T>(a: T, b: T) -> T { return a; }
+func swap<A, B>(pair: (A, B)) -> (B, A) { return (pair[1], pair[0]); }
+func first<T>(xs: List<T>) -> T { return xs[0]; }
+func id<T>(x: T) -> T { return x; }
+let s = pick(\"a\", \"b\") + \"c\";
+let (word, n) = swap((1, \"z\"));
+let m = -n + first([1, 2]);
+let w = word + \"!\";
+let u = id([1, 2]);
+let v = pick(id(1), 2);"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let cases = [
+		(
+			"func pick<T>(a: T, b: T) -> T { return a; }\nlet s = pick(1, \"b\");",
+			"`pick` needs `T` to be both `Number` and `String`",
+		),
+		(
+			"func first<T>(xs: List<T>) -> T { return xs[0]; }
+let x = first([1, \"a\"]);",
+			"Mismatched types: expected `Number`, found `String`",
+		),
+		(
+			"func id<T>(x: T) -> T { return x; }\nlet y = -id(\"a\");",
+			"Mismatched types: expected `Number`, found `String`",
+		),
+		(
+			"func swap<A, B>(pair: (A, B)) -> (B, A) { return (pair[1], pair[0]); }
+let y = swap(1);",
+			"`swap` expects a `(A, B)` argument, not `Number`",
+		),
+		(
+			"func f(a: Number, b) { }\nlet y = f(\"a\", 1);",
+			"`f` expects a `Number` argument, not `String`",
+		),
+		(
+			"func f<T, T>(a: T) { }",
+			"Type parameter 'T' already declared in function 'f'",
+		),
+		("func f<T>(a: U) { }", "Unknown type `U`"),
+		("func f(a) -> List<U> { }", "Unknown type `U`"),
+	];
+
+	for (input, message) in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+}
+
+#[test]
+fn testing_size_parameters()
+{
+	let valid = "func trace<n>(m: Matrix<n, n>) -> Number { return 1; }
+func rows<r, c>(m: Matrix<r, c>, n: Matrix<c, r>) -> Number { return 1; }
+func square(m: Matrix<2, 2>) -> Number { return 1; }
+let a = [1, 2 | 3, 4];
+let t = trace(a) + square(a);
+let b = rows([1, 2, 3 | 4, 5, 6], [1, 2 | 3, 4 | 5, 6]);"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let cases = [
+		(
+			"func trace<n>(m: Matrix<n, n>) -> Number { return 1; }
+let t = trace([1, 2, 3 | 4, 5, 6]);",
+			"`trace` needs `n` to be both 2 and 3",
+		),
+		(
+			"func f(m: Matrix<2, 2>) { }\nlet y = f([1, 2, 3 | 4, 5, 6]);",
+			"`f` expects a `Matrix<2, 2>` argument, not a 2 by 3 matrix",
+		),
+		(
+			"func f<n>(m: Matrix<n, n>) { }\nlet y = f([1, 2, 3]);",
+			"`f` expects a `Matrix<n, n>` argument, not a value with 1 dimension",
+		),
+		(
+			"func f<n>(m: Matrix<n, n>) { }\nlet y = f(\"ab\");",
+			"`f` expects a `Matrix<n, n>` argument, not `String`",
+		),
+		(
+			"let n = 2;\nfunc f<n>(m: Matrix<n, n>) -> Number { return n; }",
+			"`n` is a type or size parameter, not a value",
+		),
+		(
+			"func f<T>(x: T) -> T { let y = T; return x; }",
+			"`T` is a type or size parameter, not a value",
+		),
+		("func f<n>(m: Matrix<k, n>) { }", "Unknown size `k`"),
+		("struct S { m: Matrix<n, 2> }", "Unknown size `n`"),
+	];
+
+	for (input, message) in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+}
+
+#[test]
+fn testing_generic_syntax_errors()
+{
+	for invalid in [
+		"func f<T(a) { }",
+		"func f<>(a) { }",
+		"func f<1>(a) { }",
+		"func f(a: List<Number) { }",
+		"func f(a: List<>) { }",
+		"func f(a: Matrix<2>) { }",
+		"func f(a: Matrix<2, 1.5>) { }",
+		"func f(a: Number<2>) { }",
+		"func f(a:) { }",
+	]
+	{
+		assert!(invalid.to_string().compile().is_err(), "{invalid}");
+	}
+}
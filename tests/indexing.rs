@@ -0,0 +1,164 @@
+use quark::compiler::{Compile, Diagnostic};
+const HEADER: &str = "import numpy as np\n";
+
+#[test]
+fn testing_indexing()
+{
+	let input = "let xs = [1, 2, 3];
+var m = [1, 2 | 3, 4 | 5, 6];
+let s = \"hello\";
+echo xs[0] + xs[-1];
+echo m[1, 0];
+echo m[2][1];
+echo s[1..3];"
+		.to_string();
+
+	let expected = "xs = [1, 2, 3]
+m = np.array([[1, 2],[3, 4],[5, 6],])
+s = 'hello'
+print(xs[0] + xs[-1])
+print(m[1, 0])
+print(m[2][1])
+print(s[1:3])";
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_slicing()
+{
+	let input = "var m = [1, 2 | 3, 4 | 5, 6];
+let n = 1;
+echo m[1..3, :];
+echo m[..=1, 1];
+echo m[0..=-1, ..];
+echo m[n..=n + 1, 0];"
+		.to_string();
+
+	let expected = "m = np.array([[1, 2],[3, 4],[5, 6],])
+n = 1
+print(m[1:3, :])
+print(m[:2, 1])
+print(m[0:, :])
+print(m[n:(n + 1) + 1, 0])";
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_element_assignment()
+{
+	let input = "var m = [1, 2 | 3, 4];
+m[1, 0] = 10;
+m[0, ..] = [0, 0];"
+		.to_string();
+
+	let expected = "m = np.array([[1, 2],[3, 4],])
+m[1, 0] = 10
+m[0, :] = [0, 0]";
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+
+	let constant = "let m = [1, 2 | 3, 4];\nm[0, 0] = 1;".to_string();
+	assert!(constant.compile().is_err());
+
+	let string = "var s = \"ab\";\ns[0] = \"c\";".to_string();
+	assert!(string.compile().is_err());
+
+	let missing = "var m = [1, 2];\nm[0];".to_string();
+	assert!(missing.compile().is_err());
+}
+
+#[test]
+fn testing_static_bounds()
+{
+	let valid = "let m = [1, 2 | 3, 4];
+let a = m[1, -2];
+let b = m[0..2, 1];
+let c = m[1][1];"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let cases = [
+		(
+			"let xs = [1, 2, 3];\nlet y = xs[3];",
+			"Index `3` is out of bounds for a dimension of length 3",
+		),
+		(
+			"let xs = [1, 2, 3];\nlet y = xs[-4];",
+			"Index `-4` is out of bounds for a dimension of length 3",
+		),
+		(
+			"let xs = [1, 2, 3];\nlet y = xs[0, 1];",
+			"Too many indices for a value with 1 dimension",
+		),
+		(
+			"let m = [1, 2 | 3, 4];\nlet y = m[0..=2, 0];",
+			"Slice bound `2` is out of bounds for a dimension of length 2",
+		),
+		(
+			"let m = [1, 2 | 3, 4];\nlet y = m[1][2];",
+			"Index `2` is out of bounds for a dimension of length 2",
+		),
+		(
+			"var m = [1, 2 | 3, 4];\nm[2, 0] = 1;",
+			"Index `2` is out of bounds for a dimension of length 2",
+		),
+		(
+			"let xs = [1, 2, 3];\necho xs[3];",
+			"Index `3` is out of bounds for a dimension of length 3",
+		),
+		(
+			"func f(n) { return n; }\nlet xs = [1];\nf(xs[1]);",
+			"Index `1` is out of bounds for a dimension of length 1",
+		),
+		(
+			"if true { let y = [1][5]; }",
+			"Index `5` is out of bounds for a dimension of length 1",
+		),
+		(
+			"let xs = [1, 2, 3];\nfunc g() -> Number { return xs[9]; }",
+			"Index `9` is out of bounds for a dimension of length 3",
+		),
+		(
+			"var y = 0;\ny = [1][2];",
+			"Index `2` is out of bounds for a dimension of length 1",
+		),
+		(
+			"while true { echo [1][1]; break; }",
+			"Index `1` is out of bounds for a dimension of length 1",
+		),
+		(
+			"let x = 1;\nlet y = x[0];",
+			"Cannot index a value of type `Number`",
+		),
+		(
+			"let xs = [1];\nlet y = xs[\"a\"];",
+			"Index must be a `Number`, not `String`",
+		),
+	];
+
+	for (input, message) in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+}
+
+#[test]
+fn testing_unknown_shapes()
+{
+	// The loop body may change the shape, so the index cannot be checked.
+	let reassigned = "var xs = [1];
+while true { xs = [1, 2, 3]; }
+xs[2] = 5;"
+		.to_string();
+	assert!(reassigned.compile().is_ok());
+
+	let resized = "var xs = [1];\nxs = [1, 2, 3];\nxs[3] = 5;".to_string();
+	assert!(resized.compile().is_err());
+}
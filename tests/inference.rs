@@ -0,0 +1,197 @@
+use quark::compiler::{Compile, Diagnostic};
+
+#[test]
+fn testing_inference()
+{
+	let valid = "func double(x) { return x * 2; }
+func greet(name) { return \"Hello, \" + name; }
+func fact(n) { if n <= 1 { return 1; } return n * fact(n - 1); }
+func apply(f, x) { return f(x); }
+func first(pair) { let (a, _) = pair; return a; }
+let a = double(3) + fact(4);
+let b = greet(\"you\") + \"!\";
+let c = apply(double, 1);
+let d = first((1, 2));"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let cases = [
+		(
+			"func double(x) { return x * 2; }\nlet y = double(\"a\");",
+			"`double` expects a `Number` argument, not `String`",
+		),
+		(
+			"func greet(name) { return \"Hello, \" + name; }\nlet y = greet(1);",
+			"`greet` expects a `String` argument, not `Number`",
+		),
+		(
+			"func double(x) { return x * 2; }\nlet y = -double(1) + \"a\";",
+			"Mismatched types: expected `Number`, found `String`",
+		),
+		(
+			"func f(x) { if x { return 1; } return x; }",
+			"Mismatched types: expected `Number`, found `Bool`",
+		),
+		(
+			"func f(x) -> String { let y = x * 2; return y; }",
+			"Mismatched types: expected `String`, found `Number`",
+		),
+		(
+			"func f(x) { let y = x(1); return y + x(\"a\"); }",
+			"Mismatched types: expected `Number`, found `String`",
+		),
+	];
+
+	for (input, message) in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+}
+
+#[test]
+fn testing_inference_errors_relate_constraints()
+{
+	let input = "func f(x) { let y = x * 2; return x + \"s\"; }";
+	let error = input.to_string().compile().unwrap_err();
+	let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+
+	assert_eq!(
+		diagnostic.message,
+		"Mismatched types: expected `Number`, found `String`"
+	);
+	assert_eq!(diagnostic.span.start.offset, 38);
+
+	let [(span, note)] = diagnostic.related.as_slice()
+	else
+	{
+		panic!("{:?}", diagnostic.related);
+	};
+	assert_eq!(span.start.offset, 20);
+	assert_eq!(note, "`Number` is inferred from this");
+
+	let expected = "--> <source>:1:39
+1 | func f(x) { let y = x * 2; return x + \"s\"; }
+                                          ^^^
+Mismatched types: expected `Number`, found `String`
+--> <source>:1:21
+1 | func f(x) { let y = x * 2; return x + \"s\"; }
+                        ^
+`Number` is inferred from this";
+	assert_eq!(error.to_string(), expected);
+}
+
+#[test]
+fn testing_inference_keeps_annotations()
+{
+	let valid = "func id<T>(x: T) -> T { return x; }
+func f(x: Number, y) { return id(x) + id(y); }
+let z = f(1, 2) * 2;"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let input = "func f<T>(x: T) -> Number { return x; }";
+	let error = input.to_string().compile().unwrap_err();
+	let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+	assert_eq!(
+		diagnostic.message,
+		"Mismatched types: expected `Number`, found `T`"
+	);
+}
+
+#[test]
+fn testing_function_bodies_are_analysed()
+{
+	let cases = [
+		(
+			"func f() -> Number { return zzz; }",
+			"Unknown identifier `zzz`",
+		),
+		("func f() { return g(1); }", "Unknown identifier `g`"),
+		(
+			"struct P { a: Number }\nfunc f(p: P) -> Number { return p.zzz; }",
+			"`P` has no field `zzz`",
+		),
+		(
+			"func f() { let b = true; match b { true => { } } return 1; }",
+			"Match is not exhaustive; add an arm for `false`",
+		),
+		(
+			"func f() { let (a, b, c) = (1, 2); }",
+			"Cannot destructure a `(Number, Number)` into 3 names",
+		),
+		(
+			"func f() { let y = [1, 2][5]; }",
+			"Index `5` is out of bounds for a dimension of length 2",
+		),
+		(
+			"func f() { let n = \"a\".nope(); }",
+			"`String` has no member `nope`",
+		),
+		(
+			"enum E { A }\nfunc f() { let e = E::Z; }",
+			"`E` has no variant `Z`",
+		),
+	];
+
+	for (input, message) in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+
+	let input = "func f() -> Number { return zzz; }";
+	let error = input.to_string().compile().unwrap_err();
+	let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+	assert_eq!(diagnostic.span.start.offset, 28);
+}
+
+#[test]
+fn testing_top_level_inference()
+{
+	let valid = "func id(x) { return x; }
+func size(x) { return x.len(); }
+let a = id(1) + id(2);
+let b = size(\"abc\") + size([1, 2]);"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let cases = [
+		(
+			"func id(x) { return x; }\necho id(1) + id(\"a\");",
+			"Mismatched types: expected `Number`, found `String`",
+		),
+		(
+			"func f(x) { return x.len(); }\necho f(3);",
+			"`Number` has no member `len`",
+		),
+		(
+			"func f(x) { return x.len(); }\nfunc g(y) { return f(y); }\necho g(3);",
+			"`Number` has no member `len`",
+		),
+		(
+			"let xs = [1, 2];\nlet y = xs[0] + \"a\";",
+			"Mismatched types: expected `Number`, found `String`",
+		),
+	];
+
+	for (input, message) in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+
+	// Operands are checked alike at the top level and in function bodies.
+	let outside = "echo 1 + \"a\";".to_string().compile().unwrap_err();
+	let inside = "func f() { echo 1 + \"a\"; }"
+		.to_string()
+		.compile()
+		.unwrap_err();
+	assert_eq!(
+		outside.downcast_ref::<Diagnostic>().unwrap().message,
+		inside.downcast_ref::<Diagnostic>().unwrap().message
+	);
+}
@@ -0,0 +1,203 @@
+use quark::compiler::{Compile, Diagnostic};
+use quark::language::semantics::r#type::Type;
+const HEADER: &str = "import numpy as np\n";
+
+#[test]
+fn testing_lambdas()
+{
+	let input = "let k = 3;
+let f = |x| x * k;
+let add = |a, b| a + b;
+let zero = || 0;
+echo f(2), add(1, 2), zero();"
+		.to_string();
+
+	let expected = "k = 3
+f = lambda x: x * k
+add = lambda a, b: a + b
+zero = lambda: 0
+print(f(2), add(1, 2), zero())";
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_functions_as_values()
+{
+	let input = "func minimise(cost, x0)
+{
+	return cost(x0);
+}
+func square(x) -> Number
+{
+	return x * x;
+}
+let f = square;
+let best = minimise(f, 0.5) + 1;
+echo minimise(|x| x - 1, 2);"
+		.to_string();
+
+	let expected = "def minimise(cost, x0):
+    return cost(x0)
+    
+def square(x):
+    return x * x
+    
+f = square
+best = minimise(f, 0.5) + 1
+print(minimise(lambda x: x - 1, 2))";
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_block_lambdas()
+{
+	let input = "func apply(g: |Number| -> Number, v: Number) -> Number
+{
+	return g(v);
+}
+let k = 2;
+let f = |x, y| {
+	let s = x + y;
+	return s * k;
+};
+while true
+{
+	echo f(1, 2);
+	let g = |x| apply(|y| { return x + y; }, 1);
+}"
+	.to_string();
+
+	let expected = "def apply(g, v):
+    return g(v)
+    
+k = 2
+def __quark_lambda_0(x, y):
+    s = x + y
+    return s * k
+    
+f = __quark_lambda_0
+while True:
+    print(f(1, 2))
+    def __quark_lambda_2(x):
+        def __quark_lambda_1(y):
+            return x + y
+            
+        return apply(__quark_lambda_1, 1)
+        
+    g = __quark_lambda_2";
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_function_types()
+{
+	let function = Type::Function {
+		parameters: vec![Type::Unit, Type::Number],
+		returns: Box::new(Type::Boolean),
+	};
+	assert_eq!(function.to_string(), "|Unit, Number| -> Bool");
+
+	// Function types are annotated as they are displayed.
+	let valid = "func apply(g: |Number| -> Number, v: Number) -> Number
+{
+	return g(v);
+}
+func twice(g: |Number| -> Number) -> |Number| -> Number
+{
+	return |x| g(g(x));
+}
+func test(p: || -> Bool) -> Bool { return p(); }
+let n = apply(twice(|x| x + 1), 2);"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let cases = [
+		(
+			"func d(a) { return a; }\nlet n = d(1, 2);",
+			"`d` takes 1 argument but 2 were given",
+		),
+		(
+			"let f = |x, y| x;\nlet n = f(1);",
+			"`f` takes 2 arguments but 1 was given",
+		),
+		("let n = 1;\nlet m = n(2);", "Invalid function call"),
+		("let f = |x| x;\nlet n = f(y);", "Unknown identifier `y`"),
+		(
+			"func apply(g: |Number| -> Number) { }\napply(5);",
+			"`apply` expects a `|Number| -> Number` argument, not `Number`",
+		),
+		(
+			"func apply(g: |Number| -> Number) { }\napply(|x, y| x);",
+			"`apply` expects a `|Number| -> Number` argument, not `|Unit, Unit| -> Unit`",
+		),
+		(
+			"let f = |a| a * 2;\necho f(\"s\");",
+			"`f` expects a `Number` argument, not `String`",
+		),
+		(
+			"func apply(g: |Number| -> Number, v: Number) -> Number { return g(v); }
+echo apply(|s| s + \"a\", 2);",
+			"`apply` expects a `|Number| -> Number` argument, not `|String| -> String`",
+		),
+		(
+			"func apply(g: |Number| -> Number) { }
+apply(|s| { return s.upper(); });",
+			"`Number` has no member `upper`",
+		),
+		(
+			"func f(x) { let g = |y| { return \"a\"; }; return g(x) * 2; }",
+			"Mismatched types: expected `Number`, found `String`",
+		),
+	];
+
+	for (input, message) in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+
+	assert!("let f = |x x;".to_string().compile().is_err());
+	assert!("let f = |x|".to_string().compile().is_err());
+	assert!("func f(g: |Number|) { }".to_string().compile().is_err());
+	assert!("func f(g: |Number -> Bool) { }"
+		.to_string()
+		.compile()
+		.is_err());
+}
+
+#[test]
+fn testing_closures()
+{
+	let valid = "let k = 2;
+func double(a) { return a * 2; }
+let f = |x| double(x) * k * pi;
+let g = |x| { var total = x; total = total + k; return total; };"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let cases = [
+		(
+			"var t = 1;\nlet f = |x| x * t;",
+			"Closures can only capture `let` bindings; `t` is declared with `var`",
+		),
+		(
+			"var t = 1;\nlet f = || { t = 2; };",
+			"Closures can only capture `let` bindings; `t` is declared with `var`",
+		),
+		("let f = |x| x * y;", "Unknown identifier `y`"),
+	];
+
+	for (input, message) in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+}
@@ -0,0 +1,87 @@
+use quark::compiler::lexer::Lex;
+use quark::language::lexicon::token::Kind::*;
+use quark::language::utils::SourceMap;
+
+#[test]
+fn testing_token_offsets()
+{
+	let mut source = SourceMap::new();
+	let file = source.add("test.q", "let π = 1.5i;\n  x >= \"a\nb\";".to_string());
+
+	let tokens = file.lex(&source).unwrap();
+
+	let kinds: Vec<_> = tokens.iter().map(|token| token.kind.clone()).collect();
+	let expected = [
+		Constant,
+		Identifier("π".to_string()),
+		Equal,
+		Number("1.5j".to_string()),
+		Semicolon,
+		Identifier("x".to_string()),
+		GreaterEqual,
+		String("a\nb".to_string()),
+		Semicolon,
+	];
+	assert_eq!(kinds, expected);
+
+	let offsets: Vec<_> = tokens
+		.iter()
+		.map(|token| (token.span.start.offset, token.span.end.offset))
+		.collect();
+	let expected = [
+		(0, 2),
+		(4, 4),
+		(7, 7),
+		(9, 12),
+		(13, 13),
+		(17, 17),
+		(19, 20),
+		(22, 26),
+		(27, 27),
+	];
+	assert_eq!(offsets, expected);
+}
+
+#[test]
+fn testing_lazy_locations()
+{
+	let mut source = SourceMap::new();
+	let file = source.add("test.q", "let π = 1;\n  x = 2;\n".to_string());
+
+	let tokens = file.lex(&source).unwrap();
+	let file = source.file(file);
+
+	let location = file.location(tokens[2].span.start.offset);
+	assert_eq!((location.line, location.column), (0, 6));
+
+	let location = file.location(tokens[5].span.start.offset);
+	assert_eq!((location.line, location.column), (1, 2));
+}
+
+#[test]
+fn testing_match_tokens()
+{
+	let mut source = SourceMap::new();
+	let file = source.add("test.q", "match x { 1..2 | 3..=4 => {} }".to_string());
+
+	let tokens = file.lex(&source).unwrap();
+
+	let kinds: Vec<_> = tokens.iter().map(|token| token.kind.clone()).collect();
+	let expected = [
+		Match,
+		Identifier("x".to_string()),
+		BraceLeft,
+		Number("1".to_string()),
+		DotDot,
+		Number("2".to_string()),
+		Bar,
+		Number("3".to_string()),
+		DotDotEqual,
+		Number("4".to_string()),
+		FatArrow,
+		BraceLeft,
+		BraceRight,
+		BraceRight,
+	];
+	assert_eq!(kinds, expected);
+}
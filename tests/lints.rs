@@ -0,0 +1,188 @@
+use std::path::Path;
+
+use quark::compiler::{Level, Levels, Lint, Project};
+
+/// Lints the entry file of a project at the default levels.
+fn lint(code: &str) -> Vec<(Lint, String)>
+{
+	let project = Project::load_source(Path::new("main.q"), code.into()).unwrap();
+
+	project
+		.lint(&Levels::default())
+		.unwrap()
+		.into_iter()
+		.map(|warning| (warning.lint, warning.message))
+		.collect()
+}
+
+#[test]
+fn testing_clean_programme()
+{
+	let code = "let n = 3;
+var total = 0;
+func double(a) -> Number { return a * 2; }
+func _helper() { }
+while total < n { total = total + double(1); }
+let (x, _) = (1, 2);
+let f = |y| y + x;
+echo f(total);";
+
+	assert_eq!(lint(code), []);
+}
+
+#[test]
+fn testing_unused_names()
+{
+	let code = "let a = 1;
+var b = 2;
+func f(x) { let y = 1; return 2; }
+func g() { return h(); }
+func h() { return 1; }
+echo g();";
+
+	assert_eq!(
+		lint(code),
+		[
+			(Lint::UnusedVariable, "`a` is never used".into()),
+			(Lint::UnusedVariable, "`b` is never used".into()),
+			(
+				Lint::NeverReassigned,
+				"`b` is never reassigned; declare it with `let` instead".into()
+			),
+			(Lint::UnusedFunction, "Function `f` is never used".into()),
+			(Lint::UnusedVariable, "`x` is never used".into()),
+			(Lint::UnusedVariable, "`y` is never used".into()),
+		]
+	);
+}
+
+#[test]
+fn testing_control_flow_lints()
+{
+	let code = "func f(n)
+{
+	while true
+	{
+		if n > 1 { break; }
+	}
+	while true
+	{
+		while n > 0 { break; }
+	}
+	return n;
+	echo n;
+	echo n;
+}
+if true { echo 1; }
+while (1 > 2) { echo 2; }
+echo f(1);";
+
+	assert_eq!(
+		lint(code),
+		[
+			(
+				Lint::InfiniteLoop,
+				"This `while true` loop never breaks".into()
+			),
+			(
+				Lint::UnreachableCode,
+				"Unreachable statement after `return`".into()
+			),
+			(Lint::ConstantCondition, "This condition is constant".into()),
+			(Lint::ConstantCondition, "This condition is constant".into()),
+		]
+	);
+}
+
+#[test]
+fn testing_unreachable_after_branches()
+{
+	let code = "func f(n)
+{
+	if n > 0 { return 1; } else { return 2; }
+	echo n;
+	echo n;
+}
+func g(n)
+{
+	while n > 0 { continue; echo n; }
+	match n { _ => { return n; } }
+	echo n;
+}
+echo f(1) + g(1);";
+
+	assert_eq!(
+		lint(code),
+		[
+			(
+				Lint::UnreachableCode,
+				"Unreachable statement, as every branch of the `if` before it leaves"
+					.into()
+			),
+			(
+				Lint::UnreachableCode,
+				"Unreachable statement after `continue`".into()
+			),
+			(
+				Lint::UnreachableCode,
+				"Unreachable statement, as every arm of the `match` before it leaves"
+					.into()
+			),
+		]
+	);
+}
+
+#[test]
+fn testing_shadowing()
+{
+	let code = "let x = 1;
+func f(x) { return x; }
+if x > 0 { let x = 2; echo x; }
+echo f(3);";
+
+	let shadowing =
+		(Lint::Shadowing, "`x` shadows an earlier declaration".into());
+	assert_eq!(lint(code), [shadowing.clone(), shadowing]);
+}
+
+#[test]
+fn testing_lint_levels()
+{
+	let project =
+		Project::load_source(Path::new("main.q"), "let a = 1;".into()).unwrap();
+
+	let mut levels = Levels::default();
+	levels.set(Lint::UnusedVariable, Level::Allow);
+	assert!(project.lint(&levels).unwrap().is_empty());
+
+	levels.set(Lint::UnusedVariable, Level::Deny);
+	let error = project.lint(&levels).unwrap_err().to_string();
+	assert!(
+		error.ends_with("`a` is never used [unused-variable]"),
+		"{error}"
+	);
+
+	levels.set(Lint::UnusedVariable, Level::Warn);
+	let warnings = project.lint(&levels).unwrap();
+	assert!(warnings[0].to_string().starts_with("--> main.q:1:1"));
+	assert_eq!(warnings[0].lint.code(), "unused-variable");
+}
+
+#[test]
+fn testing_exports_are_not_unused()
+{
+	let entry = Path::new("tests/modules/project/main.q");
+	let warnings = Project::load(entry)
+		.unwrap()
+		.lint(&Levels::default())
+		.unwrap();
+
+	let messages: Vec<_> = warnings.iter().map(|w| w.message.as_str()).collect();
+	assert_eq!(
+		messages,
+		[
+			"`private` is never used",
+			"`private` is never reassigned; declare it with `let` instead",
+		]
+	);
+}
@@ -0,0 +1,548 @@
+#![cfg(feature = "serde")]
+
+use std::io::Cursor;
+
+use quark::compiler::serve;
+use quark::language::lexicon::token;
+use serde_json::{json, Value};
+
+/// The URI of the document the scripted client edits.
+const URI: &str = "file:///tmp/quark%20lsp/main.q";
+
+/// The document the scripted client opens.
+const TEXT: &str = "/// The number of qubits.
+let n = 3;
+var total = 0;
+/// Doubles a number.
+func double(a) -> Number
+{
+	return a * 2;
+}
+total = double(n);
+echo \"{total}\";
+";
+
+/// Frames a message as the client would send it.
+fn frame(message: Value) -> String
+{
+	let body = message.to_string();
+	format!("Content-Length: {}\r\n\r\n{body}", body.len())
+}
+
+/// Runs the server on a script of messages, sent after opening the document,
+/// and collects everything it sends back.
+fn session(text: &str, script: &[Value]) -> Vec<Value>
+{
+	let open = json!({
+		"jsonrpc": "2.0",
+		"method": "textDocument/didOpen",
+		"params": {
+			"textDocument": {
+				"uri": URI,
+				"languageId": "quark",
+				"version": 1,
+				"text": text,
+			},
+		},
+	});
+
+	let input: String = [open]
+		.iter()
+		.chain(script)
+		.chain(&[json!({ "jsonrpc": "2.0", "method": "exit" })])
+		.cloned()
+		.map(frame)
+		.collect();
+
+	let mut output = Vec::new();
+	serve(Cursor::new(input), &mut output).unwrap();
+
+	let mut messages = Vec::new();
+	let mut rest = String::from_utf8(output).unwrap();
+
+	while let Some((header, body)) = rest.split_once("\r\n\r\n")
+	{
+		let length = header["Content-Length: ".len()..].parse().unwrap();
+		messages.push(serde_json::from_str(&body[..length]).unwrap());
+		rest = body[length..].to_string();
+	}
+
+	messages
+}
+
+/// Creates a request about a position in the document.
+fn request(id: u64, method: &str, line: u64, character: u64) -> Value
+{
+	json!({
+		"jsonrpc": "2.0",
+		"id": id,
+		"method": method,
+		"params": {
+			"textDocument": { "uri": URI },
+			"position": { "line": line, "character": character },
+			"context": { "includeDeclaration": true },
+		},
+	})
+}
+
+/// Finds the response to a request.
+fn response(messages: &[Value], id: u64) -> &Value
+{
+	messages.iter().find(|message| message["id"] == id).unwrap()
+}
+
+/// Creates a range on a single line.
+fn range(line: u64, start: u64, end: u64) -> Value
+{
+	json!({
+		"start": { "line": line, "character": start },
+		"end": { "line": line, "character": end },
+	})
+}
+
+#[test]
+fn testing_initialize_and_shutdown()
+{
+	let initialize = json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"method": "initialize",
+		"params": { "capabilities": {} },
+	});
+	let shutdown = json!({ "jsonrpc": "2.0", "id": 2, "method": "shutdown" });
+	let unknown = json!({ "jsonrpc": "2.0", "id": 3, "method": "foo/bar" });
+
+	let messages = session(TEXT, &[initialize, shutdown, unknown]);
+
+	let capabilities = &response(&messages, 1)["result"]["capabilities"];
+	assert_eq!(capabilities["textDocumentSync"], 1);
+	assert_eq!(capabilities["hoverProvider"], true);
+	assert_eq!(response(&messages, 2)["result"], Value::Null);
+	assert_eq!(response(&messages, 3)["error"]["code"], -32600);
+}
+
+#[test]
+fn testing_unknown_method()
+{
+	let unknown = json!({ "jsonrpc": "2.0", "id": 1, "method": "foo/bar" });
+	let messages = session(TEXT, &[unknown]);
+	assert_eq!(response(&messages, 1)["error"]["code"], -32601);
+}
+
+#[test]
+fn testing_diagnostics_on_change()
+{
+	let change = |text: &str| {
+		json!({
+			"jsonrpc": "2.0",
+			"method": "textDocument/didChange",
+			"params": {
+				"textDocument": { "uri": URI, "version": 2 },
+				"contentChanges": [{ "text": text }],
+			},
+		})
+	};
+
+	let messages =
+		session(TEXT, &[change("let x = 1;\nlet y = z;\n"), change("")]);
+
+	let published: Vec<_> = messages
+		.iter()
+		.filter(|message| message["method"] == "textDocument/publishDiagnostics")
+		.map(|message| &message["params"]["diagnostics"])
+		.collect();
+
+	assert_eq!(published.len(), 3);
+	assert_eq!(published[0], &json!([]));
+	assert_eq!(published[2], &json!([]));
+
+	let diagnostics = published[1].as_array().unwrap();
+	assert_eq!(diagnostics.len(), 1);
+	assert_eq!(diagnostics[0]["severity"], 1);
+	assert_eq!(diagnostics[0]["range"]["start"]["line"], 1);
+	assert!(!diagnostics[0]["message"].as_str().unwrap().contains('|'));
+}
+
+#[test]
+fn testing_syntax_diagnostics()
+{
+	let messages = session("let x = ;\n", &[]);
+	let diagnostics = &messages[0]["params"]["diagnostics"];
+	assert_eq!(diagnostics.as_array().unwrap().len(), 1);
+	assert_eq!(diagnostics[0]["range"]["start"]["line"], 0);
+}
+
+#[test]
+fn testing_warning_diagnostics()
+{
+	let messages = session("let x = 1;\n", &[]);
+	let diagnostics = &messages[0]["params"]["diagnostics"];
+	assert_eq!(diagnostics.as_array().unwrap().len(), 1);
+	assert_eq!(diagnostics[0]["severity"], 2);
+	assert_eq!(diagnostics[0]["code"], "unused-variable");
+	assert_eq!(diagnostics[0]["message"], "`x` is never used");
+}
+
+#[test]
+fn testing_hover()
+{
+	let messages = session(
+		TEXT,
+		&[
+			request(1, "textDocument/hover", 8, 16),
+			request(2, "textDocument/hover", 8, 10),
+			request(3, "textDocument/hover", 6, 8),
+			request(4, "textDocument/hover", 0, 4),
+		],
+	);
+
+	let hover = &response(&messages, 1)["result"];
+	assert_eq!(
+		hover["contents"]["value"],
+		"```quark\nlet n: Number\n```\n\nThe number of qubits."
+	);
+	assert_eq!(hover["range"], range(8, 15, 16));
+
+	let hover = &response(&messages, 2)["result"];
+	let value = hover["contents"]["value"].as_str().unwrap();
+	assert!(value.starts_with("```quark\nfunc double(a: Number) -> Number\n```"));
+	assert!(value.ends_with("Doubles a number."));
+
+	let hover = &response(&messages, 3)["result"];
+	assert_eq!(hover["contents"]["value"], "```quark\na: Number\n```");
+
+	assert_eq!(response(&messages, 4)["result"], Value::Null);
+}
+
+#[test]
+fn testing_lambda_parameters()
+{
+	let text = "let x = 2;\nlet f = |x| x * 2;\nlet y = x;";
+	let messages = session(
+		text,
+		&[
+			request(1, "textDocument/definition", 1, 12),
+			request(2, "textDocument/definition", 2, 8),
+		],
+	);
+
+	let location = &response(&messages, 1)["result"];
+	assert_eq!(location["range"], range(1, 9, 10));
+
+	let location = &response(&messages, 2)["result"];
+	assert_eq!(location["range"], range(0, 4, 5));
+}
+
+#[test]
+fn testing_definition()
+{
+	let messages = session(
+		TEXT,
+		&[
+			request(1, "textDocument/definition", 8, 16),
+			request(2, "textDocument/definition", 9, 8),
+			request(3, "textDocument/definition", 6, 8),
+		],
+	);
+
+	let definition = &response(&messages, 1)["result"];
+	assert_eq!(definition["uri"], URI);
+	assert_eq!(definition["range"], range(1, 4, 5));
+
+	let definition = &response(&messages, 2)["result"];
+	assert_eq!(definition["range"], range(2, 4, 9));
+
+	let definition = &response(&messages, 3)["result"];
+	assert_eq!(definition["range"], range(4, 12, 13));
+}
+
+#[test]
+fn testing_references()
+{
+	let mut exclude = request(2, "textDocument/references", 2, 5);
+	exclude["params"]["context"]["includeDeclaration"] = json!(false);
+
+	let messages = session(
+		TEXT,
+		&[request(1, "textDocument/references", 2, 5), exclude],
+	);
+
+	let ranges: Vec<_> = response(&messages, 1)["result"]
+		.as_array()
+		.unwrap()
+		.iter()
+		.map(|location| location["range"].clone())
+		.collect();
+	assert_eq!(ranges, [range(2, 4, 9), range(8, 0, 5), range(9, 7, 12)]);
+
+	let references = response(&messages, 2)["result"].as_array().unwrap();
+	assert_eq!(references.len(), 2);
+}
+
+#[test]
+fn testing_shadowing()
+{
+	let text = "let x = 1;\nfunc f(x) { return x; }\necho x;\n";
+	let messages = session(
+		text,
+		&[
+			request(1, "textDocument/definition", 1, 19),
+			request(2, "textDocument/definition", 2, 5),
+		],
+	);
+
+	assert_eq!(response(&messages, 1)["result"]["range"], range(1, 7, 8));
+	assert_eq!(response(&messages, 2)["result"]["range"], range(0, 4, 5));
+}
+
+#[test]
+fn testing_document_symbols()
+{
+	let symbols = json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"method": "textDocument/documentSymbol",
+		"params": { "textDocument": { "uri": URI } },
+	});
+
+	let messages = session(TEXT, &[symbols]);
+
+	let symbols: Vec<_> = response(&messages, 1)["result"]
+		.as_array()
+		.unwrap()
+		.iter()
+		.map(|symbol| {
+			(
+				symbol["name"].as_str().unwrap(),
+				symbol["kind"].as_u64().unwrap(),
+				symbol["containerName"].as_str(),
+			)
+		})
+		.collect();
+
+	assert_eq!(
+		symbols,
+		[
+			("n", 14, None),
+			("total", 13, None),
+			("double", 12, None),
+			("a", 13, Some("double")),
+		]
+	);
+}
+
+#[test]
+fn testing_completion()
+{
+	let messages = session(
+		TEXT,
+		&[
+			request(1, "textDocument/completion", 6, 1),
+			request(2, "textDocument/completion", 1, 0),
+		],
+	);
+
+	let labels = |id| -> Vec<String> {
+		response(&messages, id)["result"]
+			.as_array()
+			.unwrap()
+			.iter()
+			.map(|item| item["label"].as_str().unwrap().to_string())
+			.collect()
+	};
+
+	let inside = labels(1);
+	for label in ["let", "while", "xor", "pi", "n", "total", "double", "a"]
+	{
+		assert!(inside.contains(&label.to_string()), "{label}");
+	}
+
+	let before = labels(2);
+	assert!(before.contains(&"double".to_string()));
+	assert!(!before.contains(&"n".to_string()));
+	assert!(!before.contains(&"a".to_string()));
+}
+
+#[test]
+fn testing_member_completion()
+{
+	let text = "let s = \"ab\";\nlet n = s.";
+	let messages = session(text, &[request(1, "textDocument/completion", 1, 10)]);
+
+	let items = response(&messages, 1)["result"].as_array().unwrap().clone();
+	let item = |label: &str| {
+		items
+			.iter()
+			.find(|item| item["label"] == label)
+			.unwrap_or_else(|| panic!("{label}"))
+			.clone()
+	};
+
+	assert_eq!(item("len")["detail"], "len() -> Number");
+	assert_eq!(item("shape")["detail"], "shape: Unit");
+	assert_eq!(item("shape")["kind"], 10);
+	assert_eq!(item("replace")["kind"], 2);
+	assert_eq!(
+		items.iter().filter(|item| item["label"] == "len").count(),
+		1
+	);
+	assert!(items.iter().all(|item| item["label"] != "let"));
+}
+
+#[test]
+fn testing_records()
+{
+	let text = "struct Run { distance: Number }
+enum Outcome { Zero, Mixed(Number, Number) }
+let o = Outcome::Mixed(1, 2);
+match o {
+\tOutcome::Mixed(a, _) => { echo a; }
+\tOutcome::Zero => {}
+}
+";
+	let symbols = json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"method": "textDocument/documentSymbol",
+		"params": { "textDocument": { "uri": URI } },
+	});
+
+	let messages = session(
+		text,
+		&[
+			symbols,
+			request(2, "textDocument/definition", 4, 32),
+			request(3, "textDocument/hover", 4, 32),
+		],
+	);
+
+	let symbols: Vec<_> = response(&messages, 1)["result"]
+		.as_array()
+		.unwrap()
+		.iter()
+		.map(|symbol| {
+			(
+				symbol["name"].as_str().unwrap(),
+				symbol["kind"].as_u64().unwrap(),
+				symbol["containerName"].as_str(),
+			)
+		})
+		.collect();
+
+	assert_eq!(
+		symbols,
+		[
+			("Run", 23, None),
+			("distance", 8, Some("Run")),
+			("Outcome", 10, None),
+			("Zero", 22, Some("Outcome")),
+			("Mixed", 22, Some("Outcome")),
+			("o", 14, None),
+			("a", 13, None),
+		]
+	);
+
+	let location = &response(&messages, 2)["result"];
+	assert_eq!(location["range"], range(4, 16, 17));
+
+	let hover = &response(&messages, 3)["result"];
+	assert_eq!(hover["contents"]["value"], "```quark\na: Number\n```");
+
+	// Fields are only known while the document parses.
+	let text =
+		"struct Run { distance: Number }\nlet r = Run(1);\nlet d = r.distance;";
+	let messages = session(text, &[request(1, "textDocument/completion", 2, 10)]);
+
+	let items = response(&messages, 1)["result"].as_array().unwrap().clone();
+	let item = items
+		.iter()
+		.find(|item| item["label"] == "distance")
+		.unwrap();
+	assert_eq!(item["detail"], "distance: Number");
+	assert_eq!(item["kind"], 5);
+}
+
+#[test]
+fn testing_destructuring()
+{
+	let text = "let (ok, n, _) = (true, 2, \"c\");\nlet m = n;";
+	let messages = session(
+		text,
+		&[
+			request(1, "textDocument/hover", 1, 8),
+			request(2, "textDocument/definition", 1, 8),
+		],
+	);
+
+	let hover = &response(&messages, 1)["result"];
+	assert_eq!(hover["contents"]["value"], "```quark\nlet n: Number\n```");
+
+	let location = &response(&messages, 2)["result"];
+	assert_eq!(location["range"], range(0, 9, 10));
+}
+
+#[test]
+fn testing_generic_signatures()
+{
+	let text =
+		"func first<T>(xs: List<T>) -> T { return xs[0]; }\nlet x = first([1]);";
+	let messages = session(
+		text,
+		&[
+			request(1, "textDocument/hover", 1, 8),
+			request(2, "textDocument/hover", 0, 42),
+		],
+	);
+
+	let hover = &response(&messages, 1)["result"];
+	assert_eq!(
+		hover["contents"]["value"],
+		"```quark\nfunc first<T>(xs: List<T>) -> T\n```"
+	);
+
+	let hover = &response(&messages, 2)["result"];
+	assert_eq!(hover["contents"]["value"], "```quark\nxs: List<T>\n```");
+}
+
+#[test]
+fn testing_inferred_types()
+{
+	let text = "func double(x) { let y = x * 2; return y; }";
+	let messages = session(
+		text,
+		&[
+			request(1, "textDocument/hover", 0, 6),
+			request(2, "textDocument/hover", 0, 12),
+			request(3, "textDocument/hover", 0, 21),
+		],
+	);
+
+	let hover = &response(&messages, 1)["result"];
+	assert_eq!(
+		hover["contents"]["value"],
+		"```quark\nfunc double(x: Number) -> Number\n```"
+	);
+
+	let hover = &response(&messages, 2)["result"];
+	assert_eq!(hover["contents"]["value"], "```quark\nx: Number\n```");
+
+	let hover = &response(&messages, 3)["result"];
+	assert_eq!(hover["contents"]["value"], "```quark\nlet y: Number\n```");
+
+	let messages = session("func f(x) { let y = -x; return x + \"s\"; }", &[]);
+	let diagnostics = &messages[0]["params"]["diagnostics"];
+	let related = &diagnostics[0]["relatedInformation"][0];
+	assert_eq!(related["location"]["uri"], URI);
+	assert_eq!(related["location"]["range"]["start"]["character"], 21);
+	assert_eq!(related["message"], "`Number` is inferred from this");
+}
+
+#[test]
+fn testing_keywords_are_not_identifiers()
+{
+	for keyword in token::Kind::KEYWORDS
+	{
+		let kind = token::Kind::from_identifier(keyword);
+		assert!(!matches!(kind, token::Kind::Identifier(_)), "{keyword}");
+	}
+}
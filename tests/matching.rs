@@ -0,0 +1,236 @@
+use std::path::Path;
+
+use quark::compiler::{Compile, Diagnostic, Project, Version};
+const HEADER: &str = "import numpy as np\n";
+
+/// Compiles Quark code for a version of Python.
+fn compile_for(code: &str, version: &str) -> String
+{
+	let version: Version = version.parse().unwrap();
+	let project = Project::load_source(Path::new("main.q"), code.into()).unwrap();
+	let (_, python) = project.synthesise_for(version).pop().unwrap();
+	python
+}
+
+#[test]
+fn testing_match_statement()
+{
+	let input = "let outcome = 0b10;
+match outcome {
+    0b00 | 0b11 => { echo 0; }
+    0b01 if true => { echo 1; }
+    _ => { echo 2; }
+}"
+	.to_string();
+
+	let expected = "outcome = 0b10
+match outcome:
+    case 0b00 | 0b11:
+        print(0)
+        
+    case 0b01 if True:
+        print(1)
+        
+    case _:
+        print(2)";
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_match_ranges()
+{
+	let input = "let n = 4;
+match n + 1 {
+    0 => { echo 0; }
+    1..5 | 10..=20 => { echo 1; }
+    _ => { echo 2; }
+}";
+
+	let expected = "n = 4
+match (__quark_match := n + 1):
+    case 0:
+        print(0)
+        
+    case _ if (1 <= __quark_match and __quark_match < 5) or (10 <= __quark_match and __quark_match <= 20):
+        print(1)
+        
+    case _:
+        print(2)";
+
+	assert_eq!(
+		compile_for(input, "3.10"),
+		format!("{}{}", HEADER, expected)
+	);
+}
+
+#[test]
+fn testing_match_lowering()
+{
+	let input = "let n = 4;
+match n + 1 {
+    0 | 1 => { echo 0; }
+    2..=5 if n > 2 => { echo 1; }
+    _ => { echo 2; }
+    3 => { echo 3; }
+}
+match n {
+    _ => { echo 4; }
+}";
+
+	let expected = "n = 4
+__quark_match = n + 1
+if (__quark_match == 0) or (__quark_match == 1):
+    print(0)
+    
+elif (2 <= __quark_match and __quark_match <= 5) and (n > 2):
+    print(1)
+    
+else:
+    print(2)
+    
+print(4)";
+
+	assert_eq!(compile_for(input, "3.9"), format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_match_exhaustiveness()
+{
+	let valid = "match true { true => {} false => {} }".to_string();
+	assert!(valid.compile().is_ok());
+
+	let valid = "match 0b1 { 0b0 | 0b1 => {} }".to_string();
+	assert!(valid.compile().is_ok());
+
+	let valid = "match 0b01 { 0b00 if false => {} _ => {} }".to_string();
+	assert!(valid.compile().is_ok());
+
+	let missing = "match true { true => {} }".to_string();
+	let error = missing.compile().unwrap_err();
+	let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+	assert_eq!(
+		diagnostic.message,
+		"Match is not exhaustive; add an arm for `false`"
+	);
+
+	let missing = "match true { true if 1 > 0 => {} false => {} }".to_string();
+	assert!(missing.compile().is_err());
+
+	let missing = "match 0b00 { 0b00 => {} 0b01 => {} }".to_string();
+	let error = missing.compile().unwrap_err();
+	let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+	assert_eq!(
+		diagnostic.message,
+		"Match is not exhaustive; add arms for `0b10`, `0b11`"
+	);
+
+	let widths = "match 0b00 { 0b0 => {} 0b11 => {} }".to_string();
+	assert!(widths.compile().is_err());
+
+	let valid = "let b = 0b1;\nmatch (b) { 0b0 => {} 0b1 => {} }".to_string();
+	assert!(valid.compile().is_ok());
+
+	// Only a constant bound to a bit-string is known to have its width.
+	let cases = [
+		"let n = 5;\nmatch n { 0b0 => {} 0b1 => {} }",
+		"let n = 2.5;\nmatch n { 0b0 => {} 0b1 => {} }",
+		"var b = 0b1;\nmatch b { 0b0 => {} 0b1 => {} }",
+		"func f(b) { match b { 0b0 => {} 0b1 => {} } }",
+	];
+
+	for input in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(
+			diagnostic.message, "Match is not exhaustive; add an arm for `_`",
+			"{input}"
+		);
+	}
+
+	let widths = "let b = 0b10;\nmatch b { 0b0 => {} 0b1 => {} }".to_string();
+	let error = widths.compile().unwrap_err();
+	let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+	assert_eq!(
+		diagnostic.message,
+		"Bit-string patterns must have the width of the matched value, 2 bits"
+	);
+}
+
+#[test]
+fn testing_empty_arms()
+{
+	let input = "let b = true;\nmatch b { true => { } _ => { } }";
+
+	let expected = "b = True
+match b:
+    case True:
+        pass
+        
+    case _:
+        pass";
+
+	let output = input.to_string().compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+
+	let expected = "b = True
+if b == True:
+    pass
+    
+else:
+    pass";
+
+	assert_eq!(compile_for(input, "3.9"), format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_nested_match_exhaustiveness()
+{
+	let valid = "enum S { On(Number), Off }
+let s = S::On(1);
+while true { match s { S::On(n) => { echo n + 1; } S::Off => { } } break; }"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let cases = [
+		"let b = true;\nwhile true { match b { true => { } } break; }",
+		"let b = true;\nif b { match b { true => { } } }",
+		"let b = true;\nif b { } else { match b { true => { } } }",
+		"let b = true;\nmatch b { true => { match b { true => { } } } _ => { } }",
+		"func f(b: Bool) { if b { match b { true => { } } } }",
+	];
+
+	for input in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(
+			diagnostic.message, "Match is not exhaustive; add an arm for `false`",
+			"{input}"
+		);
+	}
+}
+
+#[test]
+fn testing_match_errors()
+{
+	let invalid = "match 1 { \"a\" => {} _ => {} }".to_string();
+	assert!(invalid.compile().is_err());
+
+	let invalid = "match 1 { 5..2 => {} _ => {} }".to_string();
+	assert!(invalid.compile().is_err());
+
+	let invalid = "match 1 { 0 if 1 => {} _ => {} }".to_string();
+	assert!(invalid.compile().is_err());
+
+	let missing = "match 1 { }".to_string();
+	assert!(missing.compile().is_err());
+
+	let missing = "match 1 { 0 {} }".to_string();
+	assert!(missing.compile().is_err());
+
+	let missing = "match 1 { 0.. => {} }".to_string();
+	assert!(missing.compile().is_err());
+}
@@ -0,0 +1,135 @@
+use quark::compiler::{Compile, Diagnostic};
+use quark::language::semantics::member::{self, AGGREGATE, NUMBER, STRING};
+use quark::language::semantics::r#type::Type;
+const HEADER: &str = "import numpy as np\n";
+
+#[test]
+fn testing_properties()
+{
+	let input = "let m = [1, 2 | 3, 4];
+let t = m.T;
+echo m.shape;
+echo m.T.H;
+let x = 2.5;
+echo x.real + x.imag;"
+		.to_string();
+
+	let expected = "m = np.array([[1, 2],[3, 4],])
+t = np.transpose(m)
+print(np.shape(m))
+print(np.conj(np.transpose(np.transpose(m))))
+x = 2.5
+print(np.real(x) + np.imag(x))";
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_method_calls()
+{
+	let input = "let s = \"hello\";
+let state = [0.6, 0.8];
+let n = s.len();
+let b = s.upper().starts_with(\"HE\");
+echo s.replace(\"l\", \"L\"), s.contains(\"ell\");
+echo state.probabilities()[0], (-2).abs();"
+		.to_string();
+
+	let expected = "s = 'hello'
+state = [0.6, 0.8]
+n = len(s)
+b = s.upper().startswith('HE')
+print(s.replace('l', 'L'), ('ell' in s))
+print((np.abs(state) ** 2)[0], np.abs((-2)))";
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_member_types()
+{
+	let valid = "let s = \"ab\";
+let n = s.len() + 1;
+let b = s.contains(\"a\") and true;
+let m = [1, 0 | 0, 1];
+let total = m.sum() * 2;"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let cases = [
+		(
+			"let s = \"a\";\nlet n = s.size();",
+			"`String` has no member `size`",
+		),
+		(
+			"let b = true;\nlet n = b.len();",
+			"`Bool` has no member `len`",
+		),
+		(
+			"let m = [1];\nlet n = m.upper();",
+			"`List<Number>` has no member `upper`",
+		),
+		(
+			"let s = \"a\";\nlet n = s.len;",
+			"`len` is a method; call it with `len()`",
+		),
+		(
+			"let m = [1];\nlet n = m.shape();",
+			"`shape` is a property; remove the parentheses",
+		),
+		(
+			"let s = \"a\";\nlet t = s.replace(\"a\");",
+			"`replace` takes 2 arguments but 1 was given",
+		),
+		(
+			"let s = \"a\";\nlet t = s.upper(1);",
+			"`upper` takes 0 arguments but 1 was given",
+		),
+		(
+			"let s = \"a\";\nlet b = s.contains(1);",
+			"`contains` expects a `String` argument, not `Number`",
+		),
+		(
+			"if true { let n = \"a\".nope(); }",
+			"`String` has no member `nope`",
+		),
+		(
+			"func f() { echo \"a\".nope(); }",
+			"`String` has no member `nope`",
+		),
+		(
+			"let s = \"a\";\nlet n = s.len() + \"b\";",
+			"Mismatched types: expected `Number`, found `String`",
+		),
+	];
+
+	for (input, message) in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+
+	assert!("let s = \"a\";\nlet n = s.;".to_string().compile().is_err());
+}
+
+#[test]
+fn testing_member_tables()
+{
+	assert_eq!(
+		member::lookup(&Type::String, "len").unwrap().r#type,
+		Type::Number
+	);
+	assert!(member::lookup(&Type::Number, "len").is_none());
+	assert!(member::members(&Type::Boolean).is_empty());
+
+	// Synthesis does not know the type of the value, so members that share a
+	// name must share their Python expression.
+	for member in STRING.iter().chain(&NUMBER).chain(&AGGREGATE)
+	{
+		let found = member::find(member.name).unwrap();
+		assert_eq!(found.python, member.python, "{}", member.name);
+	}
+}
@@ -0,0 +1,99 @@
+use std::path::{Path, PathBuf};
+
+use quark::compiler::{Compile, Diagnostic, Project};
+const HEADER: &str = "import numpy as np\n";
+
+#[test]
+fn testing_project_modules()
+{
+	let entry = Path::new("tests/modules/project/main.q");
+
+	let output = Project::load(entry).unwrap().synthesise();
+
+	let paths: Vec<_> = output.iter().map(|(path, _)| path.clone()).collect();
+	let expected = [
+		PathBuf::from("lib/constants.py"),
+		PathBuf::from("lib/gates.py"),
+		PathBuf::from("main.py"),
+	];
+	assert_eq!(paths, expected);
+
+	let gates = "import lib.constants as constants
+shots = constants.shots
+def bell(n):
+    return n + shots";
+	assert_eq!(output[1].1, format!("{}{}", HEADER, gates));
+
+	let main = "import lib.gates as gates
+import lib.constants as constants
+bell = gates.bell
+shots = constants.shots
+print(bell(shots))";
+	assert_eq!(output[2].1, format!("{}{}", HEADER, main));
+}
+
+#[test]
+fn testing_private_variable_is_not_exported()
+{
+	let entry = Path::new("tests/modules/project/private.q");
+
+	let error = Project::load(entry).err().unwrap().to_string();
+	let expected = "--> private.q:2:1
+2 | use constants::private;
+    ^^^^^^^^^^^^^^^^^^^^^^^
+`private` is not exported by module `constants`";
+	assert_eq!(error, expected);
+}
+
+#[test]
+fn testing_import_cycle()
+{
+	let entry = Path::new("tests/modules/cycle/a.q");
+
+	let error = Project::load(entry).err().unwrap().to_string();
+	assert!(error.contains("This import creates a cycle: a.q -> b.q -> c.q -> a.q"));
+}
+
+#[test]
+fn testing_unresolved_import()
+{
+	let input = "import \"missing.q\";".to_string();
+
+	let error = input.compile().err().unwrap();
+	let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+	assert_eq!(
+		diagnostic.message,
+		"Module `missing.q` could not be resolved"
+	);
+}
+
+#[test]
+fn testing_diagnostic_names_file()
+{
+	let entry = Path::new("tests/modules/broken/main.q");
+
+	let error = Project::load(entry).err().unwrap().to_string();
+	let expected = "--> gates.q:2:9
+2 | let y = $;
+            ^
+Unexpected symbol";
+	assert_eq!(error, expected);
+}
+
+#[test]
+fn testing_reserved_module_names()
+{
+	let entry = Path::new("tests/modules/reserved/main.q");
+
+	let error = Project::load(entry).err().unwrap().to_string();
+	let expected = "--> main.q:1:1
+1 | import \"np.q\";
+    ^^^^^^^^^^^^^
+Module file names must not be Python keywords or names the generated Python uses: `np`";
+	assert_eq!(error, expected);
+
+	let entry = Path::new("tests/modules/reserved/keyword.q");
+
+	let error = Project::load(entry).err().unwrap().to_string();
+	assert!(error.ends_with("uses: `class`"));
+}
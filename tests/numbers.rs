@@ -0,0 +1,89 @@
+use quark::compiler::Compile;
+const HEADER: &str = "import numpy as np\n";
+
+#[test]
+fn testing_radix_literals()
+{
+	let input = "let x = 0xFF_FF + 0o17 + 0B1010;".to_string();
+
+	let expected = "x = 0xFFFF + 0o17 + 0b1010".to_string();
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_scientific_literals()
+{
+	let input = "let x = 6.02e23 + 1e-9 + 1_000.5 + .5E+3i;".to_string();
+
+	let expected = "x = 6.02e23 + 1e-9 + 1000.5 + .5E+3j".to_string();
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_prelude_constants()
+{
+	let input = "let angle = tau / 4 + pi;\necho e;".to_string();
+
+	let expected = "pi = np.pi
+tau = 2 * np.pi
+e = np.e
+angle = tau / 4 + pi
+print(e)"
+		.to_string();
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_malformed_exponent()
+{
+	let input = "let x = 1e;".to_string();
+
+	let error = input.compile().err().unwrap().to_string();
+	assert!(error.ends_with("1 | let x = 1e;\n            ^^\nExpected the digits of an exponent after this"));
+}
+
+#[test]
+fn testing_malformed_radix()
+{
+	let input = "let x = 0x;".to_string();
+
+	let error = input.compile().err().unwrap().to_string();
+	assert!(error.ends_with("Expected digits after this prefix"));
+
+	let input = "let x = 0b102;".to_string();
+
+	let error = input.compile().err().unwrap().to_string();
+	assert!(error.ends_with("This digit is not valid in a number of this base"));
+}
+
+#[test]
+fn testing_misplaced_separator()
+{
+	let input = "let x = 1__000;".to_string();
+
+	let error = input.compile().err().unwrap().to_string();
+	assert!(error.ends_with("Digit separators `_` must sit between two digits"));
+}
+
+#[test]
+fn testing_leading_zeros()
+{
+	let input = "echo 00 + 0_0 + 09.5 + 09e1 + 09i;".to_string();
+
+	let expected = "print(00 + 00 + 09.5 + 09e1 + 09j)";
+	assert_eq!(input.compile().unwrap(), format!("{HEADER}{expected}"));
+
+	let input = "echo 09;".to_string();
+
+	let error = input.compile().err().unwrap().to_string();
+	assert!(error.ends_with(
+		"1 | echo 09;\n         ^^\nDecimal integers cannot start with `0`; use \
+		 `0o` for an octal number"
+	));
+}
@@ -0,0 +1,205 @@
+use std::path::Path;
+
+use quark::compiler::{Compile, Diagnostic, Project, Version};
+const HEADER: &str = "import numpy as np\n";
+
+/// A programme that declares and matches on records.
+const INPUT: &str = "struct Run { distance: Number, name: String }
+enum Shot { Miss, Hit(Number, Run) }
+let run = Run(5, \"a\");
+let shot = Shot::Hit(2, run);
+match shot {
+	Shot::Hit(n, r) if n > 1 => { echo r.name; }
+	Shot::Hit(_, r) => { echo r.distance; }
+	Shot::Miss => { echo 0; }
+}";
+
+/// The Python classes of the records in [`INPUT`].
+const CLASSES: &str = "from dataclasses import dataclass
+@dataclass
+class Run:
+    distance: complex
+    name: str
+    
+class Shot:
+    @dataclass
+    class Miss:
+        pass
+        
+    @dataclass
+    class Hit:
+        _0: complex
+        _1: 'Run'
+        
+    
+";
+
+/// Compiles Quark code for a version of Python.
+fn compile_for(code: &str, version: &str) -> String
+{
+	let version: Version = version.parse().unwrap();
+	let project = Project::load_source(Path::new("main.q"), code.into()).unwrap();
+	let (_, python) = project.synthesise_for(version).pop().unwrap();
+	python
+}
+
+/// Compiles Quark code that must fail, and gets its error message.
+fn error(code: &str) -> String
+{
+	let error = code.to_string().compile().unwrap_err();
+	error.downcast::<Diagnostic>().unwrap().message
+}
+
+#[test]
+fn testing_records()
+{
+	let expected = "run = Run(5, 'a')
+shot = Shot.Hit(2, run)
+match shot:
+    case Shot.Hit(n, r) if n > 1:
+        print(r.name)
+        
+    case Shot.Hit(_, r):
+        print(r.distance)
+        
+    case Shot.Miss():
+        print(0)";
+
+	assert_eq!(
+		compile_for(INPUT, "3.10"),
+		format!("{HEADER}{CLASSES}{expected}")
+	);
+}
+
+#[test]
+fn testing_record_lowering()
+{
+	let expected = "run = Run(5, 'a')
+shot = Shot.Hit(2, run)
+if isinstance(shot, Shot.Hit) and (shot._0 > 1):
+    n = shot._0
+    r = shot._1
+    print(r.name)
+    
+elif isinstance(shot, Shot.Hit):
+    r = shot._1
+    print(r.distance)
+    
+elif isinstance(shot, Shot.Miss):
+    print(0)";
+
+	assert_eq!(
+		compile_for(INPUT, "3.9"),
+		format!("{HEADER}{CLASSES}{expected}")
+	);
+}
+
+#[test]
+fn testing_record_types()
+{
+	let valid = "struct Node { next: Node, n: Number }".to_string();
+	assert!(valid.compile().is_ok());
+
+	let valid = "enum E { A(Number), B }
+func f(e) -> E { return e; }
+let n = f(E::A(1));"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	assert_eq!(
+		error("struct A { x: Number } let a = A(\"s\");"),
+		"`A` expects a `Number` argument, not `String`"
+	);
+	assert_eq!(
+		error("struct A { x: Number } let a = A(1); let y = a.y;"),
+		"`A` has no field `y`"
+	);
+	assert_eq!(
+		error("enum E { A(Number) } let e = E::A(1); let x = e.x;"),
+		"`E` is an enum; use `match` to read the payload of its variants"
+	);
+	assert_eq!(
+		error("enum E { A(Number), B } let e = E::A(1, 2);"),
+		"`E::A` takes 1 argument but 2 were given"
+	);
+	assert_eq!(
+		error("enum E { A, B } let e = E::C;"),
+		"`E` has no variant `C`"
+	);
+	assert_eq!(
+		error("enum E { A, B } if true { let e = E::C; }"),
+		"`E` has no variant `C`"
+	);
+	assert_eq!(
+		error("enum E { A, B } func f() -> E { return E::C; }"),
+		"`E` has no variant `C`"
+	);
+	assert_eq!(error("let e = F::C;"), "Unknown enum `F`");
+	assert_eq!(error("struct A { x: Foo }"), "Unknown type `Foo`");
+}
+
+#[test]
+fn testing_record_declaration_errors()
+{
+	assert_eq!(
+		error("struct A { x: Number, x: String }"),
+		"Field 'x' already declared in struct 'A'"
+	);
+	assert_eq!(
+		error("struct A { shape: Number }"),
+		"Field 'shape' would hide the built-in property `shape`"
+	);
+	assert_eq!(
+		error("enum E { A, A }"),
+		"Variant 'A' already declared in enum 'E'"
+	);
+	assert_eq!(
+		error("struct A {} enum A { B }"),
+		"Enum 'A' already declared"
+	);
+
+	for invalid in [
+		"struct { x: Number }",
+		"struct A { x Number }",
+		"struct A { x: }",
+		"struct A { x: Number y: Number }",
+		"struct A x: Number",
+		"enum E { A(Number }",
+		"enum E { A(), }",
+		"enum E { 1 }",
+	]
+	{
+		assert!(invalid.to_string().compile().is_err(), "{invalid}");
+	}
+}
+
+#[test]
+fn testing_record_matching()
+{
+	let valid = "enum E { A(Number), B }
+let e = E::B;
+match e { E::A(x) if x > 1 => {} E::A(_) | E::B => {} }"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	assert_eq!(
+		error("enum E { A(Number), B } match E::B { E::A(x) => {} }"),
+		"Match is not exhaustive; add an arm for `E::B`"
+	);
+	assert_eq!(
+		error("enum E { A(Number), B } match E::B { E::B => {} }"),
+		"Match is not exhaustive; add an arm for `E::A(_)`"
+	);
+	assert_eq!(
+		error("enum E { A(Number), B } match E::B { E::A(x, y) => {} _ => {} }"),
+		"`E::A` carries 1 value but the pattern binds 2"
+	);
+	assert_eq!(
+		error("enum E { A(Number), B(Number) } match E::B(1) { E::A(x) | E::B(x) => {} }"),
+		"Patterns joined with `|` cannot bind names"
+	);
+	assert_eq!(
+		error("enum E { A(Number), B } match E::B { E::A(x) if x => {} _ => {} }"),
+		"Invalid condition"
+	);
+}
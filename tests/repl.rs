@@ -0,0 +1,239 @@
+use std::cell::RefCell;
+use std::io::Cursor;
+use std::rc::Rc;
+
+use quark::compiler::repl::{Python, Reply, Runtime, Session};
+
+/// A runtime that records the code it is given instead of running it.
+#[derive(Clone, Default)]
+struct Recorder
+{
+	/// The requests made so far.
+	log: Rc<RefCell<Vec<String>>>,
+
+	/// The value every expression evaluates to.
+	value: Option<String>,
+}
+
+impl Runtime for Recorder
+{
+	fn execute(&mut self, code: &str) -> anyhow::Result<String>
+	{
+		self.log.borrow_mut().push(format!("exec {code}"));
+		Ok(String::new())
+	}
+
+	fn evaluate(&mut self, code: &str)
+		-> anyhow::Result<(String, Option<String>)>
+	{
+		self.log.borrow_mut().push(format!("eval {code}"));
+		Ok((String::new(), self.value.clone()))
+	}
+
+	fn reset(&mut self) -> anyhow::Result<()>
+	{
+		self.log.borrow_mut().push("reset".to_string());
+		Ok(())
+	}
+}
+
+/// Starts a session on a recording runtime whose expressions evaluate to
+/// `True`.
+fn session() -> (Session<Recorder>, Rc<RefCell<Vec<String>>>)
+{
+	let recorder = Recorder {
+		value: Some("True".to_string()),
+		..Recorder::default()
+	};
+	let log = recorder.log.clone();
+	(Session::new(recorder).unwrap(), log)
+}
+
+/// Gets the output of a complete input.
+fn output(reply: anyhow::Result<Reply>) -> String
+{
+	match reply.unwrap()
+	{
+		Reply::Output(text) => text,
+		reply => panic!("Expected output, got {reply:?}"),
+	}
+}
+
+#[test]
+fn testing_prelude_is_defined()
+{
+	let (_, log) = session();
+	let log = log.borrow();
+	assert_eq!(log.len(), 1);
+	assert!(log[0].starts_with("exec import numpy as np\npi = np.pi\n"));
+}
+
+#[test]
+fn testing_persistent_symbols()
+{
+	let (mut session, log) = session();
+
+	assert_eq!(output(session.line("let x = 1")), "");
+	assert_eq!(log.borrow().last().unwrap(), "exec x = 1");
+
+	assert_eq!(output(session.line(":type x + 2")), "Number");
+	assert_eq!(output(session.line("not false")), "true: Bool");
+	assert_eq!(log.borrow().last().unwrap(), "eval not False");
+
+	assert!(session.line("y").is_err());
+}
+
+#[test]
+fn testing_failed_inputs_declare_nothing()
+{
+	let (mut session, log) = session();
+
+	assert!(session.line("let x = 1; let y = z;").is_err());
+	assert_eq!(log.borrow().len(), 1);
+	assert!(session.line(":type x").is_err());
+}
+
+#[test]
+fn testing_continuation()
+{
+	let (mut session, log) = session();
+
+	assert_eq!(session.line("func double(a) {").unwrap(), Reply::Incomplete);
+	assert_eq!(session.prompt(), "...... ");
+	assert_eq!(session.line("\treturn a * 2;").unwrap(), Reply::Incomplete);
+	assert_eq!(output(session.line("}")), "");
+	assert_eq!(session.prompt(), "quark> ");
+	assert_eq!(
+		log.borrow().last().unwrap(),
+		"exec def double(a):\n    return a * 2"
+	);
+
+	assert_eq!(session.line("echo (1 +").unwrap(), Reply::Incomplete);
+	assert_eq!(output(session.line("2);")), "");
+	assert_eq!(log.borrow().last().unwrap(), "exec print((1 + 2))");
+
+	// An empty line gives up on an input that never balances.
+	assert_eq!(session.line("echo (1").unwrap(), Reply::Incomplete);
+	assert!(session.line("").is_err());
+	assert_eq!(session.prompt(), "quark> ");
+}
+
+#[test]
+fn testing_records()
+{
+	let (mut session, log) = session();
+
+	assert!(log.borrow()[0].contains("from dataclasses import dataclass"));
+
+	assert_eq!(session.line("struct Point {").unwrap(), Reply::Incomplete);
+	assert_eq!(output(session.line("x: Number }")), "");
+	assert_eq!(
+		log.borrow().last().unwrap(),
+		"exec @dataclass\nclass Point:\n    x: complex"
+	);
+
+	assert_eq!(output(session.line(":type Point(1).x")), "Number");
+	assert!(session.line(":type Point(1).y").is_err());
+}
+
+#[test]
+fn testing_meta_commands()
+{
+	let (mut session, log) = session();
+
+	assert_eq!(output(session.line(":type \"a\" + \"b\"")), "String");
+	assert_eq!(output(session.line(":py 1 + 2")), "1 + 2");
+	assert_eq!(
+		output(session.line(":py if true { echo 1; }")),
+		"if True:\n    print(1)"
+	);
+	assert_eq!(
+		output(session.line(":py || { return 1; }")),
+		"def __quark_lambda_0():\n    return 1\n__quark_lambda_0"
+	);
+	assert_eq!(output(session.line(":type |x| x")), "|Unit| -> Unit");
+	assert_eq!(
+		output(session.line(":type |x| x * 2")),
+		"|Number| -> Number"
+	);
+
+	let ast = output(session.line(":ast 1 + 2"));
+	assert!(ast.starts_with("Expression {"));
+	assert!(ast.contains("Infix"));
+
+	assert!(session.line(":type let x = 1;").is_err());
+	assert!(session.line(":frobnicate").is_err());
+	assert_eq!(session.line(":quit").unwrap(), Reply::Quit);
+
+	// None of the meta-commands run any code.
+	assert_eq!(log.borrow().len(), 1);
+}
+
+#[test]
+fn testing_values_of_unknown_type()
+{
+	let recorder = Recorder {
+		value: Some("[[1 2]\n [3 4]]".to_string()),
+		..Recorder::default()
+	};
+	let mut session = Session::new(recorder).unwrap();
+
+	assert_eq!(
+		output(session.line("[1, 2 | 3, 4]")),
+		"[[1 2]\n [3 4]]: unknown"
+	);
+	assert_eq!(output(session.line(":type [1, 2 | 3, 4].T")), "unknown");
+
+	// An expression evaluating to `None` only shows what it printed.
+	let mut session = Session::new(Recorder::default()).unwrap();
+	assert_eq!(output(session.line("[1, 2 | 3, 4]")), "");
+}
+
+#[test]
+fn testing_reset()
+{
+	let (mut session, log) = session();
+
+	session.line("var x = 1;").unwrap();
+	assert_eq!(output(session.line(":reset")), "");
+	assert!(session.line(":type x").is_err());
+
+	let log = log.borrow();
+	assert_eq!(log[2], "reset");
+	assert!(log[3].starts_with("exec import numpy as np"));
+}
+
+#[test]
+fn testing_run_loop()
+{
+	let (mut session, _) = session();
+
+	let input = Cursor::new("let x = 1;\n:type x\n:quit\n:type x\n");
+	let mut output = Vec::new();
+	session.run(input, &mut output).unwrap();
+
+	assert_eq!(
+		String::from_utf8(output).unwrap(),
+		"quark> quark> Number\nquark> "
+	);
+}
+
+#[test]
+fn testing_python_runtime()
+{
+	let mut python = Python::new("python3").unwrap();
+
+	assert_eq!(python.execute("x = 20\nprint('hi')").unwrap(), "hi\n");
+	assert_eq!(
+		python.evaluate("x + 1").unwrap(),
+		("".into(), Some("21".into()))
+	);
+	assert_eq!(python.evaluate("print(x)").unwrap(), ("20\n".into(), None));
+
+	let error = python.execute("x / 0").unwrap_err().to_string();
+	assert!(error.contains("ZeroDivisionError"));
+	assert_eq!(python.evaluate("x").unwrap().1.unwrap(), "20");
+
+	python.reset().unwrap();
+	assert!(python.evaluate("x").is_err());
+}
@@ -0,0 +1,95 @@
+use quark::compiler::Compile;
+const HEADER: &str = "import numpy as np\n";
+
+#[test]
+fn testing_escape_sequences()
+{
+	let input = r#"echo "it's \"quoted\"\t\\ \u{1F600}";"#.to_string();
+
+	let expected = r#"print('it\'s "quoted"\t\\ 😀')"#.to_string();
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_string_with_delimiters()
+{
+	let input = r#"echo "a; b \{ c \}";"#.to_string();
+
+	let expected = "print('a; b { c }')".to_string();
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_string_interpolation()
+{
+	let input = r#"
+let x = 3;
+echo "x = {x + 1}; \{x\} is {"literal"}";
+"#
+	.to_string();
+
+	let expected = r#"x = 3
+print(f"x = {x + 1}; {{x}} is {'literal'}")"#
+		.to_string();
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_interpolation_with_escapes()
+{
+	let input = r#"
+let s = "x";
+echo "{s + "\n"} \{ {1}";
+echo "{"{s}"}";
+"#
+	.to_string();
+
+	let expected = r#"s = 'x'
+print("{} {{ {}".format(s + '\n', 1))
+print("{}".format(f"{s}"))"#
+		.to_string();
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_interpolation_syntax_error()
+{
+	let input = r#"echo "{1 +}";"#.to_string();
+
+	let error = input.compile().err().unwrap().to_string();
+	assert!(error.starts_with("--> <source>:1:10\n1 | echo \"{1 +}\";\n"));
+}
+
+#[test]
+fn testing_unknown_escape_sequence()
+{
+	let input = r#"echo "\q";"#.to_string();
+
+	let error = input.compile().err().unwrap().to_string();
+	assert!(error.contains("1 | echo \"\\q\";\n          ^^\nUnknown escape sequence"));
+}
+
+#[test]
+fn testing_invalid_unicode_escape()
+{
+	let input = r#"echo "\u{110000}";"#.to_string();
+
+	let error = input.compile().err().unwrap().to_string();
+	assert!(error.ends_with("Invalid unicode escape. Try `\\u{...}` with 1 to 6 hexadecimal digits"));
+}
+
+#[test]
+fn testing_interpolation_is_type_checked()
+{
+	let input = r#"let s = "{1 + true}";"#.to_string();
+
+	assert!(input.compile().is_err());
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "serde")]
+
+use quark::compiler::{Syntax, SCHEMA_VERSION};
+use serde_json::Value;
+
+#[test]
+fn testing_syntax_json()
+{
+	let syntax = Syntax::new("let x = \"a{x}\";").unwrap();
+	let json: Value = serde_json::from_str(&syntax.to_json().unwrap()).unwrap();
+
+	assert_eq!(json["version"], SCHEMA_VERSION);
+	assert_eq!(json["text"], "let x = \"a{x}\";");
+
+	assert_eq!(json["tokens"][0]["kind"], "Constant");
+	assert_eq!(json["tokens"][1]["kind"]["Identifier"], "x");
+	assert_eq!(json["tokens"][1]["span"]["start"]["offset"], 4);
+
+	let segments = &json["tokens"][3]["kind"]["Interpolation"];
+	assert_eq!(segments[0]["Text"], "a");
+	assert_eq!(segments[1]["Code"][0]["kind"]["Identifier"], "x");
+
+	let statement = &json["programme"]["statements"][0];
+	assert_eq!(statement["span"]["end"]["offset"], 14);
+	assert!(statement["kind"]["Declaration"].is_object());
+}
+
+#[test]
+fn testing_syntax_round_trip()
+{
+	let code = "func f(a) -> Number { return a * 2; }
+if f(1) > 0 { echo [1, 2 | 3, 4]m; } else { echo \"{f(2)}\"; }";
+	let syntax = Syntax::new(code).unwrap();
+
+	let json = syntax.to_json().unwrap();
+	assert_eq!(Syntax::from_json(&json).unwrap(), syntax);
+}
+
+#[test]
+fn testing_syntax_errors()
+{
+	assert!(Syntax::new("let x = ;").is_err());
+	assert!(Syntax::from_json("{}").is_err());
+
+	let mut json: Value =
+		serde_json::from_str(&Syntax::new("echo 1;").unwrap().to_json().unwrap())
+			.unwrap();
+	json["version"] = (SCHEMA_VERSION + 1).into();
+	assert!(Syntax::from_json(&json.to_string()).is_err());
+}
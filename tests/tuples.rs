@@ -0,0 +1,130 @@
+use quark::compiler::{Compile, Diagnostic};
+use quark::language::semantics::r#type::Type;
+const HEADER: &str = "import numpy as np\n";
+
+#[test]
+fn testing_tuples()
+{
+	let input = "let pair = (1, \"a\");
+let single = (2,);
+let grouped = (3);
+let nested = ((1, 2), pair[1]);
+echo pair[0], single, grouped, nested;"
+		.to_string();
+
+	let expected = "pair = (1, 'a')
+single = (2,)
+grouped = (3)
+nested = ((1, 2), pair[1])
+print(pair[0], single, grouped, nested)";
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_multiple_return_values()
+{
+	let input = "func measure(state) -> (Bool, Number)
+{
+	return (true, state);
+}
+let (outcome, after) = measure(1);
+var (n, _) = (2, 3);
+let (only,) = (4,);
+let sum = after + n + only;"
+		.to_string();
+
+	let expected = "def measure(state):
+    return (True, state)
+    
+outcome, after = measure(1)
+n, _ = (2, 3)
+only, = (4,)
+sum = after + n + only";
+
+	let output = input.compile().unwrap();
+	assert_eq!(output, format!("{}{}", HEADER, expected));
+}
+
+#[test]
+fn testing_tuple_types()
+{
+	let pair = Type::Tuple(vec![Type::Boolean, Type::Named("State".into())]);
+	assert_eq!(pair.to_string(), "(Bool, State)");
+	assert_eq!(Type::Tuple(vec![Type::Number]).to_string(), "(Number,)");
+
+	let unknown = Type::Tuple(vec![Type::Unit, Type::Number]);
+	assert!(unknown.accepts(&Type::Tuple(vec![Type::String, Type::Number])));
+	assert!(!unknown.accepts(&Type::Tuple(vec![Type::Number])));
+	assert!(!pair.accepts(&Type::Number));
+
+	let valid = "struct P { at: (Number, Number) }
+func f(x) { return x; }
+let p = P((f(1), 2));
+let z = p.at[1] + p.at[-2];"
+		.to_string();
+	assert!(valid.compile().is_ok());
+
+	let cases = [
+		(
+			"let (a, b) = (1, 2, 3);",
+			"Cannot destructure a `(Number, Number, Number)` into 2 names",
+		),
+		(
+			"let p = (1, 2);\nif true { let (a, b, c) = p; }",
+			"Cannot destructure a `(Number, Number)` into 3 names",
+		),
+		(
+			"let p = (1, 2);\nfunc f() { let (a, b, c) = p; }",
+			"Cannot destructure a `(Number, Number)` into 3 names",
+		),
+		("let (a,) = 1;", "Cannot destructure a `Number` into 1 name"),
+		("let (a, a) = (1, 2);", "Constant 'a' already declared"),
+		(
+			"let t = (1, 2);\nlet x = t[2];",
+			"A tuple of 2 values is indexed by an integer from -2 to 1",
+		),
+		(
+			"let t = (1, 2);\nlet i = 0;\nlet x = t[i];",
+			"A tuple of 2 values is indexed by an integer from -2 to 1",
+		),
+		(
+			"func f() -> (Number, String) { return (1, \"a\"); }
+let (n, s) = f();
+let m = -s;",
+			"Mismatched types: expected `Number`, found `String`",
+		),
+		(
+			"struct P { at: (Number, Number) }\nlet p = P((1, \"a\"));",
+			"`P` expects a `(Number, Number)` argument, not `(Number, String)`",
+		),
+		("struct P { at: (Number, Foo) }", "Unknown type `Foo`"),
+	];
+
+	for (input, message) in cases
+	{
+		let error = input.to_string().compile().unwrap_err();
+		let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+		assert_eq!(diagnostic.message, message, "{input}");
+	}
+}
+
+#[test]
+fn testing_tuple_syntax_errors()
+{
+	for invalid in [
+		"let t = (1, 2;",
+		"let t = (1,,);",
+		"let t = ();",
+		"let () = (1,);",
+		"let ((a, b), c) = ((1, 2), 3);",
+		"let (a, b) (1, 2);",
+		"func f() -> (Number, { }",
+		"func f() -> ( { }",
+		"struct P { at: (Number Number) }",
+	]
+	{
+		assert!(invalid.to_string().compile().is_err(), "{invalid}");
+	}
+}
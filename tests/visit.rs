@@ -0,0 +1,187 @@
+use std::path::Path;
+
+use quark::compiler::Project;
+use quark::language::grammar::expression::{self, Expression};
+use quark::language::grammar::{visit, visit_mut};
+use quark::language::grammar::{
+	Fold, FunctionDclr, Programme, Visit, VisitMut,
+};
+use quark::language::lexicon::{token, Token};
+
+fn parse(code: &str) -> Programme
+{
+	let project = Project::load_source(Path::new("main.q"), code.into()).unwrap();
+	let (_, programme) = project.modules().last().unwrap();
+	programme.clone()
+}
+
+/// Collects the names of identifiers, and of the functions they appear in.
+#[derive(Default)]
+struct Names<'ast>
+{
+	identifiers: Vec<&'ast str>,
+	functions: Vec<&'ast str>,
+}
+
+impl<'ast> Visit<'ast> for Names<'ast>
+{
+	fn visit_function(&mut self, node: &'ast FunctionDclr)
+	{
+		self.functions.push(&node.name);
+		visit::walk_function(self, node);
+	}
+
+	fn visit_expression(&mut self, node: &'ast Expression)
+	{
+		if let expression::Kind::Identifier(Token {
+			kind: token::Kind::Identifier(name),
+			..
+		}) = &node.kind
+		{
+			self.identifiers.push(name);
+		}
+
+		visit::walk_expression(self, node);
+	}
+}
+
+/// Renames every use of one identifier.
+struct Rename(&'static str, &'static str);
+
+impl VisitMut for Rename
+{
+	fn visit_token_mut(&mut self, node: &mut Token)
+	{
+		if node.kind == token::Kind::Identifier(self.0.into())
+		{
+			node.kind = token::Kind::Identifier(self.1.into());
+		}
+	}
+}
+
+/// Folds the sums of number literals into a single literal.
+struct Sums;
+
+impl Fold for Sums
+{
+	fn fold_expression(&mut self, node: Expression) -> Expression
+	{
+		let node = quark::language::grammar::fold::walk_expression(self, node);
+
+		let expression::Kind::Infix {
+			left,
+			operator,
+			right,
+		} = &node.kind
+		else
+		{
+			return node;
+		};
+
+		match (&left.kind, &operator.kind, &right.kind)
+		{
+			(
+				expression::Kind::Literal(Token {
+					kind: token::Kind::Number(left),
+					..
+				}),
+				token::Kind::Plus,
+				expression::Kind::Literal(Token {
+					kind: token::Kind::Number(right),
+					..
+				}),
+			) =>
+			{
+				let sum = left.parse::<i64>().unwrap() + right.parse::<i64>().unwrap();
+				let token = Token {
+					span: node.span,
+					kind: token::Kind::Number(sum.to_string()),
+				};
+
+				Expression {
+					span: node.span,
+					kind: expression::Kind::Literal(token),
+				}
+			}
+
+			_ => node,
+		}
+	}
+}
+
+#[test]
+fn testing_visit()
+{
+	let programme = parse(
+		"let a = 1;
+func f(b) -> String { if b > a { return \"{b}\"; } return \"{a}\"; }
+echo [a, f(a) | 1, 2]m;",
+	);
+
+	let mut names = Names::default();
+	names.visit_programme(&programme);
+
+	assert_eq!(names.functions, ["f"]);
+	assert_eq!(names.identifiers, ["b", "a", "b", "a", "a", "a"]);
+}
+
+#[test]
+fn testing_visit_mut()
+{
+	let mut programme = parse("let a = 1;\nvar b = a * (a + 2);\nb = -a;");
+
+	Rename("a", "c").visit_programme_mut(&mut programme);
+
+	let mut names = Names::default();
+	names.visit_programme(&programme);
+	assert_eq!(names.identifiers, ["c", "c", "c"]);
+
+	// The default walk reaches every statement kind.
+	struct Count(usize);
+
+	impl VisitMut for Count
+	{
+		fn visit_expression_mut(&mut self, node: &mut Expression)
+		{
+			self.0 += 1;
+			visit_mut::walk_expression(self, node);
+		}
+	}
+
+	let mut count = Count(0);
+	count.visit_programme_mut(&mut programme);
+	assert_eq!(count.0, 9);
+}
+
+#[test]
+fn testing_fold()
+{
+	let programme = parse("echo 1 + 2 + 3, 4;\nwhile false { echo (5 + 6); }");
+	let folded = Sums.fold_programme(programme.clone());
+
+	assert_ne!(folded, programme);
+	assert_eq!(folded.span, programme.span);
+
+	let mut literals = Literals(Vec::new());
+	literals.visit_programme(&folded);
+	assert_eq!(literals.0, ["6", "4", "11"]);
+}
+
+/// Collects the text of number literals.
+struct Literals(Vec<String>);
+
+impl Visit<'_> for Literals
+{
+	fn visit_expression(&mut self, node: &Expression)
+	{
+		if let expression::Kind::Literal(Token {
+			kind: token::Kind::Number(number),
+			..
+		}) = &node.kind
+		{
+			self.0.push(number.clone());
+		}
+
+		visit::walk_expression(self, node);
+	}
+}